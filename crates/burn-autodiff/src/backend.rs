@@ -11,6 +11,26 @@ use core::marker::PhantomData;
 ///
 /// This works as a backend decorator, extending the functionality of any backend with
 /// backpropagation.
+///
+/// # Higher order derivatives
+///
+/// The decorator can be nested, e.g. `Autodiff<Autodiff<B>>`, to differentiate through a
+/// gradient. The backward pass of the outer decorator is computed with the operations of the inner
+/// one, so the gradients are tensors of the inner decorator which carry their own graph, and can
+/// be differentiated with a second backward pass. The inputs should require gradients with both
+/// decorators:
+///
+/// ```rust, ignore
+/// let x_inner = Tensor::<Autodiff<B>, 2>::from_data(data, &device).require_grad();
+/// let x = Tensor::<Autodiff<Autodiff<B>>, 2>::from_inner(x_inner.clone()).require_grad();
+///
+/// let grads = f(x.clone()).backward();
+/// let grad = x.grad(&grads).unwrap();
+///
+/// // Gradient penalty: the gradient of the squared norm of the gradient.
+/// let grads = grad.powf_scalar(2.0).sum().backward();
+/// let grad_penalty = x_inner.grad(&grads).unwrap();
+/// ```
 #[derive(Clone, Copy, Debug, Default)]
 pub struct Autodiff<B, C = NoCheckpointing> {
     _b: PhantomData<B>,
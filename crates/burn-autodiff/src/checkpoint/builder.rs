@@ -109,6 +109,16 @@ impl CheckpointerBuilder {
         self.explicit_actions.len() + self.backup_actions.len()
     }
 
+    #[cfg(feature = "export_tests")]
+    /// Returns the number of actions keeping an already computed output
+    pub(crate) fn num_computed(&self) -> usize {
+        self.explicit_actions
+            .iter()
+            .chain(self.backup_actions.iter())
+            .filter(|action| matches!(action, CheckpointingAction::Computed { .. }))
+            .count()
+    }
+
     pub(crate) fn build(self, graph: &NodeSteps) -> Checkpointer {
         let node_tree = self.make_tree(graph);
         let mut backward_states_map = HashMap::new();
@@ -2,6 +2,10 @@
 pub mod base;
 pub(crate) mod builder;
 pub(crate) mod retro_forward;
+/// Checkpoint scope module
+pub mod scope;
 pub(crate) mod state;
 /// CheckpointStrategy module
 pub mod strategy;
+
+pub use scope::checkpoint;
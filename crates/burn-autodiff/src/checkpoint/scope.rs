@@ -0,0 +1,55 @@
+use std::cell::Cell;
+
+std::thread_local! {
+    static CHECKPOINT_SCOPES: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Runs a sub-computation with gradient checkpointing, trading compute for memory.
+///
+/// Within the sub-computation, the operations are checkpointed as they are marked, as with the
+/// [balanced checkpointing](super::strategy::BalancedCheckpointing) strategy, whatever the
+/// strategy of the backend: the memory bound operations don't keep their outputs for the backward
+/// pass, which recomputes them from their parents instead. The compute bound operations, such as
+/// matrix multiplications, still keep their outputs since they would be expensive to recompute.
+///
+/// The gradients are the same as without checkpointing.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let output = checkpoint(|| module.forward(input));
+/// ```
+pub fn checkpoint<O, F: FnOnce() -> O>(func: F) -> O {
+    let _scope = CheckpointScope::enter();
+    func()
+}
+
+/// Whether the operations are currently registered within a [checkpoint] call.
+pub(crate) fn is_checkpointing() -> bool {
+    CHECKPOINT_SCOPES.with(|scopes| scopes.get() > 0)
+}
+
+/// Exits the scope when dropped, even if the sub-computation panics.
+struct CheckpointScope;
+
+impl CheckpointScope {
+    fn enter() -> Self {
+        CHECKPOINT_SCOPES.with(|scopes| scopes.set(scopes.get() + 1));
+        Self
+    }
+}
+
+impl Drop for CheckpointScope {
+    fn drop(&mut self) {
+        CHECKPOINT_SCOPES.with(|scopes| scopes.set(scopes.get() - 1));
+    }
+}
+
+#[cfg(feature = "export_tests")]
+/// Returns the number of tensors kept for the backward pass by the graph of the given tensor.
+/// Useful for testing
+pub fn num_checkpointed_tensors<B: burn_tensor::backend::Backend, const D: usize>(
+    tensor: &crate::tensor::AutodiffTensor<B, D>,
+) -> usize {
+    tensor.graph.num_checkpointed_tensors()
+}
@@ -145,6 +145,11 @@ impl Graph {
         builder.build(&self.steps.lock())
     }
 
+    #[cfg(feature = "export_tests")]
+    pub(crate) fn num_checkpointed_tensors(&self) -> usize {
+        self.checkpointing_actions.lock().num_computed()
+    }
+
     pub(crate) fn extend_checkpointer_builder(&self, checkpointing_actions: CheckpointerBuilder) {
         self.checkpointing_actions
             .lock()
@@ -4,7 +4,8 @@ use crate::{
         base::Checkpointer,
         builder::{ActionType, CheckpointerBuilder},
         retro_forward::RetroForward,
-        strategy::CheckpointStrategy,
+        scope::is_checkpointing,
+        strategy::{BalancedCheckpointing, CheckpointStrategy},
     },
     grads::Gradients,
     graph::{ComputingProperty, Graph, NodeID, NodeRef, Requirement, Step},
@@ -91,7 +92,10 @@ where
             self.graphs,
             self.requirement,
             self.backward,
-            C::compute_property(retro_forward),
+            match is_checkpointing() {
+                true => BalancedCheckpointing::compute_property(retro_forward),
+                false => C::compute_property(retro_forward),
+            },
             self.checkpointer_builder,
         )
     }
@@ -113,7 +117,12 @@ where
         B2: Backend,
         A: IntoIterator<Item = &'a AutodiffTensor<B2, D2>>,
     {
-        C::checkpoint_parents(parents, &mut self.checkpointer_builder);
+        match is_checkpointing() {
+            true => {
+                BalancedCheckpointing::checkpoint_parents(parents, &mut self.checkpointer_builder)
+            }
+            false => C::checkpoint_parents(parents, &mut self.checkpointer_builder),
+        }
 
         OpsPrep::new(
             self.nodes,
@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(ad_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_max_pool2d;
+    use burn_tensor::Data;
+
+    #[test]
+    fn test_adaptive_max_pool2d_overlapping_windows() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data(
+            Data::from([[[
+                [1.0, -2.0, 3.0, 0.5],
+                [4.0, 9.0, -1.0, 2.0],
+                [0.0, 6.0, 7.0, -3.0],
+                [5.0, -4.0, 8.0, 1.0],
+                [2.0, 3.0, -5.0, 6.0],
+            ]]]),
+            &device,
+        )
+        .require_grad();
+
+        let output = adaptive_max_pool2d(x.clone(), [3, 3]);
+        let grads = output.backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        // The maximum of each window receives the gradient of every window it belongs to.
+        x_grad.to_data().assert_approx_eq(
+            &Data::from([[[
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 4.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [1.0, 0.0, 3.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+            ]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_global() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data(
+            Data::from([[[[1.0, 5.0], [-2.0, 3.0]], [[0.0, -1.0], [4.0, 2.0]]]]),
+            &device,
+        )
+        .require_grad();
+
+        let output = adaptive_max_pool2d(x.clone(), [1, 1]);
+        let grads = output.backward();
+        let x_grad = x.grad(&grads).unwrap();
+
+        x_grad.to_data().assert_approx_eq(
+            &Data::from([[[[0.0, 1.0], [0.0, 0.0]], [[0.0, 0.0], [1.0, 0.0]]]]),
+            3,
+        );
+    }
+}
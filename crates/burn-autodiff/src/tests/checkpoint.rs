@@ -1,6 +1,7 @@
 #[burn_tensor_testgen::testgen(checkpoint)]
 mod tests {
     use super::*;
+    use burn_autodiff::checkpoint::{checkpoint, scope::num_checkpointed_tensors};
     use burn_tensor::{Bool, Data, Tensor};
 
     #[test]
@@ -162,6 +163,38 @@ mod tests {
         assert_checkpoint(tensor_21)
     }
 
+    #[test]
+    fn test_autodiff_checkpoint_scope_should_recompute_the_activations() {
+        let data = Data::from([[0.1, 0.2], [0.3, 0.4]]);
+        let device = Default::default();
+
+        let x = TestAutodiffTensor::from_data(data.clone(), &device).require_grad();
+        let output = chain_of_memory_bound(x.clone());
+        let num_retained = num_checkpointed_tensors(&output.clone().into_primitive());
+        let grads = output.sum().backward();
+        let grad = x.grad(&grads).unwrap();
+
+        let x_checkpointed = TestAutodiffTensor::from_data(data, &device).require_grad();
+        let output = checkpoint(|| chain_of_memory_bound(x_checkpointed.clone()));
+        let num_retained_checkpointed = num_checkpointed_tensors(&output.clone().into_primitive());
+        let grads = output.sum().backward();
+        let grad_checkpointed = x_checkpointed.grad(&grads).unwrap();
+
+        assert_eq!(grad.into_data(), grad_checkpointed.into_data());
+        assert!(
+            num_retained_checkpointed < num_retained,
+            "Expected fewer retained tensors with checkpointing, got {num_retained_checkpointed} and {num_retained} without"
+        );
+    }
+
+    fn chain_of_memory_bound<const D: usize>(
+        tensor: TestAutodiffTensor<D>,
+    ) -> TestAutodiffTensor<D> {
+        (0..4).fold(tensor, |tensor, _| {
+            tensor.clone().mul(tensor).exp().mul_scalar(0.5)
+        })
+    }
+
     fn assert_checkpoint<const D: usize>(tensor: TestAutodiffTensor<D>) {
         // Assert is not explicit here, but the test can fail
         // - when a tensor is actually required more than n_required, it won't be found and will panic
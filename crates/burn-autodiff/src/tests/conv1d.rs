@@ -202,6 +202,81 @@ mod tests {
         test.assert_grads(grads);
     }
 
+    #[test]
+    fn test_conv1d_groups_different_channels() {
+        let test = Conv1dTestCase {
+            batch_size: 2,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size: 3,
+            padding: 1,
+            stride: 1,
+            dilation: 1,
+            groups: 2,
+            length: 4,
+        };
+        let device = Default::default();
+        let grads = Grads {
+            x: TestTensor::from_floats(
+                [
+                    [[8., 15., 15., 12.], [32., 51., 51., 36.]],
+                    [[8., 15., 15., 12.], [32., 51., 51., 36.]],
+                ],
+                &device,
+            ),
+            weight: TestTensor::from_floats(
+                [
+                    [[30., 44., 36.]],
+                    [[30., 44., 36.]],
+                    [[54., 76., 60.]],
+                    [[54., 76., 60.]],
+                ],
+                &device,
+            ),
+            bias: TestTensor::from_floats([8., 8., 8., 8.], &device),
+        };
+        test.assert_grads(grads);
+    }
+
+    #[test]
+    fn test_conv1d_depthwise_dilation() {
+        let test = Conv1dTestCase {
+            batch_size: 2,
+            channels_in: 3,
+            channels_out: 3,
+            kernel_size: 3,
+            padding: 1,
+            stride: 1,
+            dilation: 2,
+            groups: 3,
+            length: 5,
+        };
+        let device = Default::default();
+        let grads = Grads {
+            x: TestTensor::from_floats(
+                [
+                    [
+                        [0., 1., 1., 3., 2.],
+                        [3., 7., 4., 9., 5.],
+                        [6., 13., 7., 15., 8.],
+                    ],
+                    [
+                        [0., 1., 1., 3., 2.],
+                        [3., 7., 4., 9., 5.],
+                        [6., 13., 7., 15., 8.],
+                    ],
+                ],
+                &device,
+            ),
+            weight: TestTensor::from_floats(
+                [[[32., 57., 44.]], [[52., 87., 64.]], [[72., 117., 84.]]],
+                &device,
+            ),
+            bias: TestTensor::from_floats([6., 6., 6.], &device),
+        };
+        test.assert_grads(grads);
+    }
+
     struct Conv1dTestCase {
         batch_size: usize,
         channels_in: usize,
@@ -0,0 +1,191 @@
+#[burn_tensor_testgen::testgen(ad_conv_transpose3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::{module::conv_transpose3d, ops::ConvTransposeOptions, Data, Shape};
+
+    #[test]
+    fn test_conv_transpose3d_basic() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 2,
+            channels: [2, 2],
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            padding_out: [0, 0, 0],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 1,
+            size: [2, 2, 2],
+        };
+        test.assert_grads_numerically();
+    }
+
+    #[test]
+    fn test_conv_transpose3d_stride_padding() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels: [2, 3],
+            kernel_size: [3, 2, 2],
+            padding: [1, 1, 0],
+            padding_out: [1, 0, 0],
+            stride: [2, 2, 1],
+            dilation: [1, 1, 1],
+            groups: 1,
+            size: [2, 3, 2],
+        };
+        test.assert_grads_numerically();
+    }
+
+    #[test]
+    fn test_conv_transpose3d_dilation_groups() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels: [2, 4],
+            kernel_size: [2, 2, 2],
+            padding: [0, 1, 0],
+            padding_out: [0, 0, 0],
+            stride: [1, 1, 1],
+            dilation: [2, 1, 2],
+            groups: 2,
+            size: [2, 2, 3],
+        };
+        test.assert_grads_numerically();
+    }
+
+    struct ConvTranspose3dTestCase {
+        batch_size: usize,
+        channels: [usize; 2],
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        padding_out: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        groups: usize,
+        size: [usize; 3],
+    }
+
+    impl ConvTranspose3dTestCase {
+        /// Compare the gradients computed by autodiff with central finite differences of the
+        /// loss `sum(output * probe)`, where the probe is a fixed tensor.
+        fn assert_grads_numerically(self) {
+            let [depth, height, width] = self.size;
+            let [kernel_1, kernel_2, kernel_3] = self.kernel_size;
+            let shape_x = Shape::new([self.batch_size, self.channels[0], depth, height, width]);
+            let shape_weight = Shape::new([
+                self.channels[0],
+                self.channels[1] / self.groups,
+                kernel_1,
+                kernel_2,
+                kernel_3,
+            ]);
+            let shape_bias = Shape::new([self.channels[1]]);
+            let device = Default::default();
+
+            let values_x = values(shape_x.num_elements(), 0.3);
+            let values_weight = values(shape_weight.num_elements(), 0.7);
+            let values_bias = values(shape_bias.num_elements(), 1.1);
+
+            let options = ConvTransposeOptions::new(
+                self.stride,
+                self.padding,
+                self.padding_out,
+                self.dilation,
+                self.groups,
+            );
+            let output = conv_transpose3d(
+                TestTensor::from_data(tensor_data(&values_x, &shape_x), &device),
+                TestTensor::from_data(tensor_data(&values_weight, &shape_weight), &device),
+                Some(TestTensor::from_data(
+                    tensor_data(&values_bias, &shape_bias),
+                    &device,
+                )),
+                options.clone(),
+            );
+            let shape_out = output.shape();
+            let values_probe = values(shape_out.num_elements(), 1.7);
+            let probe = TestTensor::from_data(tensor_data(&values_probe, &shape_out), &device);
+
+            let loss = |x: &[f32], weight: &[f32], bias: &[f32]| -> f32 {
+                let output = conv_transpose3d(
+                    TestTensor::from_data(tensor_data(x, &shape_x), &device),
+                    TestTensor::from_data(tensor_data(weight, &shape_weight), &device),
+                    Some(TestTensor::from_data(
+                        tensor_data(bias, &shape_bias),
+                        &device,
+                    )),
+                    options.clone(),
+                );
+                (output * probe.clone())
+                    .sum()
+                    .into_data()
+                    .convert::<f32>()
+                    .value[0]
+            };
+
+            let x = TestAutodiffTensor::from_data(tensor_data(&values_x, &shape_x), &device)
+                .require_grad();
+            let weight =
+                TestAutodiffTensor::from_data(tensor_data(&values_weight, &shape_weight), &device)
+                    .require_grad();
+            let bias =
+                TestAutodiffTensor::from_data(tensor_data(&values_bias, &shape_bias), &device)
+                    .require_grad();
+            let probe =
+                TestAutodiffTensor::from_data(tensor_data(&values_probe, &shape_out), &device);
+
+            let output = conv_transpose3d(
+                x.clone(),
+                weight.clone(),
+                Some(bias.clone()),
+                options.clone(),
+            );
+            let grads = (output * probe).sum().backward();
+
+            let x_grad_expected =
+                numerical_grad(&values_x, |x| loss(x, &values_weight, &values_bias));
+            let weight_grad_expected = numerical_grad(&values_weight, |weight| {
+                loss(&values_x, weight, &values_bias)
+            });
+            let bias_grad_expected =
+                numerical_grad(&values_bias, |bias| loss(&values_x, &values_weight, bias));
+
+            tensor_data(&bias_grad_expected, &shape_bias)
+                .assert_approx_eq(&bias.grad(&grads).unwrap().into_data(), 2);
+            tensor_data(&weight_grad_expected, &shape_weight)
+                .assert_approx_eq(&weight.grad(&grads).unwrap().into_data(), 2);
+            tensor_data(&x_grad_expected, &shape_x)
+                .assert_approx_eq(&x.grad(&grads).unwrap().into_data(), 2);
+        }
+    }
+
+    /// Deterministic values in the range `[-1, 1]`.
+    fn values(num_elements: usize, frequency: f32) -> Vec<f32> {
+        (0..num_elements)
+            .map(|i| (i as f32 * frequency + 0.5).sin())
+            .collect()
+    }
+
+    fn tensor_data<const D: usize>(
+        values: &[f32],
+        shape: &Shape<D>,
+    ) -> Data<<TestBackend as burn_tensor::backend::Backend>::FloatElem, D> {
+        Data::new(values.to_vec(), shape.clone()).convert()
+    }
+
+    /// Central finite differences, exact up to rounding errors since the loss is linear in each
+    /// of the inputs.
+    fn numerical_grad<F: Fn(&[f32]) -> f32>(values: &[f32], loss: F) -> Vec<f32> {
+        let epsilon = 0.5;
+
+        (0..values.len())
+            .map(|i| {
+                let mut values = values.to_vec();
+                values[i] += epsilon;
+                let loss_plus = loss(&values);
+                values[i] -= 2.0 * epsilon;
+                let loss_minus = loss(&values);
+
+                (loss_plus - loss_minus) / (2.0 * epsilon)
+            })
+            .collect()
+    }
+}
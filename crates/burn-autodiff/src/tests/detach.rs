@@ -0,0 +1,68 @@
+#[burn_tensor_testgen::testgen(ad_detach)]
+mod tests {
+    use super::*;
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_not_diff_detached_branch() {
+        let data_1 = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+        let data_2 = Data::<f32, 2>::from([[4.0, 7.0], [2.0, 3.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data_1, &device).require_grad();
+        let tensor_2 = TestAutodiffTensor::from_data(data_2, &device).require_grad();
+
+        let tensor_3 = tensor_1.clone().mul_scalar(2.0).detach();
+        let tensor_4 = tensor_3.mul(tensor_2.clone());
+        let grads = tensor_4.clone().sum().backward();
+
+        // The detached branch still participates in the forward pass.
+        assert_eq!(tensor_4.into_data(), Data::from([[8.0, 98.0], [8.0, 18.0]]));
+        assert!(tensor_1.grad(&grads).is_none());
+        assert_eq!(
+            tensor_2.grad(&grads).unwrap().to_data(),
+            Data::from([[2.0, 14.0], [4.0, 6.0]])
+        );
+    }
+
+    #[test]
+    fn should_not_diff_stop_gradient() {
+        let data = Data::<f32, 2>::from([[1.0, 7.0], [2.0, 3.0]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        let tensor_2 = tensor_1.clone().stop_gradient();
+        assert!(!tensor_2.is_require_grad());
+
+        let tensor_3 = tensor_1.clone().mul(tensor_2);
+        let grads = tensor_3.sum().backward();
+
+        // The stopped tensor is a constant, so the gradient is not doubled.
+        assert_eq!(
+            tensor_1.grad(&grads).unwrap().to_data(),
+            Data::from([[1.0, 7.0], [2.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_diff_straight_through_estimator() {
+        let data = Data::<f32, 2>::from([[0.2, 0.7], [1.4, 2.6]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::from_data(data, &device).require_grad();
+
+        // The forward pass computes the exponential, while the gradient is the identity.
+        let tensor_2 =
+            tensor_1.clone() + (tensor_1.clone().exp() - tensor_1.clone()).stop_gradient();
+        let grads = tensor_2.clone().sum().backward();
+
+        tensor_2
+            .into_data()
+            .assert_approx_eq(&tensor_1.clone().exp().into_data(), 3);
+        assert_eq!(
+            tensor_1.grad(&grads).unwrap().to_data(),
+            Data::from([[1.0, 1.0], [1.0, 1.0]])
+        );
+    }
+}
@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(ad_double_backward)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    type TestDoubleAutodiffBackend = burn_autodiff::Autodiff<TestAutodiffBackend>;
+
+    #[test]
+    fn should_diff_the_squared_gradient_norm() {
+        let data = Data::<f32, 2>::from([[1.0, 2.0], [-1.0, 0.5]]);
+
+        let device = Default::default();
+        // The input is tracked by both autodiff decorators.
+        let tensor_inner = TestAutodiffTensor::from_data(data, &device).require_grad();
+        let tensor =
+            Tensor::<TestDoubleAutodiffBackend, 2>::from_inner(tensor_inner.clone()).require_grad();
+
+        // f(x) = sum(x^3), so the gradient is 3x^2.
+        let output = tensor.clone().mul(tensor.clone()).mul(tensor.clone()).sum();
+        let grads = output.backward();
+        let grad = tensor.grad(&grads).unwrap();
+
+        grad.to_data()
+            .assert_approx_eq(&Data::from([[3.0, 12.0], [3.0, 0.75]]), 3);
+
+        // The squared norm of the gradient is sum(9x^4), so its gradient is 36x^3.
+        let penalty = grad.clone().mul(grad).sum();
+        let grads = penalty.backward();
+        let grad_penalty = tensor_inner.grad(&grads).unwrap();
+
+        grad_penalty
+            .to_data()
+            .assert_approx_eq(&Data::from([[36.0, 288.0], [-36.0, 4.5]]), 3);
+    }
+}
@@ -3,6 +3,7 @@
 mod abs;
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_maxpool2d;
 mod add;
 mod aggregation;
 mod avgpool1d;
@@ -16,9 +17,12 @@ mod conv1d;
 mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
 mod cos;
 mod cross_entropy;
+mod detach;
 mod div;
+mod double_backward;
 mod erf;
 mod exp;
 mod flip;
@@ -52,6 +56,7 @@ mod sqrt;
 mod sub;
 mod tanh;
 mod transpose;
+mod value_and_grad;
 
 #[macro_export]
 macro_rules! testgen_all {
@@ -63,6 +68,8 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_broadcast!();
         burn_autodiff::testgen_gradients!();
         burn_autodiff::testgen_checkpoint!();
+        burn_autodiff::testgen_ad_value_and_grad!();
+        burn_autodiff::testgen_ad_double_backward!();
 
         // Activation
         burn_autodiff::testgen_ad_relu!();
@@ -73,12 +80,14 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_conv2d!();
         burn_autodiff::testgen_ad_conv_transpose1d!();
         burn_autodiff::testgen_ad_conv_transpose2d!();
+        burn_autodiff::testgen_ad_conv_transpose3d!();
         burn_autodiff::testgen_ad_max_pool1d!();
         burn_autodiff::testgen_ad_max_pool2d!();
         burn_autodiff::testgen_ad_avg_pool1d!();
         burn_autodiff::testgen_ad_avg_pool2d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool1d!();
         burn_autodiff::testgen_ad_adaptive_avg_pool2d!();
+        burn_autodiff::testgen_ad_adaptive_max_pool2d!();
         burn_autodiff::testgen_module_backward!();
         burn_autodiff::testgen_ad_nearest_interpolate!();
 
@@ -91,6 +100,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_cat!();
         burn_autodiff::testgen_ad_cos!();
         burn_autodiff::testgen_ad_cross_entropy_loss!();
+        burn_autodiff::testgen_ad_detach!();
         burn_autodiff::testgen_ad_div!();
         burn_autodiff::testgen_ad_erf!();
         burn_autodiff::testgen_ad_exp!();
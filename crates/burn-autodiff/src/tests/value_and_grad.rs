@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(ad_value_and_grad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{grad, value_and_grad, Data, Shape};
+
+    const EPSILON: f32 = 1e-2;
+
+    // f(x, y) = sum(x * exp(y) + x^2)
+    fn func(inputs: [TestAutodiffTensor<2>; 2]) -> TestAutodiffTensor<1> {
+        let [x, y] = inputs;
+        (x.clone().mul(y.exp()) + x.clone().mul(x)).sum()
+    }
+
+    fn eval(inputs: &[Vec<f32>; 2], shape: &Shape<2>) -> f32 {
+        let device = Default::default();
+        let inputs = inputs
+            .clone()
+            .map(|values| TestAutodiffTensor::from_data(Data::new(values, shape.clone()), &device));
+
+        func(inputs).into_data().convert().value[0]
+    }
+
+    /// Approximates the gradient of an input with central finite differences.
+    fn finite_differences(inputs: &[Vec<f32>; 2], shape: &Shape<2>, input: usize) -> Data<f32, 2> {
+        let values = (0..inputs[input].len())
+            .map(|index| {
+                let mut inputs_plus = inputs.clone();
+                let mut inputs_minus = inputs.clone();
+                inputs_plus[input][index] += EPSILON;
+                inputs_minus[input][index] -= EPSILON;
+
+                (eval(&inputs_plus, shape) - eval(&inputs_minus, shape)) / (2.0 * EPSILON)
+            })
+            .collect();
+
+        Data::new(values, shape.clone())
+    }
+
+    #[test]
+    fn should_match_finite_differences() {
+        let shape = Shape::new([2, 2]);
+        let inputs = [vec![0.5, -1.0, 2.0, 0.1], vec![0.3, 0.2, -0.4, 1.0]];
+
+        let device = Default::default();
+        let tensors = inputs
+            .clone()
+            .map(|values| TestAutodiffTensor::from_data(Data::new(values, shape.clone()), &device));
+
+        let (value, [grad_x, grad_y]) = value_and_grad(func, tensors);
+
+        assert_eq!(
+            value.into_data().convert::<f32>().value[0],
+            eval(&inputs, &shape)
+        );
+        grad_x
+            .into_data()
+            .assert_approx_eq(&finite_differences(&inputs, &shape, 0), 2);
+        grad_y
+            .into_data()
+            .assert_approx_eq(&finite_differences(&inputs, &shape, 1), 2);
+    }
+
+    #[test]
+    fn should_diff_with_grad_function() {
+        let device = Default::default();
+        let x = TestAutodiffTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device);
+        let y = TestAutodiffTensor::from_data([[5.0, 6.0], [7.0, 8.0]], &device);
+
+        // The second input is unused, so its gradient is zero.
+        let grad_func = grad(|[x, _y]: [TestAutodiffTensor<2>; 2]| x.clone().mul(x).sum());
+        let [grad_x, grad_y] = grad_func([x, y]);
+
+        assert_eq!(grad_x.into_data(), Data::from([[2.0, 4.0], [6.0, 8.0]]));
+        assert_eq!(grad_y.into_data(), Data::from([[0.0, 0.0], [0.0, 0.0]]));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::server::{ComputeServer, Handle};
+use crate::memory_management::MemoryStats;
+use crate::server::{ComputeServer, Handle, TimedRead};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 
@@ -8,6 +9,14 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
     /// Given a handle, returns owned resource as bytes
     fn read(&self, handle: &Handle<Server>) -> Reader<Vec<u8>>;
 
+    /// Given a handle, returns owned resource as bytes along with the elapsed time
+    ///
+    /// By default, the [read](ComputeChannel::read) is timed with the wall clock, or reported as
+    /// zero without the `std` feature.
+    fn read_timed(&self, handle: &Handle<Server>) -> Reader<TimedRead> {
+        crate::server::time_read(|| self.read(handle))
+    }
+
     /// Given a resource as bytes, stores it and returns the resource handle
     fn create(&self, data: &[u8]) -> Handle<Server>;
 
@@ -19,4 +28,11 @@ pub trait ComputeChannel<Server: ComputeServer>: Clone + core::fmt::Debug + Send
 
     /// Wait for the completion of every task in the server.
     fn sync(&self);
+
+    /// Fetch the statistics about the memory currently held by the server.
+    ///
+    /// Empty statistics are returned by default.
+    fn memory_stats(&self) -> MemoryStats {
+        MemoryStats::default()
+    }
 }
@@ -1,5 +1,6 @@
 use super::ComputeChannel;
-use crate::server::{ComputeServer, Handle};
+use crate::memory_management::MemoryStats;
+use crate::server::{ComputeServer, Handle, TimedRead};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -46,6 +47,10 @@ where
         self.server.borrow_mut().read(handle)
     }
 
+    fn read_timed(&self, handle: &Handle<Server>) -> Reader<TimedRead> {
+        self.server.borrow_mut().read_timed(handle)
+    }
+
     fn create(&self, resource: &[u8]) -> Handle<Server> {
         self.server.borrow_mut().create(resource)
     }
@@ -63,6 +68,10 @@ where
     fn sync(&self) {
         self.server.borrow_mut().sync()
     }
+
+    fn memory_stats(&self) -> MemoryStats {
+        self.server.borrow_mut().memory_stats()
+    }
 }
 
 /// This is unsafe, since no concurrency is supported by the `RefCell` channel.
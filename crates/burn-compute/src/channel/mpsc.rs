@@ -6,7 +6,8 @@ use std::{
 use burn_common::reader::Reader;
 
 use super::ComputeChannel;
-use crate::server::{ComputeServer, Handle};
+use crate::memory_management::MemoryStats;
+use crate::server::{ComputeServer, Handle, TimedRead};
 
 /// Create a channel using the [multi-producer, single-consumer channel](mpsc) to communicate with
 /// the compute server spawn on its own thread.
@@ -34,10 +35,12 @@ where
     Server: ComputeServer,
 {
     Read(Handle<Server>, Callback<Reader<Vec<u8>>>),
+    ReadTimed(Handle<Server>, Callback<Reader<TimedRead>>),
     Create(Vec<u8>, Callback<Handle<Server>>),
     Empty(usize, Callback<Handle<Server>>),
     ExecuteKernel(Server::Kernel, Vec<Handle<Server>>),
     Sync(Callback<()>),
+    MemoryStats(Callback<MemoryStats>),
 }
 
 impl<Server> MpscComputeChannel<Server>
@@ -56,6 +59,11 @@ where
                         core::mem::drop(handle);
                         callback.send(data).unwrap();
                     }
+                    Message::ReadTimed(handle, callback) => {
+                        let data = server.read_timed(&handle);
+                        core::mem::drop(handle);
+                        callback.send(data).unwrap();
+                    }
                     Message::Create(data, callback) => {
                         let handle = server.create(&data);
                         callback.send(handle).unwrap();
@@ -71,6 +79,10 @@ where
                         server.sync();
                         callback.send(()).unwrap();
                     }
+                    Message::MemoryStats(callback) => {
+                        let stats = server.memory_stats();
+                        callback.send(stats).unwrap();
+                    }
                 };
             }
         });
@@ -104,6 +116,17 @@ where
         self.response(response)
     }
 
+    fn read_timed(&self, handle: &Handle<Server>) -> Reader<TimedRead> {
+        let (callback, response) = mpsc::sync_channel(1);
+
+        self.state
+            .sender
+            .send(Message::ReadTimed(handle.clone(), callback))
+            .unwrap();
+
+        self.response(response)
+    }
+
     fn create(&self, data: &[u8]) -> Handle<Server> {
         let (callback, response) = mpsc::sync_channel(1);
 
@@ -146,6 +169,17 @@ where
 
         self.response(response)
     }
+
+    fn memory_stats(&self) -> MemoryStats {
+        let (callback, response) = mpsc::sync_channel(1);
+
+        self.state
+            .sender
+            .send(Message::MemoryStats(callback))
+            .unwrap();
+
+        self.response(response)
+    }
 }
 
 impl<Server: ComputeServer> MpscComputeChannel<Server> {
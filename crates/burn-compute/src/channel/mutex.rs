@@ -1,5 +1,6 @@
 use super::ComputeChannel;
-use crate::server::{ComputeServer, Handle};
+use crate::memory_management::MemoryStats;
+use crate::server::{ComputeServer, Handle, TimedRead};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -39,6 +40,10 @@ where
         self.server.lock().read(handle)
     }
 
+    fn read_timed(&self, handle: &Handle<Server>) -> Reader<TimedRead> {
+        self.server.lock().read_timed(handle)
+    }
+
     fn create(&self, data: &[u8]) -> Handle<Server> {
         self.server.lock().create(data)
     }
@@ -54,4 +59,8 @@ where
     fn sync(&self) {
         self.server.lock().sync()
     }
+
+    fn memory_stats(&self) -> MemoryStats {
+        self.server.lock().memory_stats()
+    }
 }
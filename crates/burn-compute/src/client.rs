@@ -1,6 +1,7 @@
 use crate::{
     channel::ComputeChannel,
-    server::{ComputeServer, Handle},
+    memory_management::MemoryStats,
+    server::{ComputeServer, Handle, TimedRead},
     tune::{AutotuneOperationSet, Tuner},
 };
 use alloc::vec::Vec;
@@ -44,6 +45,15 @@ where
         self.channel.read(handle)
     }
 
+    /// Given a handle, returns owned resource as bytes along with the time it took to be
+    /// available.
+    ///
+    /// Every pending task is executed before the resource is read, so the elapsed time includes
+    /// their execution. See [timing](TimedRead::timing) to know how the time was measured.
+    pub fn read_timed(&self, handle: &Handle<Server>) -> Reader<TimedRead> {
+        self.channel.read_timed(handle)
+    }
+
     /// Given a resource, stores it and returns the resource handle.
     pub fn create(&self, data: &[u8]) -> Handle<Server> {
         self.channel.create(data)
@@ -64,6 +74,14 @@ where
         self.channel.sync()
     }
 
+    /// Fetch the statistics about the memory currently held by the server.
+    ///
+    /// This is cheap enough to be called at every iteration, e.g. to log the memory growth over a
+    /// training loop.
+    pub fn memory_stats(&self) -> MemoryStats {
+        self.channel.memory_stats()
+    }
+
     /// Executes the fastest kernel in the autotune operation, using (cached) runtime benchmarks
     pub fn autotune_execute(
         &self,
@@ -50,4 +50,25 @@ pub trait MemoryManagement<Storage: ComputeStorage>: Send + core::fmt::Debug {
     /// This is useful if you need to time the deallocations based on async computation, or to
     /// change the mode of storage for different reasons.
     fn storage(&mut self) -> &mut Storage;
+
+    /// Fetch the statistics about the memory currently held by the memory manager.
+    ///
+    /// Empty statistics are returned by default.
+    fn memory_stats(&self) -> MemoryStats {
+        MemoryStats::default()
+    }
+}
+
+/// Statistics about the memory held by a [memory management](MemoryManagement) strategy.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoryStats {
+    /// The total number of bytes reserved in the storage.
+    pub reserved_bytes: usize,
+    /// The number of bytes used by chunks and slices that are currently in use.
+    pub in_use_bytes: usize,
+    /// The number of chunks allocated in the storage.
+    pub num_chunks: usize,
+    /// The ratio needed before a chunk can be used as a slice, if the
+    /// [slice strategy](super::SliceStrategy) is based on a ratio.
+    pub slice_ratio: Option<f32>,
 }
@@ -1,4 +1,4 @@
-use super::{MemoryHandle, MemoryManagement};
+use super::{MemoryHandle, MemoryManagement, MemoryStats};
 use crate::{
     memory_id_type,
     storage::{ComputeStorage, StorageHandle, StorageUtilization},
@@ -57,6 +57,18 @@ pub enum DeallocStrategy {
         /// Current state. Should start at now.
         state: time::Instant,
     },
+    /// Whenever a new chunk would make the reserved memory cross the high watermark, free chunks
+    /// are deallocated until the reserved memory, including the new chunk, is back under the
+    /// target.
+    ///
+    /// This reacts synchronously to the allocation, which is useful when the memory fills up
+    /// faster than a period would catch.
+    PressureThreshold {
+        /// Number of reserved bytes that triggers the deallocation.
+        high_watermark_bytes: usize,
+        /// Number of reserved bytes to free down to.
+        target_bytes: usize,
+    },
     /// Never deallocate.
     Never,
 }
@@ -96,6 +108,20 @@ impl DeallocStrategy {
         DeallocStrategy::PeriodTick { period, state: 0 }
     }
 
+    /// Create a new strategy deallocating when the reserved memory crosses the high watermark,
+    /// freeing down to the target.
+    pub fn new_pressure_threshold(high_watermark_bytes: usize, target_bytes: usize) -> Self {
+        assert!(
+            target_bytes <= high_watermark_bytes,
+            "The target should be lower than the high watermark."
+        );
+
+        DeallocStrategy::PressureThreshold {
+            high_watermark_bytes,
+            target_bytes,
+        }
+    }
+
     fn should_dealloc(&mut self) -> bool {
         match self {
             DeallocStrategy::PeriodTick { period, state } => {
@@ -111,6 +137,8 @@ impl DeallocStrategy {
                     false
                 }
             }
+            // Handled when creating new chunks.
+            DeallocStrategy::PressureThreshold { .. } => false,
             DeallocStrategy::Never => false,
         }
     }
@@ -202,6 +230,35 @@ impl<Storage: ComputeStorage> MemoryManagement<Storage> for SimpleMemoryManageme
     fn storage(&mut self) -> &mut Storage {
         &mut self.storage
     }
+
+    fn memory_stats(&self) -> MemoryStats {
+        let mut stats = MemoryStats {
+            num_chunks: self.chunks.len(),
+            slice_ratio: match self.slice_strategy {
+                SliceStrategy::Ratio(ratio) => Some(ratio),
+                _ => None,
+            },
+            ..Default::default()
+        };
+
+        for (chunk_id, (resource, slices)) in self.chunks.iter() {
+            stats.reserved_bytes += resource.size();
+
+            // Chunks used by slices are referenced by the slice map, so only the memory used by
+            // the slices is considered in use.
+            if slices.is_empty() && !chunk_id.is_free() {
+                stats.in_use_bytes += resource.size();
+            }
+        }
+
+        for (slice_id, (resource, _chunk_id)) in self.slices.iter() {
+            if !slice_id.is_free() {
+                stats.in_use_bytes += resource.size();
+            }
+        }
+
+        stats
+    }
 }
 
 impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
@@ -299,6 +356,16 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
 
     /// Creates a chunk of given size by allocating on the storage.
     fn create_chunk(&mut self, size: usize) -> SimpleHandle {
+        if let DeallocStrategy::PressureThreshold {
+            high_watermark_bytes,
+            target_bytes,
+        } = self.dealloc_strategy
+        {
+            if self.reserved_bytes() + size > high_watermark_bytes {
+                self.cleanup_chunks_until(target_bytes.saturating_sub(size));
+            }
+        }
+
         let resource = self.storage.alloc(size);
         let chunk_id = ChunkId::new();
 
@@ -325,6 +392,37 @@ impl<Storage: ComputeStorage> SimpleMemoryManagement<Storage> {
             });
     }
 
+    /// Deallocates free chunks, the largest ones first, until the reserved memory is at most
+    /// `target_bytes` or no free chunk remains.
+    fn cleanup_chunks_until(&mut self, target_bytes: usize) {
+        let mut reserved_bytes = self.reserved_bytes();
+        let mut free_chunks = self
+            .chunks
+            .iter()
+            .filter(|(chunk_id, (_resource, slices))| slices.is_empty() && chunk_id.is_free())
+            .map(|(chunk_id, (resource, _slices))| (chunk_id.clone(), resource.size()))
+            .collect::<Vec<_>>();
+
+        free_chunks.sort_by(|(_, size_a), (_, size_b)| size_b.cmp(size_a));
+
+        for (chunk_id, size) in free_chunks {
+            if reserved_bytes <= target_bytes {
+                break;
+            }
+
+            let (resource, _slices) = self.chunks.remove(&chunk_id).unwrap();
+            self.storage.dealloc(resource.id);
+            reserved_bytes -= size;
+        }
+    }
+
+    fn reserved_bytes(&self) -> usize {
+        self.chunks
+            .values()
+            .map(|(resource, _slices)| resource.size())
+            .sum()
+    }
+
     /// Removes free slices from slice map and corresponding chunks.
     fn cleanup_slices(&mut self) {
         let mut ids_to_remove = Vec::new();
@@ -420,6 +518,28 @@ mod tests {
         assert_eq!(memory_management.chunks.len(), 0);
     }
 
+    #[test]
+    fn memory_stats_track_reserved_and_in_use_memory() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::Never,
+            SliceStrategy::Ratio(0.5),
+        );
+        let handle_1 = memory_management.reserve(8);
+        let handle_2 = memory_management.reserve(4);
+        drop(handle_1);
+
+        let stats = memory_management.memory_stats();
+
+        assert_eq!(stats.reserved_bytes, 12);
+        assert_eq!(stats.in_use_bytes, 4);
+        assert_eq!(stats.num_chunks, 2);
+        assert_eq!(stats.slice_ratio, Some(0.5));
+
+        drop(handle_2);
+        assert_eq!(memory_management.memory_stats().in_use_bytes, 0);
+    }
+
     #[test]
     fn never_dealloc_strategy_never_deallocs() {
         let mut never_dealloc = DeallocStrategy::Never;
@@ -441,6 +561,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn pressure_threshold_dealloc_strategy_bounds_reserved_memory() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::new_pressure_threshold(100, 50),
+            SliceStrategy::Never,
+        );
+        let mut peak_reserved_bytes = 0;
+
+        for size in 1..=40 {
+            let handle = memory_management.reserve(size);
+            let stats = memory_management.memory_stats();
+            peak_reserved_bytes = usize::max(peak_reserved_bytes, stats.reserved_bytes);
+            drop(handle);
+        }
+
+        assert!(peak_reserved_bytes <= 100);
+    }
+
+    #[test]
+    fn pressure_threshold_dealloc_strategy_keeps_chunks_in_use() {
+        let mut memory_management = SimpleMemoryManagement::new(
+            BytesStorage::default(),
+            DeallocStrategy::new_pressure_threshold(10, 0),
+            SliceStrategy::Never,
+        );
+        let handle_1 = memory_management.reserve(8);
+        let _handle_2 = memory_management.reserve(8);
+
+        assert_eq!(memory_management.chunks.len(), 2);
+        assert!(handle_1.can_mut());
+    }
+
     #[test]
     fn slice_strategy_minimum_bytes() {
         let strategy = SliceStrategy::MinimumSize(100);
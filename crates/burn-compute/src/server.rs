@@ -1,7 +1,7 @@
 use core::fmt::Debug;
 
 use crate::{
-    memory_management::{MemoryHandle, MemoryManagement},
+    memory_management::{MemoryHandle, MemoryManagement, MemoryStats},
     storage::ComputeStorage,
     tune::AutotuneKey,
 };
@@ -28,6 +28,16 @@ where
     /// Given a handle, returns the owned resource as bytes.
     fn read(&mut self, handle: &Handle<Self>) -> Reader<Vec<u8>>;
 
+    /// Given a handle, returns the owned resource as bytes along with the time spent executing
+    /// the pending tasks and reading the resource.
+    ///
+    /// By default, the [read](ComputeServer::read) is timed with the
+    /// [wall clock](TimingMethod::WallClock). Without the `std` feature, no clock is available and
+    /// the elapsed time is always zero.
+    fn read_timed(&mut self, handle: &Handle<Self>) -> Reader<TimedRead> {
+        time_read(|| self.read(handle))
+    }
+
     /// Given a resource as bytes, stores it and returns the memory handle.
     fn create(&mut self, data: &[u8]) -> Handle<Self>;
 
@@ -42,6 +52,65 @@ where
 
     /// Wait for the completion of every task in the server.
     fn sync(&mut self);
+
+    /// Fetch the statistics about the memory currently held by the server.
+    ///
+    /// Empty statistics are returned by default.
+    fn memory_stats(&mut self) -> MemoryStats {
+        MemoryStats::default()
+    }
+}
+
+/// Data read from the server along with the time it took to be available.
+#[derive(new, Debug)]
+pub struct TimedRead {
+    /// The data read, as bytes.
+    pub data: Vec<u8>,
+    /// The elapsed time in nanoseconds.
+    pub elapsed_nanos: u64,
+    /// How the elapsed time was measured.
+    pub timing: TimingMethod,
+}
+
+/// Time the given read with the [wall clock](TimingMethod::WallClock), until the data is
+/// available.
+#[cfg(feature = "std")]
+pub(crate) fn time_read<F>(read: F) -> Reader<TimedRead>
+where
+    F: FnOnce() -> Reader<Vec<u8>>,
+{
+    #[cfg(not(target_family = "wasm"))]
+    use std::time::Instant;
+    #[cfg(target_family = "wasm")]
+    use web_time::Instant;
+
+    let start = Instant::now();
+
+    read().map(move |data| {
+        TimedRead::new(
+            data,
+            start.elapsed().as_nanos() as u64,
+            TimingMethod::WallClock,
+        )
+    })
+}
+
+/// Without a clock to time the read, the elapsed time is reported as zero.
+#[cfg(not(feature = "std"))]
+pub(crate) fn time_read<F>(read: F) -> Reader<TimedRead>
+where
+    F: FnOnce() -> Reader<Vec<u8>>,
+{
+    read().map(|data| TimedRead::new(data, 0, TimingMethod::WallClock))
+}
+
+/// How the elapsed time of a [timed read](TimedRead) was measured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimingMethod {
+    /// Measured by the device, only the execution of the tasks is included.
+    Device,
+    /// Measured with the wall clock of the host, the submission and the readback are included.
+    WallClock,
 }
 
 /// Server handle containing the [memory handle](MemoryManagement::Handle).
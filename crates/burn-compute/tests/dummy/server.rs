@@ -2,8 +2,8 @@ use std::sync::Arc;
 
 use burn_common::reader::Reader;
 use burn_compute::{
-    memory_management::{MemoryManagement, SimpleMemoryManagement},
-    server::{ComputeServer, Handle},
+    memory_management::{MemoryManagement, MemoryStats, SimpleMemoryManagement},
+    server::{ComputeServer, Handle, TimedRead, TimingMethod},
     storage::BytesStorage,
 };
 use derive_new::new;
@@ -32,6 +32,18 @@ where
         Reader::Concrete(bytes.read().to_vec())
     }
 
+    fn read_timed(&mut self, handle: &Handle<Self>) -> Reader<TimedRead> {
+        let start = std::time::Instant::now();
+        let bytes = self.memory_management.get(&handle.memory).read().to_vec();
+        let elapsed_nanos = start.elapsed().as_nanos() as u64;
+
+        Reader::Concrete(TimedRead::new(
+            bytes,
+            elapsed_nanos,
+            TimingMethod::WallClock,
+        ))
+    }
+
     fn create(&mut self, data: &[u8]) -> Handle<Self> {
         let handle = self.memory_management.reserve(data.len());
         let resource = self.memory_management.get(&handle);
@@ -61,4 +73,8 @@ where
     fn sync(&mut self) {
         // Nothing to do with dummy backend.
     }
+
+    fn memory_stats(&mut self) -> MemoryStats {
+        self.memory_management.memory_stats()
+    }
 }
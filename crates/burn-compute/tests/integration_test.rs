@@ -3,7 +3,7 @@ mod dummy;
 use std::sync::Arc;
 
 use crate::dummy::{client, DummyDevice, DummyElementwiseAddition};
-use burn_compute::ComputeRuntime;
+use burn_compute::{server::TimingMethod, ComputeRuntime};
 
 #[allow(unused)]
 use serial_test::serial;
@@ -19,6 +19,18 @@ fn created_resource_is_the_same_when_read() {
     assert_eq!(resource, obtained_resource.read())
 }
 
+#[test]
+fn created_resource_is_the_same_when_read_timed() {
+    let client = client(&DummyDevice);
+    let resource = Vec::from([0, 1, 2]);
+    let resource_description = client.create(&resource);
+
+    let obtained_resource = client.read_timed(&resource_description).read();
+
+    assert_eq!(resource, obtained_resource.data);
+    assert_eq!(obtained_resource.timing, TimingMethod::WallClock);
+}
+
 #[test]
 fn empty_allocates_memory() {
     let client = client(&DummyDevice);
@@ -52,6 +52,49 @@ where
         self
     }
 
+    /// Sets the [strategy](BatchStrategy) to batch the items, e.g. the
+    /// [bucket batch strategy](super::BucketBatchStrategy) to batch items of similar lengths.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - The batch strategy.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder.
+    pub fn batch_strategy<S>(mut self, strategy: S) -> Self
+    where
+        S: BatchStrategy<I> + 'static,
+    {
+        self.strategy = Some(Box::new(strategy));
+        self
+    }
+
+    /// Sets the function collating the items into a batch, replacing the batcher.
+    ///
+    /// This is useful to customize the batching without changing the dataset, e.g. to pad
+    /// variable-length sequences to the longest one of each batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `collate` - The function collating the items into a batch.
+    ///
+    /// # Returns
+    ///
+    /// The data loader builder, with the batch type of the collate function.
+    pub fn with_collate<B, F>(self, collate: F) -> DataLoaderBuilder<I, B>
+    where
+        B: Send + Sync + Clone + std::fmt::Debug + 'static,
+        F: Fn(Vec<I>) -> B + Send + Sync + 'static,
+    {
+        DataLoaderBuilder {
+            strategy: self.strategy,
+            batcher: Arc::new(CollateBatcher { collate }),
+            num_threads: self.num_threads,
+            shuffle: self.shuffle,
+        }
+    }
+
     /// Sets the seed for shuffling.
     ///
     /// Each time the dataloader starts a new iteration, the dataset will be shuffled.
@@ -70,6 +113,9 @@ where
 
     /// Sets the number of workers.
     ///
+    /// Each worker prepares the batches of its own part of the dataset in a background thread,
+    /// ahead of the training loop, so the data preparation overlaps with the computation.
+    ///
     /// # Arguments
     ///
     /// * `num_workers` - The number of workers.
@@ -115,3 +161,61 @@ where
         Arc::new(BatchDataLoader::new(strategy, dataset, self.batcher, rng))
     }
 }
+
+/// A batcher calling a collate function.
+struct CollateBatcher<F> {
+    collate: F,
+}
+
+impl<I, O, F> Batcher<I, O> for CollateBatcher<F>
+where
+    F: Fn(Vec<I>) -> O + Send + Sync,
+{
+    fn batch(&self, items: Vec<I>) -> O {
+        (self.collate)(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::batcher::TestBatcher;
+    use crate::data::dataset::InMemDataset;
+    use crate::tensor::{Data, Int, Tensor};
+    use crate::TestBackend;
+
+    /// Pads the sequences with zeros to the length of the longest one.
+    fn pad(sequences: Vec<Vec<i32>>) -> Tensor<TestBackend, 2, Int> {
+        let max_length = sequences.iter().map(Vec::len).max().unwrap_or(0);
+        let num_sequences = sequences.len();
+        let values = sequences
+            .into_iter()
+            .flat_map(|mut sequence| {
+                sequence.resize(max_length, 0);
+                sequence
+            })
+            .collect::<Vec<_>>();
+
+        Tensor::<TestBackend, 1, Int>::from_ints(values.as_slice(), &Default::default())
+            .reshape([num_sequences, max_length])
+    }
+
+    #[test]
+    fn dataloader_should_collate_the_items_with_the_custom_function() {
+        let sequences = vec![vec![1], vec![1, 2, 3], vec![1, 2], vec![1, 2, 3, 4, 5]];
+        let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+            .with_collate(pad)
+            .batch_size(2)
+            .build(InMemDataset::new(sequences));
+
+        let batches = dataloader.iter().collect::<Vec<_>>();
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].dims(), [2, 3]);
+        assert_eq!(batches[1].dims(), [2, 5]);
+        assert_eq!(
+            batches[0].to_data().convert::<i32>(),
+            Data::from([[1, 0, 0], [1, 2, 3]])
+        );
+    }
+}
@@ -5,6 +5,10 @@ use std::thread;
 const MAX_QUEUED_ITEMS: usize = 100;
 
 /// A multi-threaded data loader that can be used to iterate over a dataset.
+///
+/// Each data loader runs on its own worker thread, which prefetches and collates the batches into
+/// a bounded queue, so the data preparation overlaps with the computation on the batches. The
+/// workers stop when the iterator is dropped.
 pub struct MultiThreadDataLoader<O> {
     dataloaders: Vec<Arc<dyn DataLoader<O> + Send + Sync>>,
 }
@@ -149,3 +153,158 @@ impl<O: std::fmt::Debug> Iterator for MultiThreadsDataloaderIterator<O> {
         }
     }
 }
+
+impl<O> Drop for MultiThreadsDataloaderIterator<O> {
+    fn drop(&mut self) {
+        // Disconnect the channel first, so the workers blocked on a full queue stop sending.
+        let (_, receiver) = mpsc::sync_channel(0);
+        drop(std::mem::replace(&mut self.receiver, receiver));
+
+        for worker in self.workers.drain(..) {
+            // A worker that panicked has already stopped.
+            worker.join().ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::dataloader::{batcher::TestBatcher, DataLoaderBuilder};
+    use crate::data::dataset::Dataset;
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc, Arc, Mutex,
+        },
+        thread,
+        time::{Duration, Instant},
+    };
+
+    /// A dataset sleeping to build each item, counting the items built.
+    struct SlowDataset {
+        len: usize,
+        delay: Duration,
+        num_built: Arc<AtomicUsize>,
+    }
+
+    impl Dataset<usize> for SlowDataset {
+        fn get(&self, index: usize) -> Option<usize> {
+            if index >= self.len {
+                return None;
+            }
+
+            thread::sleep(self.delay);
+            self.num_built.fetch_add(1, Ordering::SeqCst);
+            Some(index)
+        }
+
+        fn len(&self) -> usize {
+            self.len
+        }
+    }
+
+    /// A dataset notifying the index of each item built.
+    struct NotifyingDataset {
+        len: usize,
+        sender: Mutex<mpsc::Sender<usize>>,
+    }
+
+    impl Dataset<usize> for NotifyingDataset {
+        fn get(&self, index: usize) -> Option<usize> {
+            if index >= self.len {
+                return None;
+            }
+
+            self.sender.lock().unwrap().send(index).ok();
+            Some(index)
+        }
+
+        fn len(&self) -> usize {
+            self.len
+        }
+    }
+
+    #[test]
+    fn multi_thread_dataloader_should_prefetch_the_batches() {
+        const NUM_BATCHES: usize = 8;
+        let (sender, receiver) = mpsc::channel();
+        let dataset = NotifyingDataset {
+            len: NUM_BATCHES,
+            sender: Mutex::new(sender),
+        };
+        let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+            .batch_size(1)
+            .num_workers(2)
+            .build(dataset);
+
+        let mut iterator = dataloader.iter();
+        assert!(iterator.next().is_some());
+
+        // The workers build every batch while the first one is being processed, without waiting
+        // for the next ones to be requested.
+        for _ in 0..NUM_BATCHES {
+            receiver
+                .recv_timeout(Duration::from_secs(10))
+                .expect("The batches should be prefetched");
+        }
+        assert_eq!(iterator.count(), NUM_BATCHES - 1);
+    }
+
+    #[test]
+    #[ignore = "Measures the wall time, which is unreliable on loaded machines"]
+    fn multi_thread_dataloader_should_overlap_data_preparation_with_computation() {
+        const NUM_BATCHES: usize = 8;
+        let delay = Duration::from_millis(40);
+        let dataset = SlowDataset {
+            len: NUM_BATCHES,
+            delay,
+            num_built: Arc::new(AtomicUsize::new(0)),
+        };
+        let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+            .batch_size(1)
+            .num_workers(2)
+            .build(dataset);
+
+        let start = Instant::now();
+        let mut num_batches = 0;
+        for _batch in dataloader.iter() {
+            // The computation on a batch takes as long as its preparation.
+            thread::sleep(delay);
+            num_batches += 1;
+        }
+        let elapsed = start.elapsed();
+
+        // Without prefetching, the preparation and the computation would take twice as long. The
+        // bound is loose, the overlap should take closer to half of the sequential time.
+        let sequential = 2 * delay * NUM_BATCHES as u32;
+        assert_eq!(num_batches, NUM_BATCHES);
+        assert!(
+            elapsed < sequential,
+            "Expected the loading to overlap the computation, took {elapsed:?} (sequential {sequential:?})"
+        );
+    }
+
+    #[test]
+    fn multi_thread_dataloader_should_stop_the_workers_when_dropped() {
+        let num_built = Arc::new(AtomicUsize::new(0));
+        let dataset = SlowDataset {
+            len: 100_000,
+            delay: Duration::from_micros(10),
+            num_built: num_built.clone(),
+        };
+        let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+            .batch_size(1)
+            .num_workers(2)
+            .build(dataset);
+
+        let mut iterator = dataloader.iter();
+        assert!(iterator.next().is_some());
+        drop(iterator);
+
+        // The workers are joined, so no item is built after the iterator is dropped.
+        let num_built_after_drop = num_built.load(Ordering::SeqCst);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(num_built.load(Ordering::SeqCst), num_built_after_drop);
+        assert!(num_built_after_drop < 100_000);
+    }
+}
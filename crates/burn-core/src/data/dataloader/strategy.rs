@@ -1,3 +1,6 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::sync::Arc;
+
 /// A strategy to batch items.
 pub trait BatchStrategy<I>: Send + Sync {
     /// Adds an item to the strategy.
@@ -74,3 +77,227 @@ impl<I: Send + Sync + 'static> BatchStrategy<I> for FixBatchStrategy<I> {
         Box::new(Self::new(self.batch_size))
     }
 }
+
+/// A strategy to batch items of similar lengths together, to minimize the padding of sequences.
+///
+/// The items are grouped into buckets by length, where the boundaries `[b_0, b_1, ..., b_n]`
+/// create the buckets `[0, b_0)`, `[b_0, b_1)`, ..., `[b_n, inf)`, and each batch is taken
+/// from a single bucket.
+pub struct BucketBatchStrategy<I> {
+    buckets: Vec<Vec<I>>,
+    boundaries: Vec<usize>,
+    batch_size: usize,
+    length: Arc<dyn Fn(&I) -> usize + Send + Sync>,
+    shuffle: Option<BucketShuffle>,
+}
+
+/// Shuffle the items of each bucket in a pool of multiple batches.
+struct BucketShuffle {
+    rng: spin::Mutex<StdRng>,
+    num_batches: usize,
+}
+
+impl<I> BucketBatchStrategy<I> {
+    /// Creates a new strategy to batch items of similar lengths with a fixed batch size.
+    ///
+    /// # Arguments
+    ///
+    /// * `batch_size` - The batch size.
+    /// * `boundaries` - The increasing length boundaries between the buckets.
+    /// * `length` - The function returning the length of an item.
+    ///
+    /// # Returns
+    ///
+    /// The strategy.
+    pub fn new<F>(batch_size: usize, boundaries: Vec<usize>, length: F) -> Self
+    where
+        F: Fn(&I) -> usize + Send + Sync + 'static,
+    {
+        Self::with_length(batch_size, boundaries, Arc::new(length))
+    }
+
+    fn with_length(
+        batch_size: usize,
+        boundaries: Vec<usize>,
+        length: Arc<dyn Fn(&I) -> usize + Send + Sync>,
+    ) -> Self {
+        assert!(
+            boundaries.windows(2).all(|pair| pair[0] < pair[1]),
+            "The bucket boundaries should be strictly increasing, got {boundaries:?}"
+        );
+
+        Self {
+            buckets: (0..=boundaries.len()).map(|_| Vec::new()).collect(),
+            boundaries,
+            batch_size,
+            length,
+            shuffle: None,
+        }
+    }
+
+    /// Shuffles the items within each bucket.
+    ///
+    /// Each bucket keeps a pool of `num_batches` batches of items, and a batch is randomly drawn
+    /// from the pool once it is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed.
+    /// * `num_batches` - The number of batches in the pool of each bucket.
+    ///
+    /// # Returns
+    ///
+    /// The strategy.
+    pub fn with_shuffle(self, seed: u64, num_batches: usize) -> Self {
+        self.with_bucket_shuffle(StdRng::seed_from_u64(seed), num_batches)
+    }
+
+    fn with_bucket_shuffle(mut self, rng: StdRng, num_batches: usize) -> Self {
+        self.shuffle = Some(BucketShuffle {
+            rng: spin::Mutex::new(rng),
+            num_batches: num_batches.max(1),
+        });
+        self
+    }
+
+    fn capacity(&self) -> usize {
+        match &self.shuffle {
+            Some(shuffle) => self.batch_size * shuffle.num_batches,
+            None => self.batch_size,
+        }
+    }
+
+    fn take_batch(&mut self, bucket: usize) -> Vec<I> {
+        let items = &mut self.buckets[bucket];
+
+        match &mut self.shuffle {
+            Some(shuffle) => {
+                items.shuffle(shuffle.rng.get_mut());
+                let start = items.len().saturating_sub(self.batch_size);
+                items.split_off(start)
+            }
+            None => {
+                let end = usize::min(self.batch_size, items.len());
+                items.drain(..end).collect()
+            }
+        }
+    }
+}
+
+impl<I: Send + Sync + 'static> BatchStrategy<I> for BucketBatchStrategy<I> {
+    fn add(&mut self, item: I) {
+        let length = (self.length)(&item);
+        let bucket = self
+            .boundaries
+            .partition_point(|boundary| *boundary <= length);
+
+        self.buckets[bucket].push(item);
+    }
+
+    fn batch(&mut self, force: bool) -> Option<Vec<I>> {
+        let capacity = self.capacity();
+        let bucket = match force {
+            // Flush the remaining items one bucket at a time.
+            true => self.buckets.iter().position(|items| !items.is_empty()),
+            false => self
+                .buckets
+                .iter()
+                .position(|items| items.len() >= capacity),
+        }?;
+
+        Some(self.take_batch(bucket))
+    }
+
+    fn new_like(&self) -> Box<dyn BatchStrategy<I>> {
+        let strategy = Self::with_length(
+            self.batch_size,
+            self.boundaries.clone(),
+            self.length.clone(),
+        );
+
+        match &self.shuffle {
+            Some(shuffle) => {
+                let rng = StdRng::seed_from_u64(shuffle.rng.lock().gen());
+                Box::new(strategy.with_bucket_shuffle(rng, shuffle.num_batches))
+            }
+            None => Box::new(strategy),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::dataloader::{batcher::TestBatcher, DataLoaderBuilder};
+    use crate::data::dataset::InMemDataset;
+
+    const BOUNDARIES: [usize; 3] = [4, 8, 16];
+
+    fn bucket(length: usize) -> usize {
+        BOUNDARIES.partition_point(|boundary| *boundary <= length)
+    }
+
+    fn sequences() -> Vec<Vec<u8>> {
+        // Sequences of lengths 1 to 24, in an interleaved order.
+        (0..24).map(|i| vec![0; (i * 7) % 24 + 1]).collect()
+    }
+
+    fn assert_batches_within_buckets(batches: &[Vec<Vec<u8>>], batch_size: usize) {
+        let mut num_items = 0;
+
+        for batch in batches {
+            assert!(!batch.is_empty() && batch.len() <= batch_size);
+            let first = bucket(batch[0].len());
+            for item in batch {
+                assert_eq!(bucket(item.len()), first, "Batch mixing buckets: {batch:?}");
+            }
+            num_items += batch.len();
+        }
+
+        assert_eq!(num_items, sequences().len());
+    }
+
+    #[test]
+    fn bucket_batch_strategy_should_batch_items_of_the_same_bucket() {
+        let dataloader = DataLoaderBuilder::new(TestBatcher::new())
+            .batch_strategy(BucketBatchStrategy::new(
+                3,
+                BOUNDARIES.to_vec(),
+                |item: &Vec<u8>| item.len(),
+            ))
+            .shuffle(42)
+            .build(InMemDataset::new(sequences()));
+
+        let batches = dataloader.iter().collect::<Vec<_>>();
+
+        assert_batches_within_buckets(&batches, 3);
+    }
+
+    #[test]
+    fn bucket_batch_strategy_should_batch_items_of_the_same_bucket_when_shuffled() {
+        let mut strategy =
+            BucketBatchStrategy::new(3, BOUNDARIES.to_vec(), |item: &Vec<u8>| item.len())
+                .with_shuffle(42, 2);
+        let mut batches = Vec::new();
+
+        for item in sequences() {
+            strategy.add(item);
+            if let Some(batch) = strategy.batch(false) {
+                // A batch is only drawn from the pool of a bucket once it is full.
+                assert_eq!(batch.len(), 3);
+                batches.push(batch);
+            }
+        }
+        while let Some(batch) = strategy.batch(true) {
+            batches.push(batch);
+        }
+
+        assert_batches_within_buckets(&batches, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bucket_batch_strategy_should_panic_with_unsorted_boundaries() {
+        BucketBatchStrategy::new(3, vec![8, 4], |item: &Vec<u8>| item.len());
+    }
+}
@@ -2,6 +2,9 @@
 #[cfg(feature = "dataset")]
 pub mod dataloader;
 
+/// Data augmentation module.
+pub mod transform;
+
 /// Dataset module.
 #[cfg(feature = "dataset")]
 pub mod dataset {
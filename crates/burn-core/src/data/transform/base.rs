@@ -0,0 +1,169 @@
+use crate::tensor::{backend::Backend, Tensor};
+use rand::{rngs::StdRng, SeedableRng};
+
+/// A transformation of an image, like a data augmentation.
+///
+/// The transformations can be composed with [Compose](Compose), and applied on the fly in a
+/// [batcher](crate::data::dataloader::batcher::Batcher) with an [Augmentation](Augmentation), so
+/// each dataloader worker augments the items when they are batched.
+pub trait Transform<B: Backend>: Send + Sync {
+    /// Applies the transformation on an image.
+    ///
+    /// The random transformations draw their parameters from the given random number generator,
+    /// so the results are reproducible with a seeded one.
+    ///
+    /// # Shapes
+    ///
+    /// - image: `[channels, height, width]`
+    fn apply(&self, image: Tensor<B, 3>, rng: &mut StdRng) -> Tensor<B, 3>;
+}
+
+/// Applies multiple transformations in sequence.
+pub struct Compose<B: Backend> {
+    transforms: Vec<Box<dyn Transform<B>>>,
+}
+
+impl<B: Backend> Compose<B> {
+    /// Creates a new composition without any transformation.
+    pub fn new() -> Self {
+        Self {
+            transforms: Vec::new(),
+        }
+    }
+
+    /// Adds a transformation, applied after the previous ones.
+    pub fn with<T: Transform<B> + 'static>(mut self, transform: T) -> Self {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+}
+
+impl<B: Backend> Default for Compose<B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B: Backend> Transform<B> for Compose<B> {
+    fn apply(&self, image: Tensor<B, 3>, rng: &mut StdRng) -> Tensor<B, 3> {
+        self.transforms
+            .iter()
+            .fold(image, |image, transform| transform.apply(image, rng))
+    }
+}
+
+/// A transformation with its own random number generator, to augment the images in a batcher.
+///
+/// With a seed, the sequence of augmentations is reproducible as long as the images are augmented
+/// in the same order, e.g. with a single dataloader worker.
+pub struct Augmentation<B: Backend> {
+    transform: Box<dyn Transform<B>>,
+    rng: spin::Mutex<StdRng>,
+}
+
+impl<B: Backend> Augmentation<B> {
+    /// Creates a new augmentation with a random seed.
+    pub fn new<T: Transform<B> + 'static>(transform: T) -> Self {
+        Self::with_rng(transform, StdRng::from_entropy())
+    }
+
+    /// Creates a new augmentation with a fixed seed.
+    pub fn with_seed<T: Transform<B> + 'static>(transform: T, seed: u64) -> Self {
+        Self::with_rng(transform, StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng<T: Transform<B> + 'static>(transform: T, rng: StdRng) -> Self {
+        Self {
+            transform: Box::new(transform),
+            rng: spin::Mutex::new(rng),
+        }
+    }
+
+    /// Augments an image.
+    ///
+    /// # Shapes
+    ///
+    /// - image: `[channels, height, width]`
+    pub fn augment(&self, image: Tensor<B, 3>) -> Tensor<B, 3> {
+        let mut rng = self.rng.lock();
+        self.transform.apply(image, &mut rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::transform::{Normalize, RandomCrop, RandomHorizontalFlip, RandomRotation};
+    use crate::tensor::{Distribution, Int};
+    use crate::TestBackend;
+
+    fn augmentation(seed: u64) -> Augmentation<TestBackend> {
+        let transform = Compose::new()
+            .with(RandomHorizontalFlip::new())
+            .with(RandomRotation::new(30.0))
+            .with(RandomCrop::new([4, 4]))
+            .with(Normalize::new(vec![0.5, 0.5, 0.5], vec![0.2, 0.2, 0.2]));
+
+        Augmentation::with_seed(transform, seed)
+    }
+
+    fn images() -> Vec<Tensor<TestBackend, 3>> {
+        let device = Default::default();
+        TestBackend::seed(0);
+
+        (0..8)
+            .map(|_| Tensor::random([3, 6, 6], Distribution::Default, &device))
+            .collect()
+    }
+
+    #[test]
+    fn augmentation_with_same_seed_should_be_reproducible() {
+        let images = images();
+        let (augmentation_1, augmentation_2) = (augmentation(42), augmentation(42));
+
+        for image in images {
+            let augmented_1 = augmentation_1.augment(image.clone());
+            let augmented_2 = augmentation_2.augment(image);
+
+            assert_eq!(augmented_1.dims(), [3, 4, 4]);
+            assert_eq!(augmented_1.into_data(), augmented_2.into_data());
+        }
+    }
+
+    #[test]
+    fn augmentation_with_different_seeds_should_differ() {
+        let images = images();
+        let (augmentation_1, augmentation_2) = (augmentation(1), augmentation(2));
+
+        let augmented_1 = images
+            .iter()
+            .map(|image| augmentation_1.augment(image.clone()))
+            .collect();
+        let augmented_2 = images
+            .into_iter()
+            .map(|image| augmentation_2.augment(image))
+            .collect();
+
+        let augmented_1 = Tensor::<TestBackend, 3>::cat(augmented_1, 0).into_data();
+        let augmented_2 = Tensor::<TestBackend, 3>::cat(augmented_2, 0).into_data();
+        assert_ne!(augmented_1, augmented_2);
+    }
+
+    #[test]
+    fn compose_should_apply_the_transforms_in_order() {
+        let device = Default::default();
+        let image = Tensor::<TestBackend, 1, Int>::arange(0..9, &device)
+            .float()
+            .reshape([1, 3, 3]);
+        let transform = Compose::<TestBackend>::new()
+            .with(RandomHorizontalFlip::new().with_probability(1.0))
+            .with(RandomCrop::new([1, 3]));
+
+        let output = transform.apply(image, &mut StdRng::seed_from_u64(0));
+
+        // The crop keeps one of the flipped rows.
+        let row = output.into_data().value;
+        let flipped_rows: [[f32; 3]; 3] = [[2.0, 1.0, 0.0], [5.0, 4.0, 3.0], [8.0, 7.0, 6.0]];
+        assert!(flipped_rows.iter().any(|expected| row == expected));
+    }
+}
@@ -0,0 +1,70 @@
+use super::Transform;
+use crate::tensor::{backend::Backend, Tensor};
+use rand::{rngs::StdRng, Rng};
+
+/// Crops the images at a random position.
+#[derive(new, Debug, Clone)]
+pub struct RandomCrop {
+    /// The size of the cropped images, `[height, width]`.
+    pub size: [usize; 2],
+}
+
+impl<B: Backend> Transform<B> for RandomCrop {
+    fn apply(&self, image: Tensor<B, 3>, rng: &mut StdRng) -> Tensor<B, 3> {
+        let [channels, height, width] = image.dims();
+        let [crop_height, crop_width] = self.size;
+
+        assert!(
+            crop_height <= height && crop_width <= width,
+            "Random crop: the crop size {:?} should not be larger than the image size {:?}",
+            self.size,
+            [height, width]
+        );
+
+        let top = rng.gen_range(0..=height - crop_height);
+        let left = rng.gen_range(0..=width - crop_width);
+
+        image.slice([0..channels, top..top + crop_height, left..left + crop_width])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Int;
+    use crate::TestBackend;
+    use rand::SeedableRng;
+
+    #[test]
+    fn random_crop_should_keep_a_window_of_the_image() {
+        let device = Default::default();
+        let image = Tensor::<TestBackend, 1, Int>::arange(0..32, &device)
+            .float()
+            .reshape([2, 4, 4]);
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let cropped = RandomCrop::new([2, 3]).apply(image, &mut rng);
+        assert_eq!(cropped.dims(), [2, 2, 3]);
+
+        // The values of a window are consecutive in each row, and the rows are 4 values apart.
+        let values = cropped.into_data().value;
+        let first = values[0];
+        for channel in 0..2 {
+            for row in 0..2 {
+                for col in 0..3 {
+                    let expected = first + (channel * 16 + row * 4 + col) as f32;
+                    assert_eq!(values[channel * 6 + row * 3 + col], expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn random_crop_should_panic_when_larger_than_the_image() {
+        let device = Default::default();
+        let image = Tensor::<TestBackend, 3>::zeros([1, 2, 2], &device);
+
+        RandomCrop::new([3, 2]).apply(image, &mut StdRng::seed_from_u64(0));
+    }
+}
@@ -0,0 +1,60 @@
+use super::Transform;
+use crate::tensor::{backend::Backend, Tensor};
+use rand::{rngs::StdRng, Rng};
+
+/// Flips the images horizontally with a given probability.
+#[derive(new, Debug, Clone)]
+pub struct RandomHorizontalFlip {
+    /// The probability to flip an image.
+    #[new(value = "0.5")]
+    pub probability: f64,
+}
+
+impl RandomHorizontalFlip {
+    /// Sets the probability to flip an image, which is 0.5 by default.
+    pub fn with_probability(mut self, probability: f64) -> Self {
+        self.probability = probability;
+        self
+    }
+}
+
+impl<B: Backend> Transform<B> for RandomHorizontalFlip {
+    fn apply(&self, image: Tensor<B, 3>, rng: &mut StdRng) -> Tensor<B, 3> {
+        match rng.gen_bool(self.probability) {
+            true => image.flip([2]),
+            false => image,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+    use rand::SeedableRng;
+
+    #[test]
+    fn random_horizontal_flip_should_flip_the_width() {
+        let device = Default::default();
+        let image =
+            Tensor::<TestBackend, 3>::from_floats([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]], &device);
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let flipped = RandomHorizontalFlip::new()
+            .with_probability(1.0)
+            .apply(image.clone(), &mut rng);
+        let kept = RandomHorizontalFlip::new()
+            .with_probability(0.0)
+            .apply(image, &mut rng);
+
+        assert_eq!(
+            flipped.into_data(),
+            Data::from([[[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]]])
+        );
+        assert_eq!(
+            kept.into_data(),
+            Data::from([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]])
+        );
+    }
+}
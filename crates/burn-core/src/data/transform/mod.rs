@@ -0,0 +1,11 @@
+mod base;
+mod crop;
+mod flip;
+mod normalize;
+mod rotation;
+
+pub use base::*;
+pub use crop::*;
+pub use flip::*;
+pub use normalize::*;
+pub use rotation::*;
@@ -0,0 +1,58 @@
+use super::Transform;
+use crate::tensor::{backend::Backend, Tensor};
+use rand::rngs::StdRng;
+
+/// Normalizes each channel of the images with a mean and a standard deviation.
+///
+/// `output[channel] = (input[channel] - mean[channel]) / std[channel]`
+#[derive(new, Debug, Clone)]
+pub struct Normalize {
+    /// The mean of each channel.
+    pub mean: Vec<f32>,
+    /// The standard deviation of each channel.
+    pub std: Vec<f32>,
+}
+
+impl<B: Backend> Transform<B> for Normalize {
+    fn apply(&self, image: Tensor<B, 3>, _rng: &mut StdRng) -> Tensor<B, 3> {
+        let [channels, _, _] = image.dims();
+        let device = image.device();
+
+        assert!(
+            self.mean.len() == channels && self.std.len() == channels,
+            "Normalize: expected a mean and a standard deviation for each of the {channels} channels"
+        );
+
+        let mean =
+            Tensor::<B, 1>::from_floats(self.mean.as_slice(), &device).reshape([channels, 1, 1]);
+        let std =
+            Tensor::<B, 1>::from_floats(self.std.as_slice(), &device).reshape([channels, 1, 1]);
+
+        (image - mean) / std
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor::Data;
+    use crate::TestBackend;
+    use rand::SeedableRng;
+
+    #[test]
+    fn normalize_should_use_the_statistics_of_each_channel() {
+        let device = Default::default();
+        let image = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 2.0], [3.0, 4.0]], [[1.0, 2.0], [3.0, 4.0]]],
+            &device,
+        );
+
+        let output = Normalize::new(vec![1.0, 2.0], vec![1.0, 0.5])
+            .apply(image, &mut StdRng::seed_from_u64(0));
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[0.0, 1.0], [2.0, 3.0]], [[-2.0, 0.0], [2.0, 4.0]]]),
+            3,
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use super::Transform;
+use crate::tensor::{backend::Backend, Data, Int, Shape, Tensor};
+use rand::{rngs::StdRng, Rng};
+
+/// Rotates the images around their center by a random angle.
+///
+/// The angle is drawn uniformly in `[-degrees, degrees]`, the rotated pixels are the nearest ones
+/// of the input and the pixels outside of the input are filled with zeros.
+#[derive(new, Debug, Clone)]
+pub struct RandomRotation {
+    /// The maximum angle of the rotation in degrees.
+    pub degrees: f64,
+}
+
+impl<B: Backend> Transform<B> for RandomRotation {
+    fn apply(&self, image: Tensor<B, 3>, rng: &mut StdRng) -> Tensor<B, 3> {
+        let angle = match self.degrees > 0.0 {
+            true => rng.gen_range(-self.degrees..=self.degrees),
+            false => 0.0,
+        };
+
+        rotate(image, angle)
+    }
+}
+
+/// Rotates an image counterclockwise by an angle in degrees.
+fn rotate<B: Backend>(image: Tensor<B, 3>, angle: f64) -> Tensor<B, 3> {
+    let [channels, height, width] = image.dims();
+    let device = image.device();
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let (center_y, center_x) = ((height as f64 - 1.0) / 2.0, (width as f64 - 1.0) / 2.0);
+
+    // The index of the input pixel of each output pixel, where the index after the last pixel is
+    // a zero padding.
+    let padding = (height * width) as i32;
+    let mut indices = Vec::with_capacity(height * width);
+    for y in 0..height {
+        for x in 0..width {
+            let (dy, dx) = (y as f64 - center_y, x as f64 - center_x);
+            let source_x = (cos * dx - sin * dy + center_x).round();
+            let source_y = (sin * dx + cos * dy + center_y).round();
+
+            let inside =
+                (0.0..width as f64).contains(&source_x) && (0.0..height as f64).contains(&source_y);
+            indices.push(match inside {
+                true => source_y as i32 * width as i32 + source_x as i32,
+                false => padding,
+            });
+        }
+    }
+    let indices =
+        Tensor::<B, 1, Int>::from_ints(Data::new(indices, Shape::new([height * width])), &device);
+
+    let pixels = Tensor::cat(
+        vec![
+            image.reshape([channels, height * width]),
+            Tensor::zeros([channels, 1], &device),
+        ],
+        1,
+    );
+
+    pixels.select(1, indices).reshape([channels, height, width])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use rand::SeedableRng;
+
+    fn image() -> Tensor<TestBackend, 3> {
+        let device = Default::default();
+        Tensor::<TestBackend, 1, Int>::arange(1..10, &device)
+            .float()
+            .reshape([1, 3, 3])
+    }
+
+    #[test]
+    fn rotate_should_turn_a_quarter_counterclockwise() {
+        let rotated = rotate(image(), 90.0);
+
+        assert_eq!(
+            rotated.into_data(),
+            Data::from([[[3.0, 6.0, 9.0], [2.0, 5.0, 8.0], [1.0, 4.0, 7.0]]])
+        );
+    }
+
+    #[test]
+    fn rotate_should_fill_the_outside_with_zeros() {
+        let device = Default::default();
+        let image = Tensor::<TestBackend, 1, Int>::arange(1..26, &device)
+            .float()
+            .reshape([1, 5, 5]);
+
+        let rotated = rotate(image, 45.0);
+
+        // The corners come from outside of the input, the center is kept.
+        let values = rotated.into_data().value;
+        assert_eq!([values[0], values[4], values[20], values[24]], [0.0; 4]);
+        assert_eq!(values[12], 13.0);
+    }
+
+    #[test]
+    fn random_rotation_without_angle_should_keep_the_image() {
+        let rotated = RandomRotation::new(0.0).apply(image(), &mut StdRng::seed_from_u64(0));
+
+        assert_eq!(rotated.into_data(), image().into_data());
+    }
+}
@@ -1,7 +1,13 @@
 use crate as burn;
 
-use crate::{config::Config, tensor::Tensor};
-use burn_tensor::backend::Backend;
+use crate::{
+    config::Config,
+    module::{AutodiffModule, ModuleVisitor, ParamId},
+    optim::GradientsParams,
+    tensor::Tensor,
+};
+use burn_tensor::backend::{AutodiffBackend, Backend};
+use core::marker::PhantomData;
 
 /// Gradient Clipping provides a way to mitigate exploding gradients
 #[derive(Config)]
@@ -11,6 +17,9 @@ pub enum GradientClippingConfig {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients by the global norm computed over all parameters.
+    GlobalNorm(f32),
 }
 
 impl GradientClippingConfig {
@@ -23,6 +32,7 @@ impl GradientClippingConfig {
         match self {
             GradientClippingConfig::Value(val) => GradientClipping::Value(*val),
             GradientClippingConfig::Norm(val) => GradientClipping::Norm(*val),
+            GradientClippingConfig::GlobalNorm(val) => GradientClipping::GlobalNorm(*val),
         }
     }
 }
@@ -36,6 +46,13 @@ pub enum GradientClipping {
 
     /// Clip the gradient by norm.
     Norm(f32),
+
+    /// Clip the gradients by the global norm.
+    ///
+    /// The L2 norm is computed over the gradients of all parameters, as if they were concatenated
+    /// into a single vector. When it exceeds the maximum norm, every gradient is scaled by the
+    /// same factor, which preserves the direction of the update.
+    GlobalNorm(f32),
 }
 
 impl GradientClipping {
@@ -48,13 +65,73 @@ impl GradientClipping {
     /// # Returns
     ///
     /// The clipped gradient.
+    ///
+    /// # Notes
+    ///
+    /// With [global norm](GradientClipping::GlobalNorm) clipping, the given gradient is considered
+    /// to be the only one. Use [clip_gradients](GradientClipping::clip_gradients) to clip the
+    /// gradients of a whole module.
     pub fn clip_gradient<B: Backend, const D: usize>(&self, grad: Tensor<B, D>) -> Tensor<B, D> {
         match self {
             GradientClipping::Value(threshold) => self.clip_by_value(grad, *threshold),
             GradientClipping::Norm(max_norm) => self.clip_by_norm(grad, *max_norm),
+            GradientClipping::GlobalNorm(max_norm) => self.clip_by_norm(grad, *max_norm),
         }
     }
 
+    /// Clip the gradients of every parameter of the given module.
+    ///
+    /// # Arguments
+    ///
+    /// * `module` - The module the gradients belong to.
+    /// * `grads` - The gradients to clip.
+    ///
+    /// # Returns
+    ///
+    /// The clipped gradients.
+    pub fn clip_gradients<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        mut grads: GradientsParams,
+    ) -> GradientsParams {
+        let scale = match self {
+            GradientClipping::GlobalNorm(max_norm) => {
+                let global_norm = Self::global_norm(module, &grads);
+
+                if global_norm <= *max_norm {
+                    return grads;
+                }
+
+                Some(max_norm / global_norm)
+            }
+            _ => None,
+        };
+
+        let mut visitor = GradientsClipper::<M, B>::new(self, scale, &mut grads);
+        module.visit(&mut visitor);
+
+        grads
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    fn global_norm<B: AutodiffBackend, M: AutodiffModule<B>>(
+        _module: &M,
+        _grads: &GradientsParams,
+    ) -> f32 {
+        todo!("Not yet supported on wasm");
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn global_norm<B: AutodiffBackend, M: AutodiffModule<B>>(
+        module: &M,
+        grads: &GradientsParams,
+    ) -> f32 {
+        let mut visitor = GradientsSquaredNorm::<M, B>::new(grads, 0.0);
+        module.visit(&mut visitor);
+
+        libm::sqrtf(visitor.squared_norm)
+    }
+
     fn clip_by_value<B: Backend, const D: usize>(
         &self,
         grad: Tensor<B, D>,
@@ -105,10 +182,60 @@ impl GradientClipping {
     }
 }
 
+#[derive(new)]
+struct GradientsClipper<'a, M, B> {
+    clipping: &'a GradientClipping,
+    scale: Option<f32>,
+    grads: &'a mut GradientsParams,
+    phantom: PhantomData<(M, B)>,
+}
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsClipper<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            let grad = match self.scale {
+                Some(scale) => grad.mul_scalar(scale),
+                None => self.clipping.clip_gradient(grad),
+            };
+
+            self.grads.register::<B::InnerBackend, D>(id.clone(), grad);
+        }
+    }
+}
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+#[derive(new)]
+struct GradientsSquaredNorm<'a, M, B> {
+    grads: &'a GradientsParams,
+    squared_norm: f32,
+    phantom: PhantomData<(M, B)>,
+}
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+impl<'a, B, M> ModuleVisitor<B> for GradientsSquaredNorm<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        use burn_tensor::ElementConversion;
+
+        if let Some(grad) = self.grads.get::<B::InnerBackend, D>(id) {
+            let squared_norm = grad.powf_scalar(2.0).sum().into_scalar().elem::<f32>();
+            self.squared_norm += squared_norm;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tensor::Tensor;
+    use crate::optim::testing::{given_linear_grads, given_linear_layer};
+    use crate::tensor::{Data, Tensor};
     use crate::TestBackend;
 
     #[test]
@@ -146,4 +273,50 @@ mod tests {
             assert!(value <= 0.88);
         }
     }
+
+    #[test]
+    fn test_clip_by_global_norm() {
+        let layer = given_linear_layer(Data::from([[1.0], [1.0]]), Some(Data::from([0.0])));
+        let grads =
+            given_linear_grads(&layer, Data::from([[3.0], [4.0]]), Some(Data::from([12.0])));
+
+        // The global norm is sqrt(3² + 4² + 12²) = 13, gradients are scaled by 6.5 / 13.
+        let grads = GradientClipping::GlobalNorm(6.5).clip_gradients(&layer, grads);
+
+        let grad_weight = grads
+            .get::<TestBackend, 2>(&layer.weight.id)
+            .unwrap()
+            .into_data();
+        let grad_bias = grads
+            .get::<TestBackend, 1>(&layer.bias.as_ref().unwrap().id)
+            .unwrap()
+            .into_data();
+
+        grad_weight.assert_approx_eq(&Data::from([[1.5], [2.0]]), 5);
+        grad_bias.assert_approx_eq(&Data::from([6.0]), 5);
+
+        let global_norm = GradientClipping::global_norm(&layer, &grads);
+        assert!((global_norm - 6.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_clip_by_global_norm_below_max() {
+        let layer = given_linear_layer(Data::from([[1.0], [1.0]]), Some(Data::from([0.0])));
+        let grads =
+            given_linear_grads(&layer, Data::from([[3.0], [4.0]]), Some(Data::from([12.0])));
+
+        let grads = GradientClipping::GlobalNorm(13.5).clip_gradients(&layer, grads);
+
+        let grad_weight = grads
+            .get::<TestBackend, 2>(&layer.weight.id)
+            .unwrap()
+            .into_data();
+        let grad_bias = grads
+            .get::<TestBackend, 1>(&layer.bias.as_ref().unwrap().id)
+            .unwrap()
+            .into_data();
+
+        grad_weight.assert_approx_eq(&Data::from([[3.0], [4.0]]), 5);
+        grad_bias.assert_approx_eq(&Data::from([12.0]), 5);
+    }
 }
@@ -17,3 +17,11 @@ pub trait LrScheduler<B: Backend>: Send + Sync {
     /// Load the state of the scheduler as a [record](Record).
     fn load_record(self, record: Self::Record) -> Self;
 }
+
+/// Learning rate scheduler driven by a monitored metric, such as the validation loss, instead of
+/// the number of iterations.
+pub trait MetricLrScheduler<B: Backend>: LrScheduler<B> {
+    /// Observe the latest value of the monitored metric, usually at the end of an epoch, and
+    /// return the possibly updated learning rate.
+    fn observe(&mut self, metric: f64) -> LearningRate;
+}
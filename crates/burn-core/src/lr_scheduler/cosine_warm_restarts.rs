@@ -0,0 +1,187 @@
+use super::LrScheduler;
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The configuration for creating a Cosine Annealing learning rate scheduler with warm restarts,
+/// as described in [SGDR: Stochastic Gradient Descent with Warm Restarts](https://arxiv.org/abs/1608.03983).
+///
+/// This scheduler starts at a learning rate `initial_lr`, then decreases it towards `min_lr` by
+/// following a cosine function over a cycle of `t_0` iterations. At the end of each cycle, the
+/// learning rate is reset to `initial_lr` and the length of the next cycle is multiplied by
+/// `t_mult`.
+#[derive(Config)]
+pub struct CosineAnnealingWarmRestartsLrSchedulerConfig {
+    // The initial learning rate.
+    initial_lr: LearningRate,
+    // The number of iterations of the first cycle.
+    t_0: usize,
+    // The factor by which the number of iterations grows after each restart.
+    #[config(default = 1)]
+    t_mult: usize,
+    // The final learning rate of each cycle.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+}
+
+impl CosineAnnealingWarmRestartsLrSchedulerConfig {
+    /// Initializes a [Cosine learning rate scheduler with warm restarts](CosineAnnealingWarmRestartsLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `initial_lr` is not between 0 and 1, if `min_lr` is not between 0
+    /// and `initial_lr`, or if `t_0` or `t_mult` is 0.
+    pub fn init(&self) -> CosineAnnealingWarmRestartsLrScheduler {
+        assert!(
+            self.initial_lr > 0. && self.initial_lr <= 1.,
+            "Initial learning rate must be greater than 0 and at most 1"
+        );
+        assert!(
+            self.min_lr >= 0.0 && self.min_lr <= self.initial_lr,
+            "Minimum learning rate must be at least 0 and at most equal to the initial learning rate"
+        );
+        assert!(self.t_0 > 0, "Number of iterations must be at least 1");
+        assert!(
+            self.t_mult > 0,
+            "Cycle length multiplier must be at least 1"
+        );
+
+        CosineAnnealingWarmRestartsLrScheduler {
+            min_lr: self.min_lr,
+            max_lr: self.initial_lr,
+            t_mult: self.t_mult,
+            cycle_iters: self.t_0,
+            current_iter: 0,
+        }
+    }
+}
+
+/// A Cosine Annealing learning rate scheduler with warm restarts.
+///
+/// See [CosineAnnealingWarmRestartsLrSchedulerConfig] for more information.
+#[derive(Clone, Copy, Debug)]
+pub struct CosineAnnealingWarmRestartsLrScheduler {
+    min_lr: LearningRate,
+    max_lr: LearningRate,
+    t_mult: usize,
+    // The number of iterations of the current cycle.
+    cycle_iters: usize,
+    // The position in the current cycle.
+    current_iter: usize,
+}
+
+impl<B: Backend> LrScheduler<B> for CosineAnnealingWarmRestartsLrScheduler {
+    /// The number of iterations of the current cycle and the position in that cycle.
+    type Record = (usize, usize);
+
+    fn step(&mut self) -> LearningRate {
+        let progress = self.current_iter as f64 / self.cycle_iters as f64;
+        let lr = self.min_lr
+            + 0.5
+                * (self.max_lr - self.min_lr)
+                * (1.0 + libm::cos(progress * core::f64::consts::PI));
+
+        self.current_iter += 1;
+        if self.current_iter == self.cycle_iters {
+            self.current_iter = 0;
+            self.cycle_iters *= self.t_mult;
+        }
+
+        lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (self.cycle_iters, self.current_iter)
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        (self.cycle_iters, self.current_iter) = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Initial learning rate must be greater than 0 and at most 1"]
+    fn config_initial_lr_too_high() {
+        CosineAnnealingWarmRestartsLrSchedulerConfig::new(1.5, 10).init();
+    }
+
+    #[test]
+    #[should_panic = "Minimum learning rate must be at least 0 and at most equal to the initial learning rate"]
+    fn config_min_lr_too_high() {
+        CosineAnnealingWarmRestartsLrSchedulerConfig::new(0.5, 10)
+            .with_min_lr(0.6)
+            .init();
+    }
+
+    #[test]
+    #[should_panic = "Number of iterations must be at least 1"]
+    fn config_t_0_too_low() {
+        CosineAnnealingWarmRestartsLrSchedulerConfig::new(0.5, 0).init();
+    }
+
+    #[test]
+    #[should_panic = "Cycle length multiplier must be at least 1"]
+    fn config_t_mult_too_low() {
+        CosineAnnealingWarmRestartsLrSchedulerConfig::new(0.5, 10)
+            .with_t_mult(0)
+            .init();
+    }
+
+    #[test]
+    fn test_lr_over_several_restarts() {
+        const INITIAL_LR: LearningRate = 0.5;
+        const MIN_LR: LearningRate = 0.1;
+
+        let mut scheduler = CosineAnnealingWarmRestartsLrSchedulerConfig::new(INITIAL_LR, 2)
+            .with_t_mult(2)
+            .with_min_lr(MIN_LR)
+            .init();
+
+        let mut lrs = [0.0; 15];
+        for lr in lrs.iter_mut() {
+            *lr = LrScheduler::<TestBackend>::step(&mut scheduler);
+        }
+
+        // The cycles last 2, 4 and 8 iterations.
+        let restarts = lrs
+            .iter()
+            .enumerate()
+            .filter(|(_, lr)| **lr == INITIAL_LR)
+            .map(|(i, _)| i);
+        assert!(restarts.eq([0, 2, 6, 14]));
+
+        // The learning rate follows a cosine within each cycle.
+        let expected = [0.5, 0.3, 0.5, 0.441421, 0.3, 0.158579, 0.5];
+        for (lr, expected) in lrs.iter().zip(expected) {
+            assert!((lr - expected).abs() < 1e-6, "{lr} != {expected}");
+        }
+        for lrs in lrs[6..14].windows(2) {
+            assert!(lrs[1] < lrs[0] && lrs[1] > MIN_LR);
+        }
+    }
+
+    #[test]
+    fn test_lr_resumes_from_record() {
+        let config = CosineAnnealingWarmRestartsLrSchedulerConfig::new(0.5, 3).with_t_mult(2);
+        let mut scheduler = config.init();
+
+        for _ in 0..5 {
+            LrScheduler::<TestBackend>::step(&mut scheduler);
+        }
+
+        let record = LrScheduler::<TestBackend>::to_record(&scheduler);
+        let mut resumed = LrScheduler::<TestBackend>::load_record(config.init(), record);
+
+        for _ in 0..10 {
+            assert_eq!(
+                LrScheduler::<TestBackend>::step(&mut resumed),
+                LrScheduler::<TestBackend>::step(&mut scheduler),
+            );
+        }
+    }
+}
@@ -7,12 +7,21 @@ pub mod linear;
 /// Noam learning rate scheduler
 pub mod noam;
 
+/// One cycle learning rate scheduler
+pub mod one_cycle;
+
+/// Learning rate scheduler reducing the learning rate when a metric stops improving
+pub mod reduce_on_plateau;
+
 /// Exponential learning rate scheduler
 pub mod exponential;
 
 /// Cosine learning rate scheduler
 pub mod cosine;
 
+/// Cosine learning rate scheduler with warm restarts
+pub mod cosine_warm_restarts;
+
 mod base;
 
 pub use base::*;
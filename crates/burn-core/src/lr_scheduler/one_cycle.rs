@@ -0,0 +1,217 @@
+use super::LrScheduler;
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// The strategy used to anneal the learning rate between two values.
+#[derive(Config, Debug, PartialEq)]
+pub enum AnnealStrategy {
+    /// Follow half a cosine period.
+    Cos,
+    /// Change the learning rate by a constant amount on each iteration.
+    Linear,
+}
+
+impl AnnealStrategy {
+    fn anneal(&self, start: LearningRate, end: LearningRate, pct: f64) -> LearningRate {
+        match self {
+            AnnealStrategy::Cos => {
+                end + 0.5 * (start - end) * (1.0 + libm::cos(pct * core::f64::consts::PI))
+            }
+            AnnealStrategy::Linear => start + (end - start) * pct,
+        }
+    }
+}
+
+/// The configuration for creating a one cycle learning rate scheduler, as described in
+/// [Super-Convergence: Very Fast Training of Neural Networks Using Large Learning Rates](https://arxiv.org/abs/1708.07120).
+///
+/// This scheduler starts at a learning rate `max_lr / div_factor` and warms up to `max_lr` during the
+/// first `pct_start` fraction of the `total_steps` iterations. It then anneals the learning rate
+/// down to `max_lr / (div_factor * final_div_factor)`, which is reached at the last iteration.
+#[derive(Config)]
+pub struct OneCycleLrSchedulerConfig {
+    // The peak learning rate, reached at the end of the warmup.
+    max_lr: LearningRate,
+    // The total number of iterations of the cycle.
+    total_steps: usize,
+    // The fraction of the iterations spent warming up.
+    #[config(default = 0.3)]
+    pct_start: f64,
+    // The strategy used to warm up and anneal the learning rate.
+    #[config(default = "AnnealStrategy::Cos")]
+    anneal_strategy: AnnealStrategy,
+    // The initial learning rate is `max_lr / div_factor`.
+    #[config(default = 25.0)]
+    div_factor: f64,
+    // The final learning rate is the initial learning rate divided by `final_div_factor`.
+    #[config(default = 1e4)]
+    final_div_factor: f64,
+}
+
+impl OneCycleLrSchedulerConfig {
+    /// Initializes a [one cycle learning rate scheduler](OneCycleLrScheduler).
+    ///
+    /// # Panics
+    /// This function panics if `max_lr` is not between 0 and 1, if `total_steps` is 0, if
+    /// `pct_start` is not between 0 and 1, or if a division factor is smaller than 1.
+    pub fn init(&self) -> OneCycleLrScheduler {
+        assert!(
+            self.max_lr > 0. && self.max_lr <= 1.,
+            "Maximum learning rate must be greater than 0 and at most 1"
+        );
+        assert!(self.total_steps > 0, "Total steps must be at least 1");
+        assert!(
+            self.pct_start >= 0. && self.pct_start < 1.,
+            "Warmup fraction must be at least 0 and less than 1"
+        );
+        assert!(
+            self.div_factor >= 1. && self.final_div_factor >= 1.,
+            "Division factors must be at least 1"
+        );
+
+        let initial_lr = self.max_lr / self.div_factor;
+
+        OneCycleLrScheduler {
+            initial_lr,
+            max_lr: self.max_lr,
+            final_lr: initial_lr / self.final_div_factor,
+            warmup_end: self.pct_start * self.total_steps as f64 - 1.0,
+            total_end: self.total_steps as f64 - 1.0,
+            anneal_strategy: self.anneal_strategy.clone(),
+            current_iter: 0,
+        }
+    }
+}
+
+/// A one cycle learning rate scheduler.
+///
+/// See [OneCycleLrSchedulerConfig] for more information.
+#[derive(Clone, Debug)]
+pub struct OneCycleLrScheduler {
+    initial_lr: LearningRate,
+    max_lr: LearningRate,
+    final_lr: LearningRate,
+    // The iteration at which the maximum learning rate is reached.
+    warmup_end: f64,
+    // The iteration at which the final learning rate is reached.
+    total_end: f64,
+    anneal_strategy: AnnealStrategy,
+    current_iter: usize,
+}
+
+impl<B: Backend> LrScheduler<B> for OneCycleLrScheduler {
+    type Record = usize;
+
+    fn step(&mut self) -> LearningRate {
+        let iter = self.current_iter as f64;
+        self.current_iter += 1;
+
+        if iter <= self.warmup_end {
+            let pct = match self.warmup_end > 0.0 {
+                true => iter / self.warmup_end,
+                false => 1.0,
+            };
+            return self
+                .anneal_strategy
+                .anneal(self.initial_lr, self.max_lr, pct);
+        }
+
+        // The final learning rate is kept after the last iteration.
+        let warmup_end = f64::max(self.warmup_end, 0.0);
+        let pct = match self.total_end > warmup_end {
+            true => f64::min((iter - warmup_end) / (self.total_end - warmup_end), 1.0),
+            false => 1.0,
+        };
+        self.anneal_strategy.anneal(self.max_lr, self.final_lr, pct)
+    }
+
+    fn to_record(&self) -> Self::Record {
+        self.current_iter
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        self.current_iter = record;
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TestBackend;
+
+    const MAX_LR: LearningRate = 0.1;
+    const TOTAL_STEPS: usize = 100;
+    // The last warmup iteration, with the default warmup fraction of 0.3.
+    const PEAK_ITER: usize = 29;
+
+    #[test]
+    #[should_panic = "Maximum learning rate must be greater than 0 and at most 1"]
+    fn config_max_lr_too_high() {
+        OneCycleLrSchedulerConfig::new(1.5, 100).init();
+    }
+
+    #[test]
+    #[should_panic = "Warmup fraction must be at least 0 and less than 1"]
+    fn config_pct_start_too_high() {
+        OneCycleLrSchedulerConfig::new(0.1, 100)
+            .with_pct_start(1.0)
+            .init();
+    }
+
+    #[test]
+    fn test_lr_peaks_at_warmup_end_with_cos() {
+        let scheduler = OneCycleLrSchedulerConfig::new(MAX_LR, TOTAL_STEPS).init();
+        assert_cycle(scheduler);
+    }
+
+    #[test]
+    fn test_lr_peaks_at_warmup_end_with_linear() {
+        let scheduler = OneCycleLrSchedulerConfig::new(MAX_LR, TOTAL_STEPS)
+            .with_anneal_strategy(AnnealStrategy::Linear)
+            .init();
+        assert_cycle(scheduler);
+    }
+
+    #[test]
+    fn test_lr_linear_warmup() {
+        let mut scheduler = OneCycleLrSchedulerConfig::new(MAX_LR, 11)
+            .with_pct_start(0.5)
+            .with_anneal_strategy(AnnealStrategy::Linear)
+            .with_div_factor(10.0)
+            .init();
+
+        // Warmup from 0.01 to 0.1 over the iterations 0 to 4.5.
+        for expected in [0.01, 0.03, 0.05, 0.07, 0.09] {
+            let lr = LrScheduler::<TestBackend>::step(&mut scheduler);
+            assert!((lr - expected).abs() < 1e-9, "{lr} != {expected}");
+        }
+    }
+
+    fn assert_cycle(mut scheduler: OneCycleLrScheduler) {
+        let lrs: [LearningRate; TOTAL_STEPS + 10] =
+            core::array::from_fn(|_| LrScheduler::<TestBackend>::step(&mut scheduler));
+
+        assert!((lrs[0] - MAX_LR / 25.0).abs() < 1e-12);
+        assert!((lrs[PEAK_ITER] - MAX_LR).abs() < 1e-12);
+
+        for lrs in lrs[..=PEAK_ITER].windows(2) {
+            assert!(
+                lrs[1] > lrs[0],
+                "Learning rate should increase during warmup"
+            );
+        }
+        for lrs in lrs[PEAK_ITER..TOTAL_STEPS].windows(2) {
+            assert!(
+                lrs[1] < lrs[0],
+                "Learning rate should decrease after warmup"
+            );
+        }
+
+        let final_lr = MAX_LR / 25.0 / 1e4;
+        for lr in &lrs[TOTAL_STEPS - 1..] {
+            assert!((lr - final_lr).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use super::{LrScheduler, MetricLrScheduler};
+use crate as burn;
+use crate::{config::Config, LearningRate};
+use burn_tensor::backend::Backend;
+
+/// Whether the monitored metric improves when it decreases or when it increases.
+#[derive(Config, Debug, PartialEq)]
+pub enum PlateauMode {
+    /// The metric improves when it decreases, like a loss.
+    Min,
+    /// The metric improves when it increases, like an accuracy.
+    Max,
+}
+
+/// The configuration for creating a learning rate scheduler that reduces the learning rate when a
+/// monitored metric stops improving.
+///
+/// The learning rate starts at `initial_lr` and is multiplied by `factor` each time the metric
+/// [observed](MetricLrScheduler::observe) by the scheduler hasn't improved for more than
+/// `patience` epochs, without going below `min_lr`. A value is an improvement when it is better
+/// than the best one by more than the relative `threshold`.
+#[derive(Config)]
+pub struct ReduceLrOnPlateauConfig {
+    /// The initial learning rate.
+    initial_lr: LearningRate,
+    /// The factor by which the learning rate is multiplied when reduced.
+    #[config(default = 0.1)]
+    factor: f64,
+    /// The number of epochs without improvement before the learning rate is reduced.
+    #[config(default = 10)]
+    patience: usize,
+    /// Whether the metric is minimized or maximized.
+    #[config(default = "PlateauMode::Min")]
+    mode: PlateauMode,
+    /// The relative change of the metric required to count as an improvement.
+    #[config(default = 1e-4)]
+    threshold: f64,
+    /// The lower bound of the learning rate.
+    #[config(default = 0.0)]
+    min_lr: LearningRate,
+}
+
+impl ReduceLrOnPlateauConfig {
+    /// Initializes a [reduce on plateau learning rate scheduler](ReduceLrOnPlateau).
+    ///
+    /// # Panics
+    /// This function panics if `initial_lr` is not between 0 and 1, if `factor` is not between
+    /// 0 and 1, or if `threshold` is negative.
+    pub fn init(&self) -> ReduceLrOnPlateau {
+        assert!(
+            self.initial_lr > 0. && self.initial_lr <= 1.,
+            "Initial learning rate must be greater than 0 and at most 1"
+        );
+        assert!(
+            self.factor > 0. && self.factor < 1.,
+            "Factor must be greater than 0 and less than 1"
+        );
+        assert!(self.threshold >= 0., "Threshold must be at least 0");
+
+        ReduceLrOnPlateau {
+            lr: self.initial_lr,
+            factor: self.factor,
+            patience: self.patience,
+            mode: self.mode.clone(),
+            threshold: self.threshold,
+            min_lr: self.min_lr,
+            best: None,
+            num_bad_epochs: 0,
+        }
+    }
+}
+
+/// A learning rate scheduler that reduces the learning rate when a metric stops improving.
+///
+/// Stepping the scheduler returns the current learning rate without changing it, the learning
+/// rate is only updated when a metric is [observed](MetricLrScheduler::observe).
+///
+/// See [ReduceLrOnPlateauConfig] for more information.
+#[derive(Clone, Debug)]
+pub struct ReduceLrOnPlateau {
+    lr: LearningRate,
+    factor: f64,
+    patience: usize,
+    mode: PlateauMode,
+    threshold: f64,
+    min_lr: LearningRate,
+    // The best value of the metric observed so far.
+    best: Option<f64>,
+    // The number of epochs since the last improvement.
+    num_bad_epochs: usize,
+}
+
+impl ReduceLrOnPlateau {
+    fn is_improvement(&self, metric: f64) -> bool {
+        let best = match self.best {
+            Some(best) => best,
+            None => return true,
+        };
+
+        match self.mode {
+            PlateauMode::Min => metric < best - best.abs() * self.threshold,
+            PlateauMode::Max => metric > best + best.abs() * self.threshold,
+        }
+    }
+}
+
+impl<B: Backend> LrScheduler<B> for ReduceLrOnPlateau {
+    type Record = (LearningRate, Option<f64>, usize);
+
+    fn step(&mut self) -> LearningRate {
+        self.lr
+    }
+
+    fn to_record(&self) -> Self::Record {
+        (self.lr, self.best, self.num_bad_epochs)
+    }
+
+    fn load_record(mut self, record: Self::Record) -> Self {
+        (self.lr, self.best, self.num_bad_epochs) = record;
+        self
+    }
+}
+
+impl<B: Backend> MetricLrScheduler<B> for ReduceLrOnPlateau {
+    fn observe(&mut self, metric: f64) -> LearningRate {
+        if self.is_improvement(metric) {
+            self.best = Some(metric);
+            self.num_bad_epochs = 0;
+        } else {
+            self.num_bad_epochs += 1;
+        }
+
+        if self.num_bad_epochs > self.patience {
+            self.lr = f64::max(self.lr * self.factor, self.min_lr);
+            self.num_bad_epochs = 0;
+        }
+
+        self.lr
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    #[should_panic = "Factor must be greater than 0 and less than 1"]
+    fn config_factor_too_high() {
+        ReduceLrOnPlateauConfig::new(0.1).with_factor(1.5).init();
+    }
+
+    #[test]
+    fn test_lr_drops_after_patience() {
+        let mut scheduler = ReduceLrOnPlateauConfig::new(0.1)
+            .with_factor(0.5)
+            .with_patience(2)
+            .init();
+
+        // The loss improves, then plateaus. Changes smaller than the threshold don't count as
+        // improvements.
+        let metrics = [1.0, 0.8, 0.6, 0.6, 0.59999, 0.6, 0.6, 0.6, 0.6];
+        let expected = [0.1, 0.1, 0.1, 0.1, 0.1, 0.05, 0.05, 0.05, 0.025];
+
+        for (metric, expected) in metrics.into_iter().zip(expected) {
+            let lr = MetricLrScheduler::<TestBackend>::observe(&mut scheduler, metric);
+            assert_eq!(lr, expected);
+            assert_eq!(LrScheduler::<TestBackend>::step(&mut scheduler), expected);
+        }
+    }
+
+    #[test]
+    fn test_lr_max_mode_and_min_lr() {
+        let mut scheduler = ReduceLrOnPlateauConfig::new(0.1)
+            .with_patience(0)
+            .with_mode(PlateauMode::Max)
+            .with_min_lr(0.005)
+            .init();
+
+        // Each decrease of the accuracy reduces the learning rate.
+        let metrics = [0.5, 0.6, 0.55, 0.7, 0.65, 0.6];
+        let expected = [0.1, 0.1, 0.01, 0.01, 0.005, 0.005];
+
+        for (metric, expected) in metrics.into_iter().zip(expected) {
+            let lr = MetricLrScheduler::<TestBackend>::observe(&mut scheduler, metric);
+            assert!((lr - expected).abs() < 1e-12, "{lr} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_state_resumes_from_record() {
+        let config = ReduceLrOnPlateauConfig::new(0.1).with_patience(1);
+        let mut scheduler = config.init();
+
+        for metric in [1.0, 1.0] {
+            MetricLrScheduler::<TestBackend>::observe(&mut scheduler, metric);
+        }
+
+        let record = LrScheduler::<TestBackend>::to_record(&scheduler);
+        let mut resumed = LrScheduler::<TestBackend>::load_record(config.init(), record);
+
+        // The second epoch without improvement reduces the learning rate.
+        let lr = MetricLrScheduler::<TestBackend>::observe(&mut resumed, 1.0);
+        assert!((lr - 0.01).abs() < 1e-12);
+    }
+}
@@ -33,10 +33,11 @@ pub struct MultiHeadAttentionConfig {
     /// Reference: <https://www.evanmiller.org/attention-is-off-by-one.html>
     #[config(default = false)]
     quiet_softmax: bool,
-    /// The type of function used to initialize neural network parameters
-    #[config(
-        default = "Initializer::KaimingUniform{gain:1.0/libm::sqrt(3.0), fan_out_only:false}"
-    )]
+    /// The type of function used to initialize neural network parameters.
+    ///
+    /// Defaults to the Xavier uniform initialization commonly used for the projections of
+    /// scaled dot-product attention.
+    #[config(default = "Initializer::XavierUniform{gain:1.0}")]
     pub initializer: Initializer,
 }
 
@@ -70,11 +71,20 @@ pub struct MhaInput<B: Backend> {
     value: Tensor<B, 3>,
     mask_pad: Option<Tensor<B, 2, Bool>>,
     mask_attn: Option<Tensor<B, 3, Bool>>,
+    need_weights: bool,
 }
 
 impl MultiHeadAttentionConfig {
     /// Initialize a new [multihead attention](MultiHeadAttention) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> MultiHeadAttention<B> {
+        assert_eq!(
+            self.d_model % self.n_heads,
+            0,
+            "The model size ({}) should be divisible by the number of heads ({}).",
+            self.d_model,
+            self.n_heads
+        );
+
         let linear = |config: &Self| {
             nn::LinearConfig::new(config.d_model, config.d_model)
                 .with_initializer(self.initializer.clone())
@@ -130,6 +140,7 @@ impl<B: Backend> MhaInput<B> {
             value: tensor,
             mask_pad: None,
             mask_attn: None,
+            need_weights: true,
         }
     }
 
@@ -141,6 +152,7 @@ impl<B: Backend> MhaInput<B> {
             value,
             mask_pad: None,
             mask_attn: None,
+            need_weights: true,
         }
     }
 
@@ -151,17 +163,30 @@ impl<B: Backend> MhaInput<B> {
     }
 
     /// Register the attention mask.
+    ///
+    /// Positions set to `true` are masked, e.g. the future positions of a causal mask created
+    /// with [generate_autoregressive_mask](crate::nn::attention::generate_autoregressive_mask).
     pub fn mask_attn(mut self, mask_attn: Tensor<B, 3, Bool>) -> Self {
         self.mask_attn = Some(mask_attn);
         self
     }
+
+    /// Set if the attention weights should be returned, which is the default.
+    ///
+    /// Disabling it avoids keeping a tensor of `[batch_size, n_heads, seq_length_1, seq_length_2]`
+    /// alive after the forward pass.
+    pub fn need_weights(mut self, need_weights: bool) -> Self {
+        self.need_weights = need_weights;
+        self
+    }
 }
 
 /// [Multihead attention](MultiHeadAttention) outputs.
 #[derive(Debug, Clone)]
 pub struct MhaOutput<B: Backend> {
-    /// The attention weights [batch_size, n_heads, seq_length_1, seq_length_2].
-    pub weights: Tensor<B, 4>,
+    /// The attention weights [batch_size, n_heads, seq_length_1, seq_length_2], unless they
+    /// were [not needed](MhaInput::need_weights).
+    pub weights: Option<Tensor<B, 4>>,
     /// The context tensor [batch_size, seq_length_1, d_model].
     pub context: Tensor<B, 3>,
 }
@@ -186,6 +211,7 @@ impl<B: Backend> MultiHeadAttention<B> {
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
 
         let context = weights.clone().matmul(value);
+        let weights = input.need_weights.then_some(weights);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -219,6 +245,7 @@ impl<B: Backend> MultiHeadAttention<B> {
         let weights = self.attn_weights(attn_scores, input.mask_pad, input.mask_attn);
 
         let context = weights.clone().matmul(value);
+        let weights = input.need_weights.then_some(weights);
         let context = context
             .swap_dims(1, 2)
             .reshape([batch_size, seq_length_1, d_model]);
@@ -334,7 +361,7 @@ mod tests {
     use super::*;
     use crate::{nn::attention::generate_autoregressive_mask, TestBackend};
     use alloc::vec::Vec;
-    use burn::tensor::{Distribution, Shape};
+    use burn::tensor::{Data, Distribution, Shape};
     use burn_tensor::Int;
 
     #[test]
@@ -356,7 +383,7 @@ mod tests {
             "Context should have the correct shape",
         );
         assert_eq!(
-            output.weights.shape(),
+            output.weights.unwrap().shape(),
             Shape::new([batch_size, n_heads, seq_length, seq_length]),
             "Weights should have the correct shape",
         );
@@ -394,7 +421,7 @@ mod tests {
             "Context should have the correct shape",
         );
         assert_eq!(
-            output.weights.shape(),
+            output.weights.unwrap().shape(),
             Shape::new([batch_size, n_heads, seq_length_1, seq_length_2]),
             "Weights should have the correct shape",
         );
@@ -490,4 +517,112 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_2.into_data(), 3);
     }
+
+    #[test]
+    fn test_causal_mask_zeroes_future_positions() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 5, 8, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let mask_attn = generate_autoregressive_mask(batch_size, seq_length, &device);
+        let input = MhaInput::self_attn(tensor).mask_attn(mask_attn.clone());
+
+        let weights = mha.forward(input).weights.unwrap();
+
+        let future = mask_attn
+            .reshape([batch_size, 1, seq_length, seq_length])
+            .repeat(1, n_heads);
+        weights
+            .clone()
+            .mask_fill(future.bool_not(), 0.0)
+            .into_data()
+            .assert_approx_eq(
+                &Data::zeros([batch_size, n_heads, seq_length, seq_length]),
+                5,
+            );
+        weights.sum_dim(3).into_data().assert_approx_eq(
+            &Data::ones(Shape::new([batch_size, n_heads, seq_length, 1])),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_mask_pad_does_not_leak_into_softmax() {
+        let [batch_size, seq_length_1, seq_length_2, d_model, n_heads, num_padded] =
+            [2, 3, 6, 8, 2, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+
+        let mask_pad = Tensor::<TestBackend, 2, Int>::arange(0..seq_length_2 as i64, &device)
+            .reshape([1, seq_length_2])
+            .repeat(0, batch_size)
+            .greater_equal_elem((seq_length_2 - num_padded) as i64);
+        let key = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length_2, d_model],
+            Distribution::Default,
+            &device,
+        );
+        // Huge padded keys would dominate the softmax if the mask was leaking.
+        let key = key.slice_assign(
+            [
+                0..batch_size,
+                seq_length_2 - num_padded..seq_length_2,
+                0..d_model,
+            ],
+            Tensor::full([batch_size, num_padded, d_model], 1000.0, &device),
+        );
+        let query = Tensor::random(
+            [batch_size, seq_length_1, d_model],
+            Distribution::Default,
+            &device,
+        );
+        let input = MhaInput::new(query, key.clone(), key).mask_pad(mask_pad);
+
+        let weights = mha.forward(input).weights.unwrap();
+
+        weights
+            .clone()
+            .slice([
+                0..batch_size,
+                0..n_heads,
+                0..seq_length_1,
+                seq_length_2 - num_padded..seq_length_2,
+            ])
+            .into_data()
+            .assert_approx_eq(
+                &Data::zeros([batch_size, n_heads, seq_length_1, num_padded]),
+                5,
+            );
+        weights.sum_dim(3).into_data().assert_approx_eq(
+            &Data::ones(Shape::new([batch_size, n_heads, seq_length_1, 1])),
+            3,
+        );
+    }
+
+    #[test]
+    fn test_weights_are_not_returned_when_not_needed() {
+        let [batch_size, seq_length, d_model, n_heads] = [2, 3, 8, 2];
+        let device = Default::default();
+        let mha = MultiHeadAttentionConfig::new(d_model, n_heads).init::<TestBackend>(&device);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [batch_size, seq_length, d_model],
+            Distribution::Default,
+            &device,
+        );
+
+        let output_1 = mha.forward(MhaInput::self_attn(tensor.clone()));
+        let output_2 = mha.forward(MhaInput::self_attn(tensor).need_weights(false));
+
+        assert!(output_1.weights.is_some());
+        assert!(output_2.weights.is_none());
+        output_1
+            .context
+            .into_data()
+            .assert_approx_eq(&output_2.context.into_data(), 3);
+    }
 }
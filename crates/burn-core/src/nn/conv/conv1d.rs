@@ -162,4 +162,50 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(conv.weight.shape()), 3);
     }
+
+    #[test]
+    fn depthwise_convolution_keeps_channels_separate() {
+        let device = Default::default();
+        let config = Conv1dConfig::new(2, 2, 3)
+            .with_groups(2)
+            .with_padding(PaddingConfig1d::Explicit(1))
+            .with_bias(false)
+            .with_initializer(Initializer::Ones);
+        let conv = config.init::<TestBackend>(&device);
+
+        assert_eq!(conv.weight.dims(), [2, 1, 3]);
+
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [[[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0]]],
+            &device,
+        );
+        let output = conv.forward(input);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[1.0, 3.0, 6.0, 5.0], [9.0, 15.0, 18.0, 13.0]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn dilation_expands_receptive_field_without_new_params() {
+        let device = Default::default();
+        let config = Conv1dConfig::new(1, 1, 3)
+            .with_bias(false)
+            .with_initializer(Initializer::Ones);
+        let conv = config.init::<TestBackend>(&device);
+        let conv_dilated = config.clone().with_dilation(2).init::<TestBackend>(&device);
+
+        assert_eq!(conv.num_params(), conv_dilated.num_params());
+
+        let input = Tensor::<TestBackend, 3>::from_floats([[[0.0, 1.0, 2.0, 3.0, 4.0]]], &device);
+
+        conv.forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&Data::from([[[3.0, 6.0, 9.0]]]), 3);
+        conv_dilated
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&Data::from([[[6.0]]]), 3);
+    }
 }
@@ -50,10 +50,79 @@ impl Dropout {
     }
 }
 
+/// Configuration to create an [AlphaDropout](AlphaDropout) layer.
+#[derive(Config, Debug)]
+pub struct AlphaDropoutConfig {
+    /// The probability of randomly setting some elements of the input tensor to the negative
+    /// saturation value of SELU during training.
+    pub prob: f64,
+}
+
+/// Set at random some elements of the input tensor to the negative saturation value of the SELU
+/// activation during training, keeping the mean and variance of the input.
+///
+/// This variant of dropout is introduced in the paper
+/// [Self-Normalizing Neural Networks](https://arxiv.org/abs/1706.02515) and is meant to be used
+/// with the SELU activation, where setting elements to zero would break the self-normalizing
+/// property.
+///
+/// The dropped elements are set to `alpha' = -scale * alpha` and the result is transformed to
+/// `a * x + b`, where `a = (q + alpha'^2 * q * (1 - q))^(-1/2)`, `b = -a * alpha' * (1 - q)` and
+/// `q` is the probability of keeping an element.
+#[derive(Module, Clone, Debug)]
+pub struct AlphaDropout {
+    prob: f64,
+}
+
+/// The negative saturation value of SELU, `-scale * alpha`.
+const SELU_ALPHA_PRIME: f64 = -1.7580993408473766;
+
+impl AlphaDropoutConfig {
+    /// Initialize a new [alpha dropout](AlphaDropout) module.
+    pub fn init(&self) -> AlphaDropout {
+        assert!(
+            (0.0..1.0).contains(&self.prob),
+            "The dropout probability({}) must be in the range [0, 1)",
+            self.prob
+        );
+
+        AlphaDropout { prob: self.prob }
+    }
+}
+
+impl AlphaDropout {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        if !B::ad_enabled() || self.prob == 0.0 {
+            return input;
+        }
+
+        let prob_keep = 1.0 - self.prob;
+        let a = 1.0
+            / libm::sqrt(prob_keep + SELU_ALPHA_PRIME * SELU_ALPHA_PRIME * prob_keep * self.prob);
+        let b = -a * SELU_ALPHA_PRIME * self.prob;
+
+        let keep = input.random_like(Distribution::Bernoulli(prob_keep));
+        let dropped = keep
+            .clone()
+            .neg()
+            .add_scalar(1.0)
+            .mul_scalar(SELU_ALPHA_PRIME);
+        let x = input * keep + dropped;
+
+        x.mul_scalar(a).add_scalar(b)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tensor::Shape;
+    use crate::tensor::{Data, Shape};
 
     #[cfg(feature = "std")]
     use crate::{TestAutodiffBackend, TestBackend};
@@ -82,4 +151,57 @@ mod tests {
 
         assert_eq!(tensor.to_data(), output.to_data());
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn alpha_dropout_with_ad_backend_should_mark_input() {
+        let tensor =
+            Tensor::<TestAutodiffBackend, 2>::ones(Shape::new([100, 100]), &Default::default());
+        let dropout = AlphaDropoutConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_ne!(tensor.to_data(), output.to_data());
+    }
+
+    #[test]
+    fn alpha_dropout_without_ad_backend_should_not_change_input() {
+        let tensor = Tensor::<TestBackend, 2>::ones(Shape::new([100, 100]), &Default::default());
+        let dropout = AlphaDropoutConfig::new(0.5).init();
+
+        let output = dropout.forward(tensor.clone());
+
+        assert_eq!(tensor.to_data(), output.to_data());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn alpha_dropout_should_preserve_moments_of_selu_activations() {
+        TestAutodiffBackend::seed(0);
+
+        let input = Tensor::<TestAutodiffBackend, 1>::random(
+            Shape::new([100_000]),
+            Distribution::Normal(0.0, 1.0),
+            &Default::default(),
+        );
+        // SELU keeps the mean and variance of normalized inputs at 0 and 1.
+        let (scale, alpha) = (1.0507009873554805, 1.6732632423543772);
+        let activations = (input.clone().clamp_min(0.0)
+            + input.clamp_max(0.0).exp().sub_scalar(1.0).mul_scalar(alpha))
+        .mul_scalar(scale);
+        let dropout = AlphaDropoutConfig::new(0.2).init();
+
+        let output = dropout.forward(activations.clone());
+        let (var, mean) = output.clone().var_mean(0);
+
+        assert_ne!(activations.to_data(), output.to_data());
+        mean.to_data().assert_approx_eq(&Data::from([0.0]), 1);
+        var.to_data().assert_approx_eq(&Data::from([1.0]), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn alpha_dropout_should_panic_with_invalid_prob() {
+        let _ = AlphaDropoutConfig::new(1.0).init();
+    }
 }
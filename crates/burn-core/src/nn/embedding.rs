@@ -18,6 +18,9 @@ pub struct EmbeddingConfig {
     /// The type of function used to initialize neural network parameters
     #[config(default = "Initializer::Normal{mean:0.0, std:1.0}")]
     pub initializer: Initializer,
+    /// If set, the vector at this index is initialized to zeros and never updated, which is
+    /// usually used for the padding tokens of a sequence.
+    pub padding_idx: Option<usize>,
 }
 
 /// Lookup table to store a fix number of vectors.
@@ -31,25 +34,46 @@ pub struct Embedding<B: Backend> {
     /// The learnable weights of the module of shape [n_embedding, d_model] initialized
     /// from a normal distribution `N(0, 1)`.
     pub weight: Param<Tensor<B, 2>>,
+    padding_idx: Option<usize>,
 }
 
 impl EmbeddingConfig {
     /// Initialize a new [embedding](Embedding) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> Embedding<B> {
-        let weight = self
+        self.check_padding_idx();
+
+        let mut weight = self
             .initializer
-            .init([self.n_embedding, self.d_model], device)
-            .require_grad();
+            .init([self.n_embedding, self.d_model], device);
+
+        if let Some(padding_idx) = self.padding_idx {
+            weight = zero_row(weight, padding_idx);
+        }
 
         Embedding {
-            weight: Param::from(weight),
+            weight: Param::from(weight.require_grad()),
+            padding_idx: self.padding_idx,
         }
     }
 
     /// Initialize a new [embedding](Embedding) module with a [record](EmbeddingRecord).
     pub fn init_with<B: Backend>(&self, record: EmbeddingRecord<B>) -> Embedding<B> {
+        self.check_padding_idx();
+
         Embedding {
             weight: record.weight,
+            padding_idx: self.padding_idx,
+        }
+    }
+
+    fn check_padding_idx(&self) {
+        if let Some(padding_idx) = self.padding_idx {
+            assert!(
+                padding_idx < self.n_embedding,
+                "padding_idx({}) must be smaller than n_embedding({})",
+                padding_idx,
+                self.n_embedding
+            );
         }
     }
 }
@@ -62,16 +86,41 @@ impl<B: Backend> Embedding<B> {
     /// - input: [batch_size, seq_length]
     /// - output: [batch_size, d_model]
     pub fn forward(&self, input: Tensor<B, 2, Int>) -> Tensor<B, 3> {
-        burn_tensor::module::embedding(self.weight.val(), input)
+        let output = burn_tensor::module::embedding(self.weight.val(), input.clone());
+
+        match self.padding_idx {
+            // The padding vectors of the output are detached, so the padding row receives no
+            // gradient.
+            Some(padding_idx) => {
+                let [_, _, d_model] = output.dims();
+                let mask = input
+                    .equal_elem(padding_idx as i64)
+                    .unsqueeze_dim::<3>(2)
+                    .repeat(2, d_model);
+
+                output.clone().mask_where(mask, output.detach())
+            }
+            None => output,
+        }
     }
 }
 
+fn zero_row<B: Backend>(weight: Tensor<B, 2>, index: usize) -> Tensor<B, 2> {
+    let [_, d_model] = weight.dims();
+    let zeros = Tensor::zeros([1, d_model], &weight.device());
+
+    weight.slice_assign([index..index + 1, 0..d_model], zeros)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::TestBackend;
     use burn_tensor::Data;
 
+    #[cfg(feature = "std")]
+    use crate::TestAutodiffBackend;
+
     #[test]
     fn initializer_default() {
         TestBackend::seed(0);
@@ -107,4 +156,59 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::zeros(embed.weight.shape()), 3);
     }
+
+    #[test]
+    fn padding_idx_initialized_to_zeros() {
+        TestBackend::seed(0);
+
+        let config = EmbeddingConfig::new(4, 3).with_padding_idx(Some(2));
+        let embed = config.init::<TestBackend>(&Default::default());
+
+        embed
+            .weight
+            .val()
+            .slice([2..3, 0..3])
+            .to_data()
+            .assert_approx_eq(&Data::zeros([1, 3]), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn padding_idx_out_of_range() {
+        let _ = EmbeddingConfig::new(4, 3)
+            .with_padding_idx(Some(4))
+            .init::<TestBackend>(&Default::default());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn padding_idx_receives_no_gradient() {
+        let device = Default::default();
+        let mut embed = EmbeddingConfig::new(4, 3)
+            .with_padding_idx(Some(0))
+            .init::<TestAutodiffBackend>(&device);
+        // A padding vector loaded with other values is used as is in the forward pass.
+        embed.weight = Param::from(Tensor::ones([4, 3], &device).require_grad());
+        let input = Tensor::from_ints([[0, 1, 0], [3, 0, 1]], &device);
+
+        let output = embed.forward(input);
+        output
+            .clone()
+            .slice([0..1, 0..1, 0..3])
+            .to_data()
+            .assert_approx_eq(&Data::ones([1, 1, 3].into()), 3);
+
+        let grads = output.sum().backward();
+        let weight_grad = embed.weight.grad(&grads).unwrap();
+
+        assert_eq!(
+            weight_grad.to_data(),
+            Data::from([
+                [0.0, 0.0, 0.0],
+                [2.0, 2.0, 2.0],
+                [0.0, 0.0, 0.0],
+                [1.0, 1.0, 1.0]
+            ])
+        );
+    }
 }
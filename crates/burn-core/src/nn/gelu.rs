@@ -1,17 +1,54 @@
 use crate as burn;
 
+use crate::config::Config;
 use crate::module::Module;
 use crate::tensor::backend::Backend;
 use crate::tensor::Tensor;
 
+/// The way the Gaussian cumulative distribution function of [Gelu](Gelu) is computed.
+#[derive(Module, Config, Debug, PartialEq)]
+pub enum GeluApproximation {
+    /// The exact computation, using the error function.
+    Erf,
+    /// The approximation using tanh, as used by the GPT family of models.
+    Tanh,
+}
+
+/// Configuration to create a [Gelu](Gelu) layer.
+#[derive(Config, Debug)]
+pub struct GeluConfig {
+    /// How the Gaussian cumulative distribution function is computed.
+    #[config(default = "GeluApproximation::Erf")]
+    pub approximation: GeluApproximation,
+}
+
 /// Applies the Gaussian Error Linear Units function element-wise.
-#[derive(Module, Clone, Debug, Default)]
-pub struct Gelu {}
+#[derive(Module, Clone, Debug)]
+pub struct Gelu {
+    approximation: GeluApproximation,
+}
+
+impl GeluConfig {
+    /// Initialize a new [gelu](Gelu) module.
+    pub fn init(&self) -> Gelu {
+        Gelu {
+            approximation: self.approximation.clone(),
+        }
+    }
+}
+
+impl Default for Gelu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Gelu {
-    /// Create the module.
+    /// Create the module, using the exact computation.
     pub fn new() -> Self {
-        Self {}
+        Self {
+            approximation: GeluApproximation::Erf,
+        }
     }
 
     /// Applies the forward pass on the input tensor.
@@ -21,6 +58,31 @@ impl Gelu {
     /// - input: `[..., any]`
     /// - output: `[..., any]`
     pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
-        crate::tensor::activation::gelu(input)
+        match self.approximation {
+            GeluApproximation::Erf => crate::tensor::activation::gelu(input),
+            GeluApproximation::Tanh => crate::tensor::activation::gelu_tanh(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[test]
+    fn forward_with_tanh_approximation() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 1>::from_floats([-1.0, 0.0, 1.0, 3.0], &device);
+        let gelu = GeluConfig::new()
+            .with_approximation(GeluApproximation::Tanh)
+            .init();
+
+        let output = gelu.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([-0.1588, 0.0, 0.8412, 2.9964]), 3);
     }
 }
@@ -1,4 +1,6 @@
-use burn_tensor::Shape;
+use alloc::vec::Vec;
+
+use burn_tensor::{Data, Shape};
 use libm::sqrt;
 
 use crate::config::Config;
@@ -65,6 +67,15 @@ pub enum Initializer {
         /// The gain to use in initialization formula
         gain: f64,
     },
+    /// Fills tensor with a (semi) orthogonal matrix, as described in [Exact solutions to the
+    /// nonlinear dynamics of learning in deep linear neural networks](https://arxiv.org/abs/1312.6120).
+    ///
+    /// The tensor is viewed as a matrix of shape `[dim_0, dim_1 * ... * dim_n]`, whose rows are
+    /// orthonormal if there are fewer rows than columns, and whose columns are orthonormal otherwise.
+    Orthogonal {
+        /// The gain to use in initialization formula
+        gain: f64,
+    },
 }
 
 impl Initializer {
@@ -119,6 +130,7 @@ impl Initializer {
                 let std = *gain * self.xavier_std(fan_in, fan_out);
                 normal_draw(shape, 0.0, std, device)
             }
+            Initializer::Orthogonal { gain } => orthogonal_draw(shape, *gain, device),
         }
     }
 
@@ -169,6 +181,59 @@ fn normal_draw<B: Backend, const D: usize, S: Into<Shape<D>>>(
     Tensor::<B, D>::random(shape, distribution, device)
 }
 
+fn orthogonal_draw<B: Backend, const D: usize>(
+    shape: Shape<D>,
+    gain: f64,
+    device: &B::Device,
+) -> Tensor<B, D> {
+    assert!(
+        D >= 2,
+        "Orthogonal initialization requires a tensor with at least two dimensions"
+    );
+
+    let rows = shape.dims[0];
+    let cols = shape.num_elements() / usize::max(rows, 1);
+    // The columns of the tall matrix are orthonormalized, with the Gram-Schmidt process.
+    let (tall_rows, tall_cols) = (usize::max(rows, cols), usize::min(rows, cols));
+
+    let values = normal_draw::<B, 2, _>([tall_cols, tall_rows], 0.0, 1.0, device)
+        .into_data()
+        .convert::<f64>()
+        .value;
+    let mut columns: Vec<Vec<f64>> = values
+        .chunks(usize::max(tall_rows, 1))
+        .map(|column| column.to_vec())
+        .collect();
+
+    for i in 0..columns.len() {
+        let (previous, next) = columns.split_at_mut(i);
+        let column = &mut next[0];
+
+        for other in previous.iter() {
+            let projection: f64 = column.iter().zip(other).map(|(a, b)| a * b).sum();
+            column
+                .iter_mut()
+                .zip(other)
+                .for_each(|(a, b)| *a -= projection * b);
+        }
+
+        // The columns stay unit vectors, so the next projections remove every component.
+        let norm = sqrt(column.iter().map(|a| a * a).sum());
+        column.iter_mut().for_each(|a| *a /= norm);
+    }
+
+    let mut values = Vec::with_capacity(shape.num_elements());
+    match rows < cols {
+        // Each orthonormal vector is a row of the matrix.
+        true => columns.iter().for_each(|row| values.extend(row)),
+        false => (0..tall_rows).for_each(|row| values.extend(columns.iter().map(|col| col[row]))),
+    }
+    // The gain is only applied once the vectors are orthonormal.
+    values.iter_mut().for_each(|value| *value *= gain);
+
+    Tensor::from_data(Data::new(values, shape).convert(), device)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -382,4 +447,63 @@ mod tests {
         let _: Tensor<TB, 2> =
             Initializer::XavierUniform { gain }.init([fan_out, fan_in], &Default::default());
     }
+
+    #[test]
+    fn initializer_kaiming_uniform_fan_in_variance() {
+        TB::seed(0);
+
+        let gain = sqrt(2.0);
+        let (fan_in, fan_out) = (400, 100);
+        let expected_var = gain * gain / fan_in as f64;
+
+        let tensor: Tensor<TB, 2> = Initializer::KaimingUniform {
+            gain,
+            fan_out_only: false,
+        }
+        .init_with([fan_out, fan_in], Some(fan_in), None, &Default::default());
+        let actual_var: f32 = tensor.reshape([-1]).var(0).into_scalar().elem();
+        let actual_var = actual_var as f64;
+
+        assert!(
+            (actual_var - expected_var).abs() <= 0.1 * expected_var,
+            "Expected variance to be {expected_var} += 10%, but got {actual_var}"
+        );
+    }
+
+    fn assert_identity(tensor: Tensor<TB, 2>, gain: f64) {
+        let [size, _] = tensor.dims();
+        let identity: Tensor<TB, 2> = Tensor::eye(size, &Default::default());
+
+        tensor
+            .into_data()
+            .assert_approx_eq(&identity.mul_scalar(gain * gain).into_data(), 3);
+    }
+
+    #[test]
+    fn initializer_orthogonal_init_orthonormal_columns() {
+        TB::seed(0);
+
+        let tensor: Tensor<TB, 2> =
+            Initializer::Orthogonal { gain: 1.0 }.init([6, 4], &Default::default());
+
+        assert_identity(tensor.clone().transpose().matmul(tensor), 1.0);
+    }
+
+    #[test]
+    fn initializer_orthogonal_init_orthonormal_rows() {
+        TB::seed(0);
+
+        let gain = 2.0;
+        let tensor: Tensor<TB, 3> =
+            Initializer::Orthogonal { gain }.init([3, 2, 4], &Default::default());
+        let tensor = tensor.reshape([3, 8]);
+
+        assert_identity(tensor.clone().matmul(tensor.transpose()), gain);
+    }
+
+    #[test]
+    #[should_panic]
+    fn initializer_orthogonal_init_vector() {
+        let _: Tensor<TB, 1> = Initializer::Orthogonal { gain: 1.0 }.init([4], &Default::default());
+    }
 }
@@ -0,0 +1,182 @@
+use crate as burn;
+
+use crate::{config::Config, module::Module};
+use burn_tensor::backend::Backend;
+use burn_tensor::{Int, Tensor};
+use core::marker::PhantomData;
+
+use super::Reduction;
+
+/// Configuration to create a [Cosine embedding loss](CosineEmbeddingLoss).
+#[derive(Config, Debug)]
+pub struct CosineEmbeddingLossConfig {
+    /// The cosine similarity under which dissimilar pairs don't contribute to the loss, usually
+    /// between 0 and 0.5.
+    #[config(default = 0.0)]
+    pub margin: f32,
+}
+
+impl CosineEmbeddingLossConfig {
+    /// Initialize [Cosine embedding loss](CosineEmbeddingLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> CosineEmbeddingLoss<B> {
+        // device is not needed as of now, but it is consistent with other loss functions
+        let _ = device;
+        self.assertions();
+        CosineEmbeddingLoss {
+            margin: self.margin,
+            _backend: PhantomData,
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            (-1.0..=1.0).contains(&self.margin),
+            "Margin of cosine embedding loss must be in the interval [-1, 1]. Got {}",
+            self.margin
+        );
+    }
+}
+
+/// Calculate the cosine embedding loss between pairs of vectors, used to learn embeddings where
+/// similar pairs are aligned.
+///
+/// The loss of each pair is given by
+///
+/// ```text
+/// L(x1, x2, y) = 1 - cos(x1, x2)                 if y = 1
+/// L(x1, x2, y) = max(0, cos(x1, x2) - margin)    if y = -1
+/// ```
+#[derive(Module, Debug)]
+pub struct CosineEmbeddingLoss<B: Backend> {
+    margin: f32,
+    _backend: PhantomData<B>,
+}
+
+/// Avoids dividing by zero for null vectors.
+const EPSILON: f32 = 1e-8;
+
+impl<B: Backend> CosineEmbeddingLoss<B> {
+    /// Compute the loss of each pair, then reduce to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - input_1: `[batch_size, d_model]`
+    /// - input_2: `[batch_size, d_model]`
+    /// - targets: `[batch_size]`, with `1` for similar pairs and `-1` for dissimilar pairs
+    /// - output: `[1]`
+    pub fn forward(
+        &self,
+        input_1: Tensor<B, 2>,
+        input_2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(input_1, input_2, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss of each pair.
+    ///
+    /// # Shapes
+    ///
+    /// - input_1: `[batch_size, d_model]`
+    /// - input_2: `[batch_size, d_model]`
+    /// - targets: `[batch_size]`, with `1` for similar pairs and `-1` for dissimilar pairs
+    /// - output: `[batch_size]`
+    pub fn forward_no_reduction(
+        &self,
+        input_1: Tensor<B, 2>,
+        input_2: Tensor<B, 2>,
+        targets: Tensor<B, 1, Int>,
+    ) -> Tensor<B, 1> {
+        let [batch_size, _] = input_1.dims();
+        let [targets_size] = targets.dims();
+        assert!(
+            input_1.dims() == input_2.dims(),
+            "Shapes of the inputs ({:?} and {:?}) should be equal.",
+            input_1.dims(),
+            input_2.dims()
+        );
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({}) should correspond to outer shape of inputs ({}).",
+            targets_size,
+            batch_size
+        );
+
+        let dot = (input_1.clone() * input_2.clone()).sum_dim(1);
+        let norm_1 = input_1
+            .powf_scalar(2.0)
+            .sum_dim(1)
+            .add_scalar(EPSILON)
+            .sqrt();
+        let norm_2 = input_2
+            .powf_scalar(2.0)
+            .sum_dim(1)
+            .add_scalar(EPSILON)
+            .sqrt();
+        let cosine = (dot / (norm_1 * norm_2)).reshape([batch_size]);
+
+        let similar = cosine.clone().neg().add_scalar(1.0);
+        let dissimilar = cosine.sub_scalar(self.margin).clamp_min(0.0);
+
+        dissimilar.mask_where(targets.equal_elem(1), similar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_cosine_embedding_loss() {
+        let device = Default::default();
+        let input_1 =
+            TestTensor::<2>::from_floats([[1.0, 0.0], [1.0, 1.0], [0.0, 2.0], [3.0, 4.0]], &device);
+        let input_2 = TestTensor::<2>::from_floats(
+            [[2.0, 0.0], [-1.0, 0.0], [1.0, 1.0], [4.0, 3.0]],
+            &device,
+        );
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([1, 1, -1, -1]), &device);
+
+        let loss = CosineEmbeddingLossConfig::new()
+            .with_margin(0.5)
+            .init(&device);
+        let loss_no_reduction =
+            loss.forward_no_reduction(input_1.clone(), input_2.clone(), targets.clone());
+        let loss_sum = loss.forward(input_1, input_2, targets, Reduction::Sum);
+
+        // The cosine similarities are [1.0, -0.7071, 0.7071, 0.96].
+        loss_no_reduction
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 1.7071, 0.2071, 0.46]), 3);
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([2.3742]), 3);
+    }
+
+    #[test]
+    fn test_cosine_embedding_loss_penalizes_dissimilar_pairs_beyond_margin() {
+        let device = Default::default();
+        let input_1 = TestTensor::<2>::from_floats([[1.0, 0.0], [1.0, 0.0], [1.0, 0.0]], &device);
+        // The cosine similarities are [0.0, 0.6, 0.8].
+        let input_2 = TestTensor::<2>::from_floats([[0.0, 1.0], [3.0, 4.0], [4.0, 3.0]], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([-1, -1, -1]), &device);
+
+        let loss = CosineEmbeddingLossConfig::new()
+            .with_margin(0.7)
+            .init(&device)
+            .forward_no_reduction(input_1, input_2, targets);
+
+        loss.into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0, 0.1]), 3);
+    }
+}
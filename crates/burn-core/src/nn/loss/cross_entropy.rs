@@ -26,7 +26,7 @@ pub struct CrossEntropyLossConfig {
     /// Create cross-entropy with label smoothing.
     ///
     /// Hard labels {0, 1} will be changed to y_smoothed = y(1 - a) + a / nr_classes.
-    /// Alpha = 0 would be the same as default.
+    /// Alpha = 0 is exactly the same as default.
     smoothing: Option<f32>,
 
     /// Create cross-entropy with probabilities as input instead of logits.    
@@ -94,7 +94,7 @@ impl<B: Backend> CrossEntropyLoss<B> {
     pub fn forward(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
         Self::assertions(logits.clone(), targets.clone());
         match self.smoothing {
-            Some(alpha) => self.forward_smoothed(logits, targets, alpha),
+            Some(alpha) if alpha > 0.0 => self.forward_smoothed(logits, targets, alpha),
             _ => self.forward_default(logits, targets),
         }
     }
@@ -106,11 +106,7 @@ impl<B: Backend> CrossEntropyLoss<B> {
         alpha: f32,
     ) -> Tensor<B, 1> {
         let mask = self.padding_mask(&targets);
-        let tensor = if self.logits {
-            log_softmax(logits, 1)
-        } else {
-            logits.log()
-        };
+        let tensor = self.log_probabilities(logits);
         let [batch_size, nr_classes] = tensor.dims();
         let tensor = tensor
             * Self::compute_smoothed_targets([batch_size, nr_classes], targets.clone(), alpha);
@@ -137,7 +133,7 @@ impl<B: Backend> CrossEntropyLoss<B> {
         let [batch_size] = targets.dims();
 
         let mask = self.padding_mask(&targets);
-        let tensor = log_softmax(logits, 1);
+        let tensor = self.log_probabilities(logits);
         let tensor = tensor.gather(1, targets.clone().reshape([batch_size, 1]));
 
         match &self.weights {
@@ -154,6 +150,13 @@ impl<B: Backend> CrossEntropyLoss<B> {
         }
     }
 
+    fn log_probabilities(&self, input: Tensor<B, 2>) -> Tensor<B, 2> {
+        match self.logits {
+            true => log_softmax(input, 1),
+            false => input.log(),
+        }
+    }
+
     fn compute_smoothed_targets(
         shape: [usize; 2],
         targets: Tensor<B, 1, Int>,
@@ -404,4 +407,57 @@ mod tests {
 
         loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
     }
+
+    #[test]
+    fn test_label_smoothing_alpha_zero_is_exactly_default() {
+        let (logits, targets, _) = setup_padded!();
+        let device = Default::default();
+        let config = CrossEntropyLossConfig::new()
+            .with_pad_tokens(Some(vec![1]))
+            .with_weights(Some(vec![1.0, 2., 3., 4., 5.]));
+        let loss_1 = config
+            .init(&device)
+            .forward(logits.clone(), targets.clone());
+        let loss_2 = config
+            .with_smoothing(Some(0.))
+            .init(&device)
+            .forward(logits, targets);
+
+        assert_eq!(loss_1.into_data(), loss_2.into_data());
+    }
+
+    #[test]
+    fn test_label_smoothing_raises_loss_of_confident_correct_predictions() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 2>::from_floats(
+            [[10.0, 0.0, 0.0], [0.0, 10.0, 0.0], [0.0, 0.0, 10.0]],
+            &device,
+        );
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 1, 2]), &device);
+
+        let loss = |smoothing| {
+            CrossEntropyLossConfig::new()
+                .with_smoothing(smoothing)
+                .init(&device)
+                .forward(logits.clone(), targets.clone())
+                .into_scalar()
+        };
+
+        assert!(loss(Some(0.1)) > loss(None));
+        assert!(loss(Some(0.2)) > loss(Some(0.1)));
+    }
+
+    #[test]
+    fn test_cross_entropy_loss_with_probabilities() {
+        let (logits, targets, targets_logits) = setup!();
+        let device = Default::default();
+        let probabilities = burn_tensor::activation::softmax(logits.clone(), 1);
+        let loss_1 = CrossEntropyLossConfig::new()
+            .with_logits(false)
+            .init(&device)
+            .forward(probabilities, targets);
+        let loss_2 = cross_entropy_with_logits(logits, targets_logits);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
 }
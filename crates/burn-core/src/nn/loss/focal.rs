@@ -0,0 +1,241 @@
+use crate as burn;
+
+use crate::{config::Config, module::Module};
+use alloc::vec::Vec;
+use burn_tensor::activation::log_softmax;
+use burn_tensor::{backend::Backend, Bool, Int, Tensor};
+
+/// Configuration to create a [Focal loss](FocalLoss).
+#[derive(Config, Debug)]
+pub struct FocalLossConfig {
+    /// The focusing parameter, down-weighting the loss of well-classified examples.
+    ///
+    /// Gamma = 0 is the same as the cross-entropy loss.
+    #[config(default = 2.0)]
+    pub gamma: f32,
+
+    /// The weight of each class, balancing the importance of the classes.
+    ///
+    /// # Pre-conditions
+    ///   - The order of the weight vector should correspond to the label integer assignment.
+    pub alpha: Option<Vec<f32>>,
+
+    /// Prevents pad tokens from impacting loss calculation, like in the
+    /// [cross-entropy loss](super::CrossEntropyLoss).
+    pub pad_tokens: Option<Vec<usize>>,
+}
+
+impl FocalLossConfig {
+    /// Initialize [Focal loss](FocalLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> FocalLoss<B> {
+        self.assertions();
+        FocalLoss {
+            gamma: self.gamma,
+            alpha: self
+                .alpha
+                .as_ref()
+                .map(|alpha| Tensor::<B, 1>::from_floats(alpha.as_slice(), device)),
+            pad_tokens: self.pad_tokens.clone(),
+        }
+    }
+
+    fn assertions(&self) {
+        assert!(
+            self.gamma >= 0.,
+            "Gamma of focal loss must be a non-negative number. Got {}",
+            self.gamma
+        );
+        if let Some(alpha) = self.alpha.as_ref() {
+            assert!(
+                alpha.iter().all(|e| e > &0.),
+                "Alpha of focal loss has to be positive."
+            );
+        }
+    }
+}
+
+/// Calculate the focal loss from the input logits and the targets, as described in the paper
+/// [Focal Loss for Dense Object Detection](https://arxiv.org/abs/1708.02002).
+///
+/// The loss of each example is given by
+///
+/// ```text
+/// FL(p_t) = -alpha_t * (1 - p_t)^gamma * log(p_t)
+/// ```
+///
+/// where `p_t` is the predicted probability of the target class and `alpha_t` its weight.
+#[derive(Module, Debug)]
+pub struct FocalLoss<B: Backend> {
+    gamma: f32,
+    /// The weight of each class.
+    pub alpha: Option<Tensor<B, 1>>,
+    pad_tokens: Option<Vec<usize>>,
+}
+
+impl<B: Backend> FocalLoss<B> {
+    /// Compute the criterion on the input tensor.
+    ///
+    /// With class weights, the loss is normalized by the sum of the weights of the targets,
+    /// otherwise by the batch size.
+    ///
+    /// # Shapes
+    ///
+    /// - logits: `[batch_size, num_targets]`
+    /// - targets: `[batch_size]`
+    /// - output: `[1]`
+    pub fn forward(&self, logits: Tensor<B, 2>, targets: Tensor<B, 1, Int>) -> Tensor<B, 1> {
+        let [batch_size, _] = logits.dims();
+        let [targets_size] = targets.dims();
+        assert!(
+            batch_size == targets_size,
+            "Shape of targets ({}) should correspond to outer shape of logits ({}).",
+            targets_size,
+            batch_size
+        );
+
+        let mask = self.padding_mask(&targets);
+        let log_probs = log_softmax(logits, 1)
+            .gather(1, targets.clone().reshape([batch_size, 1]))
+            .reshape([batch_size]);
+        let modulating_factor = log_probs
+            .clone()
+            .exp()
+            .neg()
+            .add_scalar(1.0)
+            .powf_scalar(self.gamma);
+        let tensor = modulating_factor * log_probs;
+
+        match &self.alpha {
+            Some(alpha) => {
+                let alpha = alpha.clone().gather(0, targets);
+                let tensor = Self::apply_mask(tensor * alpha.clone(), mask);
+                tensor.sum().neg() / alpha.sum()
+            }
+            None => Self::apply_mask(tensor, mask).mean().neg(),
+        }
+    }
+
+    fn padding_mask(&self, targets: &Tensor<B, 1, Int>) -> Option<Tensor<B, 1, Bool>> {
+        let pad_tokens = self.pad_tokens.as_ref()?;
+        let mask = pad_tokens
+            .iter()
+            .map(|token| targets.clone().equal_elem(*token as i64).int())
+            .reduce(|a, b| a + b)?;
+
+        Some(mask.greater_elem(0))
+    }
+
+    fn apply_mask(tensor: Tensor<B, 1>, mask: Option<Tensor<B, 1, Bool>>) -> Tensor<B, 1> {
+        match mask {
+            Some(mask) => tensor.mask_fill(mask, 0),
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::loss::CrossEntropyLossConfig;
+    use crate::TestBackend;
+    use alloc::vec;
+    use burn_tensor::{Data, Distribution};
+
+    fn setup() -> (Tensor<TestBackend, 2>, Tensor<TestBackend, 1, Int>) {
+        let device = Default::default();
+        let logits =
+            Tensor::<TestBackend, 2>::random([4, 5], Distribution::Normal(0., 1.0), &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([2, 0, 4, 1]), &device);
+
+        (logits, targets)
+    }
+
+    #[test]
+    fn test_focal_loss_gamma_zero_is_cross_entropy() {
+        let (logits, targets) = setup();
+        let device = Default::default();
+
+        let loss_1 = FocalLossConfig::new()
+            .with_gamma(0.0)
+            .init(&device)
+            .forward(logits.clone(), targets.clone());
+        let loss_2 = CrossEntropyLossConfig::new()
+            .init(&device)
+            .forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_focal_loss_with_alpha_and_pad_tokens_gamma_zero() {
+        let (logits, targets) = setup();
+        let device = Default::default();
+        let weights = vec![1.0, 2., 3., 4., 5.];
+
+        let loss_1 = FocalLossConfig::new()
+            .with_gamma(0.0)
+            .with_alpha(Some(weights.clone()))
+            .with_pad_tokens(Some(vec![1]))
+            .init(&device)
+            .forward(logits.clone(), targets.clone());
+        let loss_2 = CrossEntropyLossConfig::new()
+            .with_weights(Some(weights))
+            .with_pad_tokens(Some(vec![1]))
+            .init(&device)
+            .forward(logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_focal_loss() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 2>::from_floats([[2.0, 0.0], [0.0, 1.0]], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 0]), &device);
+
+        let loss = FocalLossConfig::new()
+            .init(&device)
+            .forward(logits, targets);
+
+        // p_t = [0.8808, 0.2689]
+        // FL = -[(0.1192^2 * ln(0.8808)), (0.7311^2 * ln(0.2689))] = [0.0018, 0.7019]
+        loss.into_data().assert_approx_eq(&Data::from([0.3518]), 3);
+    }
+
+    #[test]
+    fn test_focal_loss_gamma_down_weights_well_classified_examples() {
+        let device = Default::default();
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0]), &device);
+        let easy = Tensor::<TestBackend, 2>::from_floats([[3.0, 0.0, 0.0]], &device);
+        let hard = Tensor::<TestBackend, 2>::from_floats([[0.0, 0.5, 0.5]], &device);
+
+        let ratio = |gamma: f32| {
+            let loss = FocalLossConfig::new().with_gamma(gamma).init(&device);
+            let easy = loss.forward(easy.clone(), targets.clone()).into_scalar();
+            let hard = loss.forward(hard.clone(), targets.clone()).into_scalar();
+
+            easy / hard
+        };
+
+        assert!(ratio(1.0) < ratio(0.0));
+        assert!(ratio(2.0) < ratio(1.0));
+        assert!(ratio(5.0) < ratio(2.0));
+    }
+
+    #[test]
+    fn test_focal_loss_ignores_pad_tokens() {
+        let device = Default::default();
+        let logits = Tensor::<TestBackend, 2>::from_floats([[2.0, 0.0], [0.0, 1.0]], &device);
+        let padded_logits =
+            Tensor::<TestBackend, 2>::from_floats([[2.0, 0.0], [5.0, -5.0]], &device);
+        let targets = Tensor::<TestBackend, 1, Int>::from_data(Data::from([0, 1]), &device);
+        let loss = FocalLossConfig::new()
+            .with_pad_tokens(Some(vec![1]))
+            .init(&device);
+
+        let loss_1 = loss.forward(logits, targets.clone());
+        let loss_2 = loss.forward(padded_logits, targets);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+}
@@ -124,6 +124,7 @@ impl<B: Backend> HuberLoss<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::nn::loss::MseLoss;
     use crate::TestBackend;
     use burn_tensor::Data;
     type TestTensor<const D: usize> = Tensor<TestBackend, D>;
@@ -175,4 +176,61 @@ mod tests {
             .to_data()
             .assert_approx_eq(&Data::from([-0.5, -0.5, 0., 0.3, 0.5]), 3);
     }
+
+    #[test]
+    fn test_huber_loss_is_half_mse_for_small_residuals() {
+        let device = Default::default();
+        let predict = TestTensor::<2>::from_floats([[0.1, -0.4], [0.9, 0.0]], &device);
+        let targets = TestTensor::<2>::from_floats([[0.0, 0.3], [1.2, -0.5]], &device);
+
+        let huber = HuberLossConfig::new(1.0).init(&device);
+        let loss_huber = huber.forward(predict.clone(), targets.clone(), Reduction::Mean);
+        let loss_mse = MseLoss::new().forward(predict, targets, Reduction::Mean);
+
+        loss_huber
+            .into_data()
+            .assert_approx_eq(&loss_mse.mul_scalar(0.5).into_data(), 5);
+    }
+
+    #[test]
+    fn test_huber_loss_is_shifted_l1_for_large_residuals() {
+        let device = Default::default();
+        let delta = 0.5;
+        let predict = TestTensor::<1>::from_floats([3.0, -2.0, 10.0, -100.0], &device);
+        let targets = TestTensor::<1>::zeros([4], &device);
+
+        let huber = HuberLossConfig::new(delta).init(&device);
+        let loss_huber = huber.forward_no_reduction(predict.clone(), targets.clone());
+        let loss_l1 = (predict - targets).abs();
+
+        loss_huber.into_data().assert_approx_eq(
+            &loss_l1
+                .mul_scalar(delta)
+                .sub_scalar(0.5 * delta * delta)
+                .into_data(),
+            3,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_huber_ad_loss_continuous_at_delta_and_finite() {
+        type TestAutodiffTensor = Tensor<crate::TestAutodiffBackend, 1>;
+
+        let device = Default::default();
+        let predict =
+            TestAutodiffTensor::from_floats([0.4999, 0.5001, -0.4999, -0.5001, 1e6, -1e6], &device)
+                .require_grad();
+        let targets = TestAutodiffTensor::zeros([6], &device);
+
+        let loss = HuberLossConfig::new(0.5).init(&device);
+        let loss = loss.forward(predict.clone(), targets, Reduction::Sum);
+
+        let grads = loss.backward();
+        let grads_predict = predict.grad(&grads).unwrap();
+
+        grads_predict
+            .to_data()
+            .assert_approx_eq(&Data::from([0.5, 0.5, -0.5, -0.5, 0.5, -0.5]), 3);
+    }
 }
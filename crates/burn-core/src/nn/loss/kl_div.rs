@@ -0,0 +1,165 @@
+use crate as burn;
+
+use crate::{config::Config, module::Module};
+use burn_tensor::backend::Backend;
+use burn_tensor::Tensor;
+use core::marker::PhantomData;
+
+use super::Reduction;
+
+/// Configuration to create a [Kullback-Leibler divergence loss](KlDivLoss).
+#[derive(Config, Debug)]
+pub struct KlDivLossConfig {
+    /// If the targets are given as log-probabilities instead of probabilities.
+    #[config(default = false)]
+    pub log_target: bool,
+}
+
+impl KlDivLossConfig {
+    /// Initialize [Kullback-Leibler divergence loss](KlDivLoss).
+    pub fn init<B: Backend>(&self, device: &B::Device) -> KlDivLoss<B> {
+        // device is not needed as of now, but it is consistent with other loss functions
+        let _ = device;
+        KlDivLoss {
+            log_target: self.log_target,
+            _backend: PhantomData,
+        }
+    }
+}
+
+/// Calculate the Kullback-Leibler divergence between the target distribution and the predicted
+/// one, given as log-probabilities.
+///
+/// The loss for each element is given by
+///
+/// ```text
+/// L(x, y) = y * (log(y) - x)
+/// ```
+///
+/// where `x` is the predicted log-probability and `y` the target probability. Elements with a
+/// target probability of zero don't contribute to the loss.
+///
+/// The divergence between two distributions is the sum of the losses over the classes, so the
+/// [sum](Reduction::Sum) divided by the batch size gives the mean divergence of a batch, while the
+/// [mean](Reduction::Mean) also divides by the number of classes.
+#[derive(Module, Debug)]
+pub struct KlDivLoss<B: Backend> {
+    log_target: bool,
+    _backend: PhantomData<B>,
+}
+
+impl<B: Backend> KlDivLoss<B> {
+    /// Compute the loss element-wise for the predictions and targets, then reduce
+    /// to a single loss value.
+    ///
+    /// `Reduction::Auto` behaves as `Reduction::Mean`.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: \[...dims\]
+    /// - targets: \[...dims\]
+    /// - output: \[1\]
+    pub fn forward<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+        reduction: Reduction,
+    ) -> Tensor<B, 1> {
+        let loss = self.forward_no_reduction(predictions, targets);
+        match reduction {
+            Reduction::Mean | Reduction::Auto => loss.mean(),
+            Reduction::Sum => loss.sum(),
+        }
+    }
+
+    /// Compute the loss element-wise for the predictions and targets.
+    ///
+    /// # Shapes
+    ///
+    /// - predictions: [...dims]
+    /// - targets: [...dims]
+    /// - output: [...dims]
+    pub fn forward_no_reduction<const D: usize>(
+        &self,
+        predictions: Tensor<B, D>,
+        targets: Tensor<B, D>,
+    ) -> Tensor<B, D> {
+        if self.log_target {
+            return targets.clone().exp() * (targets - predictions);
+        }
+
+        // 0 * log(0) is defined as 0.
+        let is_zero = targets.clone().equal_elem(0.0);
+        let loss = targets.clone() * (targets.log() - predictions);
+
+        loss.mask_fill(is_zero, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::activation::log_softmax;
+    use burn_tensor::Data;
+    type TestTensor<const D: usize> = Tensor<TestBackend, D>;
+
+    #[test]
+    fn test_kl_div_loss() {
+        let device = Default::default();
+        let predictions =
+            TestTensor::<2>::from_floats([[0.25, 0.25, 0.5], [0.1, 0.8, 0.1]], &device).log();
+        let targets = TestTensor::<2>::from_floats([[0.5, 0.5, 0.0], [0.2, 0.6, 0.2]], &device);
+
+        let kl_div = KlDivLossConfig::new().init(&device);
+        let loss_no_reduction = kl_div.forward_no_reduction(predictions.clone(), targets.clone());
+        let loss_sum = kl_div.forward(predictions, targets, Reduction::Sum);
+
+        loss_no_reduction.into_data().assert_approx_eq(
+            &Data::from([[0.3466, 0.3466, 0.0], [0.1386, -0.1726, 0.1386]]),
+            3,
+        );
+        loss_sum
+            .into_data()
+            .assert_approx_eq(&Data::from([0.7978]), 3);
+    }
+
+    #[test]
+    fn test_kl_div_loss_with_log_target() {
+        let device = Default::default();
+        let predictions =
+            TestTensor::<2>::from_floats([[0.25, 0.25, 0.5], [0.1, 0.8, 0.1]], &device).log();
+        let targets = TestTensor::<2>::from_floats([[0.5, 0.4, 0.1], [0.2, 0.6, 0.2]], &device);
+
+        let loss_1 = KlDivLossConfig::new().init(&device).forward(
+            predictions.clone(),
+            targets.clone(),
+            Reduction::Mean,
+        );
+        let loss_2 = KlDivLossConfig::new()
+            .with_log_target(true)
+            .init(&device)
+            .forward(predictions, targets.log(), Reduction::Mean);
+
+        loss_1.into_data().assert_approx_eq(&loss_2.into_data(), 3);
+    }
+
+    #[test]
+    fn test_kl_div_loss_identical_distributions() {
+        let device = Default::default();
+        let logits = TestTensor::<2>::from_floats([[1.0, -2.0, 0.5], [3.0, 0.0, 0.0]], &device);
+        let log_probs = log_softmax(logits, 1);
+
+        let kl_div = KlDivLossConfig::new().init(&device);
+        let loss = kl_div.forward(log_probs.clone(), log_probs.clone().exp(), Reduction::Sum);
+        let loss_log_target = KlDivLossConfig::new()
+            .with_log_target(true)
+            .init(&device)
+            .forward(log_probs.clone(), log_probs, Reduction::Sum);
+
+        loss.into_data().assert_approx_eq(&Data::from([0.0]), 5);
+        loss_log_target
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0]), 5);
+    }
+}
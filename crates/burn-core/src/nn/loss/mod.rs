@@ -1,11 +1,17 @@
 mod binary_cross_entropy;
+mod cosine_embedding;
 mod cross_entropy;
+mod focal;
 mod huber;
+mod kl_div;
 mod mse;
 mod reduction;
 
 pub use binary_cross_entropy::*;
+pub use cosine_embedding::*;
 pub use cross_entropy::*;
+pub use focal::*;
 pub use huber::*;
+pub use kl_div::*;
 pub use mse::*;
 pub use reduction::*;
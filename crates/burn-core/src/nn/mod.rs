@@ -24,10 +24,13 @@ mod leaky_relu;
 mod linear;
 mod norm;
 mod padding;
+mod pixel_shuffle;
 mod pos_encoding;
 mod prelu;
 mod relu;
 mod rnn;
+mod rope_encoding;
+mod silu;
 mod unfold;
 
 pub use dropout::*;
@@ -38,8 +41,11 @@ pub use leaky_relu::*;
 pub use linear::*;
 pub use norm::*;
 pub use padding::*;
+pub use pixel_shuffle::*;
 pub use pos_encoding::*;
 pub use prelu::*;
 pub use relu::*;
 pub use rnn::*;
+pub use rope_encoding::*;
+pub use silu::*;
 pub use unfold::*;
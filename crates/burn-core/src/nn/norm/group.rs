@@ -80,16 +80,19 @@ impl GroupNormConfig {
 impl<B: Backend> GroupNorm<B> {
     /// Applies the forward pass on the input tensor.
     ///
+    /// The mean and variance are computed over the channels of each group and all the spatial
+    /// dimensions.
+    ///
     /// # Shapes
     ///
-    /// - input: `[..., any, d_model]`
-    /// - output: `[..., any, d_model]`
+    /// - input: `[batch_size, num_channels, *]`
+    /// - output: `[batch_size, num_channels, *]`
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
         let shape = input.shape();
-        if shape.num_elements() <= 2 {
+        if D <= 2 {
             panic!(
                 "input rank for GroupNorm should be at least 3, but got {}",
-                shape.num_elements()
+                D
             );
         }
 
@@ -130,9 +133,15 @@ impl<B: Backend> GroupNorm<B> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::TestBackend;
+    use crate::nn::InstanceNormConfig;
     use burn_tensor::Data;
 
+    #[cfg(feature = "std")]
+    use crate::{TestAutodiffBackend, TestBackend};
+
+    #[cfg(not(feature = "std"))]
+    use crate::TestBackend;
+
     #[test]
     fn group_norm_forward_affine_false() {
         let device = Default::default();
@@ -259,4 +268,95 @@ mod tests {
             3,
         );
     }
+
+    #[test]
+    fn group_norm_num_groups_equal_num_channels_is_instance_norm() {
+        let device = Default::default();
+        let group_norm = GroupNormConfig::new(3, 3)
+            .with_affine(false)
+            .init::<TestBackend>(&device);
+        let instance_norm = InstanceNormConfig::new(3)
+            .with_affine(false)
+            .init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 5>::random(
+            [2, 3, 2, 3, 4],
+            burn_tensor::Distribution::Default,
+            &device,
+        );
+
+        // Each channel of each item is normalized over its spatial dimensions.
+        let (var, mean) = input.clone().reshape([2, 3, 24]).var_mean_bias(2);
+        let expected = (input.clone().reshape([2, 3, 24]) - mean)
+            .div(var.sqrt().add_scalar(1e-5))
+            .reshape([2, 3, 2, 3, 4])
+            .into_data();
+
+        group_norm
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+        instance_norm
+            .forward(input)
+            .into_data()
+            .assert_approx_eq(&expected, 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn group_norm_backward() {
+        let device = Default::default();
+        let module = GroupNormConfig::new(2, 4).init::<TestAutodiffBackend>(&device);
+        let tensor = Tensor::<TestAutodiffBackend, 3>::from_data(
+            Data::from([[[0.0, 1.0], [2.0, 4.0], [1.0, 3.0], [5.0, 2.0]]]),
+            &device,
+        )
+        .require_grad();
+        let weights = Tensor::<TestAutodiffBackend, 3>::from_data(
+            Data::from([[[1.0, 2.0], [0.0, -1.0], [3.0, 1.0], [-2.0, 1.0]]]),
+            &device,
+        );
+
+        let output = module.forward(tensor.clone());
+
+        output.to_data().assert_approx_eq(
+            &Data::from([[
+                [-1.1832, -0.5071],
+                [0.1690, 1.5213],
+                [-1.1832, 0.1690],
+                [1.5213, -0.5071],
+            ]]),
+            3,
+        );
+
+        let grads = output.mul(weights).sum().backward();
+
+        let tensor_grad = tensor.grad(&grads).unwrap();
+        let gamma_grad = module.gamma.as_ref().unwrap().grad(&grads).unwrap();
+        let beta_grad = module.beta.as_ref().unwrap().grad(&grads).unwrap();
+
+        gamma_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([-2.1974, -1.5213, -3.3806, -3.5496]), 3);
+        beta_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([3.0, -1.0, 4.0, -1.0]), 3);
+        tensor_grad.to_data().assert_approx_eq(
+            &Data::from([[
+                [-0.4057, 0.6954],
+                [-0.2318, -0.0580],
+                [0.1352, 0.3670],
+                [-0.0773, -0.4250],
+            ]]),
+            3,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn group_norm_rank_2_input() {
+        let device = Default::default();
+        let module = GroupNormConfig::new(2, 4).init::<TestBackend>(&device);
+
+        let _ = module.forward(Tensor::<TestBackend, 2>::zeros([4, 4], &device));
+    }
 }
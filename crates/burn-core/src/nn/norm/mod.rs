@@ -2,8 +2,10 @@ mod batch;
 mod group;
 mod instance;
 mod layer;
+mod rms;
 
 pub use batch::*;
 pub use group::*;
 pub use instance::*;
 pub use layer::*;
+pub use rms::*;
@@ -0,0 +1,185 @@
+use crate as burn;
+
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Configuration to create a [pixel shuffle](PixelShuffle) layer.
+#[derive(Config, Debug)]
+pub struct PixelShuffleConfig {
+    /// The factor by which the height and the width are increased.
+    pub upscale_factor: usize,
+}
+
+/// Configuration to create a [pixel unshuffle](PixelUnshuffle) layer.
+#[derive(Config, Debug)]
+pub struct PixelUnshuffleConfig {
+    /// The factor by which the height and the width are reduced.
+    pub downscale_factor: usize,
+}
+
+/// Rearranges the channels of the input into blocks of pixels, as described in the paper
+/// [Real-Time Single Image and Video Super-Resolution Using an Efficient Sub-Pixel Convolutional Neural Network](https://arxiv.org/abs/1609.05158).
+///
+/// The channel `c * r^2 + i * r + j` of the input is moved to the pixel `(h * r + i, w * r + j)` of
+/// the channel `c` of the output, where `r` is the upscale factor.
+#[derive(Module, Clone, Debug)]
+pub struct PixelShuffle {
+    upscale_factor: usize,
+}
+
+/// Rearranges blocks of pixels of the input into channels, the inverse of [pixel shuffle](PixelShuffle).
+#[derive(Module, Clone, Debug)]
+pub struct PixelUnshuffle {
+    downscale_factor: usize,
+}
+
+impl PixelShuffleConfig {
+    /// Initialize a new [pixel shuffle](PixelShuffle) module.
+    pub fn init(&self) -> PixelShuffle {
+        assert!(
+            self.upscale_factor > 0,
+            "The upscale factor must be positive"
+        );
+
+        PixelShuffle {
+            upscale_factor: self.upscale_factor,
+        }
+    }
+}
+
+impl PixelUnshuffleConfig {
+    /// Initialize a new [pixel unshuffle](PixelUnshuffle) module.
+    pub fn init(&self) -> PixelUnshuffle {
+        assert!(
+            self.downscale_factor > 0,
+            "The downscale factor must be positive"
+        );
+
+        PixelUnshuffle {
+            downscale_factor: self.downscale_factor,
+        }
+    }
+}
+
+impl PixelShuffle {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels * upscale_factor^2, height, width]`
+    /// - output: `[batch_size, channels, height * upscale_factor, width * upscale_factor]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of input channels is not divisible by `upscale_factor^2`.
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [batch_size, channels_in, height, width] = input.dims();
+        let factor = self.upscale_factor;
+
+        assert!(
+            channels_in % (factor * factor) == 0,
+            "The number of channels({}) must be divisible by the square of the upscale factor({})",
+            channels_in,
+            factor
+        );
+        let channels = channels_in / (factor * factor);
+
+        input
+            .reshape([batch_size, channels, factor, factor, height, width])
+            .permute([0, 1, 4, 2, 5, 3])
+            .reshape([batch_size, channels, height * factor, width * factor])
+    }
+}
+
+impl PixelUnshuffle {
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[batch_size, channels, height * downscale_factor, width * downscale_factor]`
+    /// - output: `[batch_size, channels * downscale_factor^2, height, width]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the height or the width of the input is not divisible by `downscale_factor`.
+    pub fn forward<B: Backend>(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+        let [batch_size, channels, height_in, width_in] = input.dims();
+        let factor = self.downscale_factor;
+
+        assert!(
+            height_in % factor == 0 && width_in % factor == 0,
+            "The height({}) and width({}) must be divisible by the downscale factor({})",
+            height_in,
+            width_in,
+            factor
+        );
+        let [height, width] = [height_in / factor, width_in / factor];
+
+        input
+            .reshape([batch_size, channels, height, factor, width, factor])
+            .permute([0, 1, 3, 5, 2, 4])
+            .reshape([batch_size, channels * factor * factor, height, width])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::{Data, Distribution};
+
+    #[test]
+    fn pixel_shuffle_output_shape() {
+        let input = Tensor::<TestBackend, 4>::zeros([2, 3 * 3 * 3, 4, 5], &Default::default());
+        let output = PixelShuffleConfig::new(3).init().forward(input);
+
+        assert_eq!(output.dims(), [2, 3, 12, 15]);
+    }
+
+    #[test]
+    fn pixel_shuffle_moves_channels_into_pixels() {
+        let device = Default::default();
+        let input = Tensor::<TestBackend, 1, burn_tensor::Int>::arange(0..8, &device)
+            .float()
+            .reshape([1, 4, 1, 2]);
+
+        let output = PixelShuffleConfig::new(2).init().forward(input);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[0.0, 2.0, 1.0, 3.0], [4.0, 6.0, 5.0, 7.0]]]]),
+            3,
+        );
+    }
+
+    #[test]
+    fn pixel_unshuffle_output_shape() {
+        let input = Tensor::<TestBackend, 4>::zeros([2, 3, 12, 15], &Default::default());
+        let output = PixelUnshuffleConfig::new(3).init().forward(input);
+
+        assert_eq!(output.dims(), [2, 27, 4, 5]);
+    }
+
+    #[test]
+    fn pixel_unshuffle_round_trips() {
+        let input = Tensor::<TestBackend, 4>::random(
+            [2, 8, 3, 5],
+            Distribution::Default,
+            &Default::default(),
+        );
+        let shuffle = PixelShuffleConfig::new(2).init();
+        let unshuffle = PixelUnshuffleConfig::new(2).init();
+
+        let output = unshuffle.forward(shuffle.forward(input.clone()));
+
+        output.into_data().assert_approx_eq(&input.into_data(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pixel_shuffle_with_invalid_channels() {
+        let input = Tensor::<TestBackend, 4>::zeros([1, 6, 2, 2], &Default::default());
+        let _ = PixelShuffleConfig::new(2).init().forward(input);
+    }
+}
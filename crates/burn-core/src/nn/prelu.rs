@@ -32,6 +32,13 @@ impl PReluConfig {
             ),
         }
     }
+
+    /// Initialize a new [Parametric Relu](PRelu) Layer with a [record](PReluRecord).
+    pub fn init_with<B: Backend>(&self, record: PReluRecord<B>) -> PRelu<B> {
+        PRelu {
+            alpha: record.alpha,
+        }
+    }
 }
 
 impl<B: Backend> PRelu<B> {
@@ -39,9 +46,81 @@ impl<B: Backend> PRelu<B> {
     ///
     /// # Shapes
     ///
-    /// - input: `[..., any]`
-    /// - output: `[..., any]`
+    /// - input: `[batch_size, channels, ...]`, where `channels` must be equal to `num_parameters`
+    ///   when it is greater than one.
+    /// - output: `[batch_size, channels, ...]`
     pub fn forward<const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
         crate::tensor::activation::prelu(input, self.alpha.val())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Data;
+
+    #[cfg(feature = "std")]
+    use crate::TestAutodiffBackend;
+
+    #[test]
+    fn forward_with_shared_slope() {
+        let device = Default::default();
+        let prelu = PReluConfig::new().init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 2>::from_floats([[-1.0, 2.0], [-4.0, 0.5]], &device);
+
+        let output = prelu.forward(input);
+
+        output
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.25, 2.0], [-1.0, 0.5]]), 3);
+    }
+
+    #[test]
+    fn forward_with_per_channel_slopes() {
+        let device = Default::default();
+        let mut prelu = PReluConfig::new()
+            .with_num_parameters(2)
+            .init::<TestBackend>(&device);
+        prelu.alpha = Param::from(Tensor::from_floats([0.1, 0.5], &device));
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [[[-1.0, 2.0], [-4.0, 0.5]], [[3.0, -2.0], [-1.0, -6.0]]],
+            &device,
+        );
+
+        let output = prelu.forward(input);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[-0.1, 2.0], [-2.0, 0.5]], [[3.0, -0.2], [-0.5, -3.0]]]),
+            3,
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn backward_flows_into_the_slopes() {
+        let device = Default::default();
+        let prelu = PReluConfig::new()
+            .with_num_parameters(3)
+            .init::<TestAutodiffBackend>(&device);
+        let input = Tensor::<TestAutodiffBackend, 2>::from_floats(
+            [[-1.0, 2.0, 1.0], [-3.0, 0.5, -2.0]],
+            &device,
+        )
+        .require_grad();
+
+        let output = prelu.forward(input.clone());
+        let grads = output.sum().backward();
+
+        let alpha_grad = prelu.alpha.grad(&grads).unwrap();
+        let input_grad = input.grad(&grads).unwrap();
+
+        // The slope of each channel receives the sum of its negative inputs.
+        alpha_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([-4.0, 0.0, -2.0]), 3);
+        input_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[0.25, 1.0, 1.0], [0.25, 1.0, 0.25]]), 3);
+    }
+}
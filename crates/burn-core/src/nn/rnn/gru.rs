@@ -94,68 +94,72 @@ impl GruConfig {
 }
 
 impl<B: Backend> Gru<B> {
-    /// Applies the forward pass on the input tensor. This GRU implementation
-    /// returns a single state tensor with dimensions [batch_size, sequence_length, hidden_size].
+    /// Applies the forward pass on the input tensor. This GRU implementation uses the gate
+    /// formulation of PyTorch's `GRUCell`, where the reset gate is applied after the hidden
+    /// transformation of the new gate:
+    ///
+    /// ```text
+    /// z(t) = sigmoid(Wz*X + Uz*H + bz)
+    /// r(t) = sigmoid(Wr*X + Ur*H + br)
+    /// g(t) = tanh(Wn*X + bn + r(t) * (Un*H + cn))
+    /// H(t) = (1 - z(t)) * g(t) + z(t) * H
+    /// ```
     ///
     /// Parameters:
     ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
-    ///     state: An optional tensor representing an initial cell state with the same dimensions
-    ///            as batched_input. If none is provided, one will be generated.
+    ///     state: An optional tensor representing the initial hidden state of shape
+    ///            [batch_size, hidden_size]. If none is provided, it is initialized to zeros.
     ///
     /// Returns:
-    ///     The resulting state tensor, with shape [batch_size, sequence_length, hidden_size].
+    ///     A tuple of tensors, where the first tensor represents the hidden states for each
+    ///     sequence element, with shape [batch_size, sequence_length, hidden_size], and the
+    ///     second tensor the final hidden state, with shape [batch_size, hidden_size].
+    ///     The final hidden state is the initial state for an empty sequence.
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
-        state: Option<Tensor<B, 3>>,
-    ) -> Tensor<B, 3> {
+        state: Option<Tensor<B, 2>>,
+    ) -> (Tensor<B, 3>, Tensor<B, 2>) {
         let [batch_size, seq_length, _] = batched_input.shape().dims;
+        let device = &batched_input.device();
+        let mut batched_hidden_state =
+            Tensor::zeros([batch_size, seq_length, self.d_hidden], device);
 
         let mut hidden_state = match state {
             Some(state) => state,
-            None => Tensor::zeros(
-                [batch_size, seq_length, self.d_hidden],
-                &batched_input.device(),
-            ),
+            None => Tensor::zeros([batch_size, self.d_hidden], device),
         };
 
-        for (t, (input_t, hidden_t)) in batched_input
-            .iter_dim(1)
-            .zip(hidden_state.clone().iter_dim(1))
-            .enumerate()
-        {
+        for (t, input_t) in batched_input.iter_dim(1).enumerate() {
             let input_t = input_t.squeeze(1);
-            let hidden_t = hidden_t.squeeze(1);
             // u(pdate)g(ate) tensors
-            let biased_ug_input_sum = self.gate_product(&input_t, &hidden_t, &self.update_gate);
+            let biased_ug_input_sum = self.gate_product(&input_t, &hidden_state, &self.update_gate);
             let update_values = activation::sigmoid(biased_ug_input_sum); // Colloquially referred to as z(t)
 
             // r(eset)g(ate) tensors
-            let biased_rg_input_sum = self.gate_product(&input_t, &hidden_t, &self.reset_gate);
+            let biased_rg_input_sum = self.gate_product(&input_t, &hidden_state, &self.reset_gate);
             let reset_values = activation::sigmoid(biased_rg_input_sum); // Colloquially referred to as r(t)
-            let reset_t = hidden_t.clone().mul(reset_values); // Passed as input to new_gate
 
-            // n(ew)g(ate) tensor
-            let biased_ng_input_sum = self.gate_product(&input_t, &reset_t, &self.new_gate);
-            let candidate_state = biased_ng_input_sum.tanh(); // Colloquially referred to as g(t)
+            // n(ew)g(ate) tensor, the reset gate only applies to the hidden transformation
+            let new_input = self.new_gate.input_transform.forward(input_t);
+            let new_hidden = self.new_gate.hidden_transform.forward(hidden_state.clone());
+            let candidate_state = (new_input + reset_values * new_hidden).tanh(); // Colloquially referred to as g(t)
 
             // calculate linear interpolation between previous hidden state and candidate state:
             // g(t) * (1 - z(t)) + z(t) * hidden_t
-            let state_vector = candidate_state
-                .clone()
+            hidden_state = candidate_state
                 .mul(update_values.clone().sub_scalar(1).mul_scalar(-1)) // (1 - z(t)) = -(z(t) - 1)
-                + update_values.clone().mul(hidden_t);
+                + update_values.mul(hidden_state);
 
-            let current_shape = state_vector.shape().dims;
-            let unsqueezed_shape = [current_shape[0], 1, current_shape[1]];
-            let reshaped_state_vector = state_vector.reshape(unsqueezed_shape);
-            hidden_state = hidden_state.slice_assign(
+            let unsqueezed_hidden_state =
+                hidden_state.clone().reshape([batch_size, 1, self.d_hidden]);
+            batched_hidden_state = batched_hidden_state.slice_assign(
                 [0..batch_size, t..(t + 1), 0..self.d_hidden],
-                reshaped_state_vector,
+                unsqueezed_hidden_state,
             );
         }
 
-        hidden_state
+        (batched_hidden_state, hidden_state)
     }
 
     /// Helper function for performing weighted matrix product for a gate and adds
@@ -204,84 +208,131 @@ mod tests {
     use crate::{module::Param, nn::LinearRecord, TestBackend};
     use burn_tensor::{Data, Distribution};
 
+    #[cfg(feature = "std")]
+    use crate::TestAutodiffBackend;
+
+    fn create_gate_controller<B: Backend>(
+        weights: f32,
+        biases: f32,
+        d_input: usize,
+        d_output: usize,
+        bias: bool,
+        initializer: Initializer,
+        device: &B::Device,
+    ) -> GateController<B> {
+        let record_1 = LinearRecord {
+            weight: Param::from(Tensor::from_data(Data::from([[weights]]).convert(), device)),
+            bias: Some(Param::from(Tensor::from_data(
+                Data::from([biases]).convert(),
+                device,
+            ))),
+        };
+        let record_2 = LinearRecord {
+            weight: Param::from(Tensor::from_data(Data::from([[weights]]).convert(), device)),
+            bias: Some(Param::from(Tensor::from_data(
+                Data::from([biases]).convert(),
+                device,
+            ))),
+        };
+        gate_controller::GateController::create_with_weights(
+            d_input,
+            d_output,
+            bias,
+            initializer,
+            record_1,
+            record_2,
+        )
+    }
+
+    /// A single feature Gru with the given weights and biases for the update, reset and new
+    /// gates, shared by the input and hidden transformations.
+    fn create_gru<B: Backend>(gates: [(f32, f32); 3], device: &B::Device) -> Gru<B> {
+        let mut gru = GruConfig::new(1, 1, true).init::<B>(device);
+        let gate = |(weights, biases)| {
+            create_gate_controller(
+                weights,
+                biases,
+                1,
+                1,
+                true,
+                Initializer::XavierNormal { gain: 1.0 },
+                device,
+            )
+        };
+
+        gru.update_gate = gate(gates[0]);
+        gru.reset_gate = gate(gates[1]);
+        gru.new_gate = gate(gates[2]);
+        gru
+    }
+
     /// Test forward pass with simple input vector.
     ///
     /// z_t = sigmoid(0.5*0.1 + 0.5*0) = 0.5125
     /// r_t = sigmoid(0.6*0.1 + 0.*0) = 0.5150
-    /// g_t = tanh(0.7*0.1 + 0.7*0) = 0.0699
+    /// g_t = tanh(0.7*0.1 + r_t*0.7*0) = 0.0699
     ///
     /// h_t = z_t * h' + (1 - z_t) * g_t = 0.0341
     #[test]
     fn tests_forward_single_input_single_feature() {
         TestBackend::seed(0);
-        let config = GruConfig::new(1, 1, false);
         let device = Default::default();
-        let mut gru = config.init::<TestBackend>(&device);
-
-        fn create_gate_controller(
-            weights: f32,
-            biases: f32,
-            d_input: usize,
-            d_output: usize,
-            bias: bool,
-            initializer: Initializer,
-            device: &<TestBackend as Backend>::Device,
-        ) -> GateController<TestBackend> {
-            let record_1 = LinearRecord {
-                weight: Param::from(Tensor::from_data(Data::from([[weights]]), device)),
-                bias: Some(Param::from(Tensor::from_data(Data::from([biases]), device))),
-            };
-            let record_2 = LinearRecord {
-                weight: Param::from(Tensor::from_data(Data::from([[weights]]), device)),
-                bias: Some(Param::from(Tensor::from_data(Data::from([biases]), device))),
-            };
-            gate_controller::GateController::create_with_weights(
-                d_input,
-                d_output,
-                bias,
-                initializer,
-                record_1,
-                record_2,
-            )
-        }
-
-        gru.update_gate = create_gate_controller(
-            0.5,
-            0.0,
-            1,
-            1,
-            false,
-            Initializer::XavierNormal { gain: 1.0 },
-            &device,
-        );
-        gru.reset_gate = create_gate_controller(
-            0.6,
-            0.0,
-            1,
-            1,
-            false,
-            Initializer::XavierNormal { gain: 1.0 },
-            &device,
-        );
-        gru.new_gate = create_gate_controller(
-            0.7,
-            0.0,
-            1,
-            1,
-            false,
-            Initializer::XavierNormal { gain: 1.0 },
-            &device,
-        );
+        let gru = create_gru::<TestBackend>([(0.5, 0.0), (0.6, 0.0), (0.7, 0.0)], &device);
 
         let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1]]]), &device);
 
-        let state = gru.forward(input, None);
+        let (state, _) = gru.forward(input, None);
 
         let output = state.select(0, Tensor::arange(0..1, &device)).squeeze(0);
 
         output.to_data().assert_approx_eq(&Data::from([[0.034]]), 3);
     }
 
+    /// The hidden state is carried over the sequence, values computed with PyTorch's `GRUCell`
+    /// equations.
+    #[test]
+    fn test_forward_sequence_with_initial_state() {
+        let device = Default::default();
+        let gru = create_gru::<TestBackend>([(0.5, 0.1), (0.6, -0.2), (0.7, 0.3)], &device);
+        let input =
+            Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1], [0.2], [-0.3]]]), &device);
+
+        let (states, state) = gru.forward(input.clone(), None);
+
+        states
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.2005], [0.3474], [0.3273]]]), 3);
+        state.to_data().assert_approx_eq(&Data::from([[0.3273]]), 3);
+
+        let initial_state = Tensor::from_data(Data::from([[0.5]]), &device);
+        let (states, state) = gru.forward(input, Some(initial_state));
+
+        states
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.5366], [0.5796], [0.4973]]]), 3);
+        state.to_data().assert_approx_eq(&Data::from([[0.4973]]), 3);
+    }
+
+    #[test]
+    fn test_forward_empty_sequence() {
+        let device = Default::default();
+        let gru = GruConfig::new(4, 8, true).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::zeros([2, 0, 4], &device);
+        let initial_state =
+            Tensor::<TestBackend, 2>::random([2, 8], Distribution::Default, &device);
+
+        let (states, state) = gru.forward(input.clone(), Some(initial_state.clone()));
+
+        assert_eq!(states.dims(), [2, 0, 8]);
+        state
+            .into_data()
+            .assert_approx_eq(&initial_state.into_data(), 3);
+
+        let (_, state) = gru.forward(input, None);
+
+        state.into_data().assert_approx_eq(&Data::zeros([2, 8]), 3);
+    }
+
     #[test]
     fn test_batched_forward_pass() {
         let device = Default::default();
@@ -289,8 +340,34 @@ mod tests {
         let batched_input =
             Tensor::<TestBackend, 3>::random([8, 10, 64], Distribution::Default, &device);
 
-        let hidden_state = gru.forward(batched_input, None);
+        let (hidden_states, hidden_state) = gru.forward(batched_input, None);
+
+        assert_eq!(hidden_states.shape().dims, [8, 10, 1024]);
+        assert_eq!(hidden_state.shape().dims, [8, 1024]);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_backward() {
+        let device = Default::default();
+        let gru = create_gru::<TestAutodiffBackend>([(0.5, 0.1), (0.6, -0.2), (0.7, 0.3)], &device);
+        let input = Tensor::<TestAutodiffBackend, 3>::from_data(
+            Data::from([[[0.1], [0.2], [-0.3]]]),
+            &device,
+        )
+        .require_grad();
+
+        let (_, state) = gru.forward(input.clone(), None);
+        let grads = state.backward();
+
+        let input_grad = input.grad(&grads).unwrap();
+        let weight_grad = gru.new_gate.input_transform.weight.grad(&grads).unwrap();
 
-        assert_eq!(hidden_state.shape().dims, [8, 10, 1024]);
+        input_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.0940], [0.1166], [0.3200]]]), 3);
+        weight_grad
+            .to_data()
+            .assert_approx_eq(&Data::from([[-0.0663]]), 3);
     }
 }
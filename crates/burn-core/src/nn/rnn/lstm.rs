@@ -1,8 +1,12 @@
+use alloc::vec::Vec;
+
 use crate as burn;
 
 use crate::config::Config;
 use crate::module::Module;
 use crate::nn::rnn::gate_controller;
+use crate::nn::Dropout;
+use crate::nn::DropoutConfig;
 use crate::nn::Initializer;
 use crate::nn::LinearConfig;
 use crate::tensor::backend::Backend;
@@ -23,11 +27,58 @@ pub struct LstmConfig {
     /// Lstm initializer
     #[config(default = "Initializer::XavierNormal{gain:1.0}")]
     pub initializer: Initializer,
+    /// The number of stacked layers, each layer taking the hidden states of the previous layer as
+    /// input.
+    #[config(default = "1")]
+    pub num_layers: usize,
+    /// If each layer also processes the sequence in reverse order, with its own set of gates.
+    #[config(default = false)]
+    pub bidirectional: bool,
+    /// The dropout rate applied to the outputs of each layer, except the last one.
+    #[config(default = 0.0)]
+    pub dropout_between_layers: f64,
+}
+
+/// The cell states and hidden states of a [lstm](Lstm) module.
+#[derive(Debug, Clone)]
+pub struct LstmState<B: Backend, const D: usize> {
+    /// The cell states.
+    pub cell: Tensor<B, D>,
+    /// The hidden states.
+    pub hidden: Tensor<B, D>,
 }
 
-/// The Lstm module. This implementation is for a unidirectional, stateless, Lstm.
+impl<B: Backend, const D: usize> LstmState<B, D> {
+    /// Create a new [lstm state](LstmState).
+    pub fn new(cell: Tensor<B, D>, hidden: Tensor<B, D>) -> Self {
+        Self { cell, hidden }
+    }
+}
+
+/// The Lstm module. This implementation is for a stateless Lstm, made of one or more stacked
+/// layers, each processing the sequence in one or both directions.
+///
+/// The cell of the direction `d` of the layer `l` is at the index `l * num_directions + d`, the
+/// forward direction first. The gates of the first cell are fields of the module, so a single
+/// layer and direction lstm has the same [record](LstmRecord) as before the support of stacked
+/// and bidirectional layers, and the records saved with older versions can still be loaded.
 #[derive(Module, Debug)]
 pub struct Lstm<B: Backend> {
+    input_gate: GateController<B>,
+    forget_gate: GateController<B>,
+    output_gate: GateController<B>,
+    cell_gate: GateController<B>,
+    /// The cells after the first one, `None` with a single layer and direction.
+    cells: Option<Vec<LstmCell<B>>>,
+    dropout: Dropout,
+    d_hidden: usize,
+    num_layers: usize,
+    bidirectional: bool,
+}
+
+/// A single direction of a single [lstm](Lstm) layer.
+#[derive(Module, Debug)]
+pub struct LstmCell<B: Backend> {
     input_gate: GateController<B>,
     forget_gate: GateController<B>,
     output_gate: GateController<B>,
@@ -38,56 +89,129 @@ pub struct Lstm<B: Backend> {
 impl LstmConfig {
     /// Initialize a new [lstm](Lstm) module.
     pub fn init<B: Backend>(&self, device: &B::Device) -> Lstm<B> {
-        let d_output = self.d_hidden;
+        self.check();
 
-        let input_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-            device,
-        );
-        let forget_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-            device,
-        );
-        let output_gate = gate_controller::GateController::new(
+        let first = self.init_cell(self.d_input, device);
+        let cells = self.num_cells_after_first().map(|num_cells| {
+            (1..=num_cells)
+                .map(|index| self.init_cell(self.cell_d_input(index), device))
+                .collect()
+        });
+
+        Lstm {
+            input_gate: first.input_gate,
+            forget_gate: first.forget_gate,
+            output_gate: first.output_gate,
+            cell_gate: first.cell_gate,
+            cells,
+            dropout: DropoutConfig::new(self.dropout_between_layers).init(),
+            d_hidden: self.d_hidden,
+            num_layers: self.num_layers,
+            bidirectional: self.bidirectional,
+        }
+    }
+
+    /// Initialize a new [lstm](Lstm) module with a [record](LstmRecord).
+    pub fn init_with<B: Backend>(&self, record: LstmRecord<B>) -> Lstm<B> {
+        self.check();
+
+        let first = self.init_cell_with(
             self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-            device,
+            LstmCellRecord {
+                input_gate: record.input_gate,
+                forget_gate: record.forget_gate,
+                output_gate: record.output_gate,
+                cell_gate: record.cell_gate,
+                d_hidden: record.d_hidden,
+            },
         );
-        let cell_gate = gate_controller::GateController::new(
-            self.d_input,
-            d_output,
-            self.bias,
-            self.initializer.clone(),
-            device,
+        let cells = record.cells.map(|records| {
+            records
+                .into_iter()
+                .enumerate()
+                .map(|(index, record)| self.init_cell_with(self.cell_d_input(index + 1), record))
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(
+            cells.as_ref().map(Vec::len),
+            self.num_cells_after_first(),
+            "The record should have the cells of every layer and direction of the config"
         );
 
         Lstm {
-            input_gate,
-            forget_gate,
-            output_gate,
-            cell_gate,
+            input_gate: first.input_gate,
+            forget_gate: first.forget_gate,
+            output_gate: first.output_gate,
+            cell_gate: first.cell_gate,
+            cells,
+            dropout: DropoutConfig::new(self.dropout_between_layers).init(),
             d_hidden: self.d_hidden,
+            num_layers: self.num_layers,
+            bidirectional: self.bidirectional,
         }
     }
 
-    /// Initialize a new [lstm](Lstm) module with a [record](LstmRecord).
-    pub fn init_with<B: Backend>(&self, record: LstmRecord<B>) -> Lstm<B> {
+    fn check(&self) {
+        assert!(
+            self.num_layers > 0,
+            "An lstm must have at least one layer, got num_layers({})",
+            self.num_layers
+        );
+    }
+
+    fn num_directions(&self) -> usize {
+        match self.bidirectional {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    fn num_cells_after_first(&self) -> Option<usize> {
+        match self.num_layers * self.num_directions() {
+            1 => None,
+            num_cells => Some(num_cells - 1),
+        }
+    }
+
+    /// The first layer takes the input features, the other layers the concatenated hidden states
+    /// of every direction of the previous layer.
+    fn cell_d_input(&self, index: usize) -> usize {
+        match index < self.num_directions() {
+            true => self.d_input,
+            false => self.num_directions() * self.d_hidden,
+        }
+    }
+
+    fn init_cell<B: Backend>(&self, d_input: usize, device: &B::Device) -> LstmCell<B> {
+        let d_output = self.d_hidden;
+        let gate = || {
+            gate_controller::GateController::new(
+                d_input,
+                d_output,
+                self.bias,
+                self.initializer.clone(),
+                device,
+            )
+        };
+
+        LstmCell {
+            input_gate: gate(),
+            forget_gate: gate(),
+            output_gate: gate(),
+            cell_gate: gate(),
+            d_hidden: self.d_hidden,
+        }
+    }
+
+    fn init_cell_with<B: Backend>(&self, d_input: usize, record: LstmCellRecord<B>) -> LstmCell<B> {
         let linear_config = LinearConfig {
-            d_input: self.d_input,
+            d_input,
             d_output: self.d_hidden,
             bias: self.bias,
             initializer: self.initializer.clone(),
         };
 
-        Lstm {
+        LstmCell {
             input_gate: gate_controller::GateController::new_with(
                 &linear_config,
                 record.input_gate,
@@ -107,33 +231,170 @@ impl LstmConfig {
 }
 
 impl<B: Backend> Lstm<B> {
-    /// Applies the forward pass on the input tensor. This LSTM implementation
-    /// returns the cell state and hidden state for each element in a sequence (i.e., across `seq_length`),
-    /// producing 3-dimensional tensors where the dimensions represent [batch_size, sequence_length, hidden_size].
+    /// Applies the forward pass on the input tensor. This LSTM implementation returns the hidden
+    /// states of the last layer for each element in a sequence, along with the final cell state
+    /// and hidden state of every layer and direction.
+    ///
+    /// The reverse direction of a layer processes the sequence from its last element to its first,
+    /// so its final states are the ones computed for the first element of the sequence.
+    ///
+    /// Parameters:
+    ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
+    ///     state: An optional initial state, with cell and hidden states of shape
+    ///            [num_layers * num_directions, batch_size, hidden_size].
+    ///            If no initial state is provided, the states are initialized to zeros.
+    ///
+    /// Returns:
+    ///     A tuple where the first tensor holds the hidden states of the last layer for each
+    ///     sequence element, with shape [batch_size, sequence_length, num_directions * hidden_size],
+    ///     the forward direction features coming first. The second element holds the final cell
+    ///     and hidden states, with shape [num_layers * num_directions, batch_size, hidden_size],
+    ///     the states of the direction `d` of the layer `l` being at the index
+    ///     `l * num_directions + d`.
+    ///
+    /// # Breaking change
+    ///
+    /// This method used to return the cell states and the hidden states of every sequence
+    /// element, in that order. The hidden states now come first and only the final cell states
+    /// are returned, the cell states of the other elements aren't available anymore. The initial
+    /// state also changed from a `(cell, hidden)` tuple of shape [batch_size, hidden_size] to a
+    /// [lstm state](LstmState) with the leading layer and direction dimension.
+    pub fn forward(
+        &self,
+        batched_input: Tensor<B, 3>,
+        state: Option<LstmState<B, 3>>,
+    ) -> (Tensor<B, 3>, LstmState<B, 3>) {
+        let num_directions = self.num_directions();
+        let initial_state = |index: usize| {
+            state.as_ref().map(|state| {
+                LstmState::new(
+                    state.cell.clone().narrow(0, index, 1).squeeze(0),
+                    state.hidden.clone().narrow(0, index, 1).squeeze(0),
+                )
+            })
+        };
+
+        let mut input = batched_input;
+        let mut cell_states = Vec::with_capacity(self.num_layers * num_directions);
+        let mut hidden_states = Vec::with_capacity(self.num_layers * num_directions);
+
+        for layer in 0..self.num_layers {
+            if layer > 0 {
+                input = self.dropout.forward(input);
+            }
+
+            let mut outputs = Vec::with_capacity(num_directions);
+
+            for direction in 0..num_directions {
+                let index = layer * num_directions + direction;
+                let gates = self.gates(index);
+
+                let (output, state) = match direction {
+                    0 => gates.forward(input.clone(), initial_state(index)),
+                    _ => {
+                        let (output, state) =
+                            gates.forward(input.clone().flip([1]), initial_state(index));
+                        (output.flip([1]), state)
+                    }
+                };
+
+                outputs.push(output);
+                cell_states.push(state.cell);
+                hidden_states.push(state.hidden);
+            }
+
+            input = match num_directions {
+                1 => outputs.remove(0),
+                _ => Tensor::cat(outputs, 2),
+            };
+        }
+
+        let state = LstmState::new(
+            Tensor::stack(cell_states, 0),
+            Tensor::stack(hidden_states, 0),
+        );
+
+        (input, state)
+    }
+
+    fn num_directions(&self) -> usize {
+        match self.bidirectional {
+            true => 2,
+            false => 1,
+        }
+    }
+
+    fn gates(&self, index: usize) -> LstmGates<'_, B> {
+        match (index, &self.cells) {
+            (0, _) => LstmGates {
+                input_gate: &self.input_gate,
+                forget_gate: &self.forget_gate,
+                output_gate: &self.output_gate,
+                cell_gate: &self.cell_gate,
+                d_hidden: self.d_hidden,
+            },
+            (_, Some(cells)) => cells[index - 1].gates(),
+            (_, None) => panic!("The lstm has a single cell, got the index {index}"),
+        }
+    }
+}
+
+impl<B: Backend> LstmCell<B> {
+    /// Applies the forward pass of a single direction of a single layer on the input tensor,
+    /// processing the sequence from its first element to its last.
     ///
     /// Parameters:
     ///     batched_input: The input tensor of shape [batch_size, sequence_length, input_size].
-    ///     state: An optional tuple of tensors representing the initial cell state and hidden state.
-    ///            Each state tensor has shape [batch_size, hidden_size].
-    ///            If no initial state is provided, these tensors are initialized to zeros.
+    ///     state: An optional initial state, with cell and hidden states of shape
+    ///            [batch_size, hidden_size].
+    ///            If no initial state is provided, the states are initialized to zeros.
     ///
     /// Returns:
-    ///     A tuple of tensors, where the first tensor represents the cell states and
-    ///     the second tensor represents the hidden states for each sequence element.
-    ///     Both output tensors have the shape [batch_size, sequence_length, hidden_size].
+    ///     A tuple where the first tensor holds the hidden states for each sequence element, with
+    ///     shape [batch_size, sequence_length, hidden_size], and the second element holds the final
+    ///     cell and hidden states, with shape [batch_size, hidden_size].
     pub fn forward(
         &self,
         batched_input: Tensor<B, 3>,
-        state: Option<(Tensor<B, 2>, Tensor<B, 2>)>,
-    ) -> (Tensor<B, 3>, Tensor<B, 3>) {
+        state: Option<LstmState<B, 2>>,
+    ) -> (Tensor<B, 3>, LstmState<B, 2>) {
+        self.gates().forward(batched_input, state)
+    }
+
+    fn gates(&self) -> LstmGates<'_, B> {
+        LstmGates {
+            input_gate: &self.input_gate,
+            forget_gate: &self.forget_gate,
+            output_gate: &self.output_gate,
+            cell_gate: &self.cell_gate,
+            d_hidden: self.d_hidden,
+        }
+    }
+}
+
+/// The gates of a single direction of a single layer, borrowed from a [lstm](Lstm) or a
+/// [lstm cell](LstmCell).
+struct LstmGates<'a, B: Backend> {
+    input_gate: &'a GateController<B>,
+    forget_gate: &'a GateController<B>,
+    output_gate: &'a GateController<B>,
+    cell_gate: &'a GateController<B>,
+    d_hidden: usize,
+}
+
+impl<'a, B: Backend> LstmGates<'a, B> {
+    fn forward(
+        &self,
+        batched_input: Tensor<B, 3>,
+        state: Option<LstmState<B, 2>>,
+    ) -> (Tensor<B, 3>, LstmState<B, 2>) {
         let [batch_size, seq_length, _] = batched_input.shape().dims;
         let device = &batched_input.device();
-        let mut batched_cell_state = Tensor::zeros([batch_size, seq_length, self.d_hidden], device);
         let mut batched_hidden_state =
             Tensor::zeros([batch_size, seq_length, self.d_hidden], device);
 
         let (mut cell_state, mut hidden_state) = match state {
-            Some((cell_state, hidden_state)) => (cell_state, hidden_state),
+            Some(state) => (state.cell, state.hidden),
             None => (
                 Tensor::zeros([batch_size, self.d_hidden], device),
                 Tensor::zeros([batch_size, self.d_hidden], device),
@@ -143,41 +404,38 @@ impl<B: Backend> Lstm<B> {
         for (t, input_t) in batched_input.iter_dim(1).enumerate() {
             let input_t = input_t.squeeze(1);
             // f(orget)g(ate) tensors
-            let biased_fg_input_sum = self.gate_product(&input_t, &hidden_state, &self.forget_gate);
+            let biased_fg_input_sum = self.gate_product(&input_t, &hidden_state, self.forget_gate);
             let forget_values = activation::sigmoid(biased_fg_input_sum); // to multiply with cell state
 
             // i(nput)g(ate) tensors
-            let biased_ig_input_sum = self.gate_product(&input_t, &hidden_state, &self.input_gate);
+            let biased_ig_input_sum = self.gate_product(&input_t, &hidden_state, self.input_gate);
             let add_values = activation::sigmoid(biased_ig_input_sum);
 
             // o(output)g(ate) tensors
-            let biased_og_input_sum = self.gate_product(&input_t, &hidden_state, &self.output_gate);
+            let biased_og_input_sum = self.gate_product(&input_t, &hidden_state, self.output_gate);
             let output_values = activation::sigmoid(biased_og_input_sum);
 
             // c(ell)g(ate) tensors
-            let biased_cg_input_sum = self.gate_product(&input_t, &hidden_state, &self.cell_gate);
+            let biased_cg_input_sum = self.gate_product(&input_t, &hidden_state, self.cell_gate);
             let candidate_cell_values = biased_cg_input_sum.tanh();
 
             cell_state = forget_values * cell_state.clone() + add_values * candidate_cell_values;
             hidden_state = output_values * cell_state.clone().tanh();
 
-            let unsqueezed_shape = [cell_state.shape().dims[0], 1, cell_state.shape().dims[1]];
-
-            let unsqueezed_cell_state = cell_state.clone().reshape(unsqueezed_shape);
+            let unsqueezed_shape = [batch_size, 1, self.d_hidden];
             let unsqueezed_hidden_state = hidden_state.clone().reshape(unsqueezed_shape);
 
-            // store the state for this timestep
-            batched_cell_state = batched_cell_state.slice_assign(
-                [0..batch_size, t..(t + 1), 0..self.d_hidden],
-                unsqueezed_cell_state.clone(),
-            );
+            // store the hidden state for this timestep
             batched_hidden_state = batched_hidden_state.slice_assign(
                 [0..batch_size, t..(t + 1), 0..self.d_hidden],
-                unsqueezed_hidden_state.clone(),
+                unsqueezed_hidden_state,
             );
         }
 
-        (batched_cell_state, batched_hidden_state)
+        (
+            batched_hidden_state,
+            LstmState::new(cell_state, hidden_state),
+        )
     }
 
     /// Helper function for performing weighted matrix product for a gate and adds
@@ -224,6 +482,7 @@ impl<B: Backend> Lstm<B> {
 mod tests {
     use super::*;
     use crate::{module::Param, nn::LinearRecord, TestBackend};
+    use alloc::vec;
     use burn_tensor::{Data, Distribution};
 
     #[cfg(feature = "std")]
@@ -238,12 +497,12 @@ mod tests {
         let lstm = config.init::<TestBackend>(&Default::default());
 
         let gate_to_data =
-            |gate: GateController<TestBackend>| gate.input_transform.weight.val().to_data();
+            |gate: &GateController<TestBackend>| gate.input_transform.weight.val().to_data();
 
-        gate_to_data(lstm.input_gate).assert_within_range(0..1);
-        gate_to_data(lstm.forget_gate).assert_within_range(0..1);
-        gate_to_data(lstm.output_gate).assert_within_range(0..1);
-        gate_to_data(lstm.cell_gate).assert_within_range(0..1);
+        gate_to_data(&lstm.input_gate).assert_within_range(0..1);
+        gate_to_data(&lstm.forget_gate).assert_within_range(0..1);
+        gate_to_data(&lstm.output_gate).assert_within_range(0..1);
+        gate_to_data(&lstm.cell_gate).assert_within_range(0..1);
     }
 
     /// Test forward pass with simple input vector.
@@ -329,19 +588,18 @@ mod tests {
         // single timestep with single feature
         let input = Tensor::<TestBackend, 3>::from_data(Data::from([[[0.1]]]), &device);
 
-        let (cell_state_batch, hidden_state_batch) = lstm.forward(input, None);
-        let cell_state = cell_state_batch
-            .select(0, Tensor::arange(0..1, &device))
-            .squeeze(0);
-        let hidden_state = hidden_state_batch
-            .select(0, Tensor::arange(0..1, &device))
-            .squeeze(0);
-        cell_state
+        let (output, state) = lstm.forward(input, None);
+        state
+            .cell
+            .to_data()
+            .assert_approx_eq(&Data::from([[[0.046]]]), 3);
+        state
+            .hidden
             .to_data()
-            .assert_approx_eq(&Data::from([[0.046]]), 3);
-        hidden_state
+            .assert_approx_eq(&Data::from([[[0.024]]]), 3);
+        output
             .to_data()
-            .assert_approx_eq(&Data::from([[0.024]]), 3)
+            .assert_approx_eq(&Data::from([[[0.024]]]), 3)
     }
 
     #[test]
@@ -351,10 +609,11 @@ mod tests {
         let batched_input =
             Tensor::<TestBackend, 3>::random([8, 10, 64], Distribution::Default, &device);
 
-        let (cell_state, hidden_state) = lstm.forward(batched_input, None);
+        let (output, state) = lstm.forward(batched_input, None);
 
-        assert_eq!(cell_state.shape().dims, [8, 10, 1024]);
-        assert_eq!(hidden_state.shape().dims, [8, 10, 1024]);
+        assert_eq!(output.shape().dims, [8, 10, 1024]);
+        assert_eq!(state.cell.shape().dims, [1, 8, 1024]);
+        assert_eq!(state.hidden.shape().dims, [1, 8, 1024]);
     }
 
     #[test]
@@ -367,8 +626,8 @@ mod tests {
         let batched_input =
             Tensor::<TestAutodiffBackend, 3>::random(shape, Distribution::Default, &device);
 
-        let (cell_state, hidden_state) = lstm.forward(batched_input.clone(), None);
-        let fake_loss = cell_state + hidden_state;
+        let (output, state) = lstm.forward(batched_input.clone(), None);
+        let fake_loss = output.sum() + state.cell.sum();
         let grads = fake_loss.backward();
 
         let some_gradient = lstm
@@ -381,4 +640,136 @@ mod tests {
         // Asserts that the gradients exist and are non-zero
         assert!(*some_gradient.any().into_data().value.first().unwrap());
     }
+
+    #[test]
+    fn test_stacked_bidirectional_forward_shapes() {
+        let device = Default::default();
+        let lstm = LstmConfig::new(6, 4, true)
+            .with_num_layers(2)
+            .with_bidirectional(true)
+            .init::<TestBackend>(&device);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([3, 5, 6], Distribution::Default, &device);
+
+        let (output, state) = lstm.forward(batched_input.clone(), None);
+
+        assert_eq!(output.shape().dims, [3, 5, 8]);
+        assert_eq!(state.cell.shape().dims, [4, 3, 4]);
+        assert_eq!(state.hidden.shape().dims, [4, 3, 4]);
+
+        let (output, state) = lstm.forward(batched_input, Some(state));
+
+        assert_eq!(output.shape().dims, [3, 5, 8]);
+        assert_eq!(state.hidden.shape().dims, [4, 3, 4]);
+    }
+
+    #[test]
+    fn test_bidirectional_reverse_direction_processes_reversed_sequence() {
+        let device = Default::default();
+        let config = LstmConfig::new(3, 4, true);
+        let forward_lstm = config.init::<TestBackend>(&device);
+        let reverse_lstm = config.init::<TestBackend>(&device);
+        let mut lstm = config.with_bidirectional(true).init::<TestBackend>(&device);
+        lstm.input_gate = forward_lstm.input_gate.clone();
+        lstm.forget_gate = forward_lstm.forget_gate.clone();
+        lstm.output_gate = forward_lstm.output_gate.clone();
+        lstm.cell_gate = forward_lstm.cell_gate.clone();
+        lstm.cells = Some(vec![LstmCell {
+            input_gate: reverse_lstm.input_gate.clone(),
+            forget_gate: reverse_lstm.forget_gate.clone(),
+            output_gate: reverse_lstm.output_gate.clone(),
+            cell_gate: reverse_lstm.cell_gate.clone(),
+            d_hidden: 4,
+        }]);
+        let batched_input =
+            Tensor::<TestBackend, 3>::random([2, 5, 3], Distribution::Default, &device);
+
+        let (output, state) = lstm.forward(batched_input.clone(), None);
+        let (expected_forward, forward_state) = forward_lstm.forward(batched_input.clone(), None);
+        let (expected_reverse, reverse_state) = reverse_lstm.forward(batched_input.flip([1]), None);
+
+        output
+            .clone()
+            .narrow(2, 0, 4)
+            .into_data()
+            .assert_approx_eq(&expected_forward.into_data(), 3);
+        output
+            .narrow(2, 4, 4)
+            .into_data()
+            .assert_approx_eq(&expected_reverse.flip([1]).into_data(), 3);
+        state.hidden.into_data().assert_approx_eq(
+            &Tensor::cat(vec![forward_state.hidden, reverse_state.hidden], 0).into_data(),
+            3,
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stacked_bidirectional_backward_reaches_every_layer() {
+        let device = Default::default();
+        let lstm = LstmConfig::new(6, 4, true)
+            .with_num_layers(2)
+            .with_bidirectional(true)
+            .with_dropout_between_layers(0.1)
+            .init::<TestAutodiffBackend>(&device);
+        let batched_input =
+            Tensor::<TestAutodiffBackend, 3>::random([3, 5, 6], Distribution::Default, &device);
+
+        let (output, _) = lstm.forward(batched_input, None);
+        let grads = output.sum().backward();
+
+        assert_eq!(lstm.cells.as_ref().map(Vec::len), Some(3));
+        for gates in (0..4).map(|index| lstm.gates(index)) {
+            for gate in [
+                gates.input_gate,
+                gates.forget_gate,
+                gates.output_gate,
+                gates.cell_gate,
+            ] {
+                for transform in [&gate.input_transform, &gate.hidden_transform] {
+                    let gradient = transform.weight.grad(&grads).unwrap();
+                    assert!(*gradient.any().into_data().value.first().unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_single_layer_record_is_loaded_from_the_gates() {
+        use crate::record::{FullPrecisionSettings, NamedMpkBytesRecorder, Recorder};
+
+        /// The layout of a single layer lstm before the support of stacked layers.
+        #[derive(Module, Debug)]
+        struct LegacyLstm<B: Backend> {
+            input_gate: GateController<B>,
+            forget_gate: GateController<B>,
+            output_gate: GateController<B>,
+            cell_gate: GateController<B>,
+            d_hidden: usize,
+        }
+
+        let device = Default::default();
+        let config = LstmConfig::new(3, 4, true);
+        let lstm = config.init::<TestBackend>(&device);
+        let legacy = LegacyLstm {
+            input_gate: lstm.input_gate.clone(),
+            forget_gate: lstm.forget_gate.clone(),
+            output_gate: lstm.output_gate.clone(),
+            cell_gate: lstm.cell_gate.clone(),
+            d_hidden: 4,
+        };
+        let recorder = NamedMpkBytesRecorder::<FullPrecisionSettings>::default();
+        let bytes = recorder.record(legacy.into_record(), ()).unwrap();
+
+        let record: LstmRecord<TestBackend> = recorder.load(bytes, &device).unwrap();
+        let loaded = config.init_with(record);
+
+        let input = Tensor::<TestBackend, 3>::random([2, 5, 3], Distribution::Default, &device);
+        let (expected, _) = lstm.forward(input.clone(), None);
+        let (output, _) = loaded.forward(input, None);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
 }
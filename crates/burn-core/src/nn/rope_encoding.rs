@@ -0,0 +1,246 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate as burn;
+use crate::config::Config;
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::{Int, Tensor};
+use burn_tensor::Data;
+
+use libm::{cosf, powf, sinf};
+
+/// Configuration to create a [RotaryEncoding](RotaryEncoding) layer.
+#[derive(Config, Debug)]
+pub struct RotaryEncodingConfig {
+    /// Maximum sequence length of the inputs.
+    pub max_sequence_length: usize,
+
+    /// The size of each vector, usually the size of an attention head.
+    pub d_model: usize,
+
+    /// The base of the rotation frequencies.
+    #[config(default = "10000.0")]
+    pub theta: f32,
+
+    /// The fraction of the vector that is rotated, the remaining features are left unchanged.
+    #[config(default = "1.0")]
+    pub partial_rotary_factor: f32,
+}
+
+/// Rotary positional encoding layer, introduced in [RoFormer: Enhanced Transformer with Rotary
+/// Position Embedding](https://arxiv.org/abs/2104.09864).
+///
+/// Each consecutive pair of rotated features `(x[2i], x[2i + 1])` is rotated by the angle
+/// `p * theta^(-2i / d)`, where `p` is the position of the vector in the sequence and `d` the number
+/// of rotated features. When applied to queries and keys, the dot product between a query and a
+/// key only depends on the difference between their positions.
+#[derive(Module, Debug)]
+pub struct RotaryEncoding<B: Backend> {
+    /// The cosines of the rotation angles, of shape `[max_sequence_length, rotary_dim]`.
+    cos: Tensor<B, 2>,
+    /// The sines of the rotation angles, of shape `[max_sequence_length, rotary_dim]`.
+    sin: Tensor<B, 2>,
+    d_model: usize,
+}
+
+impl RotaryEncodingConfig {
+    /// Initialize a new [RotaryEncoding](RotaryEncoding) module.
+    pub fn init<B: Backend>(&self, device: &B::Device) -> RotaryEncoding<B> {
+        assert!(
+            self.partial_rotary_factor > 0.0 && self.partial_rotary_factor <= 1.0,
+            "partial_rotary_factor({}) must be in the range (0, 1]",
+            self.partial_rotary_factor
+        );
+
+        // Features are rotated by pairs.
+        let rotary_dim = (self.d_model as f32 * self.partial_rotary_factor) as usize / 2 * 2;
+        assert!(
+            rotary_dim > 0,
+            "At least two features must be rotated, got d_model({}) and partial_rotary_factor({})",
+            self.d_model,
+            self.partial_rotary_factor
+        );
+
+        let mut cos = Vec::with_capacity(self.max_sequence_length * rotary_dim);
+        let mut sin = Vec::with_capacity(self.max_sequence_length * rotary_dim);
+
+        for position in 0..self.max_sequence_length {
+            for i in 0..rotary_dim / 2 {
+                let frequency = powf(self.theta, -2.0 * i as f32 / rotary_dim as f32);
+                let angle = position as f32 * frequency;
+
+                cos.extend([cosf(angle); 2]);
+                sin.extend([sinf(angle); 2]);
+            }
+        }
+
+        let shape = [self.max_sequence_length, rotary_dim];
+        let cos = Tensor::from_data(Data::new(cos, shape.into()).convert(), device);
+        let sin = Tensor::from_data(Data::new(sin, shape.into()).convert(), device);
+
+        RotaryEncoding {
+            cos,
+            sin,
+            d_model: self.d_model,
+        }
+    }
+}
+
+impl<B: Backend> RotaryEncoding<B> {
+    /// Applies the rotation to the input tensor, usually the queries or the keys of an attention
+    /// layer, given the position of each vector in the sequence.
+    ///
+    /// # Shapes
+    ///
+    /// * input: `[..., seq_length, d_model]`
+    /// * positions: `[seq_length]`
+    /// * output: `[..., seq_length, d_model]`
+    ///
+    /// # Panics
+    ///
+    /// * Panics if the input has less than two dimensions.
+    /// * Panics if the input d_model is not equal to the d_model of the encoding.
+    /// * Panics if the number of positions is not equal to the sequence length.
+    pub fn forward<const D: usize>(
+        &self,
+        input: Tensor<B, D>,
+        positions: Tensor<B, 1, Int>,
+    ) -> Tensor<B, D> {
+        assert!(D >= 2, "The input must have at least two dimensions");
+
+        let dims = input.dims();
+        let [seq_length, d_model] = [dims[D - 2], dims[D - 1]];
+        let [num_positions] = positions.dims();
+        let [_, rotary_dim] = self.cos.dims();
+
+        assert!(
+            d_model == self.d_model,
+            "d_model({}) of the input must be equal to d_model of encoding({})",
+            d_model,
+            self.d_model,
+        );
+        assert!(
+            num_positions == seq_length,
+            "The number of positions({}) must be equal to the sequence length({})",
+            num_positions,
+            seq_length,
+        );
+
+        let cos = self
+            .cos
+            .clone()
+            .select(0, positions.clone())
+            .unsqueeze::<D>();
+        let sin = self.sin.clone().select(0, positions).unsqueeze::<D>();
+
+        let rotated = input.clone().narrow(D - 1, 0, rotary_dim);
+        let rotated = rotated.clone() * cos + rotate_pairs(rotated) * sin;
+
+        match rotary_dim == d_model {
+            true => rotated,
+            false => Tensor::cat(
+                vec![
+                    rotated,
+                    input.narrow(D - 1, rotary_dim, d_model - rotary_dim),
+                ],
+                D - 1,
+            ),
+        }
+    }
+}
+
+/// Maps each pair of features `(a, b)` to `(-b, a)`.
+fn rotate_pairs<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let shape = tensor.shape();
+    let pairs = tensor.reshape([shape.num_elements() / 2, 2]);
+
+    let first = pairs.clone().narrow(1, 0, 1);
+    let second = pairs.narrow(1, 1, 1);
+
+    Tensor::cat(vec![second.neg(), first], 1).reshape(shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_tensor::Distribution;
+
+    #[test]
+    fn test_rotary_encoding_forward() {
+        let device = Default::default();
+        let rope = RotaryEncodingConfig::new(4, 4)
+            .with_theta(100.0)
+            .init::<TestBackend>(&device);
+
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [[[1.0, 2.0, 3.0, 4.0], [1.0, 2.0, 3.0, 4.0]]],
+            &device,
+        );
+        let positions = Tensor::arange(0..2, &device);
+
+        let output = rope.forward(input, positions);
+
+        // Position 1 rotates the pairs by the angles 1 and 0.1.
+        let (cos_1, sin_1) = (cosf(1.0), sinf(1.0));
+        let (cos_2, sin_2) = (cosf(0.1), sinf(0.1));
+        let expected = Data::from([[
+            [1.0, 2.0, 3.0, 4.0],
+            [
+                cos_1 - 2.0 * sin_1,
+                sin_1 + 2.0 * cos_1,
+                3.0 * cos_2 - 4.0 * sin_2,
+                3.0 * sin_2 + 4.0 * cos_2,
+            ],
+        ]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_rotary_encoding_partial() {
+        let device = Default::default();
+        let rope = RotaryEncodingConfig::new(4, 4)
+            .with_partial_rotary_factor(0.5)
+            .init::<TestBackend>(&device);
+
+        let input = Tensor::<TestBackend, 2>::from_floats([[1.0, 2.0, 3.0, 4.0]], &device);
+        let output = rope.forward(input, Tensor::from_ints([3], &device));
+
+        let (cos, sin) = (cosf(3.0), sinf(3.0));
+        let expected = Data::from([[cos - 2.0 * sin, sin + 2.0 * cos, 3.0, 4.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_rotary_encoding_preserves_relative_position() {
+        let device = Default::default();
+        let d_model = 8;
+        let rope = RotaryEncodingConfig::new(16, d_model).init::<TestBackend>(&device);
+
+        let query = Tensor::<TestBackend, 2>::random([1, d_model], Distribution::Default, &device);
+        let key = Tensor::<TestBackend, 2>::random([1, d_model], Distribution::Default, &device);
+
+        let score = |position_query: i32, position_key: i32| {
+            let query = rope.forward(query.clone(), Tensor::from_ints([position_query], &device));
+            let key = rope.forward(key.clone(), Tensor::from_ints([position_key], &device));
+
+            (query * key).sum().into_data()
+        };
+
+        score(2, 5).assert_approx_eq(&score(9, 12), 3);
+        score(4, 0).assert_approx_eq(&score(15, 11), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotary_encoding_wrong_d_model() {
+        let device = Default::default();
+        let rope = RotaryEncodingConfig::new(4, 4).init::<TestBackend>(&device);
+
+        let input = Tensor::<TestBackend, 2>::zeros([2, 6], &device);
+        let _ = rope.forward(input, Tensor::arange(0..2, &device));
+    }
+}
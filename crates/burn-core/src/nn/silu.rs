@@ -0,0 +1,32 @@
+use crate as burn;
+
+use crate::module::Module;
+use crate::tensor::backend::Backend;
+use crate::tensor::Tensor;
+
+/// Applies the sigmoid linear unit function, also known as Swish, element-wise:
+///
+/// `y = x * sigmoid(x)`
+#[derive(Module, Clone, Debug, Default)]
+pub struct Silu {}
+
+impl Silu {
+    /// Create the module.
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Applies the forward pass on the input tensor.
+    ///
+    /// # Shapes
+    ///
+    /// - input: `[..., any]`
+    /// - output: `[..., any]`
+    pub fn forward<B: Backend, const D: usize>(&self, input: Tensor<B, D>) -> Tensor<B, D> {
+        crate::tensor::activation::silu(input)
+    }
+}
+
+/// Alias of [Silu](Silu), the name used in the paper
+/// [Searching for Activation Functions](https://arxiv.org/abs/1710.05941).
+pub type Swish = Silu;
@@ -106,15 +106,15 @@ impl AdamConfig {
 /// Adaptive momentum state.
 #[derive(Record, new, Clone)]
 pub struct AdaptiveMomentumState<B: Backend, const D: usize> {
-    time: usize,
-    moment_1: Tensor<B, D>,
-    moment_2: Tensor<B, D>,
+    pub(crate) time: usize,
+    pub(crate) moment_1: Tensor<B, D>,
+    pub(crate) moment_2: Tensor<B, D>,
 }
 
-struct AdaptiveMomentum {
-    beta_1: f32,
-    beta_2: f32,
-    epsilon: f32,
+pub(crate) struct AdaptiveMomentum {
+    pub(crate) beta_1: f32,
+    pub(crate) beta_2: f32,
+    pub(crate) epsilon: f32,
 }
 
 impl AdaptiveMomentum {
@@ -123,7 +123,31 @@ impl AdaptiveMomentum {
         grad: Tensor<B, D>,
         momentum_state: Option<AdaptiveMomentumState<B, D>>,
     ) -> (Tensor<B, D>, AdaptiveMomentumState<B, D>) {
-        let state = if let Some(mut state) = momentum_state {
+        let state = self.update(grad, momentum_state);
+
+        let time = (state.time as i32).elem();
+        let moment_1_corrected = state
+            .moment_1
+            .clone()
+            .div_scalar(1f32 - self.beta_1.powi(time));
+        let moment_2_corrected = state
+            .moment_2
+            .clone()
+            .div_scalar(1f32 - self.beta_2.powi(time));
+
+        let grad = moment_1_corrected.div(moment_2_corrected.sqrt().add_scalar(self.epsilon));
+
+        (grad, state)
+    }
+
+    /// Update the first and second moment estimates with the given gradient, without the bias
+    /// correction.
+    pub(crate) fn update<B: Backend, const D: usize>(
+        &self,
+        grad: Tensor<B, D>,
+        momentum_state: Option<AdaptiveMomentumState<B, D>>,
+    ) -> AdaptiveMomentumState<B, D> {
+        if let Some(mut state) = momentum_state {
             let factor = 1.0 - self.beta_1;
             state.moment_1 = state
                 .moment_1
@@ -147,21 +171,7 @@ impl AdaptiveMomentum {
             let moment_2 = grad.powf_scalar(2.0).mul_scalar(factor);
 
             AdaptiveMomentumState::new(1, moment_1, moment_2)
-        };
-
-        let time = (state.time as i32).elem();
-        let moment_1_corrected = state
-            .moment_1
-            .clone()
-            .div_scalar(1f32 - self.beta_1.powi(time));
-        let moment_2_corrected = state
-            .moment_2
-            .clone()
-            .div_scalar(1f32 - self.beta_2.powi(time));
-
-        let grad = moment_1_corrected.div(moment_2_corrected.sqrt().add_scalar(self.epsilon));
-
-        (grad, state)
+        }
     }
 }
 
@@ -197,8 +197,9 @@ impl<B: Backend, const D: usize> AdaptiveMomentumWState<B, D> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::module::{Module, Param};
-    use crate::optim::{GradientsParams, Optimizer};
+    use crate::module::Module;
+    use crate::optim::testing::given_linear_layer;
+    use crate::optim::{decay::WeightDecayConfig, AdamConfig, GradientsParams, Optimizer};
     use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
     use crate::tensor::{Data, Distribution, Tensor};
     use crate::{nn, TestAutodiffBackend, TestBackend};
@@ -242,7 +243,9 @@ mod tests {
                 [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
                 [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
             ]),
-            Data::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+            Some(Data::from([
+                -0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130,
+            ])),
         );
         let device = Default::default();
         let x_1 = Tensor::from_floats(
@@ -318,7 +321,9 @@ mod tests {
                 [0.3300, -0.2182, 0.3717, -0.1729, 0.3796, -0.0304],
                 [-0.0159, -0.0120, 0.1258, 0.1921, 0.0293, 0.3833],
             ]),
-            Data::from([-0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130]),
+            Some(Data::from([
+                -0.3905, 0.0884, -0.0970, 0.1176, 0.1366, 0.0130,
+            ])),
         );
 
         let x = Tensor::from_floats(
@@ -349,17 +354,45 @@ mod tests {
         assert!(!state_updated.weight.to_data().value[0].is_nan());
     }
 
-    fn given_linear_layer(
-        weight: Data<f32, 2>,
-        bias: Data<f32, 1>,
-    ) -> nn::Linear<TestAutodiffBackend> {
+    #[test]
+    fn test_adamw_differs_from_adam_with_l2_penalty() {
         let device = Default::default();
-        let record = nn::LinearRecord {
-            weight: Param::from(Tensor::from_data(weight, &device)),
-            bias: Some(Param::from(Tensor::from_data(bias, &device))),
-        };
+        let mut adamw = AdamWConfig::new()
+            .with_epsilon(1e-8)
+            .with_weight_decay(0.5)
+            .init();
+        let mut adam = AdamConfig::new()
+            .with_epsilon(1e-8)
+            .with_weight_decay(Some(WeightDecayConfig { penalty: 0.5 }))
+            .init();
+
+        let mut linear_adamw = given_linear_layer(Data::from([[0.5]]), None);
+        let mut linear_adam = given_linear_layer(Data::from([[0.5]]), None);
+
+        // The L2 penalty is added to the gradient and then divided by the adaptive denominator,
+        // while AdamW shrinks the parameter directly by `lr * weight_decay`.
+        for grad in [1.0, 0.3] {
+            let x = Tensor::from_floats([[grad]], &device);
+
+            let grads = linear_adamw.forward(x.clone()).backward();
+            let grads = GradientsParams::from_grads(grads, &linear_adamw);
+            linear_adamw = adamw.step(0.1, linear_adamw, grads);
+
+            let grads = linear_adam.forward(x).backward();
+            let grads = GradientsParams::from_grads(grads, &linear_adam);
+            linear_adam = adam.step(0.1, linear_adam, grads);
+        }
 
-        nn::LinearConfig::new(6, 6).init_with(record)
+        linear_adamw
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.270680]]), 5);
+        linear_adam
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.310142]]), 5);
     }
 
     fn create_adamw(
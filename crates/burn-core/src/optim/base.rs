@@ -1,4 +1,4 @@
-use super::GradientsParams;
+use super::{GradientsParams, ParamGroups};
 use crate::module::AutodiffModule;
 use crate::record::Record;
 use crate::tensor::backend::AutodiffBackend;
@@ -15,8 +15,29 @@ where
 
     /// Perform the optimizer step using the given learning rate and gradients.
     /// The updated module is returned.
+    ///
+    /// The learning rate is scaled for the parameters that are part of a
+    /// [group](Optimizer::with_param_groups).
     fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M;
 
+    /// Set the [parameter groups](ParamGroups), each scaling the learning rate of its
+    /// parameters.
+    ///
+    /// By default, the groups are ignored and the optimizer is returned as is.
+    fn with_param_groups(self, _groups: ParamGroups) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Get the [parameter groups](ParamGroups) of the optimizer, if any.
+    ///
+    /// By default, the optimizer has no groups.
+    fn param_groups(&self) -> Option<&ParamGroups> {
+        None
+    }
+
     /// Get the current state of the optimizer as a [record](Record).
     fn to_record(&self) -> Self::Record;
 
@@ -0,0 +1,188 @@
+use core::marker::PhantomData;
+
+use crate::module::{Module, ModuleMapper, ModuleVisitor, ParamId};
+
+use burn_tensor::{backend::Backend, container::TensorContainer, Tensor};
+
+/// Exponential moving average of the parameters of a [module](Module).
+///
+/// A shadow copy of the module is kept, and each [update](Ema::update) moves its float
+/// parameters towards the ones of the given module:
+///
+/// `shadow = decay * shadow + (1 - decay) * param`
+///
+/// The averaged module is usually used for evaluation. When training with an autodiff backend,
+/// the average is kept on the [valid](crate::module::AutodiffModule::valid) module, which is the
+/// one used by the validation step.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut ema = Ema::new(&model.valid(), 0.999);
+///
+/// for batch in dataloader.iter() {
+///     // ...
+///     model = optim.step(lr, model, grads);
+///     ema.update(&model.valid());
+/// }
+///
+/// let model_eval = ema.model();
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ema<B: Backend, M: Module<B>> {
+    shadow: M,
+    decay: f64,
+    phantom: PhantomData<B>,
+}
+
+impl<B: Backend, M: Module<B>> Ema<B, M> {
+    /// Create a new exponential moving average, starting from the parameters of the given
+    /// module.
+    ///
+    /// # Panics
+    ///
+    /// If the decay isn't in the range [0, 1].
+    pub fn new(module: &M, decay: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&decay),
+            "The decay must be in the range [0, 1], got {decay}"
+        );
+
+        Self {
+            shadow: module.clone(),
+            decay,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Update the average with the parameters of the given module, usually after each optimizer
+    /// step.
+    ///
+    /// The module must come from the one used to create the average, since the parameters are
+    /// matched by their [ids](ParamId).
+    pub fn update(&mut self, module: &M) {
+        let mut params = TensorContainer::new();
+        module.visit(&mut ParamsCollector::<B>::new(&mut params));
+
+        let mut mapper = EmaMapper::<B>::new(&mut params, self.decay);
+        self.shadow = self.shadow.clone().map(&mut mapper);
+    }
+
+    /// The averaged module.
+    pub fn model(&self) -> &M {
+        &self.shadow
+    }
+
+    /// Consume the average and return the averaged module.
+    pub fn into_model(self) -> M {
+        self.shadow
+    }
+}
+
+#[derive(new)]
+struct ParamsCollector<'a, B: Backend> {
+    params: &'a mut TensorContainer<ParamId>,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, B: Backend> ModuleVisitor<B> for ParamsCollector<'a, B> {
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, tensor: &Tensor<B, D>) {
+        self.params.register(id.clone(), tensor.clone());
+    }
+}
+
+#[derive(new)]
+struct EmaMapper<'a, B: Backend> {
+    params: &'a mut TensorContainer<ParamId>,
+    decay: f64,
+    phantom: PhantomData<B>,
+}
+
+impl<'a, B: Backend> ModuleMapper<B> for EmaMapper<'a, B> {
+    fn map_float<const D: usize>(&mut self, id: &ParamId, tensor: Tensor<B, D>) -> Tensor<B, D> {
+        match self.params.remove::<B, D>(id) {
+            Some(param) => tensor
+                .mul_scalar(self.decay)
+                .add(param.mul_scalar(1.0 - self.decay)),
+            None => tensor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{Linear, LinearConfig},
+        TestBackend,
+    };
+
+    #[test]
+    fn test_ema_lags_behind_the_parameters() {
+        let model = layer();
+        let weight = model.weight.val();
+        let bias = model.bias.as_ref().unwrap().val();
+        let mut ema = Ema::new(&model, 0.5);
+
+        let model = model.map(&mut AddScalar(1.0));
+        ema.update(&model);
+        let model = model.map(&mut AddScalar(1.0));
+        ema.update(&model);
+
+        // The model moved by 2, the average by 0.5 * (0.5 * 0 + 0.5 * 1) + 0.5 * 2.
+        let ema = ema.into_model();
+        ema.weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&weight.add_scalar(1.25).into_data(), 5);
+        ema.bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&bias.add_scalar(1.25).into_data(), 5);
+    }
+
+    #[test]
+    fn test_ema_without_decay_follows_the_parameters() {
+        let model = layer();
+        let mut ema = Ema::new(&model, 0.0);
+
+        let model = model.map(&mut AddScalar(3.0));
+        ema.update(&model);
+
+        ema.model()
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&model.weight.val().into_data(), 5);
+        ema.into_model()
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&model.bias.unwrap().val().into_data(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ema_decay_out_of_range() {
+        let _ = Ema::new(&layer(), 1.5);
+    }
+
+    fn layer() -> Linear<TestBackend> {
+        LinearConfig::new(4, 3).init(&Default::default())
+    }
+
+    /// Shift every float parameter by a constant, keeping the parameter ids.
+    struct AddScalar(f32);
+
+    impl<B: Backend> ModuleMapper<B> for AddScalar {
+        fn map_float<const D: usize>(
+            &mut self,
+            _id: &ParamId,
+            tensor: Tensor<B, D>,
+        ) -> Tensor<B, D> {
+            tensor.add_scalar(self.0)
+        }
+    }
+}
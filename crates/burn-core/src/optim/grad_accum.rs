@@ -79,6 +79,7 @@ mod tests {
     use super::*;
     use crate::{
         nn::{Linear, LinearConfig},
+        optim::{Optimizer, SgdConfig},
         TestAutodiffBackend,
     };
     use burn_tensor::{backend::Backend, Distribution};
@@ -114,6 +115,42 @@ mod tests {
         assert_eq!(grads.len(), 2)
     }
 
+    #[test]
+    fn test_accumulated_update_matches_larger_batch() {
+        let device = Default::default();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let x_1 = random_tensor::<TestAutodiffBackend>(&device);
+        let x_2 = random_tensor::<TestAutodiffBackend>(&device);
+        let x = Tensor::cat(vec![x_1.clone(), x_2.clone()], 0);
+
+        // Two micro-batches of size 2 with accumulated gradients.
+        let mut accumulator = GradientsAccumulator::new();
+        for x in [x_1, x_2] {
+            let loss = layer.forward(x).sum();
+            accumulator.accumulate(&layer, GradientsParams::from_grads(loss.backward(), &layer));
+        }
+        let mut optim = SgdConfig::new().init();
+        let layer_accumulated = optim.step(0.1, layer.clone(), accumulator.grads());
+
+        // A single batch of size 4.
+        let loss = layer.forward(x).sum();
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+        let mut optim = SgdConfig::new().init();
+        let layer_full = optim.step(0.1, layer, grads);
+
+        layer_accumulated
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&layer_full.weight.val().into_data(), 5);
+        layer_accumulated
+            .bias
+            .unwrap()
+            .val()
+            .into_data()
+            .assert_approx_eq(&layer_full.bias.unwrap().val().into_data(), 5);
+    }
+
     fn layer<B: Backend>(device: &B::Device) -> Linear<B> {
         LinearConfig::new(20, 20).with_bias(true).init(device)
     }
@@ -0,0 +1,248 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::{
+    adam::{AdaptiveMomentum, AdaptiveMomentumState},
+    Optimizer, SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Lamb configuration.
+#[derive(Config)]
+pub struct LambConfig {
+    /// Parameter for Lamb.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for Lamb.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-6)]
+    epsilon: f32,
+    /// Decoupled weight decay, added to the update before the trust ratio is applied.
+    #[config(default = 0.01)]
+    weight_decay: f32,
+    /// Upper bound of the trust ratio.
+    #[config(default = 10.0)]
+    max_trust_ratio: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Lamb optimizer as described in the paper [Large Batch Optimization for Deep Learning: Training BERT in 76 minutes](https://arxiv.org/abs/1904.00962).
+///
+/// The Adam update of each parameter is rescaled by the trust ratio `||w|| / ||update||`, so that
+/// every parameter moves by the same step relative to its norm. The trust ratio is clamped to
+/// [max_trust_ratio](LambConfig::max_trust_ratio), and is 1 when either norm is zero.
+pub struct Lamb<B: Backend> {
+    momentum: AdaptiveMomentum,
+    weight_decay: f32,
+    max_trust_ratio: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// Lamb state.
+#[derive(Record, Clone, new)]
+pub struct LambState<B: Backend, const D: usize> {
+    momentum: AdaptiveMomentumState<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lamb<B> {
+    type State<const D: usize> = LambState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        let (update, state_momentum) = self
+            .momentum
+            .transform(grad, state.map(|state| state.momentum));
+
+        let update = update.add(tensor.clone().mul_scalar(self.weight_decay));
+
+        let weight_norm = l2_norm(tensor.clone());
+        let update_norm = l2_norm(update.clone());
+        let trust_ratio = weight_norm
+            .clone()
+            .div(update_norm.clone())
+            .mask_fill(weight_norm.equal_elem(0.0), 1.0)
+            .mask_fill(update_norm.equal_elem(0.0), 1.0)
+            .clamp_max(self.max_trust_ratio);
+
+        let state = LambState::new(state_momentum);
+        let delta = update.mul(trust_ratio.unsqueeze()).mul_scalar(lr);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+}
+
+fn l2_norm<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, 1> {
+    tensor.powf_scalar(2.0).sum().sqrt()
+}
+
+impl LambConfig {
+    /// Initialize Lamb optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> impl Optimizer<M, B> {
+        let optim = Lamb {
+            momentum: AdaptiveMomentum {
+                beta_1: self.beta_1,
+                beta_2: self.beta_2,
+                epsilon: self.epsilon,
+            },
+            weight_decay: self.weight_decay,
+            max_trust_ratio: self.max_trust_ratio,
+            _phantom: Default::default(),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::testing::given_linear_layer;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn test_lamb_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_lamb();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(optimizer.to_record(), temp_dir.path().join("test_optim"))
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_lamb();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lamb_step_is_proportional_to_weight_norm() {
+        // The weight has a norm of 5 and the bias a norm of 0.5.
+        let linear = given_linear_layer(Data::from([[3.0], [4.0]]), Some(Data::from([0.5])));
+        let mut optimizer = LambConfig::new().with_weight_decay(0.0).init();
+
+        // Both parameters receive gradients of ones, so the first Adam update is about one for
+        // every element.
+        let x = Tensor::from_floats([[1.0, 1.0]], &Default::default());
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let linear_updated = optimizer.step(LEARNING_RATE, linear.clone(), grads);
+
+        let delta_weight = linear.weight.val() - linear_updated.weight.val();
+        let delta_bias =
+            linear.bias.as_ref().unwrap().val() - linear_updated.bias.as_ref().unwrap().val();
+
+        // Every parameter moves by the learning rate relative to its norm.
+        let step_weight = l2_norm(delta_weight.clone()).into_scalar();
+        let step_bias = l2_norm(delta_bias.clone()).into_scalar();
+        Data::from([step_weight / 5.0, step_bias / 0.5])
+            .assert_approx_eq(&Data::from([0.1, 0.1]), 5);
+
+        // The trust ratios are 5 / sqrt(2) and 0.5, so the weight elements take a step about
+        // seven times larger than the bias.
+        delta_weight
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.353553], [0.353553]]), 5);
+        delta_bias
+            .into_data()
+            .assert_approx_eq(&Data::from([0.05]), 5);
+    }
+
+    #[test]
+    fn test_lamb_trust_ratio_clamp_and_zero_norm() {
+        let mut optimizer = LambConfig::new().with_weight_decay(0.0).init();
+
+        // A trust ratio of 100 is clamped to 10.
+        let linear = step(
+            &mut optimizer,
+            given_linear_layer(Data::from([[100.0]]), None),
+        );
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[99.0]]), 4);
+
+        // A zero weight norm falls back to a trust ratio of 1.
+        let linear = step(
+            &mut optimizer,
+            given_linear_layer(Data::from([[0.0]]), None),
+        );
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.1]]), 4);
+    }
+
+    fn step(
+        optimizer: &mut impl Optimizer<nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>,
+        linear: nn::Linear<TestAutodiffBackend>,
+    ) -> nn::Linear<TestAutodiffBackend> {
+        let x = Tensor::from_floats([[1.0]], &Default::default());
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+
+        optimizer.step(LEARNING_RATE, linear, grads)
+    }
+
+    fn create_lamb(
+    ) -> OptimizerAdaptor<Lamb<TestBackend>, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>
+    {
+        let config = LambConfig::new();
+        Lamb {
+            momentum: AdaptiveMomentum {
+                beta_1: config.beta_1,
+                beta_2: config.beta_2,
+                epsilon: config.epsilon,
+            },
+            weight_decay: config.weight_decay,
+            max_trust_ratio: config.max_trust_ratio,
+            _phantom: Default::default(),
+        }
+        .into()
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+use core::marker::PhantomData;
+
+use super::{Optimizer, SimpleOptimizer};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Lion configuration.
+#[derive(Config)]
+pub struct LionConfig {
+    /// Interpolation factor between the momentum and the gradient used for the update.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Decay factor of the momentum.
+    #[config(default = 0.99)]
+    beta_2: f32,
+    /// Decoupled weight decay.
+    #[config(default = 0.0)]
+    weight_decay: f32,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// Lion optimizer as described in the paper [Symbolic Discovery of Optimization Algorithms](https://arxiv.org/abs/2302.06675).
+///
+/// The update is the sign of an interpolation between the momentum and the current gradient, so
+/// every parameter moves by exactly the learning rate. Only the momentum is kept as state.
+///
+/// Since the update has a larger norm than the ones of Adam, a smaller learning rate and a larger
+/// weight decay are usually used.
+pub struct Lion<B: Backend> {
+    beta_1: f32,
+    beta_2: f32,
+    weight_decay: f32,
+    _phantom: PhantomData<B>,
+}
+
+/// Lion state.
+#[derive(Record, Clone, new)]
+pub struct LionState<B: Backend, const D: usize> {
+    momentum: Tensor<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for Lion<B> {
+    type State<const D: usize> = LionState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        // The momentum starts at zero.
+        let momentum = match state {
+            Some(state) => state.momentum,
+            None => grad.zeros_like(),
+        };
+
+        let update = momentum
+            .clone()
+            .mul_scalar(self.beta_1)
+            .add(grad.clone().mul_scalar(1.0 - self.beta_1))
+            .sign();
+
+        let momentum = momentum
+            .mul_scalar(self.beta_2)
+            .add(grad.mul_scalar(1.0 - self.beta_2));
+
+        let tensor_updated = tensor.clone() - tensor.mul_scalar(lr).mul_scalar(self.weight_decay);
+
+        (
+            tensor_updated - update.mul_scalar(lr),
+            Some(LionState::new(momentum)),
+        )
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+}
+
+impl LionConfig {
+    /// Initialize Lion optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> impl Optimizer<M, B> {
+        let optim = Lion {
+            beta_1: self.beta_1,
+            beta_2: self.beta_2,
+            weight_decay: self.weight_decay,
+            _phantom: Default::default(),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::testing::given_linear_layer;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn test_lion_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_lion();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(optimizer.to_record(), temp_dir.path().join("test_optim"))
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_lion();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_lion_update_is_sign_of_interpolated_momentum() {
+        let mut linear = given_linear_layer(Data::from([[0.5]]), None);
+        let mut optimizer = LionConfig::new().init();
+
+        // With beta_1 = 0.9 and beta_2 = 0.99, the momentum after the first step is 0.01. The
+        // interpolation of the second step is 0.9 * 0.01 + 0.1 * -0.05 = 0.004, so the parameter
+        // keeps moving in the same direction even though the gradient changed sign. The
+        // interpolation of the third step is 0.9 * 0.0094 + 0.1 * -1.0 < 0.
+        let mut weights = [0.0; 3];
+        for (weight, grad) in weights.iter_mut().zip([1.0, -0.05, -1.0]) {
+            linear = step(&mut optimizer, linear, grad);
+            *weight = linear.weight.val().into_scalar();
+        }
+
+        Data::from(weights).assert_approx_eq(&Data::from([0.4, 0.3, 0.4]), 5);
+    }
+
+    #[test]
+    fn test_lion_decoupled_weight_decay() {
+        let linear = given_linear_layer(Data::from([[0.5]]), None);
+        let mut optimizer = LionConfig::new().with_weight_decay(0.5).init();
+
+        // 0.5 - 0.1 * (sign(0.1) + 0.5 * 0.5)
+        let linear = step(&mut optimizer, linear, 1.0);
+
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.375]]), 5);
+    }
+
+    #[test]
+    fn test_lion_converges_on_quadratic() {
+        let device = Default::default();
+        let mut linear = nn::LinearConfig::new(4, 1)
+            .with_bias(false)
+            .init::<TestAutodiffBackend>(&device);
+        let target =
+            Tensor::<TestAutodiffBackend, 2>::from_floats([[0.5], [-0.25], [0.75], [0.0]], &device);
+        let mut optimizer = LionConfig::new().init();
+
+        for _ in 0..500 {
+            let loss = (linear.weight.val() - target.clone())
+                .powf_scalar(2.0)
+                .sum();
+            let grads = GradientsParams::from_grads(loss.backward(), &linear);
+            linear = optimizer.step(0.01, linear, grads);
+        }
+
+        // The sign update makes the parameters oscillate around the minimum by about the
+        // learning rate.
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&target.into_data(), 1);
+    }
+
+    fn step(
+        optimizer: &mut impl Optimizer<nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>,
+        linear: nn::Linear<TestAutodiffBackend>,
+        grad: f32,
+    ) -> nn::Linear<TestAutodiffBackend> {
+        // The gradient of the weight is the input.
+        let x = Tensor::from_floats([[grad]], &Default::default());
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+
+        optimizer.step(LEARNING_RATE, linear, grads)
+    }
+
+    fn create_lion(
+    ) -> OptimizerAdaptor<Lion<TestBackend>, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>
+    {
+        let config = LionConfig::new();
+        Lion {
+            beta_1: config.beta_1,
+            beta_2: config.beta_2,
+            weight_decay: config.weight_decay,
+            _phantom: Default::default(),
+        }
+        .into()
+    }
+}
@@ -0,0 +1,282 @@
+use crate as burn;
+
+use core::marker::PhantomData;
+
+use super::GradientsParams;
+use crate::config::Config;
+use crate::module::{AutodiffModule, ModuleVisitor, ParamId};
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// Configuration to create a [dynamic loss scaler](LossScaler).
+#[derive(Config)]
+pub struct LossScalerConfig {
+    /// The initial scale factor.
+    #[config(default = 65536.0)]
+    init_scale: f32,
+    /// The factor by which the scale is multiplied after `growth_interval` steps without
+    /// overflow.
+    #[config(default = 2.0)]
+    growth_factor: f32,
+    /// The factor by which the scale is multiplied when the gradients overflow.
+    #[config(default = 0.5)]
+    backoff_factor: f32,
+    /// The number of consecutive steps without overflow before the scale grows.
+    #[config(default = 2000)]
+    growth_interval: usize,
+}
+
+impl LossScalerConfig {
+    /// Initialize a new [dynamic loss scaler](LossScaler).
+    ///
+    /// # Panics
+    ///
+    /// If the initial scale isn't positive, if the growth factor isn't greater than 1, if the
+    /// backoff factor isn't in the range (0, 1) or if the growth interval is 0.
+    pub fn init(&self) -> LossScaler {
+        assert!(self.init_scale > 0.0, "The initial scale must be positive");
+        assert!(
+            self.growth_factor > 1.0,
+            "The growth factor must be greater than 1"
+        );
+        assert!(
+            self.backoff_factor > 0.0 && self.backoff_factor < 1.0,
+            "The backoff factor must be in the range (0, 1)"
+        );
+        assert!(
+            self.growth_interval > 0,
+            "The growth interval must be at least 1"
+        );
+
+        LossScaler {
+            scale: self.init_scale,
+            growth_factor: self.growth_factor,
+            backoff_factor: self.backoff_factor,
+            growth_interval: self.growth_interval,
+            growth_tracker: 0,
+        }
+    }
+}
+
+/// Dynamic loss scaler used to train with half precision gradients.
+///
+/// Small gradients underflow in half precision, so the loss is multiplied by a large
+/// [scale](LossScaler::scale) before the backward pass, and the gradients are divided by
+/// the same factor with [unscale](LossScaler::unscale) before the optimizer step. When the scaled
+/// gradients overflow, the step must be skipped and the scale is reduced. After enough steps
+/// without overflow, the scale grows again.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let loss = model.forward_loss(batch);
+/// let grads = scaler.scale(loss).backward();
+/// let grads = GradientsParams::from_grads(grads, &model);
+///
+/// if let Some(grads) = scaler.unscale(&model, grads) {
+///     model = optim.step(lr, model, grads);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct LossScaler {
+    scale: f32,
+    growth_factor: f32,
+    backoff_factor: f32,
+    growth_interval: usize,
+    growth_tracker: usize,
+}
+
+impl LossScaler {
+    /// The current scale factor.
+    pub fn scale_factor(&self) -> f32 {
+        self.scale
+    }
+
+    /// Scale the loss before the backward pass.
+    pub fn scale<B: Backend, const D: usize>(&self, loss: Tensor<B, D>) -> Tensor<B, D> {
+        loss.mul_scalar(self.scale)
+    }
+
+    /// Scale the gradients of the given module, which were computed from an unscaled loss.
+    ///
+    /// The result is the same as scaling the loss before the backward pass, except that small
+    /// gradients may have already underflowed.
+    pub fn scale_gradients<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &self,
+        module: &M,
+        mut grads: GradientsParams,
+    ) -> GradientsParams {
+        let mut visitor = GradientsScaler::<M, B>::new(self.scale, false, &mut grads, None);
+        module.visit(&mut visitor);
+
+        grads
+    }
+
+    /// Unscale the gradients of the given module and update the scale factor.
+    ///
+    /// # Returns
+    ///
+    /// The unscaled gradients, or `None` when any of them isn't finite, in which case the
+    /// optimizer step should be skipped.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unscale<B: AutodiffBackend, M: AutodiffModule<B>>(
+        &mut self,
+        module: &M,
+        mut grads: GradientsParams,
+    ) -> Option<GradientsParams> {
+        use burn_tensor::ElementConversion;
+
+        let mut visitor = GradientsScaler::<M, B>::new(1.0 / self.scale, true, &mut grads, None);
+        module.visit(&mut visitor);
+
+        // A single value is read from the device for all the gradients.
+        let finite = match visitor.non_finite {
+            Some(non_finite) => non_finite.into_scalar().elem::<f32>().is_finite(),
+            None => true,
+        };
+        self.update(finite);
+
+        match finite {
+            true => Some(grads),
+            false => None,
+        }
+    }
+
+    fn update(&mut self, finite: bool) {
+        if !finite {
+            self.scale *= self.backoff_factor;
+            self.growth_tracker = 0;
+            return;
+        }
+
+        self.growth_tracker += 1;
+        if self.growth_tracker == self.growth_interval {
+            self.scale *= self.growth_factor;
+            self.growth_tracker = 0;
+        }
+    }
+}
+
+#[derive(new)]
+struct GradientsScaler<'a, M, B: AutodiffBackend> {
+    factor: f32,
+    check_finite: bool,
+    grads: &'a mut GradientsParams,
+    /// The sum of all the gradients multiplied by zero, which is zero unless a value isn't finite.
+    non_finite: Option<Tensor<B::InnerBackend, 1>>,
+    phantom: PhantomData<M>,
+}
+
+impl<'a, B, M> ModuleVisitor<B> for GradientsScaler<'a, M, B>
+where
+    B: AutodiffBackend,
+    M: AutodiffModule<B>,
+{
+    fn visit_float<const D: usize>(&mut self, id: &ParamId, _tensor: &Tensor<B, D>) {
+        if let Some(grad) = self.grads.remove::<B::InnerBackend, D>(id) {
+            if self.check_finite {
+                // Finite values become zeros while infinite and NaN values become NaN, so the sum
+                // can't overflow and any value that isn't finite propagates to it.
+                let non_finite = grad.clone().mul_scalar(0.0).sum();
+
+                self.non_finite = Some(match self.non_finite.take() {
+                    Some(sum) => {
+                        let device = sum.device();
+                        sum.add(non_finite.to_device(&device))
+                    }
+                    None => non_finite,
+                });
+            }
+
+            self.grads
+                .register::<B::InnerBackend, D>(id.clone(), grad.mul_scalar(self.factor));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::testing::{given_linear_grads, given_linear_layer};
+    use crate::tensor::Data;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_unscale_gradients() {
+        let layer = given_linear_layer(Data::from([[1.0], [1.0]]), None);
+        let mut scaler = LossScalerConfig::new().with_init_scale(1024.0).init();
+
+        let grads = scaler
+            .unscale(
+                &layer,
+                given_linear_grads(&layer, Data::from([[512.0], [2048.0]]), None),
+            )
+            .unwrap();
+
+        grads
+            .get::<TestBackend, 2>(&layer.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.5], [2.0]]), 5);
+        assert_eq!(scaler.scale_factor(), 1024.0);
+    }
+
+    #[test]
+    fn test_scale_gradients() {
+        let layer = given_linear_layer(Data::from([[1.0], [1.0]]), None);
+        let scaler = LossScalerConfig::new().with_init_scale(1024.0).init();
+
+        let grads = scaler.scale_gradients(
+            &layer,
+            given_linear_grads(&layer, Data::from([[0.5], [2.0]]), None),
+        );
+
+        grads
+            .get::<TestBackend, 2>(&layer.weight.id)
+            .unwrap()
+            .into_data()
+            .assert_approx_eq(&Data::from([[512.0], [2048.0]]), 5);
+    }
+
+    #[test]
+    fn test_scale_backoff_and_growth() {
+        let layer = given_linear_layer(Data::from([[1.0], [1.0]]), None);
+        let mut scaler = LossScalerConfig::new()
+            .with_init_scale(1024.0)
+            .with_growth_interval(3)
+            .init();
+
+        // A simulated overflow skips the step and halves the scale.
+        let unscaled = scaler.unscale(
+            &layer,
+            given_linear_grads(&layer, Data::from([[f32::INFINITY], [1.0]]), None),
+        );
+        assert!(unscaled.is_none());
+        assert_eq!(scaler.scale_factor(), 512.0);
+
+        let unscaled = scaler.unscale(
+            &layer,
+            given_linear_grads(&layer, Data::from([[f32::NAN], [1.0]]), None),
+        );
+        assert!(unscaled.is_none());
+        assert_eq!(scaler.scale_factor(), 256.0);
+
+        // The scale grows back after three stable steps.
+        for _ in 0..2 {
+            assert!(scaler
+                .unscale(
+                    &layer,
+                    given_linear_grads(&layer, Data::from([[1.0], [1.0]]), None)
+                )
+                .is_some());
+            assert_eq!(scaler.scale_factor(), 256.0);
+        }
+        assert!(scaler
+            .unscale(
+                &layer,
+                given_linear_grads(&layer, Data::from([[1.0], [1.0]]), None)
+            )
+            .is_some());
+        assert_eq!(scaler.scale_factor(), 512.0);
+    }
+}
@@ -8,19 +8,34 @@ mod adagrad;
 mod adam;
 mod adamw;
 mod base;
+mod ema;
 mod grad_accum;
 mod grads;
+mod lamb;
+mod lion;
+mod loss_scaler;
+mod param_groups;
+mod radam;
 mod rmsprop;
 mod sgd;
 mod simple;
 mod visitor;
 
+#[cfg(test)]
+pub(crate) mod testing;
+
 pub use adagrad::*;
 pub use adam::*;
 pub use adamw::*;
 pub use base::*;
+pub use ema::*;
 pub use grad_accum::*;
 pub use grads::*;
+pub use lamb::*;
+pub use lion::*;
+pub use loss_scaler::*;
+pub use param_groups::*;
+pub use radam::*;
 pub use rmsprop::*;
 pub use sgd::*;
 pub use simple::*;
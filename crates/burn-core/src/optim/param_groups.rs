@@ -0,0 +1,107 @@
+use crate::module::{list_param_ids, Module, ParamId};
+use crate::LearningRate;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use burn_tensor::backend::Backend;
+use hashbrown::HashMap;
+
+/// Groups of parameters updated with their own learning rate.
+///
+/// Each group is created from a module, usually a part of the model being optimized, and scales
+/// the learning rate of its parameters by a multiplier. Parameters outside of any group use the
+/// learning rate unchanged.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // Fine-tune the backbone with a learning rate ten times smaller than the head.
+/// let groups = ParamGroups::new()
+///     .with_group("backbone", &model.backbone, 0.1)
+///     .with_group("head", &model.head, 1.0);
+///
+/// let optim = AdamConfig::new().init().with_param_groups(groups);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ParamGroups {
+    groups: Vec<(String, f64)>,
+    params: HashMap<ParamId, usize>,
+}
+
+impl ParamGroups {
+    /// Create empty parameter groups.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a group with all the parameters of the given module.
+    ///
+    /// A parameter that is already part of a group is moved to the new one.
+    ///
+    /// # Panics
+    ///
+    /// If the multiplier is negative.
+    pub fn with_group<B: Backend, M: Module<B>>(
+        mut self,
+        name: &str,
+        module: &M,
+        multiplier: f64,
+    ) -> Self {
+        assert!(
+            multiplier >= 0.0,
+            "The learning rate multiplier must be at least 0"
+        );
+
+        let index = self.groups.len();
+        self.groups.push((name.to_string(), multiplier));
+
+        for id in list_param_ids::<M, B>(module) {
+            self.params.insert(id, index);
+        }
+
+        self
+    }
+
+    /// The learning rate used to update the given parameter.
+    pub fn lr(&self, id: &ParamId, lr: LearningRate) -> LearningRate {
+        match self.params.get(id) {
+            Some(index) => lr * self.groups[*index].1,
+            None => lr,
+        }
+    }
+
+    /// The name and the learning rate of each group.
+    pub fn lrs(&self, lr: LearningRate) -> Vec<(String, LearningRate)> {
+        self.groups
+            .iter()
+            .map(|(name, multiplier)| (name.clone(), lr * multiplier))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::LinearConfig;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_param_groups_scale_lr() {
+        let device = Default::default();
+        let first = LinearConfig::new(2, 2).init::<TestBackend>(&device);
+        let second = LinearConfig::new(2, 2).init::<TestBackend>(&device);
+        let other = LinearConfig::new(2, 2).init::<TestBackend>(&device);
+
+        let groups = ParamGroups::new()
+            .with_group("first", &first, 0.1)
+            .with_group("second", &second, 2.0);
+
+        assert_eq!(groups.lr(&first.weight.id, 0.5), 0.05);
+        assert_eq!(groups.lr(&second.bias.unwrap().id, 0.5), 1.0);
+        assert_eq!(groups.lr(&other.weight.id, 0.5), 0.5);
+        assert_eq!(
+            groups.lrs(0.5),
+            [("first".to_string(), 0.05), ("second".to_string(), 1.0)]
+        );
+    }
+}
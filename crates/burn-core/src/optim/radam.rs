@@ -0,0 +1,247 @@
+use crate::{
+    self as burn, grad_clipping::GradientClippingConfig, module::AutodiffModule, record::Record,
+    LearningRate,
+};
+
+use super::{
+    adam::{AdaptiveMomentum, AdaptiveMomentumState},
+    decay::{WeightDecay, WeightDecayConfig},
+    Optimizer, SimpleOptimizer,
+};
+use crate::config::Config;
+use crate::optim::adaptor::OptimizerAdaptor;
+use crate::tensor::{backend::AutodiffBackend, Tensor};
+use burn_tensor::backend::Backend;
+
+/// RAdam configuration.
+#[derive(Config)]
+pub struct RAdamConfig {
+    /// Parameter for RAdam.
+    #[config(default = 0.9)]
+    beta_1: f32,
+    /// Parameter for RAdam.
+    #[config(default = 0.999)]
+    beta_2: f32,
+    /// A value required for numerical stability.
+    #[config(default = 1e-5)]
+    epsilon: f32,
+    /// [Weight decay](WeightDecayConfig) config.
+    weight_decay: Option<WeightDecayConfig>,
+    /// [Gradient Clipping](GradientClippingConfig) config.
+    grad_clipping: Option<GradientClippingConfig>,
+}
+
+/// RAdam optimizer as described in the paper [On the Variance of the Adaptive Learning Rate and Beyond](https://arxiv.org/abs/1908.03265).
+///
+/// The adaptive learning rate of Adam has a large variance during the first steps, since the
+/// second moment is estimated from only a few gradients. RAdam rectifies the adaptive learning
+/// rate by a factor derived from the length of the approximated simple moving average, and falls
+/// back to SGD with a bias-corrected momentum while that length is too small for the variance to
+/// be tractable.
+pub struct RAdam<B: Backend> {
+    momentum: AdaptiveMomentum,
+    weight_decay: Option<WeightDecay<B>>,
+}
+
+/// RAdam state.
+#[derive(Record, Clone, new)]
+pub struct RAdamState<B: Backend, const D: usize> {
+    momentum: AdaptiveMomentumState<B, D>,
+}
+
+impl<B: Backend> SimpleOptimizer<B> for RAdam<B> {
+    type State<const D: usize> = RAdamState<B, D>;
+
+    fn step<const D: usize>(
+        &self,
+        lr: LearningRate,
+        tensor: Tensor<B, D>,
+        mut grad: Tensor<B, D>,
+        state: Option<Self::State<D>>,
+    ) -> (Tensor<B, D>, Option<Self::State<D>>) {
+        if let Some(weight_decay) = &self.weight_decay {
+            grad = weight_decay.transform(grad, tensor.clone());
+        }
+
+        let state_momentum = self
+            .momentum
+            .update(grad, state.map(|state| state.momentum));
+
+        let time = state_momentum.time as f64;
+        let beta_1 = self.momentum.beta_1 as f64;
+        let beta_2 = self.momentum.beta_2 as f64;
+        let beta_2_time = libm::pow(beta_2, time);
+
+        let moment_1_corrected = state_momentum
+            .moment_1
+            .clone()
+            .div_scalar(1.0 - libm::pow(beta_1, time));
+
+        // Length of the approximated simple moving average.
+        let rho_inf = 2.0 / (1.0 - beta_2) - 1.0;
+        let rho = rho_inf - 2.0 * time * beta_2_time / (1.0 - beta_2_time);
+
+        let grad = if rho > 5.0 {
+            let rectification = libm::sqrt(
+                (rho - 4.0) * (rho - 2.0) * rho_inf / ((rho_inf - 4.0) * (rho_inf - 2.0) * rho),
+            );
+            let moment_2_corrected = state_momentum
+                .moment_2
+                .clone()
+                .div_scalar(1.0 - beta_2_time);
+
+            moment_1_corrected
+                .div(moment_2_corrected.sqrt().add_scalar(self.momentum.epsilon))
+                .mul_scalar(rectification)
+        } else {
+            moment_1_corrected
+        };
+
+        let state = RAdamState::new(state_momentum);
+        let delta = grad.mul_scalar(lr);
+
+        (tensor - delta, Some(state))
+    }
+
+    fn to_device<const D: usize>(
+        mut state: Self::State<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::State<D> {
+        state.momentum = state.momentum.to_device(device);
+        state
+    }
+}
+
+impl RAdamConfig {
+    /// Initialize RAdam optimizer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an optimizer that can be used to optimize a module.
+    pub fn init<B: AutodiffBackend, M: AutodiffModule<B>>(&self) -> impl Optimizer<M, B> {
+        let optim = RAdam {
+            momentum: AdaptiveMomentum {
+                beta_1: self.beta_1,
+                beta_2: self.beta_2,
+                epsilon: self.epsilon,
+            },
+            weight_decay: self.weight_decay.as_ref().map(WeightDecay::new),
+        };
+
+        let mut optim = OptimizerAdaptor::from(optim);
+        if let Some(config) = &self.grad_clipping {
+            optim = optim.with_grad_clipping(config.init());
+        }
+        optim
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::testing::given_linear_layer;
+    use crate::optim::{GradientsParams, Optimizer};
+    use crate::record::{BinFileRecorder, FullPrecisionSettings, Recorder};
+    use crate::tensor::{Data, Distribution, Tensor};
+    use crate::{nn, TestAutodiffBackend, TestBackend};
+    use tempfile::TempDir;
+
+    const LEARNING_RATE: LearningRate = 0.1;
+
+    #[test]
+    fn test_radam_optimizer_save_load_state() {
+        let device = Default::default();
+        let linear = nn::LinearConfig::new(6, 6).init(&device);
+        let x = Tensor::<TestAutodiffBackend, 2>::random([2, 6], Distribution::Default, &device);
+        let mut optimizer = create_radam();
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+        let _linear = optimizer.step(LEARNING_RATE, linear, grads);
+
+        let temp_dir = TempDir::new().unwrap();
+        BinFileRecorder::<FullPrecisionSettings>::default()
+            .record(optimizer.to_record(), temp_dir.path().join("test_optim"))
+            .unwrap();
+
+        let state_optim_before = optimizer.to_record();
+        let state_optim_before_copy = optimizer.to_record();
+        let optimizer = create_radam();
+        let optimizer = optimizer.load_record(state_optim_before_copy);
+        let state_optim_after = optimizer.to_record();
+
+        assert_eq!(state_optim_before.len(), state_optim_after.len());
+    }
+
+    #[test]
+    fn test_radam_first_steps_are_sgd_with_momentum() {
+        let mut linear = given_linear_layer(Data::from([[0.5]]), None);
+        let mut optimizer = RAdamConfig::new().init();
+
+        // With beta_2 = 0.999, the length of the approximated simple moving average is about the
+        // number of steps, so the variance isn't tractable during the first five steps and the
+        // update is the bias-corrected first moment.
+        let mut weights = [0.0; 5];
+        for (weight, grad) in weights.iter_mut().zip([1.0, 2.0, -1.0, 1.0, 1.0]) {
+            linear = step(&mut optimizer, linear, grad);
+            *weight = linear.weight.val().into_scalar();
+        }
+
+        Data::from(weights).assert_approx_eq(
+            &Data::from([0.4, 0.247368, 0.187959, 0.116746, 0.038504]),
+            5,
+        );
+    }
+
+    #[test]
+    fn test_radam_rectifies_adaptive_learning_rate() {
+        let mut linear = given_linear_layer(Data::from([[0.5]]), None);
+        let mut optimizer = RAdamConfig::new().init();
+
+        // With a constant gradient, both bias-corrected moments are 1, so the first five updates
+        // are the learning rate and the sixth one is the learning rate scaled by the
+        // rectification term r_6 = 0.025821.
+        for _ in 0..5 {
+            linear = step(&mut optimizer, linear, 1.0);
+        }
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.0]]), 5);
+
+        let linear = step(&mut optimizer, linear, 1.0);
+        linear
+            .weight
+            .val()
+            .into_data()
+            .assert_approx_eq(&Data::from([[-0.0025821]]), 5);
+    }
+
+    fn step(
+        optimizer: &mut impl Optimizer<nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>,
+        linear: nn::Linear<TestAutodiffBackend>,
+        grad: f32,
+    ) -> nn::Linear<TestAutodiffBackend> {
+        // The gradient of the weight is the input.
+        let x = Tensor::from_floats([[grad]], &Default::default());
+        let grads = linear.forward(x).backward();
+        let grads = GradientsParams::from_grads(grads, &linear);
+
+        optimizer.step(LEARNING_RATE, linear, grads)
+    }
+
+    fn create_radam(
+    ) -> OptimizerAdaptor<RAdam<TestBackend>, nn::Linear<TestAutodiffBackend>, TestAutodiffBackend>
+    {
+        let config = RAdamConfig::new();
+        RAdam {
+            momentum: AdaptiveMomentum {
+                beta_1: config.beta_1,
+                beta_2: config.beta_2,
+                epsilon: config.epsilon,
+            },
+            weight_decay: None,
+        }
+        .into()
+    }
+}
@@ -98,8 +98,9 @@ mod tests {
     use super::*;
     use crate::{
         grad_clipping::GradientClipping,
+        module::Module,
         nn::{Linear, LinearConfig},
-        optim::{GradientsParams, Optimizer},
+        optim::{GradientsParams, Optimizer, ParamGroups},
         tensor::{Distribution, Shape},
         TestAutodiffBackend, TestBackend,
     };
@@ -134,6 +135,83 @@ mod tests {
         assert!(optim.has_gradient_clipping());
     }
 
+    #[test]
+    fn should_clip_gradients_by_global_norm_before_update() {
+        let device = Default::default();
+        let layer = layer::<TestAutodiffBackend>(&device);
+        let mut optim = SgdConfig::new()
+            .with_gradient_clipping(Some(GradientClippingConfig::GlobalNorm(1.0)))
+            .init();
+        let loss = layer
+            .forward(random_tensor(&device))
+            .sum()
+            .mul_scalar(100.0);
+        let grads = GradientsParams::from_grads(loss.backward(), &layer);
+
+        let grad_weight = grads.get::<TestBackend, 2>(&layer.weight.id).unwrap();
+        let grad_bias = grads
+            .get::<TestBackend, 1>(&layer.bias.as_ref().unwrap().id)
+            .unwrap();
+        let global_norm = (grad_weight.clone().powf_scalar(2.0).sum()
+            + grad_bias.clone().powf_scalar(2.0).sum())
+        .sqrt()
+        .into_scalar();
+        assert!(global_norm > 1.0);
+
+        let weight = layer.weight.val().inner();
+        let bias = layer.bias.as_ref().unwrap().val().inner();
+        let layer = optim.step(1.0, layer, grads);
+
+        // The update is the original gradient direction rescaled to a norm of exactly 1.
+        let update_weight = weight - layer.weight.val().inner();
+        let update_bias = bias - layer.bias.as_ref().unwrap().val().inner();
+        let update_norm = (update_weight.clone().powf_scalar(2.0).sum()
+            + update_bias.clone().powf_scalar(2.0).sum())
+        .sqrt()
+        .into_scalar();
+
+        assert!((update_norm - 1.0).abs() < 1e-4);
+        update_weight
+            .into_data()
+            .assert_approx_eq(&grad_weight.div_scalar(global_norm).into_data(), 4);
+        update_bias
+            .into_data()
+            .assert_approx_eq(&grad_bias.div_scalar(global_norm).into_data(), 4);
+    }
+
+    #[test]
+    fn should_scale_lr_of_each_param_group() {
+        let device = Default::default();
+        let model = TwoLayers {
+            first: layer::<TestAutodiffBackend>(&device),
+            second: layer::<TestAutodiffBackend>(&device),
+        };
+        let groups = ParamGroups::new()
+            .with_group("first", &model.first, 0.1)
+            .with_group("second", &model.second, 2.0);
+        let mut optim = SgdConfig::new().init().with_param_groups(groups);
+
+        let x = random_tensor(&device);
+        let loss = model.first.forward(x.clone()).sum() + model.second.forward(x).sum();
+        let grads = GradientsParams::from_grads(loss.backward(), &model);
+
+        let grad_first = grads.get::<TestBackend, 2>(&model.first.weight.id).unwrap();
+        let grad_second = grads
+            .get::<TestBackend, 2>(&model.second.weight.id)
+            .unwrap();
+        let weight_first = model.first.weight.val().inner();
+        let weight_second = model.second.weight.val().inner();
+
+        let model = optim.step(LEARNING_RATE, model, grads);
+
+        (weight_first - model.first.weight.val().inner())
+            .into_data()
+            .assert_approx_eq(&grad_first.mul_scalar(LEARNING_RATE * 0.1).into_data(), 5);
+        (weight_second - model.second.weight.val().inner())
+            .into_data()
+            .assert_approx_eq(&grad_second.mul_scalar(LEARNING_RATE * 2.0).into_data(), 5);
+    }
+
     #[test]
     fn should_load_state() {
         let device = Default::default();
@@ -154,6 +232,12 @@ mod tests {
         assert_eq!(record.len(), state_restored.len());
     }
 
+    #[derive(Module, Debug)]
+    struct TwoLayers<B: Backend> {
+        first: Linear<B>,
+        second: Linear<B>,
+    }
+
     fn random_tensor<B: Backend>(device: &B::Device) -> Tensor<B, 2> {
         Tensor::<B, 2>::random(Shape::new([2, 20]), Distribution::Default, device)
     }
@@ -2,7 +2,7 @@ use super::{record::AdaptorRecord, SimpleOptimizer};
 use crate::{
     grad_clipping::GradientClipping,
     module::{AutodiffModule, ModuleMapper, ParamId},
-    optim::{GradientsParams, Optimizer},
+    optim::{GradientsParams, Optimizer, ParamGroups},
     LearningRate,
 };
 use burn_tensor::{backend::AutodiffBackend, Tensor};
@@ -21,6 +21,7 @@ where
     records: HashMap<ParamId, AdaptorRecord<O, B>>,
     module: PhantomData<M>,
     grad_clipping: Option<GradientClipping>,
+    param_groups: Option<ParamGroups>,
 }
 
 impl<O, B, M> From<O> for OptimizerAdaptor<O, M, B>
@@ -35,6 +36,7 @@ where
             records: HashMap::new(),
             module: PhantomData,
             grad_clipping: None,
+            param_groups: None,
         }
     }
 }
@@ -73,17 +75,33 @@ where
 {
     type Record = HashMap<ParamId, AdaptorRecord<O, B>>;
 
-    fn step(&mut self, lr: LearningRate, module: M, mut grads: GradientsParams) -> M {
+    fn step(&mut self, lr: LearningRate, module: M, grads: GradientsParams) -> M {
+        // Clipping happens before any parameter is updated, since some strategies depend on the
+        // gradients of every parameter.
+        let mut grads = match &self.grad_clipping {
+            Some(grad_clipping) => grad_clipping.clip_gradients(&module, grads),
+            None => grads,
+        };
+
         let mut mapper = SimpleOptimizerMapper::<M, B, O>::new(
             &self.optim,
             &mut self.records,
             &mut grads,
             lr,
-            self.grad_clipping.as_ref(),
+            self.param_groups.as_ref(),
         );
         module.map(&mut mapper)
     }
 
+    fn with_param_groups(mut self, groups: ParamGroups) -> Self {
+        self.param_groups = Some(groups);
+        self
+    }
+
+    fn param_groups(&self) -> Option<&ParamGroups> {
+        self.param_groups.as_ref()
+    }
+
     fn to_record(&self) -> Self::Record {
         self.records.clone()
     }
@@ -105,8 +123,8 @@ where
     records: &'a mut HashMap<ParamId, AdaptorRecord<O, B>>,
     grads: &'a mut GradientsParams,
     lr: LearningRate,
+    param_groups: Option<&'a ParamGroups>,
     phantom: PhantomData<M>,
-    grad_clipping: Option<&'a GradientClipping>,
 }
 
 impl<'a, M, B, O> ModuleMapper<B> for SimpleOptimizerMapper<'a, M, B, O>
@@ -122,17 +140,15 @@ where
             let device = grad.device();
             let is_require_grad = tensor.is_require_grad();
             let (key, record) = self.records.remove_entry(id).unzip();
-
-            let clipped_grad = if let Some(g_clipping) = self.grad_clipping {
-                g_clipping.clip_gradient(grad)
-            } else {
-                grad
+            let lr = match self.param_groups {
+                Some(groups) => groups.lr(id, self.lr),
+                None => self.lr,
             };
 
             let (tensor, state) = self.optimizer.step(
-                self.lr,
+                lr,
                 tensor.inner(),
-                clipped_grad,
+                grad,
                 record.map(|record| O::to_device(record.into_state(), &device)),
             );
 
@@ -0,0 +1,48 @@
+use crate::module::Param;
+use crate::nn::{Linear, LinearConfig, LinearRecord};
+use crate::optim::GradientsParams;
+use crate::tensor::{Data, Tensor};
+use crate::{TestAutodiffBackend, TestBackend};
+
+/// Creates a linear layer with the given weight, of shape `[d_input, d_output]`, and bias.
+pub fn given_linear_layer(
+    weight: Data<f32, 2>,
+    bias: Option<Data<f32, 1>>,
+) -> Linear<TestAutodiffBackend> {
+    let device = Default::default();
+    let [d_input, d_output] = weight.shape.dims;
+    let record = LinearRecord {
+        weight: Param::from(Tensor::from_data(weight, &device)),
+        bias: bias.map(|bias| Param::from(Tensor::from_data(bias, &device))),
+    };
+
+    LinearConfig::new(d_input, d_output)
+        .with_bias(record.bias.is_some())
+        .init_with(record)
+}
+
+/// Creates the gradients of the parameters of a linear layer, without a backward pass.
+///
+/// # Panics
+///
+/// If a gradient is given for the bias of a layer without bias.
+pub fn given_linear_grads(
+    layer: &Linear<TestAutodiffBackend>,
+    grad_weight: Data<f32, 2>,
+    grad_bias: Option<Data<f32, 1>>,
+) -> GradientsParams {
+    let device = Default::default();
+    let mut grads = GradientsParams::new();
+
+    grads.register::<TestBackend, 2>(
+        layer.weight.id.clone(),
+        Tensor::from_data(grad_weight, &device),
+    );
+
+    if let Some(grad_bias) = grad_bias {
+        let bias = layer.bias.as_ref().expect("The layer should have a bias.");
+        grads.register::<TestBackend, 1>(bias.id.clone(), Tensor::from_data(grad_bias, &device));
+    }
+
+    grads
+}
@@ -99,6 +99,10 @@ impl SerializerTrait for Serializer {
         Ok(NestedValue::F64(v))
     }
 
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        Ok(NestedValue::Bool(v))
+    }
+
     // The following methods are not implemented because they are not needed for the
     // serialization of Param structs.
 
@@ -116,9 +120,6 @@ impl SerializerTrait for Serializer {
     fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> {
         unimplemented!()
     }
-    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> {
-        unimplemented!()
-    }
 
     fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> {
         unimplemented!()
@@ -0,0 +1,249 @@
+use std::{
+    fs::File,
+    io::{BufRead, BufReader, Lines, Read},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+#[cfg(feature = "gzip")]
+use flate2::read::MultiGzDecoder;
+
+use crate::Dataset;
+
+/// The first bytes of a gzip file.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// The maximum number of readers kept open, usually one is used per dataloader worker.
+const MAX_READERS: usize = 32;
+
+/// Dataset streaming the lines of a text file, optionally compressed with gzip when the `gzip`
+/// feature is enabled.
+///
+/// The lines are read lazily, so the file doesn't have to fit in memory. Since the file can only
+/// be read forward, the dataset keeps a few readers open and each item is read by the reader
+/// closest before it. Sequential accesses, like the ones of each dataloader worker on its part of
+/// the dataset, are therefore cheap, while a random access may restart a reader from the
+/// beginning of the file: shuffle the corpus beforehand rather than in the dataloader.
+pub struct LineStreamDataset {
+    path: PathBuf,
+    len: usize,
+    readers: Mutex<Vec<LineReader>>,
+}
+
+struct LineReader {
+    /// The index of the next line to be read.
+    position: usize,
+    lines: Lines<BufReader<Box<dyn Read + Send>>>,
+}
+
+impl LineStreamDataset {
+    /// Creates a new line stream dataset, where the length is the number of lines of the file.
+    ///
+    /// The file is read once to count the lines, without keeping them in memory.
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self, std::io::Error> {
+        let path = path.as_ref().to_path_buf();
+
+        let mut len = 0;
+        for line in LineReader::open(&path)?.lines {
+            line?;
+            len += 1;
+        }
+
+        Ok(Self::with_len(path, len))
+    }
+
+    /// Creates a new line stream dataset with an approximate length, to avoid reading the whole
+    /// file when it is large.
+    ///
+    /// The lines past the approximate length are ignored, and when the file has fewer lines, no
+    /// item is returned after the last line.
+    pub fn with_approximate_len<P: AsRef<Path>>(
+        path: P,
+        len: usize,
+    ) -> Result<Self, std::io::Error> {
+        let path = path.as_ref().to_path_buf();
+
+        // Fail early when the file can't be read.
+        LineReader::open(&path)?;
+
+        Ok(Self::with_len(path, len))
+    }
+
+    fn with_len(path: PathBuf, len: usize) -> Self {
+        Self {
+            path,
+            len,
+            readers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Takes the reader with the highest position not after the index, or opens a new one.
+    fn take_reader(&self, index: usize) -> LineReader {
+        let mut readers = self.readers.lock().unwrap();
+
+        let closest = readers
+            .iter()
+            .enumerate()
+            .filter(|(_, reader)| reader.position <= index)
+            .max_by_key(|(_, reader)| reader.position)
+            .map(|(i, _)| i);
+
+        match closest {
+            Some(i) => readers.swap_remove(i),
+            None => LineReader::open(&self.path).expect("The file should still be readable."),
+        }
+    }
+
+    fn put_reader(&self, reader: LineReader) {
+        let mut readers = self.readers.lock().unwrap();
+        readers.push(reader);
+
+        if readers.len() > MAX_READERS {
+            // The reader with the lowest position is the least likely to be reused.
+            let (lowest, _) = readers
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, reader)| reader.position)
+                .expect("There are readers.");
+            readers.swap_remove(lowest);
+        }
+    }
+}
+
+impl LineReader {
+    fn open(path: &Path) -> Result<Self, std::io::Error> {
+        let mut file = BufReader::new(File::open(path)?);
+        let gzip = file.fill_buf()?.starts_with(&GZIP_MAGIC);
+
+        let reader: Box<dyn Read + Send> = match gzip {
+            #[cfg(feature = "gzip")]
+            true => Box::new(MultiGzDecoder::new(file)),
+            #[cfg(not(feature = "gzip"))]
+            true => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "The file is compressed with gzip, which requires the `gzip` feature.",
+                ))
+            }
+            false => Box::new(file),
+        };
+
+        Ok(Self {
+            position: 0,
+            lines: BufReader::new(reader).lines(),
+        })
+    }
+
+    /// Reads the next line, or returns `None` at the end of the file.
+    fn next_line(&mut self) -> Option<String> {
+        let line = self
+            .lines
+            .next()?
+            .expect("The line should be valid UTF-8 text.");
+        self.position += 1;
+
+        Some(line)
+    }
+}
+
+impl Dataset<String> for LineStreamDataset {
+    fn get(&self, index: usize) -> Option<String> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut reader = self.take_reader(index);
+
+        // Skip the lines before the index, the reader is dropped at the end of the file.
+        while reader.position < index {
+            reader.next_line()?;
+        }
+        let line = reader.next_line()?;
+
+        self.put_reader(reader);
+
+        Some(line)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transform::PartialDataset;
+
+    const LINES_FILE: &str = "tests/data/lines.txt";
+    const LINES_GZIP_FILE: &str = "tests/data/lines.txt.gz";
+
+    fn expected_lines() -> Vec<String> {
+        (1..=10).map(|i| format!("Line number {i}")).collect()
+    }
+
+    #[test]
+    fn line_stream_should_read_all_the_lines() {
+        let dataset = LineStreamDataset::new(LINES_FILE).unwrap();
+
+        assert_eq!(dataset.len(), 10);
+        assert_eq!(dataset.iter().collect::<Vec<_>>(), expected_lines());
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn line_stream_should_read_all_the_lines_of_a_gzip_file() {
+        let dataset = LineStreamDataset::new(LINES_GZIP_FILE).unwrap();
+
+        assert_eq!(dataset.len(), 10);
+        assert_eq!(dataset.iter().collect::<Vec<_>>(), expected_lines());
+    }
+
+    #[test]
+    fn line_stream_should_support_random_access() {
+        let dataset = LineStreamDataset::new(LINES_FILE).unwrap();
+
+        assert_eq!(dataset.get(7), Some("Line number 8".to_string()));
+        assert_eq!(dataset.get(2), Some("Line number 3".to_string()));
+        assert_eq!(dataset.get(8), Some("Line number 9".to_string()));
+        assert_eq!(dataset.get(10), None);
+    }
+
+    #[test]
+    fn line_stream_with_approximate_len_should_stop_at_the_end_of_the_file() {
+        let dataset = LineStreamDataset::with_approximate_len(LINES_FILE, 12).unwrap();
+        assert_eq!(dataset.len(), 12);
+        assert_eq!(dataset.iter().collect::<Vec<_>>(), expected_lines());
+
+        let dataset = LineStreamDataset::with_approximate_len(LINES_FILE, 4).unwrap();
+        assert_eq!(dataset.iter().collect::<Vec<_>>(), expected_lines()[..4]);
+    }
+
+    #[test]
+    fn line_stream_should_be_read_concurrently_by_workers() {
+        let dataset = LineStreamDataset::new(LINES_FILE).unwrap();
+
+        // Each worker reads its part of the dataset, like the multi-threaded dataloader.
+        let handles = PartialDataset::split(dataset, 3)
+            .into_iter()
+            .map(|dataset| std::thread::spawn(move || dataset.iter().collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        let lines = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(lines, expected_lines());
+    }
+
+    #[test]
+    fn line_stream_should_fail_when_the_file_is_missing() {
+        assert!(LineStreamDataset::new("tests/data/missing.txt").is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "gzip"))]
+    fn line_stream_should_fail_on_a_gzip_file_without_the_gzip_feature() {
+        assert!(LineStreamDataset::new(LINES_GZIP_FILE).is_err());
+    }
+}
@@ -3,6 +3,7 @@ mod base;
 mod fake;
 mod in_memory;
 mod iterator;
+mod line_stream;
 #[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
 mod sqlite;
 
@@ -11,5 +12,6 @@ pub use self::fake::*;
 pub use base::*;
 pub use in_memory::*;
 pub use iterator::*;
+pub use line_stream::*;
 #[cfg(any(feature = "sqlite", feature = "sqlite-bundled"))]
 pub use sqlite::*;
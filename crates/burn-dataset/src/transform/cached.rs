@@ -0,0 +1,290 @@
+use crate::Dataset;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    marker::PhantomData,
+    path::PathBuf,
+    sync::Mutex,
+};
+
+/// Cache the items of a dataset, so the items that are expensive to build (e.g. decoded or
+/// transformed on access) are only built once.
+///
+/// The most recently used items are kept in memory, up to a fixed capacity. With a
+/// [disk cache](CachedDataset::with_disk_cache), the items are also stored in a directory, which
+/// is still valid for later runs as long as the input dataset doesn't change.
+///
+/// The dataset can be shared between the dataloader workers: when two workers access the same
+/// missing item at the same time, the item may be built twice.
+pub struct CachedDataset<D, I> {
+    dataset: D,
+    cache: Mutex<LruCache<I>>,
+    disk: Option<Box<dyn ItemStorage<I>>>,
+}
+
+impl<D, I> CachedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Clone + Send + Sync,
+{
+    /// Creates a new cached dataset, keeping at most `capacity` items in memory.
+    pub fn new(dataset: D, capacity: usize) -> Self {
+        Self {
+            dataset,
+            cache: Mutex::new(LruCache::new(capacity)),
+            disk: None,
+        }
+    }
+}
+
+impl<D, I> CachedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Clone + Send + Sync + Serialize + DeserializeOwned + 'static,
+{
+    /// Creates a new cached dataset, keeping at most `capacity` items in memory and all the items
+    /// in the given directory.
+    pub fn with_disk_cache<P: Into<PathBuf>>(
+        dataset: D,
+        capacity: usize,
+        directory: P,
+    ) -> Result<Self, std::io::Error> {
+        let directory = directory.into();
+        std::fs::create_dir_all(&directory)?;
+
+        let mut dataset = Self::new(dataset, capacity);
+        dataset.disk = Some(Box::new(DiskStorage {
+            directory,
+            item: PhantomData,
+        }));
+
+        Ok(dataset)
+    }
+}
+
+impl<D, I> Dataset<I> for CachedDataset<D, I>
+where
+    D: Dataset<I>,
+    I: Clone + Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        if let Some(item) = self.cache.lock().unwrap().get(index) {
+            return Some(item);
+        }
+
+        // The item is built without holding the lock, so the workers aren't blocked.
+        let item = match self.disk.as_ref().and_then(|disk| disk.load(index)) {
+            Some(item) => item,
+            None => {
+                let item = self.dataset.get(index)?;
+                if let Some(disk) = self.disk.as_ref() {
+                    disk.save(index, &item);
+                }
+                item
+            }
+        };
+
+        self.cache.lock().unwrap().insert(index, item.clone());
+
+        Some(item)
+    }
+
+    fn len(&self) -> usize {
+        self.dataset.len()
+    }
+}
+
+/// A least recently used cache of items by index.
+struct LruCache<I> {
+    capacity: usize,
+    last_use: u64,
+    items: HashMap<usize, (I, u64)>,
+    usages: BTreeMap<u64, usize>,
+}
+
+impl<I: Clone> LruCache<I> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            last_use: 0,
+            items: HashMap::new(),
+            usages: BTreeMap::new(),
+        }
+    }
+
+    fn get(&mut self, index: usize) -> Option<I> {
+        self.last_use += 1;
+        let (item, last_use) = self.items.get_mut(&index)?;
+
+        self.usages.remove(last_use);
+        self.usages.insert(self.last_use, index);
+        *last_use = self.last_use;
+
+        Some(item.clone())
+    }
+
+    fn insert(&mut self, index: usize, item: I) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.last_use += 1;
+        if let Some((_, last_use)) = self.items.insert(index, (item, self.last_use)) {
+            self.usages.remove(&last_use);
+        } else if self.items.len() > self.capacity {
+            // Evict the least recently used item.
+            let (_, evicted) = self.usages.pop_first().expect("The cache is not empty.");
+            self.items.remove(&evicted);
+        }
+        self.usages.insert(self.last_use, index);
+    }
+}
+
+/// Persistent storage of the items by index.
+trait ItemStorage<I>: Send + Sync {
+    fn load(&self, index: usize) -> Option<I>;
+    fn save(&self, index: usize, item: &I);
+}
+
+/// Store each item in its own file of a directory.
+struct DiskStorage<I> {
+    directory: PathBuf,
+    item: PhantomData<fn() -> I>,
+}
+
+impl<I> DiskStorage<I> {
+    fn path(&self, index: usize) -> PathBuf {
+        self.directory.join(format!("{index}.mpk"))
+    }
+}
+
+impl<I: Serialize + DeserializeOwned> ItemStorage<I> for DiskStorage<I> {
+    fn load(&self, index: usize) -> Option<I> {
+        let bytes = std::fs::read(self.path(index)).ok()?;
+
+        // A corrupted item is built again.
+        rmp_serde::from_slice(&bytes).ok()
+    }
+
+    fn save(&self, index: usize, item: &I) {
+        // The cache is best effort: when an item can't be saved, it is built again next time.
+        if let Ok(bytes) = rmp_serde::to_vec(item) {
+            let path = self.path(index);
+            let temp_path = path.with_extension("tmp");
+
+            // Write to a temporary file first, so a partially written item is never loaded.
+            if std::fs::write(&temp_path, bytes).is_ok() {
+                let _ = std::fs::rename(temp_path, path);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_data, InMemDataset};
+    use std::sync::Arc;
+
+    /// A dataset counting the number of times each item is built.
+    struct CountingDataset {
+        dataset: InMemDataset<String>,
+        calls: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl CountingDataset {
+        fn new() -> (Self, Arc<Mutex<Vec<usize>>>) {
+            let dataset = InMemDataset::new(test_data::string_items());
+            let calls = Arc::new(Mutex::new(vec![0; dataset.len()]));
+            let dataset = Self {
+                dataset,
+                calls: calls.clone(),
+            };
+
+            (dataset, calls)
+        }
+    }
+
+    impl Dataset<String> for CountingDataset {
+        fn get(&self, index: usize) -> Option<String> {
+            let item = self.dataset.get(index)?;
+            self.calls.lock().unwrap()[index] += 1;
+            Some(item)
+        }
+
+        fn len(&self) -> usize {
+            self.dataset.len()
+        }
+    }
+
+    #[test]
+    fn cached_dataset_should_build_each_item_once_when_large_enough() {
+        let (dataset, calls) = CountingDataset::new();
+        let dataset = CachedDataset::new(dataset, 4);
+
+        for _epoch in 0..3 {
+            let items = dataset.iter().collect::<Vec<_>>();
+            assert_eq!(items, test_data::string_items());
+        }
+
+        assert_eq!(*calls.lock().unwrap(), vec![1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn cached_dataset_should_evict_the_least_recently_used_item() {
+        let (dataset, calls) = CountingDataset::new();
+        let dataset = CachedDataset::new(dataset, 2);
+
+        dataset.get(0);
+        dataset.get(1);
+        dataset.get(0);
+        // Evicts the item 1, used before the item 0.
+        dataset.get(2);
+        dataset.get(0);
+        dataset.get(1);
+
+        assert_eq!(*calls.lock().unwrap(), vec![1, 2, 1, 0]);
+    }
+
+    #[test]
+    fn cached_dataset_should_be_shared_between_threads() {
+        let (dataset, calls) = CountingDataset::new();
+        let dataset = Arc::new(CachedDataset::new(dataset, 4));
+
+        // Build the items once, then read them concurrently.
+        let items = dataset.iter().collect::<Vec<_>>();
+        let handles = (0..4)
+            .map(|_| {
+                let dataset = dataset.clone();
+                std::thread::spawn(move || dataset.iter().collect::<Vec<_>>())
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), items);
+        }
+        assert_eq!(*calls.lock().unwrap(), vec![1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn cached_dataset_should_load_the_items_from_the_disk_cache() {
+        let directory = tempfile::tempdir().unwrap();
+
+        let (dataset, calls) = CountingDataset::new();
+        let dataset = CachedDataset::with_disk_cache(dataset, 0, directory.path()).unwrap();
+        assert_eq!(
+            dataset.iter().collect::<Vec<_>>(),
+            test_data::string_items()
+        );
+        assert_eq!(*calls.lock().unwrap(), vec![1, 1, 1, 1]);
+
+        // A new cache, e.g. of a later run, uses the items saved on disk.
+        let (dataset, calls) = CountingDataset::new();
+        let dataset = CachedDataset::with_disk_cache(dataset, 0, directory.path()).unwrap();
+        assert_eq!(
+            dataset.iter().collect::<Vec<_>>(),
+            test_data::string_items()
+        );
+        assert_eq!(*calls.lock().unwrap(), vec![0, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,200 @@
+use crate::Dataset;
+use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+use std::{marker::PhantomData, sync::Mutex};
+
+/// Shard a dataset between multiple processes, so each replica trains on a disjoint part of it.
+///
+/// The items are shuffled with the seed and the epoch, so every rank computes the same
+/// permutation, and each rank keeps every `num_replicas`-th item starting at its rank. Setting a
+/// new epoch with [set_epoch](DistributedSampler::set_epoch) on every rank changes the shuffling
+/// while the shards stay disjoint.
+///
+/// * With padding (Default): Some items are repeated so every shard has the same length, which
+///   keeps the replicas in sync when they all process the same number of batches.
+///
+/// * Without padding: Every item is used exactly once, and the lengths of the shards differ by at
+///   most one item.
+pub struct DistributedSampler<D, I> {
+    dataset: D,
+    num_replicas: usize,
+    rank: usize,
+    seed: u64,
+    padding: bool,
+    state: Mutex<DistributedSamplerState>,
+    input: PhantomData<I>,
+}
+
+struct DistributedSamplerState {
+    epoch: u64,
+    indices: Vec<usize>,
+}
+
+impl<D, I> DistributedSampler<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    /// Creates a new distributed sampler with padding.
+    ///
+    /// # Panics
+    ///
+    /// If the rank is not lower than the number of replicas.
+    pub fn new(dataset: D, num_replicas: usize, rank: usize, seed: u64) -> Self {
+        Self::create(dataset, num_replicas, rank, seed, true)
+    }
+
+    /// Creates a new distributed sampler with padding.
+    pub fn with_padding(dataset: D, num_replicas: usize, rank: usize, seed: u64) -> Self {
+        Self::new(dataset, num_replicas, rank, seed)
+    }
+
+    /// Creates a new distributed sampler without padding.
+    ///
+    /// # Panics
+    ///
+    /// If the rank is not lower than the number of replicas.
+    pub fn without_padding(dataset: D, num_replicas: usize, rank: usize, seed: u64) -> Self {
+        Self::create(dataset, num_replicas, rank, seed, false)
+    }
+
+    fn create(dataset: D, num_replicas: usize, rank: usize, seed: u64, padding: bool) -> Self {
+        assert!(
+            rank < num_replicas,
+            "Distributed sampler: the rank {rank} should be lower than the number of replicas \
+             {num_replicas}"
+        );
+
+        let mut sampler = Self {
+            dataset,
+            num_replicas,
+            rank,
+            seed,
+            padding,
+            state: Mutex::new(DistributedSamplerState {
+                epoch: 0,
+                indices: Vec::new(),
+            }),
+            input: PhantomData,
+        };
+        let indices = sampler.indices(0);
+        sampler.state.get_mut().unwrap().indices = indices;
+
+        sampler
+    }
+
+    /// Sets the epoch used to shuffle the items.
+    ///
+    /// It should be called with the same epoch on every rank at the beginning of each epoch,
+    /// otherwise the items are seen in the same order at every epoch.
+    pub fn set_epoch(&self, epoch: u64) {
+        let mut state = self.state.lock().unwrap();
+
+        if state.epoch != epoch {
+            state.epoch = epoch;
+            state.indices = self.indices(epoch);
+        }
+    }
+
+    /// The epoch used to shuffle the items.
+    pub fn epoch(&self) -> u64 {
+        self.state.lock().unwrap().epoch
+    }
+
+    /// The indices of the items of the dataset in the shard of this rank, for an epoch.
+    fn indices(&self, epoch: u64) -> Vec<usize> {
+        let len = self.dataset.len();
+        let mut indices = (0..len).collect::<Vec<_>>();
+        let mut rng = StdRng::seed_from_u64(self.seed.wrapping_add(epoch));
+        indices.shuffle(&mut rng);
+
+        if self.padding && len > 0 {
+            // Repeat the first items until the length is a multiple of the number of replicas.
+            let padded_len = len.div_ceil(self.num_replicas) * self.num_replicas;
+            let padding = (0..padded_len - len)
+                .map(|i| indices[i % len])
+                .collect::<Vec<_>>();
+            indices.extend(padding);
+        }
+
+        indices
+            .into_iter()
+            .skip(self.rank)
+            .step_by(self.num_replicas)
+            .collect()
+    }
+}
+
+impl<D, I> Dataset<I> for DistributedSampler<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        let index = *self.state.lock().unwrap().indices.get(index)?;
+
+        self.dataset.get(index)
+    }
+
+    fn len(&self) -> usize {
+        self.state.lock().unwrap().indices.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    const NUM_REPLICAS: usize = 3;
+
+    fn shards(len: usize, padding: bool, epoch: u64) -> Vec<Vec<usize>> {
+        (0..NUM_REPLICAS)
+            .map(|rank| {
+                let dataset = InMemDataset::new((0..len).collect::<Vec<usize>>());
+                let sampler = match padding {
+                    true => DistributedSampler::with_padding(dataset, NUM_REPLICAS, rank, 42),
+                    false => DistributedSampler::without_padding(dataset, NUM_REPLICAS, rank, 42),
+                };
+                sampler.set_epoch(epoch);
+                sampler.iter().collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn distributed_sampler_shards_should_cover_the_dataset_exactly_once() {
+        for epoch in 0..3 {
+            let shards = shards(10, false, epoch);
+            let mut items = shards.concat();
+            items.sort_unstable();
+
+            assert_eq!(items, (0..10).collect::<Vec<_>>());
+            let lengths = shards.iter().map(Vec::len).collect::<Vec<_>>();
+            assert_eq!(lengths, vec![4, 3, 3]);
+        }
+    }
+
+    #[test]
+    fn distributed_sampler_with_padding_should_have_shards_of_equal_length() {
+        let shards = shards(10, true, 0);
+        let mut items = shards.concat();
+        items.sort_unstable();
+        items.dedup();
+
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert!(shards.iter().all(|shard| shard.len() == 4));
+    }
+
+    #[test]
+    fn distributed_sampler_should_shuffle_differently_per_epoch() {
+        assert_eq!(shards(100, false, 1), shards(100, false, 1));
+        assert_ne!(shards(100, false, 1), shards(100, false, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn distributed_sampler_should_panic_when_rank_is_out_of_bounds() {
+        let dataset = InMemDataset::new((0..10).collect::<Vec<usize>>());
+        DistributedSampler::new(dataset, NUM_REPLICAS, NUM_REPLICAS, 42);
+    }
+}
@@ -1,11 +1,17 @@
+mod cached;
 mod composed;
+mod distributed_sampler;
 mod mapper;
 mod partial;
 mod random;
 mod sampler;
+mod weighted_sampler;
 
+pub use cached::*;
 pub use composed::*;
+pub use distributed_sampler::*;
 pub use mapper::*;
 pub use partial::*;
 pub use random::*;
 pub use sampler::*;
+pub use weighted_sampler::*;
@@ -0,0 +1,207 @@
+use crate::Dataset;
+use rand::{distributions::WeightedIndex, prelude::SliceRandom, rngs::StdRng, Rng, SeedableRng};
+use std::{marker::PhantomData, ops::DerefMut, sync::Mutex};
+
+/// Sample items from a dataset with a probability proportional to their weights.
+///
+/// This is useful to handle class imbalance, by giving a higher weight to the items of the rare
+/// classes. Like the [sampler dataset](crate::transform::SamplerDataset), the sampler has a fixed
+/// size and can be used with the dataloader like any other dataset.
+///
+/// * With replacement (Default): Every item is drawn independently, so the sampling frequency of
+///   each item is proportional to its weight.
+///
+/// * Without replacement: Each cycle draws every item with a positive weight once, in a random
+///   order where the items with a higher weight are more likely to come first. Once every item
+///   has been used, a new cycle is created.
+pub struct WeightedRandomSampler<D, I> {
+    dataset: D,
+    weights: Vec<f64>,
+    size: usize,
+    state: Mutex<WeightedSamplerState>,
+    input: PhantomData<I>,
+}
+
+enum WeightedSamplerState {
+    WithReplacement(StdRng, WeightedIndex<f64>),
+    WithoutReplacement(StdRng, Vec<usize>),
+}
+
+impl<D, I> WeightedRandomSampler<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    /// Creates a new weighted sampler with replacement.
+    ///
+    /// # Panics
+    ///
+    /// If there is not one weight per item, or if the weights are negative or all zero.
+    pub fn new(dataset: D, weights: Vec<f64>, size: usize) -> Self {
+        let distribution = Self::distribution(&dataset, &weights);
+
+        Self {
+            dataset,
+            weights,
+            size,
+            state: Mutex::new(WeightedSamplerState::WithReplacement(
+                StdRng::from_entropy(),
+                distribution,
+            )),
+            input: PhantomData,
+        }
+    }
+
+    /// Creates a new weighted sampler with replacement.
+    pub fn with_replacement(dataset: D, weights: Vec<f64>, size: usize) -> Self {
+        Self::new(dataset, weights, size)
+    }
+
+    /// Creates a new weighted sampler without replacement.
+    ///
+    /// # Panics
+    ///
+    /// If there is not one weight per item, or if the weights are negative or all zero.
+    pub fn without_replacement(dataset: D, weights: Vec<f64>, size: usize) -> Self {
+        Self::distribution(&dataset, &weights);
+
+        Self {
+            dataset,
+            weights,
+            size,
+            state: Mutex::new(WeightedSamplerState::WithoutReplacement(
+                StdRng::from_entropy(),
+                Vec::new(),
+            )),
+            input: PhantomData,
+        }
+    }
+
+    fn distribution(dataset: &D, weights: &[f64]) -> WeightedIndex<f64> {
+        assert_eq!(
+            weights.len(),
+            dataset.len(),
+            "Weighted sampler: expected one weight per item"
+        );
+
+        WeightedIndex::new(weights)
+            .expect("Weighted sampler: weights should be non-negative with a positive sum")
+    }
+
+    fn index(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+
+        match state.deref_mut() {
+            WeightedSamplerState::WithReplacement(rng, distribution) => rng.sample(&*distribution),
+            WeightedSamplerState::WithoutReplacement(rng, indices) => {
+                if indices.is_empty() {
+                    // Refill the state, the items with a zero weight are never drawn.
+                    let candidates = (0..self.dataset.len()).collect::<Vec<_>>();
+                    let amount = self.weights.iter().filter(|weight| **weight > 0.0).count();
+
+                    *indices = candidates
+                        .choose_multiple_weighted(rng, amount, |index| self.weights[*index])
+                        .expect("Weights are validated on creation.")
+                        .copied()
+                        .collect();
+                    // The indices are popped from the end.
+                    indices.reverse();
+                }
+
+                indices.pop().expect("Indices are refilled when empty.")
+            }
+        }
+    }
+}
+
+impl<D, I> Dataset<I> for WeightedRandomSampler<D, I>
+where
+    D: Dataset<I>,
+    I: Send + Sync,
+{
+    fn get(&self, index: usize) -> Option<I> {
+        if index >= self.size {
+            return None;
+        }
+
+        self.dataset.get(self.index())
+    }
+
+    fn len(&self) -> usize {
+        self.size
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InMemDataset;
+
+    const WEIGHTS: [f64; 5] = [1.0, 2.0, 0.0, 3.0, 4.0];
+
+    fn frequencies(items: impl Iterator<Item = usize>) -> Vec<f64> {
+        let mut counts = vec![0; WEIGHTS.len()];
+        let mut total = 0;
+
+        for item in items {
+            counts[item] += 1;
+            total += 1;
+        }
+
+        counts
+            .into_iter()
+            .map(|count| count as f64 / total as f64)
+            .collect()
+    }
+
+    fn assert_frequencies_match_weights(frequencies: Vec<f64>) {
+        let sum = WEIGHTS.iter().sum::<f64>();
+
+        for (frequency, weight) in frequencies.into_iter().zip(WEIGHTS) {
+            let expected = weight / sum;
+            assert!(
+                (frequency - expected).abs() < 0.01,
+                "Frequency {frequency} should be close to {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn weighted_sampler_with_replacement_should_match_weights() {
+        let dataset = InMemDataset::new((0..WEIGHTS.len()).collect::<Vec<usize>>());
+        let sampler = WeightedRandomSampler::with_replacement(dataset, WEIGHTS.to_vec(), 100_000);
+
+        assert_eq!(sampler.len(), 100_000);
+        assert_frequencies_match_weights(frequencies(sampler.iter()));
+    }
+
+    #[test]
+    fn weighted_sampler_without_replacement_should_draw_each_item_once_per_cycle() {
+        let num_cycles = 50_000;
+        let cycle_size = WEIGHTS.iter().filter(|weight| **weight > 0.0).count();
+        let dataset = InMemDataset::new((0..WEIGHTS.len()).collect::<Vec<usize>>());
+        let sampler = WeightedRandomSampler::without_replacement(
+            dataset,
+            WEIGHTS.to_vec(),
+            cycle_size * num_cycles,
+        );
+        let items = sampler.iter().collect::<Vec<_>>();
+
+        for cycle in items.chunks(cycle_size) {
+            let mut cycle = cycle.to_vec();
+            cycle.sort_unstable();
+            assert_eq!(cycle, vec![0, 1, 3, 4]);
+        }
+
+        // The first item of each cycle is drawn with a probability proportional to its weight.
+        let first_items = items.chunks(cycle_size).map(|cycle| cycle[0]);
+        assert_frequencies_match_weights(frequencies(first_items));
+    }
+
+    #[test]
+    #[should_panic]
+    fn weighted_sampler_should_panic_when_weights_are_missing() {
+        let dataset = InMemDataset::new((0..WEIGHTS.len()).collect::<Vec<usize>>());
+        WeightedRandomSampler::new(dataset, vec![1.0, 2.0], 10);
+    }
+}
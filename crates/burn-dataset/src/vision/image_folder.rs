@@ -1,8 +1,8 @@
-use crate::transform::{Mapper, MapperDataset};
+use crate::transform::Mapper;
 use crate::{Dataset, InMemDataset};
 
 use globwalk::{self, DirEntry};
-use image::{self, ColorType};
+use image::{self, imageops::FilterType, ColorType};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -109,6 +109,7 @@ struct ImageDatasetItemRaw {
 
 struct PathToImageDatasetItem {
     classes: HashMap<String, usize>,
+    image_size: Option<(u32, u32)>,
 }
 
 /// Parse the image annotation to the corresponding type.
@@ -131,6 +132,12 @@ impl Mapper<ImageDatasetItemRaw, ImageDatasetItem> for PathToImageDatasetItem {
         // Load image from disk
         let image = image::open(&item.image_path).unwrap();
 
+        // Resize the image with a bilinear filter, the color type is kept
+        let image = match self.image_size {
+            Some((width, height)) => image.resize_exact(width, height, FilterType::Triangle),
+            None => image,
+        };
+
         // Image as Vec<PixelDepth>
         let img_vec = match image.color() {
             ColorType::L8 => image
@@ -209,17 +216,17 @@ pub enum ImageLoaderError {
     InvalidFileExtensionError(String),
 }
 
-type ImageDatasetMapper =
-    MapperDataset<InMemDataset<ImageDatasetItemRaw>, PathToImageDatasetItem, ImageDatasetItemRaw>;
-
 /// A generic dataset to load classification images from disk.
+///
+/// The images are loaded lazily when the items are accessed.
 pub struct ImageFolderDataset {
-    dataset: ImageDatasetMapper,
+    dataset: InMemDataset<ImageDatasetItemRaw>,
+    mapper: PathToImageDatasetItem,
 }
 
 impl Dataset<ImageDatasetItem> for ImageFolderDataset {
     fn get(&self, index: usize) -> Option<ImageDatasetItem> {
-        self.dataset.get(index)
+        self.dataset.get(index).map(|item| self.mapper.map(&item))
     }
 
     fn len(&self) -> usize {
@@ -331,10 +338,32 @@ impl ImageFolderDataset {
 
         let mapper = PathToImageDatasetItem {
             classes: classes_map,
+            image_size: None,
         };
-        let dataset = MapperDataset::new(dataset, mapper);
 
-        Ok(Self { dataset })
+        Ok(Self { dataset, mapper })
+    }
+
+    /// Resize all the images to the given size when they are loaded, so they can be batched
+    /// together.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Width of the resized images.
+    /// * `height` - Height of the resized images.
+    ///
+    /// # Returns
+    /// The dataset with resized images.
+    pub fn with_image_size(mut self, width: u32, height: u32) -> Self {
+        self.mapper.image_size = Some((width, height));
+        self
+    }
+
+    /// The map of the class names, which are the names of the image folders, to their label.
+    ///
+    /// The labels are assigned in the alphabetical order of the class names.
+    pub fn class_to_index(&self) -> &HashMap<String, usize> {
+        &self.mapper.classes
     }
 }
 
@@ -370,6 +399,34 @@ mod tests {
         assert_eq!(dataset.get(1).unwrap().annotation, Annotation::Label(1));
     }
 
+    #[test]
+    pub fn image_folder_dataset_class_to_index() {
+        let dataset = ImageFolderDataset::new_classification(DATASET_ROOT).unwrap();
+
+        let expected = HashMap::from([("orange".to_string(), 0), ("red".to_string(), 1)]);
+        assert_eq!(dataset.class_to_index(), &expected);
+    }
+
+    #[test]
+    pub fn image_folder_dataset_with_image_size() {
+        let dataset = ImageFolderDataset::new_classification(DATASET_ROOT)
+            .unwrap()
+            .with_image_size(2, 3);
+
+        // The 1x1 RGB orange dot is repeated in the 2x3 resized image
+        let item = dataset.get(0).unwrap();
+        assert_eq!(item.annotation, Annotation::Label(0));
+        assert_eq!(item.image.len(), 2 * 3 * 3);
+        for pixel in item.image.chunks(3) {
+            assert_eq!(pixel, &item.image[..3]);
+        }
+
+        // The 1x1 RGBA red dot
+        let item = dataset.get(2).unwrap();
+        assert_eq!(item.annotation, Annotation::Label(1));
+        assert_eq!(item.image.len(), 2 * 3 * 4);
+    }
+
     #[test]
     #[should_panic]
     pub fn image_folder_dataset_invalid_extension() {
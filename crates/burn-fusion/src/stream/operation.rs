@@ -967,6 +967,7 @@ pub enum InterpolateModeDescription {
 #[allow(missing_docs)]
 pub struct InterpolateOptionsDescription {
     pub mode: InterpolateModeDescription,
+    pub align_corners: bool,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
@@ -992,6 +993,7 @@ impl From<InterpolateOptionsDescription> for InterpolateOptions {
     fn from(val: InterpolateOptionsDescription) -> Self {
         Self {
             mode: val.mode.into(),
+            align_corners: val.align_corners,
         }
     }
 }
@@ -1010,6 +1012,7 @@ impl From<InterpolateOptions> for InterpolateOptionsDescription {
     fn from(val: InterpolateOptions) -> Self {
         Self {
             mode: val.mode.into(),
+            align_corners: val.align_corners,
         }
     }
 }
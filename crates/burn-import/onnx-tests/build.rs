@@ -19,23 +19,28 @@ fn main() {
         .input("tests/div/div.onnx")
         .input("tests/dropout/dropout_opset16.onnx")
         .input("tests/dropout/dropout_opset7.onnx")
+        .input("tests/dynamic_batch/dynamic_batch.onnx")
         .input("tests/equal/equal.onnx")
         .input("tests/erf/erf.onnx")
         .input("tests/exp/exp.onnx")
         .input("tests/flatten/flatten.onnx")
         .input("tests/gather/gather.onnx")
+        .input("tests/gather_scatter/gather_scatter.onnx")
         .input("tests/gelu/gelu.onnx")
         .input("tests/global_avr_pool/global_avr_pool.onnx")
         .input("tests/linear/linear.onnx")
         .input("tests/log_softmax/log_softmax.onnx")
         .input("tests/log/log.onnx")
+        .input("tests/lstm/lstm.onnx")
         .input("tests/maxpool2d/maxpool2d.onnx")
         .input("tests/mul/mul.onnx")
         .input("tests/neg/neg.onnx")
         .input("tests/recip/recip.onnx")
         .input("tests/relu/relu.onnx")
         .input("tests/reshape/reshape.onnx")
+        .input("tests/resize/resize.onnx")
         .input("tests/sigmoid/sigmoid.onnx")
+        .input("tests/slice/slice.onnx")
         .input("tests/softmax/softmax.onnx")
         .input("tests/sqrt/sqrt.onnx")
         .input("tests/sub/sub_int.onnx")
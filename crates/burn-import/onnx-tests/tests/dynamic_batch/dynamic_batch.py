@@ -0,0 +1,53 @@
+#!/usr/bin/env python3
+
+# used to generate model: dynamic_batch.onnx
+
+import torch
+import torch.nn as nn
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+        self.flatten = nn.Flatten()
+        self.linear = nn.Linear(6, 2)
+        self.relu = nn.ReLU()
+
+        # Fixed weights, so the expected outputs are easy to compute by hand
+        with torch.no_grad():
+            self.linear.weight.copy_(torch.tensor([
+                [1.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+                [0.0, -1.0, 0.0, 0.0, 0.0, 0.0],
+            ]))
+            self.linear.bias.copy_(torch.tensor([0.5, 5.0]))
+
+    def forward(self, x):
+        return self.relu(self.linear(self.flatten(x)))
+
+
+def main():
+    model = Model()
+    model.eval()
+    device = torch.device("cpu")
+
+    file_name = "dynamic_batch.onnx"
+    test_input = torch.arange(18, dtype=torch.float, device=device).reshape(3, 2, 3)
+
+    # The first dimension of the input and the output is exported as a named `batch` dimension
+    torch.onnx.export(model, test_input, file_name,
+                      input_names=["x"], output_names=["y"],
+                      dynamic_axes={"x": {0: "batch"}, "y": {0: "batch"}},
+                      verbose=False, opset_version=16)
+
+    print("Finished exporting model to {}".format(file_name))
+
+    # Output some test data for use in the test, at two different batch sizes
+    for batch_size in [1, 3]:
+        input = test_input[:batch_size]
+        print("Test input data: {}".format(input))
+        output = model.forward(input)
+        print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
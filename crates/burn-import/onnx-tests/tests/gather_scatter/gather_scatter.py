@@ -0,0 +1,63 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/gather_scatter/gather_scatter.onnx
+
+import numpy as np
+import onnx
+from onnx import TensorProto, helper
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    # The indices are graph inputs, so they are only known at runtime.
+    scatter = helper.make_node(
+        "ScatterElements",
+        inputs=["x", "scatter_index", "updates"],
+        outputs=["scattered"],
+        axis=0,
+    )
+    gather = helper.make_node(
+        "Gather",
+        inputs=["scattered", "gather_index"],
+        outputs=["gathered"],
+        axis=-1,
+    )
+
+    graph = helper.make_graph(
+        [scatter, gather],
+        "gather_scatter",
+        inputs=[
+            helper.make_tensor_value_info("x", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("scatter_index", TensorProto.INT64, [1, 3]),
+            helper.make_tensor_value_info("updates", TensorProto.FLOAT, [1, 3]),
+            helper.make_tensor_value_info("gather_index", TensorProto.INT64, [2]),
+        ],
+        outputs=[
+            helper.make_tensor_value_info("scattered", TensorProto.FLOAT, [2, 3]),
+            helper.make_tensor_value_info("gathered", TensorProto.FLOAT, [2, 2]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    model.ir_version = 8
+    onnx.checker.check_model(model)
+
+    onnx_name = "gather_scatter.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Output some test data for use in the test, with negative indices
+    test_input = {
+        "x": np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], dtype=np.float32),
+        "scatter_index": np.array([[1, 0, -1]], dtype=np.int64),
+        "updates": np.array([[10.0, 20.0, 30.0]], dtype=np.float32),
+        "gather_index": np.array([2, -3], dtype=np.int64),
+    }
+    print("Test input data: {}".format(test_input))
+    output = ReferenceEvaluator(model).run(None, test_input)
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
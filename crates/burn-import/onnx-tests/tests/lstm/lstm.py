@@ -0,0 +1,92 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/lstm/lstm.onnx
+
+import numpy as np
+import onnx
+import onnxruntime
+from onnx import TensorProto, helper, numpy_helper
+
+SEQ_LENGTH = 3
+BATCH_SIZE = 2
+INPUT_SIZE = 3
+HIDDEN_SIZE = 2
+NUM_DIRECTIONS = 2
+
+
+def values(shape, scale, modulo, offset):
+    # Deterministic values, so the test data can be written without running this script
+    count = int(np.prod(shape))
+    return ((np.arange(count) * scale % modulo - offset) / 10).astype(np.float32).reshape(shape)
+
+
+def main():
+    # The gates are ordered as input, output, forget and cell
+    w = values([NUM_DIRECTIONS, 4 * HIDDEN_SIZE, INPUT_SIZE], 7, 11, 5)
+    r = values([NUM_DIRECTIONS, 4 * HIDDEN_SIZE, HIDDEN_SIZE], 5, 9, 4)
+    b = values([NUM_DIRECTIONS, 8 * HIDDEN_SIZE], 3, 7, 3)
+
+    lstm = helper.make_node(
+        "LSTM",
+        inputs=["x", "w", "r", "b", "", "initial_h", "initial_c"],
+        outputs=["y", "y_h", "y_c"],
+        direction="bidirectional",
+        hidden_size=HIDDEN_SIZE,
+    )
+
+    graph = helper.make_graph(
+        [lstm],
+        "lstm",
+        inputs=[
+            helper.make_tensor_value_info(
+                "x", TensorProto.FLOAT, [SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE]
+            ),
+            helper.make_tensor_value_info(
+                "initial_h", TensorProto.FLOAT, [NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE]
+            ),
+            helper.make_tensor_value_info(
+                "initial_c", TensorProto.FLOAT, [NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE]
+            ),
+        ],
+        outputs=[
+            helper.make_tensor_value_info(
+                "y", TensorProto.FLOAT, [SEQ_LENGTH, NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE]
+            ),
+            helper.make_tensor_value_info(
+                "y_h", TensorProto.FLOAT, [NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE]
+            ),
+            helper.make_tensor_value_info(
+                "y_c", TensorProto.FLOAT, [NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE]
+            ),
+        ],
+        initializer=[
+            numpy_helper.from_array(w, "w"),
+            numpy_helper.from_array(r, "r"),
+            numpy_helper.from_array(b, "b"),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    model.ir_version = 8
+    onnx.checker.check_model(model)
+
+    onnx_name = "lstm.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Output some test data for use in the test
+    test_input = {
+        "x": values([SEQ_LENGTH, BATCH_SIZE, INPUT_SIZE], 1, 5, 2) * 3,
+        "initial_h": values([NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE], 1, 3, 1) * 2,
+        "initial_c": values([NUM_DIRECTIONS, BATCH_SIZE, HIDDEN_SIZE], 2, 4, 1.5),
+    }
+    print("Test input data: {}".format(test_input))
+
+    session = onnxruntime.InferenceSession(onnx_name)
+    output = session.run(None, test_input)
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
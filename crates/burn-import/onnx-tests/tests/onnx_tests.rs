@@ -26,23 +26,28 @@ include_models!(
     div,
     dropout_opset16,
     dropout_opset7,
+    dynamic_batch,
     equal,
     erf,
     exp,
     flatten,
     gather,
+    gather_scatter,
     gelu,
     global_avr_pool,
     linear,
     log_softmax,
     log,
+    lstm,
     maxpool2d,
     mul,
     neg,
     recip,
     relu,
     reshape,
+    resize,
     sigmoid,
+    slice,
     softmax,
     sqrt,
     sub_int,
@@ -254,6 +259,25 @@ mod tests {
         assert!(expected_sum.approx_eq(output_sum, (1.0e-4, 2)));
     }
 
+    #[test]
+    fn dynamic_batch() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: dynamic_batch::Model<Backend> = dynamic_batch::Model::default();
+        let device = Default::default();
+
+        // The batch dimension is symbolic, so the same model runs at different batch sizes
+        let input = Tensor::<Backend, 1, Int>::arange(0..18, &device)
+            .float()
+            .reshape([3, 2, 3]);
+
+        let output = model.forward(input.clone().slice([0..1]));
+        assert_eq!(output.to_data(), Data::from([[5.5, 4.0]]));
+
+        let output = model.forward(input);
+        let expected = Data::from([[5.5, 4.0], [17.5, 0.0], [29.5, 0.0]]);
+        assert_eq!(output.to_data(), expected);
+    }
+
     #[test]
     fn erf() {
         let model: erf::Model<Backend> = erf::Model::default();
@@ -282,6 +306,25 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn gather_scatter() {
+        let model: gather_scatter::Model<Backend> = gather_scatter::Model::default();
+
+        let device = Default::default();
+        // Run the model with negative indices, counted from the end of the dimension
+        let input = Tensor::<Backend, 2>::from_floats([[1., 2., 3.], [4., 5., 6.]], &device);
+        let scatter_index = Tensor::<Backend, 2, Int>::from_ints([[1, 0, -1]], &device);
+        let updates = Tensor::<Backend, 2>::from_floats([[10., 20., 30.]], &device);
+        let gather_index = Tensor::<Backend, 1, Int>::from_ints([2, -3], &device);
+        let (scattered, gathered) = model.forward(input, scatter_index, updates, gather_index);
+
+        assert_eq!(
+            scattered.to_data(),
+            Data::from([[1., 20., 3.], [10., 5., 30.]])
+        );
+        assert_eq!(gathered.to_data(), Data::from([[3., 1.], [30., 10.]]));
+    }
+
     #[test]
     fn globalavrpool_1d_2d() {
         // The model contains 1d and 2d global average pooling nodes
@@ -371,6 +414,63 @@ mod tests {
         assert_eq!(output2, expected2);
     }
 
+    #[test]
+    fn lstm() {
+        // Initialize the model with weights (loaded from the exported file)
+        let model: lstm::Model<Backend> = lstm::Model::default();
+
+        let device = Default::default();
+        // Run the bidirectional lstm with initial states
+        let input = Tensor::<Backend, 3>::from_floats(
+            [
+                [[-0.6, -0.3, 0.0], [0.3, 0.6, -0.6]],
+                [[-0.3, 0.0, 0.3], [0.6, -0.6, -0.3]],
+                [[0.0, 0.3, 0.6], [-0.6, -0.3, 0.0]],
+            ],
+            &device,
+        );
+        let initial_hidden = Tensor::<Backend, 3>::from_floats(
+            [[[-0.2, 0.0], [0.2, -0.2]], [[0.0, 0.2], [-0.2, 0.0]]],
+            &device,
+        );
+        let initial_cell = Tensor::<Backend, 3>::from_floats(
+            [
+                [[-0.15, 0.05], [-0.15, 0.05]],
+                [[-0.15, 0.05], [-0.15, 0.05]],
+            ],
+            &device,
+        );
+        let (output, hidden, cell) = model.forward(input, initial_hidden, initial_cell);
+
+        // Expected outputs of the ONNX LSTM equations
+        let expected_output = Data::from([
+            [
+                [[-0.066141, -0.025431], [-0.163406, -0.19899]],
+                [[0.026189, 0.076346], [0.234259, -0.002214]],
+            ],
+            [
+                [[-0.060237, -0.065305], [-0.101928, -0.16555]],
+                [[-0.019011, 0.111053], [0.104921, 0.059544]],
+            ],
+            [
+                [[-0.061703, -0.107312], [-0.055184, -0.088541]],
+                [[-0.061236, 0.107437], [0.006221, 0.040353]],
+            ],
+        ]);
+        let expected_hidden = Data::from([
+            [[-0.061703, -0.107312], [-0.055184, -0.088541]],
+            [[0.026189, 0.076346], [0.234259, -0.002214]],
+        ]);
+        let expected_cell = Data::from([
+            [[-0.127091, -0.25021], [-0.114829, -0.189211]],
+            [[0.048146, 0.141232], [0.479863, -0.004457]],
+        ]);
+
+        output.to_data().assert_approx_eq(&expected_output, 4);
+        hidden.to_data().assert_approx_eq(&expected_hidden, 4);
+        cell.to_data().assert_approx_eq(&expected_cell, 4);
+    }
+
     #[test]
     fn maxpool2d() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -455,6 +555,33 @@ mod tests {
         assert_eq!(output.to_data(), expected);
     }
 
+    #[test]
+    fn resize() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: resize::Model<Backend> = resize::Model::new(&device);
+
+        // Run the model, upsampling the image with scales and with sizes
+        let input = Tensor::<Backend, 4>::from_floats([[[[1., 2., 3.], [4., 5., 6.]]]], &device);
+        let (linear, nearest) = model.forward(input);
+
+        let expected_linear = Data::from([[[
+            [1.0, 1.25, 1.75, 2.25, 2.75, 3.0],
+            [1.75, 2.0, 2.5, 3.0, 3.5, 3.75],
+            [3.25, 3.5, 4.0, 4.5, 5.0, 5.25],
+            [4.0, 4.25, 4.75, 5.25, 5.75, 6.0],
+        ]]]);
+        let expected_nearest = Data::from([[[
+            [1., 1., 2., 2., 3., 3.],
+            [1., 1., 2., 2., 3., 3.],
+            [4., 4., 5., 5., 6., 6.],
+            [4., 4., 5., 5., 6., 6.],
+        ]]]);
+
+        linear.to_data().assert_approx_eq(&expected_linear, 3);
+        assert_eq!(nearest.to_data(), expected_nearest);
+    }
+
     #[test]
     fn flatten() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -531,6 +658,29 @@ mod tests {
         output.to_data().assert_approx_eq(&expected, 7);
     }
 
+    #[test]
+    fn slice() {
+        // Initialize the model without weights (because the exported file does not contain them)
+        let device = Default::default();
+        let model: slice::Model<Backend> = slice::Model::new(&device);
+
+        // Run the model, slicing with a step of 2 and reversing with negative steps
+        let input = Tensor::<Backend, 1, Int>::arange(0..12, &device)
+            .float()
+            .reshape([3, 4]);
+        let (strided, reversed) = model.forward(input);
+
+        // The expected values are x[:, ::2] and x[::-1, -1:0:-2] in numpy
+        assert_eq!(
+            strided.to_data(),
+            Data::from([[0., 2.], [4., 6.], [8., 10.]])
+        );
+        assert_eq!(
+            reversed.to_data(),
+            Data::from([[11., 9.], [7., 5.], [3., 1.]])
+        );
+    }
+
     #[test]
     fn transpose() {
         // Initialize the model without weights (because the exported file does not contain them)
@@ -0,0 +1,62 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/resize/resize.onnx
+
+import numpy as np
+import onnx
+from onnx import TensorProto, helper
+from onnx.reference import ReferenceEvaluator
+
+
+def main():
+    # The image is doubled by scales with a bilinear interpolation, and by sizes with a nearest one.
+    linear = helper.make_node(
+        "Resize",
+        inputs=["x", "", "scales"],
+        outputs=["linear"],
+        name="resize_linear",
+        mode="linear",
+        coordinate_transformation_mode="half_pixel",
+    )
+    nearest = helper.make_node(
+        "Resize",
+        inputs=["x", "", "", "sizes"],
+        outputs=["nearest"],
+        name="resize_nearest",
+        mode="nearest",
+        coordinate_transformation_mode="asymmetric",
+        nearest_mode="floor",
+    )
+
+    graph = helper.make_graph(
+        [linear, nearest],
+        "resize",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [1, 1, 2, 3])],
+        outputs=[
+            helper.make_tensor_value_info("linear", TensorProto.FLOAT, [1, 1, 4, 6]),
+            helper.make_tensor_value_info("nearest", TensorProto.FLOAT, [1, 1, 4, 6]),
+        ],
+        initializer=[
+            helper.make_tensor("scales", TensorProto.FLOAT, [4], [1.0, 1.0, 2.0, 2.0]),
+            helper.make_tensor("sizes", TensorProto.INT64, [4], [1, 1, 4, 6]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    model.ir_version = 8
+    onnx.checker.check_model(model)
+
+    onnx_name = "resize.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Output some test data for use in the test
+    test_input = {"x": np.array([[[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]], dtype=np.float32)}
+    print("Test input data: {}".format(test_input))
+    output = ReferenceEvaluator(model).run(None, test_input)
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,69 @@
+#!/usr/bin/env python3
+
+# used to generate model: onnx-tests/tests/slice/slice.onnx
+
+import numpy as np
+import onnx
+from onnx import TensorProto, helper
+import onnxruntime as ort
+
+INT64_MAX = np.iinfo(np.int64).max
+INT64_MIN = np.iinfo(np.int64).min
+
+
+def main():
+    # x[:, ::2]
+    strided = helper.make_node(
+        "Slice",
+        inputs=["x", "starts1", "ends1", "axes1", "steps1"],
+        outputs=["strided"],
+        name="slice_strided",
+    )
+    # x[::-1, -1:0:-2], with a negative axis
+    reversed = helper.make_node(
+        "Slice",
+        inputs=["x", "starts2", "ends2", "axes2", "steps2"],
+        outputs=["reversed"],
+        name="slice_reversed",
+    )
+
+    int64 = lambda name, values: helper.make_tensor(name, TensorProto.INT64, [len(values)], values)
+    graph = helper.make_graph(
+        [strided, reversed],
+        "slice",
+        inputs=[helper.make_tensor_value_info("x", TensorProto.FLOAT, [3, 4])],
+        outputs=[
+            helper.make_tensor_value_info("strided", TensorProto.FLOAT, [3, 2]),
+            helper.make_tensor_value_info("reversed", TensorProto.FLOAT, [3, 2]),
+        ],
+        initializer=[
+            int64("starts1", [0]),
+            int64("ends1", [INT64_MAX]),
+            int64("axes1", [1]),
+            int64("steps1", [2]),
+            int64("starts2", [-1, -1]),
+            int64("ends2", [INT64_MIN, 0]),
+            int64("axes2", [0, -1]),
+            int64("steps2", [-1, -2]),
+        ],
+    )
+
+    model = helper.make_model(graph, opset_imports=[helper.make_opsetid("", 16)])
+    model.ir_version = 8
+    onnx.checker.check_model(model)
+
+    onnx_name = "slice.onnx"
+    onnx.save(model, onnx_name)
+
+    print("Finished exporting model to {}".format(onnx_name))
+
+    # Output some test data for use in the test
+    test_input = {"x": np.arange(12, dtype=np.float32).reshape(3, 4)}
+    print("Test input data: {}".format(test_input))
+    session = ort.InferenceSession(onnx_name)
+    output = session.run(None, test_input)
+    print("Test output data: {}".format(output))
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,36 @@
+#!/usr/bin/env python3
+
+import torch
+import torch.nn as nn
+from safetensors.torch import save_file
+
+
+class Model(nn.Module):
+    def __init__(self):
+        super(Model, self).__init__()
+        self.fc1 = nn.Linear(2, 3)
+        self.fc2 = nn.Linear(3, 4, bias=False)
+
+    def forward(self, x):
+        return self.fc2(self.fc1(x))
+
+
+def main():
+    model = Model().to(torch.device("cpu"))
+
+    # The weights are saved with the PyTorch layout: [d_output, d_input]. They are the transposed
+    # weights of linear.safetensors, so both files are loaded into the same Burn module.
+    with torch.no_grad():
+        model.fc1.weight.copy_(torch.tensor([[0.5, 2.0], [-1.0, 0.0], [0.25, -0.75]]))
+        model.fc1.bias.copy_(torch.tensor([0.125, -0.5, 1.5]))
+        model.fc2.weight.copy_(
+            torch.tensor(
+                [[1.0, -2.0, 0.0], [0.5, 0.25, -1.5], [-0.5, 1.0, 2.0], [0.0, 0.75, 1.0]]
+            )
+        )
+
+    save_file(model.state_dict(), "linear_pytorch.safetensors")
+
+
+if __name__ == '__main__':
+    main()
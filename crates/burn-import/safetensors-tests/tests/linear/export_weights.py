@@ -0,0 +1,23 @@
+#!/usr/bin/env python3
+
+import numpy as np
+from safetensors.numpy import save_file
+
+
+def main():
+    # The weights are saved with the Burn layout: [d_input, d_output].
+    # Each tensor has a different dtype to check the conversions when loading.
+    tensors = {
+        "fc1.weight": np.array([[0.5, -1.0, 0.25], [2.0, 0.0, -0.75]], dtype=np.float32),
+        "fc1.bias": np.array([0.125, -0.5, 1.5], dtype=np.float64),
+        "fc2.weight": np.array(
+            [[1.0, 0.5, -0.5, 0.0], [-2.0, 0.25, 1.0, 0.75], [0.0, -1.5, 2.0, 1.0]],
+            dtype=np.float16,
+        ),
+    }
+
+    save_file(tensors, "linear.safetensors")
+
+
+if __name__ == '__main__':
+    main()
@@ -0,0 +1,112 @@
+use burn::{
+    module::Module,
+    nn::{Linear, LinearConfig},
+    tensor::backend::Backend,
+};
+
+#[derive(Module, Debug)]
+pub struct Net<B: Backend> {
+    fc1: Linear<B>,
+    fc2: Linear<B>,
+}
+
+impl<B: Backend> Net<B> {
+    /// Create a new model from the given record.
+    pub fn new_with(record: NetRecord<B>) -> Self {
+        let fc1 = LinearConfig::new(2, 3).init_with(record.fc1);
+        let fc2 = LinearConfig::new(3, 4).init_with(record.fc2);
+
+        Self { fc1, fc2 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use burn::record::{FullPrecisionSettings, HalfPrecisionSettings, Recorder};
+    use burn::tensor::Tensor;
+
+    use burn_import::safetensors::{AdapterType, SafetensorsFileRecorder};
+
+    use super::*;
+
+    fn linear_test(record: NetRecord<Backend>) {
+        let device = Default::default();
+        let model = Net::<Backend>::new_with(record);
+
+        let fc1_weight =
+            Tensor::<Backend, 2>::from_data([[0.5, -1.0, 0.25], [2.0, 0.0, -0.75]], &device);
+        let fc1_bias = Tensor::<Backend, 1>::from_data([0.125, -0.5, 1.5], &device);
+        let fc2_weight = Tensor::<Backend, 2>::from_data(
+            [
+                [1.0, 0.5, -0.5, 0.0],
+                [-2.0, 0.25, 1.0, 0.75],
+                [0.0, -1.5, 2.0, 1.0],
+            ],
+            &device,
+        );
+
+        assert_eq!(model.fc1.weight.to_data(), fc1_weight.to_data());
+        assert_eq!(model.fc1.bias.unwrap().to_data(), fc1_bias.to_data());
+        assert_eq!(model.fc2.weight.to_data(), fc2_weight.to_data());
+        assert!(model.fc2.bias.is_none());
+    }
+
+    #[test]
+    fn linear_full_precision() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .load("tests/linear/linear.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record);
+    }
+
+    #[test]
+    fn linear_half_precision() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<HalfPrecisionSettings>::default()
+            .load("tests/linear/linear.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record);
+    }
+
+    #[test]
+    fn linear_pytorch_layout() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .with_adapter(AdapterType::PyTorch)
+            .load("tests/linear/linear_pytorch.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+
+        linear_test(record);
+    }
+
+    #[test]
+    fn save_with_pytorch_adapter() {
+        let device = Default::default();
+        let record = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .load("tests/linear/linear.safetensors".into(), &device)
+            .expect("Should decode state successfully");
+        let model = Net::<Backend>::new_with(record);
+        let directory = std::env::temp_dir().join("safetensors-tests-pytorch-adapter");
+
+        let result = SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+            .with_adapter(AdapterType::PyTorch)
+            .record(model.into_record(), directory.join("linear"));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn missing_file() {
+        let device = Default::default();
+        let result: Result<NetRecord<Backend>, _> =
+            SafetensorsFileRecorder::<FullPrecisionSettings>::default()
+                .load("tests/linear/missing.safetensors".into(), &device);
+
+        assert!(result.is_err());
+    }
+}
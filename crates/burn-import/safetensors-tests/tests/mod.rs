@@ -0,0 +1,2 @@
+mod linear;
+mod round_trip;
@@ -0,0 +1,127 @@
+use burn::{
+    module::Module,
+    nn::{
+        conv::{Conv2d, Conv2dConfig},
+        BatchNorm, BatchNormConfig, Linear, LinearConfig,
+    },
+    tensor::{backend::Backend, Tensor},
+};
+
+#[derive(Module, Debug)]
+pub struct ConvBlock<B: Backend> {
+    conv: Conv2d<B>,
+    norm: BatchNorm<B, 2>,
+}
+
+#[derive(Module, Debug)]
+pub struct Net<B: Backend> {
+    blocks: Vec<ConvBlock<B>>,
+    fc: Linear<B>,
+}
+
+impl<B: Backend> Net<B> {
+    pub fn new(device: &B::Device) -> Self {
+        let block = |channels: [usize; 2]| ConvBlock {
+            conv: Conv2dConfig::new(channels, [3, 3]).init(device),
+            norm: BatchNormConfig::new(channels[1]).init(device),
+        };
+
+        Self {
+            blocks: vec![block([2, 4]), block([4, 3])],
+            fc: LinearConfig::new(4, 2).init(device),
+        }
+    }
+
+    /// Forward pass of the model.
+    pub fn forward(&self, x: Tensor<B, 4>) -> Tensor<B, 4> {
+        let x = self.blocks.iter().fold(x, |x, block| {
+            let x = block.conv.forward(x);
+            block.norm.forward(x)
+        });
+
+        self.fc.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    type Backend = burn_ndarray::NdArray<f32>;
+
+    use std::path::PathBuf;
+
+    use burn::record::{FullPrecisionSettings, HalfPrecisionSettings, Recorder};
+    use burn::tensor::Distribution;
+
+    use burn_import::safetensors::SafetensorsFileRecorder;
+
+    use super::*;
+
+    fn file_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .as_path()
+            .join(format!("burn_test_safetensors_{name}"))
+    }
+
+    /// The JSON header of a safetensors file.
+    fn header(file: &PathBuf) -> String {
+        let bytes = std::fs::read(file.with_extension("safetensors")).unwrap();
+        let len = u64::from_le_bytes(bytes[..8].try_into().unwrap()) as usize;
+
+        String::from_utf8(bytes[8..8 + len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn round_trip_full_precision() {
+        let device = Default::default();
+        let recorder = SafetensorsFileRecorder::<FullPrecisionSettings>::default();
+        let file = file_path("full");
+
+        let model = Net::<Backend>::new(&device);
+        recorder
+            .record(model.clone().into_record(), file.clone())
+            .expect("Should encode state successfully");
+        let loaded =
+            Net::<Backend>::new(&device).load_record(recorder.load(file.clone(), &device).unwrap());
+
+        let input = Tensor::<Backend, 4>::random([1, 2, 8, 8], Distribution::Default, &device);
+        model
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&loaded.forward(input).into_data(), 7);
+
+        let header = header(&file);
+        for name in [
+            "\"blocks.0.conv.weight\"",
+            "\"blocks.1.norm.running_mean\"",
+            "\"fc.weight\"",
+            "\"fc.bias\"",
+        ] {
+            assert!(header.contains(name), "{name} not found in {header}");
+        }
+        assert!(header.contains("\"F32\""));
+    }
+
+    #[test]
+    fn round_trip_half_precision() {
+        let device = Default::default();
+        let recorder = SafetensorsFileRecorder::<HalfPrecisionSettings>::default();
+        let file = file_path("half");
+
+        let model = Net::<Backend>::new(&device);
+        recorder
+            .record(model.clone().into_record(), file.clone())
+            .expect("Should encode state successfully");
+        let loaded =
+            Net::<Backend>::new(&device).load_record(recorder.load(file.clone(), &device).unwrap());
+
+        let input = Tensor::<Backend, 4>::random([1, 2, 8, 8], Distribution::Default, &device);
+        model
+            .forward(input.clone())
+            .into_data()
+            .assert_approx_eq(&loaded.forward(input).into_data(), 2);
+
+        let header = header(&file);
+        assert!(header.contains("\"F16\""));
+        assert!(!header.contains("\"F32\""));
+    }
+}
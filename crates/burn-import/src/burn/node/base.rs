@@ -3,8 +3,9 @@ use super::{
     avg_pool2d::AvgPool2dNode, batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode,
     concat::ConcatNode, constant::ConstantNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
     conv_transpose_2d::ConvTranspose2dNode, dropout::DropoutNode, gather::GatherNode,
-    global_avg_pool::GlobalAvgPoolNode, linear::LinearNode, matmul::MatmulNode,
-    max_pool2d::MaxPool2dNode, reshape::ReshapeNode, unary::UnaryNode,
+    global_avg_pool::GlobalAvgPoolNode, linear::LinearNode, lstm::LstmNode, matmul::MatmulNode,
+    max_pool2d::MaxPool2dNode, reshape::ReshapeNode, resize::ResizeNode, scatter::ScatterNode,
+    select::SelectNode, slice::SliceNode, unary::UnaryNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::backend::NdArray;
@@ -87,9 +88,14 @@ pub enum Node<PS: PrecisionSettings> {
     Gather(GatherNode),
     GlobalAvgPool(GlobalAvgPoolNode),
     Linear(LinearNode<PS>),
+    Lstm(LstmNode<PS>),
     Matmul(MatmulNode),
     MaxPool2d(MaxPool2dNode),
     Reshape(ReshapeNode),
+    Resize(ResizeNode),
+    Scatter(ScatterNode),
+    Select(SelectNode),
+    Slice(SliceNode),
     Unary(UnaryNode),
     Unsqueeze(UnsqueezeNode),
 }
@@ -111,9 +117,14 @@ macro_rules! match_all {
             Node::Gather(node) => $func(node),
             Node::GlobalAvgPool(node) => $func(node),
             Node::Linear(node) => $func(node),
+            Node::Lstm(node) => $func(node),
             Node::Matmul(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
             Node::Reshape(node) => $func(node),
+            Node::Resize(node) => $func(node),
+            Node::Scatter(node) => $func(node),
+            Node::Select(node) => $func(node),
+            Node::Slice(node) => $func(node),
             Node::Unary(node) => $func(node),
             Node::Unsqueeze(node) => $func(node),
         }
@@ -145,9 +156,14 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Gather(_) => "gather",
             Node::GlobalAvgPool(_) => "global_avg_pool",
             Node::Linear(_) => "linear",
+            Node::Lstm(_) => "lstm",
             Node::Matmul(_) => "matmul",
             Node::MaxPool2d(_) => "max_pool2d",
             Node::Reshape(_) => "reshape",
+            Node::Resize(_) => "resize",
+            Node::Scatter(_) => "scatter",
+            Node::Select(_) => "select",
+            Node::Slice(_) => "slice",
             Node::Unary(unary) => unary.kind.as_str(),
             Node::Unsqueeze(_) => "unsqueeze",
         }
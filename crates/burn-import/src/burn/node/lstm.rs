@@ -0,0 +1,398 @@
+use super::{Node, NodeCodegen, SerializationBackend};
+use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
+use burn::{
+    module::{Module, Param, ParamId},
+    nn::{GateControllerRecord, LinearRecord, LstmConfig},
+    record::{PrecisionSettings, Record},
+    tensor::{DataSerialize, Element, Tensor},
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde::Serialize;
+
+/// The weights of a gate, with the burn layout `[d_input, d_output]`.
+#[derive(Debug, Clone)]
+pub struct LstmGateData<E: Element> {
+    pub input_weight: DataSerialize<E>,
+    pub hidden_weight: DataSerialize<E>,
+    pub input_bias: Option<DataSerialize<E>>,
+    pub hidden_bias: Option<DataSerialize<E>>,
+}
+
+/// The gates of a single direction of the lstm.
+#[derive(Debug, Clone)]
+pub struct LstmCellData<E: Element> {
+    pub input_gate: LstmGateData<E>,
+    pub forget_gate: LstmGateData<E>,
+    pub output_gate: LstmGateData<E>,
+    pub cell_gate: LstmGateData<E>,
+}
+
+/// A single layer lstm, processing the sequence in the forward or reverse order, or in both.
+///
+/// The inputs and outputs follow the ONNX layout: the sequence comes first unless `batch_first`,
+/// and the hidden states of each direction are kept on their own dimension.
+#[derive(Debug, Clone)]
+pub struct LstmNode<PS: PrecisionSettings> {
+    pub field: OtherType,
+    pub input: TensorType,
+    pub initial_hidden: Option<TensorType>,
+    pub initial_cell: Option<TensorType>,
+    pub output: Option<TensorType>,
+    pub output_hidden: Option<TensorType>,
+    pub output_cell: Option<TensorType>,
+    pub cells: Vec<LstmCellData<PS::FloatElem>>,
+    pub config: LstmConfig,
+    pub reverse: bool,
+    pub batch_first: bool,
+}
+
+impl<PS: PrecisionSettings> LstmNode<PS> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<S: AsRef<str>>(
+        name: S,
+        input: TensorType,
+        initial_hidden: Option<TensorType>,
+        initial_cell: Option<TensorType>,
+        output: Option<TensorType>,
+        output_hidden: Option<TensorType>,
+        output_cell: Option<TensorType>,
+        cells: Vec<LstmCellData<PS::FloatElem>>,
+        config: LstmConfig,
+        reverse: bool,
+        batch_first: bool,
+    ) -> Self {
+        Self {
+            field: OtherType::new(
+                name,
+                quote! {
+                    Lstm<B>
+                },
+            ),
+            input,
+            initial_hidden,
+            initial_cell,
+            output,
+            output_hidden,
+            output_cell,
+            cells,
+            config,
+            reverse,
+            batch_first,
+        }
+    }
+
+    fn num_directions(&self) -> usize {
+        match self.config.bidirectional {
+            true => 2,
+            false => 1,
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for LstmNode<PS> {
+    fn input_types(&self) -> Vec<Type> {
+        [
+            Some(&self.input),
+            self.initial_hidden.as_ref(),
+            self.initial_cell.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|tensor| Type::Tensor(tensor.clone()))
+        .collect()
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        [&self.output, &self.output_hidden, &self.output_cell]
+            .into_iter()
+            .flatten()
+            .map(|tensor| Type::Tensor(tensor.clone()))
+            .collect()
+    }
+
+    fn field_type(&self) -> Option<Type> {
+        Some(Type::Other(self.field.clone()))
+    }
+
+    fn field_init(&self, with_record: bool) -> Option<TokenStream> {
+        let name = &self.field.name;
+        let d_input = self.config.d_input.to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+        let bias = self.config.bias;
+        let bidirectional = self.config.bidirectional;
+
+        let init_line = match with_record {
+            true => quote! {
+                init_with(record.#name);
+            },
+            false => quote! {
+                init(device);
+            },
+        };
+
+        let tokens = quote! {
+            let #name = LstmConfig::new(#d_input, #d_hidden, #bias)
+                .with_bidirectional(#bidirectional)
+                .#init_line
+        };
+
+        Some(tokens)
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let device = Default::default();
+        let linear = |weight: &DataSerialize<PS::FloatElem>,
+                      bias: &Option<DataSerialize<PS::FloatElem>>| {
+            LinearRecord::<SerializationBackend> {
+                weight: Param::new(
+                    ParamId::new(),
+                    Tensor::from_data(weight.clone().convert(), &device),
+                ),
+                bias: bias.as_ref().map(|bias| {
+                    Param::new(
+                        ParamId::new(),
+                        Tensor::from_data(bias.clone().convert(), &device),
+                    )
+                }),
+            }
+        };
+        let gate = |data: &LstmGateData<PS::FloatElem>| GateControllerRecord {
+            input_transform: linear(&data.input_weight, &data.input_bias),
+            hidden_transform: linear(&data.hidden_weight, &data.hidden_bias),
+        };
+
+        // The constants of the record are the ones of a module initialized with the same config.
+        let mut record = self
+            .config
+            .init::<SerializationBackend>(&device)
+            .into_record();
+        // The gates of the first cell are fields of the lstm, the others are in its cells.
+        let (first, others) = self
+            .cells
+            .split_first()
+            .expect("The lstm should have a cell");
+        record.input_gate = gate(&first.input_gate);
+        record.forget_gate = gate(&first.forget_gate);
+        record.output_gate = gate(&first.output_gate);
+        record.cell_gate = gate(&first.cell_gate);
+        for (cell, data) in record.cells.iter_mut().flatten().zip(others) {
+            cell.input_gate = gate(&data.input_gate);
+            cell.forget_gate = gate(&data.forget_gate);
+            cell.output_gate = gate(&data.output_gate);
+            cell.cell_gate = gate(&data.cell_gate);
+        }
+
+        let item = Record::into_item::<PS>(record);
+        item.serialize(serializer)
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let field = &self.field.name;
+        let num_directions = self.num_directions().to_tokens();
+        let d_hidden = self.config.d_hidden.to_tokens();
+
+        // The lstm module takes the batch first, and its states have the directions first.
+        let mut input = match self.batch_first {
+            true => quote! { #input },
+            false => quote! { #input.swap_dims(0, 1) },
+        };
+        if self.reverse {
+            input = quote! { #input.flip([1]) };
+        }
+
+        let mut state = |tensor: &Option<TensorType>| {
+            tensor.as_ref().map(|tensor| {
+                let tensor = scope.tensor_use_owned(tensor, node_position);
+                match self.batch_first {
+                    true => quote! { #tensor.swap_dims(0, 1) },
+                    false => quote! { #tensor },
+                }
+            })
+        };
+        let hidden = state(&self.initial_hidden);
+        let cell = state(&self.initial_cell);
+        let state = match (cell, hidden) {
+            (Some(cell), Some(hidden)) => quote! { Some(LstmState::new(#cell, #hidden)) },
+            (Some(cell), None) => quote! {
+                {
+                    let cell = #cell;
+                    let hidden = cell.zeros_like();
+                    Some(LstmState::new(cell, hidden))
+                }
+            },
+            (None, Some(hidden)) => quote! {
+                {
+                    let hidden = #hidden;
+                    let cell = hidden.zeros_like();
+                    Some(LstmState::new(cell, hidden))
+                }
+            },
+            (None, None) => quote! { None },
+        };
+
+        let mut names = Vec::new();
+        let mut values = Vec::new();
+
+        if let Some(output) = &self.output {
+            let mut value = quote! { output };
+            if self.reverse {
+                value = quote! { #value.flip([1]) };
+            }
+            value = quote! {
+                #value.reshape([batch_size, seq_length, #num_directions, #d_hidden])
+            };
+            if !self.batch_first {
+                value = quote! { #value.swap_dims(0, 1).swap_dims(1, 2) };
+            }
+
+            names.push(&output.name);
+            values.push(value);
+        }
+
+        for (output, value) in [
+            (&self.output_hidden, quote! { state.hidden }),
+            (&self.output_cell, quote! { state.cell }),
+        ] {
+            if let Some(output) = output {
+                let value = match self.batch_first {
+                    true => quote! { #value.swap_dims(0, 1) },
+                    false => value,
+                };
+
+                names.push(&output.name);
+                values.push(value);
+            }
+        }
+
+        let (outputs, values) = match names.len() {
+            1 => (quote! { #(#names)* }, quote! { #(#values)* }),
+            _ => (quote! { (#(#names),*) }, quote! { (#(#values),*) }),
+        };
+        let (output, dims) = match self.output {
+            Some(_) => (
+                quote! { output },
+                quote! { let [batch_size, seq_length, _] = output.dims(); },
+            ),
+            None => (quote! { _ }, quote! {}),
+        };
+        let final_state = match self.output_hidden.is_some() || self.output_cell.is_some() {
+            true => quote! { state },
+            false => quote! { _ },
+        };
+
+        quote! {
+            let #outputs = {
+                let (#output, #final_state) = self.#field.forward(#input, #state);
+                #dims
+
+                #values
+            };
+        }
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        imports.register("burn::nn::Lstm");
+        imports.register("burn::nn::LstmConfig");
+
+        if self.initial_hidden.is_some() || self.initial_cell.is_some() {
+            imports.register("burn::nn::LstmState");
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Lstm(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{graph::BurnGraph, node::test::assert_tokens, TensorType};
+    use burn::{record::FullPrecisionSettings, tensor::Data};
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let gate = || LstmGateData {
+            input_weight: Data::from([[1.], [2.]]).serialize(),
+            hidden_weight: Data::from([[3.]]).serialize(),
+            input_bias: None,
+            hidden_bias: None,
+        };
+
+        graph.register(LstmNode::new(
+            "lstm",
+            TensorType::new_float("input", 3),
+            None,
+            None,
+            Some(TensorType::new_float("output", 4)),
+            Some(TensorType::new_float("output_hidden", 3)),
+            None,
+            vec![LstmCellData {
+                input_gate: gate(),
+                forget_gate: gate(),
+                output_gate: gate(),
+                cell_gate: gate(),
+            }],
+            LstmConfig::new(2, 1, false),
+            true,
+            false,
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string()],
+            vec!["output".to_string(), "output_hidden".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::Lstm;
+            use burn::nn::LstmConfig;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                lstm: Lstm<B>,
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    let lstm = LstmConfig::new(2, 1, false)
+                        .with_bidirectional(false)
+                        .init_with(record.lstm);
+
+                    Self {
+                        lstm,
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 3>) -> (Tensor<B, 4>, Tensor<B, 3>) {
+                    let (output, output_hidden) = {
+                        let (output, state) = self.lstm.forward(input.swap_dims(0, 1).flip([1]), None);
+                        let [batch_size, seq_length, _] = output.dims();
+
+                        (
+                            output
+                                .flip([1])
+                                .reshape([batch_size, seq_length, 1, 1])
+                                .swap_dims(0, 1)
+                                .swap_dims(1, 2),
+                            state.hidden
+                        )
+                    };
+
+                    (output, output_hidden)
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -13,9 +13,14 @@ pub(crate) mod dropout;
 pub(crate) mod gather;
 pub(crate) mod global_avg_pool;
 pub(crate) mod linear;
+pub(crate) mod lstm;
 pub(crate) mod matmul;
 pub(crate) mod max_pool2d;
 pub(crate) mod reshape;
+pub(crate) mod resize;
+pub(crate) mod scatter;
+pub(crate) mod select;
+pub(crate) mod slice;
 pub(crate) mod unary;
 pub(crate) mod unsqueeze;
 pub(crate) use base::*;
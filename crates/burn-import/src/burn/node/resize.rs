@@ -0,0 +1,157 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use burn::tensor::ops::{InterpolateMode, InterpolateOptions};
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// The size of the output of a resize, along the height and width dimensions.
+#[derive(Debug, Clone)]
+pub enum ResizeSize {
+    /// The input size is multiplied by the scales, rounding down.
+    Scales([f64; 2]),
+    /// The output has a fixed size.
+    Sizes([usize; 2]),
+}
+
+/// Resize the images of the input, as the ONNX `Resize` operator.
+#[derive(Debug, Clone, new)]
+pub struct ResizeNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub size: ResizeSize,
+    pub options: InterpolateOptions,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        let mode = match self.options.mode {
+            InterpolateMode::Nearest => quote! { burn::tensor::ops::InterpolateMode::Nearest },
+            InterpolateMode::Bilinear => quote! { burn::tensor::ops::InterpolateMode::Bilinear },
+            InterpolateMode::Bicubic => quote! { burn::tensor::ops::InterpolateMode::Bicubic },
+        };
+        let align_corners = self.options.align_corners;
+        let options = quote! {
+            burn::tensor::ops::InterpolateOptions {
+                mode: #mode,
+                align_corners: #align_corners,
+            }
+        };
+
+        match &self.size {
+            ResizeSize::Scales([scale_height, scale_width]) => {
+                // Keep the decimal point, so the literals are floats even for integer scales.
+                let scale_height = Literal::f64_unsuffixed(*scale_height);
+                let scale_width = Literal::f64_unsuffixed(*scale_width);
+
+                quote! {
+                    let #output = {
+                        let [_, _, height, width] = #input_name.dims();
+                        let output_size = [
+                            (height as f64 * #scale_height) as usize,
+                            (width as f64 * #scale_width) as usize,
+                        ];
+                        burn::tensor::module::interpolate(#input, output_size, #options)
+                    };
+                }
+            }
+            ResizeSize::Sizes(sizes) => {
+                let sizes = sizes.to_tokens();
+
+                quote! {
+                    let #output = burn::tensor::module::interpolate(#input, #sizes, #options);
+                }
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Resize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{resize::ResizeNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_resize() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ResizeNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            ResizeSize::Scales([2.0, 2.0]),
+            InterpolateOptions {
+                mode: InterpolateMode::Bilinear,
+                align_corners: false,
+            },
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let [_, _, height, width] = tensor1.dims();
+                        let output_size = [
+                            (height as f64 * 2.0) as usize,
+                            (width as f64 * 2.0) as usize,
+                        ];
+                        burn::tensor::module::interpolate(
+                            tensor1,
+                            output_size,
+                            burn::tensor::ops::InterpolateOptions {
+                                mode: burn::tensor::ops::InterpolateMode::Bilinear,
+                                align_corners: false,
+                            }
+                        )
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,140 @@
+use super::{select::normalize_indices, Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use burn::tensor::ScatterReduction;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Scatter the updates into the input along a dimension, as the ONNX `ScatterElements` operator.
+#[derive(Debug, Clone, new)]
+pub struct ScatterNode {
+    pub input: TensorType,
+    pub index: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub dim: usize,
+    pub reduction: ScatterReduction,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.index.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let dim = self.dim.to_tokens();
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let index = scope.tensor_use_owned(&self.index, node_position);
+        let updates = scope.tensor_use_owned(&self.updates, node_position);
+        let output = &self.output.name;
+        let indices = normalize_indices(&self.input.name, index, &dim);
+        let reduction = match self.reduction {
+            ScatterReduction::Add => quote! { burn::tensor::ScatterReduction::Add },
+            ScatterReduction::Multiply => quote! { burn::tensor::ScatterReduction::Multiply },
+            ScatterReduction::Overwrite => quote! { burn::tensor::ScatterReduction::Overwrite },
+        };
+
+        quote! {
+            let #output = {
+                #indices
+                #input.scatter_reduce(#dim, indices, #updates, #reduction)
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Scatter(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{scatter::ScatterNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_scatter() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ScatterNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_int("tensor2", 2),
+            TensorType::new_float("tensor3", 2),
+            TensorType::new_float("tensor4", 2),
+            0,
+            ScatterReduction::Overwrite,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+            ],
+            vec!["tensor4".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2, Int>,
+                    tensor3: Tensor<B, 2>
+                ) -> Tensor<B, 2> {
+                    let tensor4 = {
+                        let indices = tensor2;
+                        let dim_size = tensor1.dims()[0] as i64;
+                        let indices = indices
+                            .clone()
+                            .mask_where(indices.clone().lower_elem(0), indices + dim_size);
+                        tensor1.scatter_reduce(
+                            0,
+                            indices,
+                            tensor3,
+                            burn::tensor::ScatterReduction::Overwrite
+                        )
+                    };
+
+                    tensor4
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
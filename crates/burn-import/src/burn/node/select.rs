@@ -0,0 +1,133 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+/// Select the slices of the input along a dimension, as the ONNX `Gather` operator with an index
+/// vector.
+#[derive(Debug, Clone, new)]
+pub struct SelectNode {
+    pub input: TensorType,
+    pub index: TensorType,
+    pub output: TensorType,
+    pub dim: usize,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SelectNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.index.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let dim = self.dim.to_tokens();
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let index = scope.tensor_use_owned(&self.index, node_position);
+        let output = &self.output.name;
+        let indices = normalize_indices(&self.input.name, index, &dim);
+
+        quote! {
+            let #output = {
+                #indices
+                #input.select(#dim, indices)
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Select(self)
+    }
+}
+
+/// Declare the `indices` variable, with the negative indices counted from the end of the
+/// dimension `dim` of the input as allowed by ONNX.
+pub(crate) fn normalize_indices(
+    input: &Ident,
+    index: TokenStream,
+    dim: &TokenStream,
+) -> TokenStream {
+    quote! {
+        let indices = #index;
+        let dim_size = #input.dims()[#dim] as i64;
+        let indices = indices.clone().mask_where(indices.clone().lower_elem(0), indices + dim_size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{select::SelectNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_select() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SelectNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_int("tensor2", 1),
+            TensorType::new_float("tensor3", 2),
+            1,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 1, Int>
+                ) -> Tensor<B, 2> {
+                    let tensor3 = {
+                        let indices = tensor2;
+                        let dim_size = tensor1.dims()[1] as i64;
+                        let indices = indices
+                            .clone()
+                            .mask_where(indices.clone().lower_elem(0), indices + dim_size);
+                        tensor1.select(1, indices)
+                    };
+
+                    tensor3
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,190 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The slice of a single axis, with the ONNX semantic: the negative indices are counted from the
+/// end of the axis, the indices are clamped to the axis and a negative step reverses the order.
+#[derive(Debug, Clone, new)]
+pub struct SliceAxis {
+    pub axis: usize,
+    pub start: i64,
+    pub end: i64,
+    pub step: i64,
+}
+
+/// Slice the input along some axes, as the ONNX `Slice` operator.
+#[derive(Debug, Clone, new)]
+pub struct SliceNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub axes: Vec<SliceAxis>,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for SliceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let axes = self.axes.iter().map(slice_axis);
+
+        quote! {
+            let #output = {
+                let tensor = #input;
+                #(#axes)*
+                tensor
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Slice(self)
+    }
+}
+
+/// Slice the `tensor` variable along an axis, where `start..end` is the range kept before the
+/// reversal and the step.
+fn slice_axis(slice: &SliceAxis) -> TokenStream {
+    let axis = slice.axis.to_tokens();
+    let index = |value: i64| match value {
+        // The indices can't be lower than -i64::MAX, so they can be negated.
+        value if value < 0 => {
+            let value = value.max(-i64::MAX).abs().to_tokens();
+            quote! { dim_size - #value }
+        }
+        value => value.to_tokens(),
+    };
+    let start = index(slice.start);
+    let end = index(slice.end);
+
+    // For a negative step, the elements are taken from the start down to the end exclusive.
+    let (range, reverse) = match slice.step > 0 {
+        true => (
+            quote! {
+                let start = i64::clamp(#start, 0, dim_size) as usize;
+                let end = i64::clamp(#end, 0, dim_size) as usize;
+            },
+            quote! {},
+        ),
+        false => (
+            quote! {
+                let (start, end) = (
+                    (i64::clamp(#end, -1, dim_size - 1) + 1) as usize,
+                    (i64::clamp(#start, 0, dim_size - 1) + 1) as usize,
+                );
+            },
+            quote! { let tensor = tensor.flip([#axis]); },
+        ),
+    };
+    let ranges = (0..slice.axis).map(|dim| {
+        let dim = dim.to_tokens();
+        quote! { 0..dims[#dim] }
+    });
+    let step = match slice.step.unsigned_abs() as usize {
+        1 => quote! {},
+        step => {
+            let step = step.to_tokens();
+            quote! {
+                let indices = Tensor::arange_step(0..(end - start) as i64, #step, &tensor.device());
+                let tensor = tensor.select(#axis, indices);
+            }
+        }
+    };
+
+    quote! {
+        let dims = tensor.dims();
+        let dim_size = dims[#axis] as i64;
+        #range
+        let tensor = tensor.slice([#(#ranges,)* start..end]);
+        #reverse
+        #step
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{slice::SliceNode, test::assert_tokens},
+        TensorType,
+    };
+
+    #[test]
+    fn test_codegen_slice() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(SliceNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            vec![
+                SliceAxis::new(0, 1, i64::MAX, 2),
+                SliceAxis::new(1, -1, i64::MIN, -1),
+            ],
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new_with(record: ModelRecord<B>) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let tensor = tensor1;
+
+                        let dims = tensor.dims();
+                        let dim_size = dims[0] as i64;
+                        let start = i64::clamp(1, 0, dim_size) as usize;
+                        let end = i64::clamp(9223372036854775807, 0, dim_size) as usize;
+                        let tensor = tensor.slice([start..end]);
+                        let indices = Tensor::arange_step(0..(end - start) as i64, 2, &tensor.device());
+                        let tensor = tensor.select(0, indices);
+
+                        let dims = tensor.dims();
+                        let dim_size = dims[1] as i64;
+                        let (start, end) = (
+                            (i64::clamp(dim_size - 9223372036854775807, -1, dim_size - 1) + 1) as usize,
+                            (i64::clamp(dim_size - 1, 0, dim_size - 1) + 1) as usize,
+                        );
+                        let tensor = tensor.slice([0..dims[0], start..end]);
+                        let tensor = tensor.flip([1]);
+
+                        tensor
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
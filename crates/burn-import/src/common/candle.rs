@@ -0,0 +1,90 @@
+use core::ops::Deref;
+
+use burn::{
+    module::ParamId,
+    record::{
+        serde::{
+            data::{NestedValue, Serializable},
+            error,
+            ser::Serializer,
+        },
+        ParamSerde, PrecisionSettings,
+    },
+    tensor::{DataSerialize, Element, ElementConversion},
+};
+
+use candle_core::WithDType;
+use half::{bf16, f16};
+use serde::Serialize;
+
+/// Serializes a candle tensor.
+///
+/// Tensors are wrapped in a `Param` struct (learnable parameters) and serialized as a `DataSerialize` struct.
+///
+/// Values are serialized as `FloatElem` or `IntElem` depending on the precision settings.
+impl Serializable for CandleTensor {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let shape = self.shape().clone().into_dims();
+        let flatten = CandleTensor(self.flatten_all().expect("Failed to flatten the tensor"));
+        let param_id = ParamId::new().into_string();
+
+        match self.dtype() {
+            candle_core::DType::U8 => {
+                serialize_data::<u8, PS::IntElem>(flatten, shape, param_id, serializer)
+            }
+            candle_core::DType::U32 => {
+                serialize_data::<u32, PS::IntElem>(flatten, shape, param_id, serializer)
+            }
+            candle_core::DType::I64 => {
+                serialize_data::<i64, PS::IntElem>(flatten, shape, param_id, serializer)
+            }
+            candle_core::DType::BF16 => {
+                serialize_data::<bf16, PS::FloatElem>(flatten, shape, param_id, serializer)
+            }
+            candle_core::DType::F16 => {
+                serialize_data::<f16, PS::FloatElem>(flatten, shape, param_id, serializer)
+            }
+            candle_core::DType::F32 => {
+                serialize_data::<f32, PS::FloatElem>(flatten, shape, param_id, serializer)
+            }
+            candle_core::DType::F64 => {
+                serialize_data::<f64, PS::FloatElem>(flatten, shape, param_id, serializer)
+            }
+        }
+    }
+}
+
+/// Helper function to serialize a candle tensor data.
+fn serialize_data<T, E>(
+    tensor: CandleTensor,
+    shape: Vec<usize>,
+    param_id: String,
+    serializer: Serializer,
+) -> Result<NestedValue, error::Error>
+where
+    E: Element + Serialize,
+    T: WithDType + ElementConversion,
+{
+    let data: Vec<E> = tensor
+        .to_vec1::<T>()
+        .map_err(|err| error::Error::Other(format!("Candle to vec1 error: {err}")))?
+        .into_iter()
+        .map(ElementConversion::elem)
+        .collect();
+
+    ParamSerde::new(param_id, DataSerialize::new(data, shape)).serialize(serializer)
+}
+
+/// New type struct for Candle tensors because we need to implement the `Serializable` trait for it.
+pub(crate) struct CandleTensor(pub(crate) candle_core::Tensor);
+
+impl Deref for CandleTensor {
+    type Target = candle_core::Tensor;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
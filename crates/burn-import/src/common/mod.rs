@@ -0,0 +1,4 @@
+#[cfg(any(feature = "pytorch", feature = "safetensors", feature = "gguf"))]
+pub(crate) mod adapter;
+#[cfg(any(feature = "pytorch", feature = "safetensors"))]
+pub(crate) mod candle;
@@ -0,0 +1,121 @@
+use half::f16;
+
+use super::{Error, GgufTensorType};
+
+/// Number of elements in a block of the `Q4_0` and `Q8_0` formats.
+pub(crate) const BLOCK_SIZE: usize = 32;
+
+/// Size in bytes of a block of the `Q4_0` format: the `f16` scale and 32 packed 4-bit values.
+const BLOCK_SIZE_Q4_0: usize = 2 + BLOCK_SIZE / 2;
+
+/// Size in bytes of a block of the `Q8_0` format: the `f16` scale and 32 8-bit values.
+const BLOCK_SIZE_Q8_0: usize = 2 + BLOCK_SIZE;
+
+/// The size in bytes of a tensor with the given type and number of elements.
+pub(crate) fn size_in_bytes(tensor_type: GgufTensorType, num_elements: usize) -> Option<usize> {
+    match tensor_type {
+        GgufTensorType::F32 => Some(num_elements * 4),
+        GgufTensorType::F16 => Some(num_elements * 2),
+        GgufTensorType::Q4_0 => Some(num_elements / BLOCK_SIZE * BLOCK_SIZE_Q4_0),
+        GgufTensorType::Q8_0 => Some(num_elements / BLOCK_SIZE * BLOCK_SIZE_Q8_0),
+        GgufTensorType::Other(_) => None,
+    }
+}
+
+/// Converts the data of a tensor to `f32` values.
+pub(crate) fn dequantize(tensor_type: GgufTensorType, data: &[u8]) -> Result<Vec<f32>, Error> {
+    let values = match tensor_type {
+        GgufTensorType::F32 => data
+            .chunks_exact(4)
+            .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect(),
+        GgufTensorType::F16 => data.chunks_exact(2).map(read_f16).collect(),
+        GgufTensorType::Q4_0 => data
+            .chunks_exact(BLOCK_SIZE_Q4_0)
+            .flat_map(dequantize_block_q4_0)
+            .collect(),
+        GgufTensorType::Q8_0 => data
+            .chunks_exact(BLOCK_SIZE_Q8_0)
+            .flat_map(dequantize_block_q8_0)
+            .collect(),
+        GgufTensorType::Other(_) => return Err(Error::UnsupportedTensorType(tensor_type)),
+    };
+
+    Ok(values)
+}
+
+fn read_f16(bytes: &[u8]) -> f32 {
+    f16::from_bits(u16::from_le_bytes([bytes[0], bytes[1]])).to_f32()
+}
+
+/// The low nibbles hold the first 16 values of the block and the high nibbles the last 16, each
+/// value being offset by 8.
+fn dequantize_block_q4_0(block: &[u8]) -> [f32; BLOCK_SIZE] {
+    let scale = read_f16(&block[..2]);
+    let mut values = [0.0; BLOCK_SIZE];
+
+    for (i, byte) in block[2..].iter().enumerate() {
+        values[i] = ((byte & 0x0F) as i32 - 8) as f32 * scale;
+        values[i + BLOCK_SIZE / 2] = ((byte >> 4) as i32 - 8) as f32 * scale;
+    }
+
+    values
+}
+
+fn dequantize_block_q8_0(block: &[u8]) -> [f32; BLOCK_SIZE] {
+    let scale = read_f16(&block[..2]);
+    let mut values = [0.0; BLOCK_SIZE];
+
+    for (value, byte) in values.iter_mut().zip(&block[2..]) {
+        *value = *byte as i8 as f32 * scale;
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dequantize_q4_0() {
+        // Scale 0.5, the value j being in the low nibble of the byte j and the value j + 16 in
+        // its high nibble.
+        let mut block = f16::from_f32(0.5).to_bits().to_le_bytes().to_vec();
+        block.extend((0..16u8).map(|j| j | ((15 - j) << 4)));
+
+        let values = dequantize(GgufTensorType::Q4_0, &block).unwrap();
+
+        assert_eq!(values.len(), 32);
+        assert_eq!(values[0], -4.0);
+        assert_eq!(values[8], 0.0);
+        assert_eq!(values[15], 3.5);
+        assert_eq!(values[16], 3.5);
+        assert_eq!(values[31], -4.0);
+    }
+
+    #[test]
+    fn test_dequantize_q8_0() {
+        let mut data = Vec::new();
+        for scale in [0.25, 2.0] {
+            data.extend(f16::from_f32(scale).to_bits().to_le_bytes());
+            data.extend((0..32).map(|i| (i as i8 - 16) as u8));
+        }
+
+        let values = dequantize(GgufTensorType::Q8_0, &data).unwrap();
+
+        assert_eq!(values.len(), 64);
+        assert_eq!(values[0], -4.0);
+        assert_eq!(values[31], 3.75);
+        assert_eq!(values[32], -32.0);
+        assert_eq!(values[63], 30.0);
+    }
+
+    #[test]
+    fn test_unsupported_type() {
+        assert!(matches!(
+            dequantize(GgufTensorType::Other(3), &[0; 20]),
+            Err(Error::UnsupportedTensorType(GgufTensorType::Other(3)))
+        ));
+    }
+}
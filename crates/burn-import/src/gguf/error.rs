@@ -0,0 +1,32 @@
+use burn::record::{serde::error, RecorderError};
+
+/// Error that can occur when reading a GGUF file.
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    /// Serde error.
+    #[error("Serde error: {0}")]
+    Serde(#[from] error::Error),
+
+    /// IO error.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The file isn't a valid GGUF file.
+    #[error("Invalid GGUF file: {0}")]
+    InvalidFile(String),
+
+    /// The tensor type can't be dequantized.
+    #[error("Unsupported tensor type: {0:?}")]
+    UnsupportedTensorType(super::GgufTensorType),
+
+    /// Other error.
+    #[error("other error: {0}")]
+    Other(String),
+}
+
+// Implement From trait for Error to RecorderError
+impl From<Error> for RecorderError {
+    fn from(error: Error) -> Self {
+        RecorderError::DeserializeError(error.to_string())
+    }
+}
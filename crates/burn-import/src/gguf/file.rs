@@ -0,0 +1,510 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use super::dequantize::{dequantize, size_in_bytes, BLOCK_SIZE};
+use super::Error;
+
+/// The magic number starting GGUF files, `GGUF` in little endian.
+const MAGIC: u32 = 0x46554747;
+
+/// The alignment of the tensor data when not specified in the metadata.
+const DEFAULT_ALIGNMENT: u64 = 32;
+
+/// A metadata value of a GGUF file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GgufValue {
+    /// An unsigned 8-bit integer.
+    U8(u8),
+    /// A signed 8-bit integer.
+    I8(i8),
+    /// An unsigned 16-bit integer.
+    U16(u16),
+    /// A signed 16-bit integer.
+    I16(i16),
+    /// An unsigned 32-bit integer.
+    U32(u32),
+    /// A signed 32-bit integer.
+    I32(i32),
+    /// An unsigned 64-bit integer.
+    U64(u64),
+    /// A signed 64-bit integer.
+    I64(i64),
+    /// A 32-bit float.
+    F32(f32),
+    /// A 64-bit float.
+    F64(f64),
+    /// A boolean.
+    Bool(bool),
+    /// A UTF-8 string.
+    String(String),
+    /// An array of values of the same type.
+    Array(Vec<GgufValue>),
+}
+
+impl GgufValue {
+    /// Get the value as an unsigned integer, if it is any integer that isn't negative.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            GgufValue::U8(value) => Some(*value as u64),
+            GgufValue::U16(value) => Some(*value as u64),
+            GgufValue::U32(value) => Some(*value as u64),
+            GgufValue::U64(value) => Some(*value),
+            GgufValue::I8(value) => u64::try_from(*value).ok(),
+            GgufValue::I16(value) => u64::try_from(*value).ok(),
+            GgufValue::I32(value) => u64::try_from(*value).ok(),
+            GgufValue::I64(value) => u64::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a string.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            GgufValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// The type of the elements of a tensor, with the block formats used by quantized tensors.
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GgufTensorType {
+    /// 32-bit floats.
+    F32,
+    /// 16-bit floats.
+    F16,
+    /// Blocks of 32 4-bit values sharing a `f16` scale.
+    Q4_0,
+    /// Blocks of 32 8-bit values sharing a `f16` scale.
+    Q8_0,
+    /// Another type, identified by its ggml type id, that can't be dequantized yet.
+    Other(u32),
+}
+
+impl GgufTensorType {
+    fn from_id(id: u32) -> Self {
+        match id {
+            0 => GgufTensorType::F32,
+            1 => GgufTensorType::F16,
+            2 => GgufTensorType::Q4_0,
+            8 => GgufTensorType::Q8_0,
+            _ => GgufTensorType::Other(id),
+        }
+    }
+}
+
+/// The description of a tensor in a GGUF file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GgufTensorInfo {
+    /// The name of the tensor, e.g. `blk.0.attn_q.weight`.
+    pub name: String,
+    /// The row-major shape of the tensor.
+    ///
+    /// GGUF files list the dimensions from the innermost one, so this is the reverse of the
+    /// dimensions stored in the file.
+    pub shape: Vec<usize>,
+    /// The type of the elements.
+    pub tensor_type: GgufTensorType,
+    /// The offset of the tensor data, from the start of the data section.
+    offset: u64,
+}
+
+impl GgufTensorInfo {
+    /// The number of elements of the tensor.
+    pub fn num_elements(&self) -> usize {
+        self.shape.iter().product()
+    }
+}
+
+/// A GGUF file, the format used by llama.cpp to store models and their quantized weights.
+///
+/// Opening the file only reads its metadata and tensor table, the tensors being read and
+/// dequantized on demand with [read_tensor](GgufFile::read_tensor).
+#[derive(Debug, Clone)]
+pub struct GgufFile {
+    path: PathBuf,
+    version: u32,
+    metadata: HashMap<String, GgufValue>,
+    tensors: Vec<GgufTensorInfo>,
+    data_offset: u64,
+}
+
+impl GgufFile {
+    /// Reads the header of a GGUF file.
+    ///
+    /// Only the versions 2 and 3 of the format are supported.
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        if read_u32(&mut reader)? != MAGIC {
+            return Err(Error::InvalidFile("wrong magic number".to_string()));
+        }
+
+        let version = read_u32(&mut reader)?;
+        if !(2..=3).contains(&version) {
+            return Err(Error::InvalidFile(format!("unsupported version {version}")));
+        }
+
+        let num_tensors = read_u64(&mut reader)?;
+        let num_metadata = read_u64(&mut reader)?;
+
+        let metadata = (0..num_metadata)
+            .map(|_| {
+                let key = read_string(&mut reader)?;
+                let value_type = read_u32(&mut reader)?;
+                let value = read_value(&mut reader, value_type)?;
+
+                Ok((key, value))
+            })
+            .collect::<Result<HashMap<_, _>, Error>>()?;
+
+        let tensors = (0..num_tensors)
+            .map(|_| read_tensor_info(&mut reader))
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let alignment = match metadata.get("general.alignment") {
+            Some(value) => value
+                .as_u64()
+                .filter(|alignment| *alignment > 0)
+                .ok_or_else(|| Error::InvalidFile("invalid alignment".to_string()))?,
+            None => DEFAULT_ALIGNMENT,
+        };
+        let position = reader.stream_position()?;
+        let data_offset = position.div_ceil(alignment) * alignment;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            version,
+            metadata,
+            tensors,
+            data_offset,
+        })
+    }
+
+    /// The version of the format.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The metadata of the file, e.g. `general.architecture`.
+    pub fn metadata(&self) -> &HashMap<String, GgufValue> {
+        &self.metadata
+    }
+
+    /// The tensors of the file, in the order of the tensor table.
+    pub fn tensor_infos(&self) -> &[GgufTensorInfo] {
+        &self.tensors
+    }
+
+    /// Reads a tensor and dequantizes its values to `f32`.
+    ///
+    /// # Returns
+    ///
+    /// The values of the tensor in row-major order, with its shape.
+    pub fn read_tensor(&self, name: &str) -> Result<(Vec<f32>, Vec<usize>), Error> {
+        let info = self
+            .tensors
+            .iter()
+            .find(|info| info.name == name)
+            .ok_or_else(|| Error::Other(format!("tensor {name} not found")))?;
+
+        let num_elements = info.num_elements();
+        if matches!(
+            info.tensor_type,
+            GgufTensorType::Q4_0 | GgufTensorType::Q8_0
+        ) && num_elements % BLOCK_SIZE != 0
+        {
+            return Err(Error::InvalidFile(format!(
+                "the tensor {name} of type {:?} doesn't have a multiple of {BLOCK_SIZE} elements",
+                info.tensor_type
+            )));
+        }
+        let size = size_in_bytes(info.tensor_type, num_elements)
+            .ok_or(Error::UnsupportedTensorType(info.tensor_type))?;
+
+        let mut file = File::open(&self.path)?;
+        file.seek(SeekFrom::Start(self.data_offset + info.offset))?;
+        let mut data = vec![0; size];
+        file.read_exact(&mut data)?;
+
+        Ok((dequantize(info.tensor_type, &data)?, info.shape.clone()))
+    }
+}
+
+fn read_tensor_info<R: Read>(reader: &mut R) -> Result<GgufTensorInfo, Error> {
+    let name = read_string(reader)?;
+    let num_dims = read_u32(reader)?;
+    let mut shape = (0..num_dims)
+        .map(|_| Ok(read_u64(reader)? as usize))
+        .collect::<Result<Vec<_>, Error>>()?;
+    shape.reverse();
+    let tensor_type = GgufTensorType::from_id(read_u32(reader)?);
+    let offset = read_u64(reader)?;
+
+    Ok(GgufTensorInfo {
+        name,
+        shape,
+        tensor_type,
+        offset,
+    })
+}
+
+fn read_value<R: Read>(reader: &mut R, value_type: u32) -> Result<GgufValue, Error> {
+    let value = match value_type {
+        0 => GgufValue::U8(read_bytes::<_, 1>(reader)?[0]),
+        1 => GgufValue::I8(i8::from_le_bytes(read_bytes(reader)?)),
+        2 => GgufValue::U16(u16::from_le_bytes(read_bytes(reader)?)),
+        3 => GgufValue::I16(i16::from_le_bytes(read_bytes(reader)?)),
+        4 => GgufValue::U32(read_u32(reader)?),
+        5 => GgufValue::I32(i32::from_le_bytes(read_bytes(reader)?)),
+        6 => GgufValue::F32(f32::from_le_bytes(read_bytes(reader)?)),
+        7 => GgufValue::Bool(read_bytes::<_, 1>(reader)?[0] != 0),
+        8 => GgufValue::String(read_string(reader)?),
+        9 => {
+            let item_type = read_u32(reader)?;
+            let len = read_u64(reader)?;
+            let items = (0..len)
+                .map(|_| read_value(reader, item_type))
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            GgufValue::Array(items)
+        }
+        10 => GgufValue::U64(read_u64(reader)?),
+        11 => GgufValue::I64(i64::from_le_bytes(read_bytes(reader)?)),
+        12 => GgufValue::F64(f64::from_le_bytes(read_bytes(reader)?)),
+        _ => {
+            return Err(Error::InvalidFile(format!(
+                "unknown metadata value type {value_type}"
+            )))
+        }
+    };
+
+    Ok(value)
+}
+
+fn read_bytes<R: Read, const N: usize>(reader: &mut R) -> Result<[u8; N], Error> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(bytes)
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32, Error> {
+    Ok(u32::from_le_bytes(read_bytes(reader)?))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> Result<u64, Error> {
+    Ok(u64::from_le_bytes(read_bytes(reader)?))
+}
+
+fn read_string<R: Read>(reader: &mut R) -> Result<String, Error> {
+    let len = read_u64(reader)? as usize;
+    let mut bytes = vec![0; len];
+    reader.read_exact(&mut bytes)?;
+
+    String::from_utf8(bytes).map_err(|err| Error::InvalidFile(err.to_string()))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use half::f16;
+
+    /// Writes hand-crafted GGUF files, the tensor data being aligned on 32 bytes.
+    #[derive(Default)]
+    pub(crate) struct GgufWriter {
+        metadata: Vec<u8>,
+        num_metadata: u64,
+        tensors: Vec<u8>,
+        num_tensors: u64,
+        data: Vec<u8>,
+    }
+
+    impl GgufWriter {
+        pub(crate) fn metadata(mut self, key: &str, value_type: u32, value: &[u8]) -> Self {
+            write_string(&mut self.metadata, key);
+            self.metadata.extend(value_type.to_le_bytes());
+            self.metadata.extend(value);
+            self.num_metadata += 1;
+            self
+        }
+
+        /// Add a tensor with its dimensions listed from the innermost one.
+        pub(crate) fn tensor(
+            mut self,
+            name: &str,
+            dims: &[u64],
+            type_id: u32,
+            data: &[u8],
+        ) -> Self {
+            write_string(&mut self.tensors, name);
+            self.tensors.extend((dims.len() as u32).to_le_bytes());
+            dims.iter()
+                .for_each(|dim| self.tensors.extend(dim.to_le_bytes()));
+            self.tensors.extend(type_id.to_le_bytes());
+            self.tensors.extend((self.data.len() as u64).to_le_bytes());
+            self.num_tensors += 1;
+
+            self.data.extend(data);
+            pad(&mut self.data);
+            self
+        }
+
+        pub(crate) fn write(self, path: &Path) {
+            let mut bytes = Vec::new();
+            bytes.extend(MAGIC.to_le_bytes());
+            bytes.extend(3u32.to_le_bytes());
+            bytes.extend(self.num_tensors.to_le_bytes());
+            bytes.extend(self.num_metadata.to_le_bytes());
+            bytes.extend(self.metadata);
+            bytes.extend(self.tensors);
+            pad(&mut bytes);
+            bytes.extend(self.data);
+
+            std::fs::write(path, bytes).unwrap();
+        }
+    }
+
+    pub(crate) fn string_value(value: &str) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_string(&mut bytes, value);
+        bytes
+    }
+
+    pub(crate) fn f32_data(values: &[f32]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    /// A `Q8_0` block with the given scale, the quantized values being `i - 16` for the index
+    /// `i` of the value.
+    pub(crate) fn q8_0_block(scale: f32) -> Vec<u8> {
+        let mut block = f16::from_f32(scale).to_bits().to_le_bytes().to_vec();
+        block.extend((0..32).map(|i| (i as i8 - 16) as u8));
+        block
+    }
+
+    fn write_string(bytes: &mut Vec<u8>, value: &str) {
+        bytes.extend((value.len() as u64).to_le_bytes());
+        bytes.extend(value.as_bytes());
+    }
+
+    fn pad(bytes: &mut Vec<u8>) {
+        bytes.resize(bytes.len().div_ceil(32) * 32, 0);
+    }
+
+    fn tiny_file(path: &Path) {
+        // An array of two strings.
+        let mut tokens = 8u32.to_le_bytes().to_vec();
+        tokens.extend(2u64.to_le_bytes());
+        tokens.extend(string_value("<s>"));
+        tokens.extend(string_value("</s>"));
+
+        let q4_0_block = {
+            let mut block = f16::from_f32(0.5).to_bits().to_le_bytes().to_vec();
+            block.extend((0..16u8).map(|j| j | ((15 - j) << 4)));
+            block
+        };
+        let f16_data = [1.0f32, -0.5, 0.25, 2.0]
+            .iter()
+            .flat_map(|value| f16::from_f32(*value).to_bits().to_le_bytes())
+            .collect::<Vec<_>>();
+
+        GgufWriter::default()
+            .metadata("general.architecture", 8, &string_value("llama"))
+            .metadata("llama.block_count", 4, &1u32.to_le_bytes())
+            .metadata("tokenizer.ggml.tokens", 9, &tokens)
+            .tensor(
+                "token_embd.weight",
+                &[3, 2],
+                0,
+                &f32_data(&[0.0, 0.1, 0.2, 1.0, 1.1, 1.2]),
+            )
+            .tensor("blk.0.attn_norm.weight", &[4], 1, &f16_data)
+            .tensor(
+                "blk.0.ffn_up.weight",
+                &[32, 2],
+                8,
+                &[q8_0_block(0.25), q8_0_block(2.0)].concat(),
+            )
+            .tensor("output.weight", &[32], 2, &q4_0_block)
+            .write(path);
+    }
+
+    #[test]
+    fn test_read_metadata_and_tensor_table() {
+        let path = std::env::temp_dir().join("burn_test_gguf_tiny.gguf");
+        tiny_file(&path);
+
+        let file = GgufFile::open(&path).unwrap();
+
+        assert_eq!(file.version(), 3);
+        assert_eq!(
+            file.metadata()["general.architecture"].as_str(),
+            Some("llama")
+        );
+        assert_eq!(file.metadata()["llama.block_count"].as_u64(), Some(1));
+        assert_eq!(
+            file.metadata()["tokenizer.ggml.tokens"],
+            GgufValue::Array(vec![
+                GgufValue::String("<s>".to_string()),
+                GgufValue::String("</s>".to_string())
+            ])
+        );
+
+        let tensors = file
+            .tensor_infos()
+            .iter()
+            .map(|info| (info.name.as_str(), info.shape.clone(), info.tensor_type))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tensors,
+            [
+                ("token_embd.weight", vec![2, 3], GgufTensorType::F32),
+                ("blk.0.attn_norm.weight", vec![4], GgufTensorType::F16),
+                ("blk.0.ffn_up.weight", vec![2, 32], GgufTensorType::Q8_0),
+                ("output.weight", vec![32], GgufTensorType::Q4_0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_dequantized_tensors() {
+        let path = std::env::temp_dir().join("burn_test_gguf_tensors.gguf");
+        tiny_file(&path);
+
+        let file = GgufFile::open(&path).unwrap();
+
+        let (values, shape) = file.read_tensor("token_embd.weight").unwrap();
+        assert_eq!(shape, [2, 3]);
+        assert_eq!(values, [0.0, 0.1, 0.2, 1.0, 1.1, 1.2]);
+
+        let (values, _) = file.read_tensor("blk.0.attn_norm.weight").unwrap();
+        assert_eq!(values, [1.0, -0.5, 0.25, 2.0]);
+
+        let (values, shape) = file.read_tensor("blk.0.ffn_up.weight").unwrap();
+        assert_eq!(shape, [2, 32]);
+        assert_eq!(
+            [values[0], values[31], values[32], values[63]],
+            [-4.0, 3.75, -32.0, 30.0]
+        );
+
+        let (values, _) = file.read_tensor("output.weight").unwrap();
+        assert_eq!([values[0], values[15], values[16]], [-4.0, 3.5, 3.5]);
+
+        assert!(file.read_tensor("missing.weight").is_err());
+    }
+
+    #[test]
+    fn test_invalid_magic_number() {
+        let path = std::env::temp_dir().join("burn_test_gguf_invalid.gguf");
+        std::fs::write(&path, b"GGML\x03\x00\x00\x00").unwrap();
+
+        assert!(matches!(GgufFile::open(&path), Err(Error::InvalidFile(_))));
+    }
+}
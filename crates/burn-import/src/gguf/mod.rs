@@ -0,0 +1,8 @@
+mod dequantize;
+mod error;
+mod file;
+mod reader;
+mod recorder;
+pub use error::Error;
+pub use file::{GgufFile, GgufTensorInfo, GgufTensorType, GgufValue};
+pub use recorder::{GgufFileRecorder, LoadArgs};
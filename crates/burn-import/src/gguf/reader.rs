@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::{Error, GgufFile};
+use crate::common::adapter::PyTorchAdapter;
+
+use burn::{
+    module::ParamId,
+    record::{
+        serde::{
+            data::{remap, unflatten, NestedValue, Serializable},
+            de::Deserializer,
+            error,
+            ser::Serializer,
+        },
+        ParamSerde, PrecisionSettings,
+    },
+    tensor::{backend::Backend, DataSerialize, ElementConversion},
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Deserializes a GGUF file.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+pub fn from_file<PS, D, B>(
+    path: &Path,
+    key_remap: Vec<(Regex, String)>,
+    debug: bool,
+) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    B: Backend,
+{
+    // Read and dequantize all the tensors of the file
+    let file = GgufFile::open(path)?;
+    let tensors = file
+        .tensor_infos()
+        .iter()
+        .map(|info| {
+            let (values, shape) = file.read_tensor(&info.name)?;
+            Ok((info.name.clone(), GgufTensor { values, shape }))
+        })
+        .collect::<Result<HashMap<_, _>, Error>>()?;
+
+    // Remap the keys (replace the keys in the map with the new keys)
+    let (tensors, remapped_keys) = remap(tensors, key_remap);
+
+    // Print the remapped keys if debug is enabled
+    if debug {
+        let mut remapped_keys = remapped_keys;
+        remapped_keys.sort();
+        println!("Debug information of keys and tensor shapes:\n---");
+        for (new_key, old_key) in remapped_keys {
+            if old_key != new_key {
+                println!("Original Key: {old_key}");
+                println!("Remapped Key: {new_key}");
+            } else {
+                println!("Key: {}", new_key);
+            }
+
+            println!("Shape: {:?}", tensors[&new_key].shape);
+            println!("---");
+        }
+    }
+
+    // Convert the map of tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // The tensors have the same layout as PyTorch, e.g. linear weights are [d_output, d_input]
+    let deserializer = Deserializer::<PyTorchAdapter<PS, B>>::new(nested_value, true);
+
+    // Deserialize the nested value into a record type
+    let value = D::deserialize(deserializer)?;
+    Ok(value)
+}
+
+/// A dequantized tensor read from a GGUF file.
+struct GgufTensor {
+    values: Vec<f32>,
+    shape: Vec<usize>,
+}
+
+/// Tensors are wrapped in a `Param` struct (learnable parameters) and serialized as a
+/// `DataSerialize` struct, with the float element of the precision settings.
+impl Serializable for GgufTensor {
+    fn serialize<PS>(&self, serializer: Serializer) -> Result<NestedValue, error::Error>
+    where
+        PS: PrecisionSettings,
+    {
+        let values: Vec<PS::FloatElem> = self.values.iter().map(|value| value.elem()).collect();
+        let data = DataSerialize::new(values, self.shape.clone());
+
+        ParamSerde::new(ParamId::new().into_string(), data).serialize(serializer)
+    }
+}
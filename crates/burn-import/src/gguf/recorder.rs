@@ -0,0 +1,205 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use regex::Regex;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::reader::from_file;
+
+/// A recorder that loads GGUF files (`.gguf`), such as the quantized models exported by
+/// llama.cpp, into Burn modules.
+///
+/// The tensors are dequantized to the float element of the precision settings and matched with
+/// the fields of the modules by name, with the same conventions as the
+/// [PyTorch recorder](crate::pytorch::PyTorchFileRecorder): the tensor `blk.0.attn_q.weight` is
+/// loaded in the field `attn_q` of the item `0` of the vector `blk`. The names of llama.cpp
+/// rarely match the ones of a Burn model, so they are usually remapped with
+/// [LoadArgs::with_key_remap].
+#[derive(new, Debug, Default, Clone)]
+pub struct GgufFileRecorder<PS: PrecisionSettings> {
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for GgufFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = LoadArgs;
+
+    fn save_item<I: Serialize>(
+        &self,
+        _item: I,
+        _file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        unimplemented!("save_item not implemented for GgufFileRecorder")
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, _file: Self::LoadArgs) -> Result<I, RecorderError> {
+        unimplemented!("load_item not implemented for GgufFileRecorder")
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item =
+            from_file::<PS, R::Item<Self::Settings>, B>(&args.file, args.key_remap, args.debug)?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+/// Arguments for loading a GGUF file.
+///
+/// # Fields
+///
+/// * `file` - The path to the file to load.
+/// * `key_remap` - A vector of tuples containing a regular expression and a replacement string.
+///                See [regex::Regex::replace](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace)
+///                for more information.
+///
+/// # Examples
+///
+/// ```text
+/// use burn_import::gguf::{GgufFileRecorder, LoadArgs};
+/// use burn::record::FullPrecisionSettings;
+/// use burn::record::Recorder;
+///
+/// let args = LoadArgs::new("tinyllama-q8_0.gguf".into())
+///    .with_key_remap("blk\\.([0-9]+)", "layers.$1"); // e.g. "blk.0.attn_q" -> "layers.0.attn_q"
+///
+/// let record = GgufFileRecorder::<FullPrecisionSettings>::default()
+///   .load(args, &device)
+///   .expect("Should decode state successfully");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LoadArgs {
+    /// The path to the file to load.
+    pub file: PathBuf,
+
+    /// A list of key remappings.
+    pub key_remap: Vec<(Regex, String)>,
+
+    /// Whether to print debug information.
+    pub debug: bool,
+}
+
+impl LoadArgs {
+    /// Creates a new `LoadArgs` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The path to the file to load.
+    pub fn new(file: PathBuf) -> Self {
+        Self {
+            file,
+            key_remap: Vec::new(),
+            debug: false,
+        }
+    }
+
+    /// Sets key remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The Regex pattern to be replaced.
+    /// * `replacement` - The pattern to replace with.
+    ///
+    /// See [Regex](https://docs.rs/regex/1.5.4/regex/#syntax) for the pattern syntax and
+    /// [Replacement](https://docs.rs/regex/latest/regex/struct.Regex.html#method.replace) for the
+    /// replacement syntax.
+    pub fn with_key_remap(mut self, pattern: &str, replacement: &str) -> Self {
+        let regex = Regex::new(pattern).expect("Valid regex");
+
+        self.key_remap.push((regex, replacement.into()));
+        self
+    }
+
+    /// Sets printing debug information on.
+    pub fn with_debug_print(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+}
+
+impl From<PathBuf> for LoadArgs {
+    fn from(val: PathBuf) -> Self {
+        LoadArgs::new(val)
+    }
+}
+
+impl From<String> for LoadArgs {
+    fn from(val: String) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+impl From<&str> for LoadArgs {
+    fn from(val: &str) -> Self {
+        LoadArgs::new(val.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gguf::file::tests::{f32_data, q8_0_block, GgufWriter};
+    use burn::{
+        backend::ndarray::NdArray,
+        module::Module,
+        nn::{Embedding, Linear},
+        record::FullPrecisionSettings,
+    };
+
+    #[derive(Module, Debug)]
+    struct Block<B: Backend> {
+        ffn_up: Linear<B>,
+    }
+
+    #[derive(Module, Debug)]
+    struct Model<B: Backend> {
+        embedding: Embedding<B>,
+        layers: Vec<Block<B>>,
+    }
+
+    #[test]
+    fn test_load_quantized_weights_into_module() {
+        let path = std::env::temp_dir().join("burn_test_gguf_recorder.gguf");
+        GgufWriter::default()
+            .tensor(
+                "token_embd.weight",
+                &[3, 2],
+                0,
+                &f32_data(&[0.0, 0.1, 0.2, 1.0, 1.1, 1.2]),
+            )
+            // A linear layer from 32 to 2 features, with the layout [d_output, d_input].
+            .tensor(
+                "blk.0.ffn_up.weight",
+                &[32, 2],
+                8,
+                &[q8_0_block(0.25), q8_0_block(2.0)].concat(),
+            )
+            .write(&path);
+
+        let args = LoadArgs::new(path)
+            .with_key_remap("token_embd", "embedding")
+            .with_key_remap("blk\\.([0-9]+)", "layers.$1");
+        let record: ModelRecord<NdArray> = GgufFileRecorder::<FullPrecisionSettings>::default()
+            .load(args, &Default::default())
+            .expect("Should decode state successfully");
+
+        let embedding = record.embedding.weight.val().into_data();
+        assert_eq!(embedding.shape.dims, [2, 3]);
+        assert_eq!(embedding.value, [0.0, 0.1, 0.2, 1.0, 1.1, 1.2]);
+
+        let weight = record.layers[0].ffn_up.weight.val().into_data();
+        assert_eq!(weight.shape.dims, [32, 2]);
+        assert_eq!(weight.value[..4], [-4.0, -32.0, -3.75, -30.0]);
+        assert!(record.layers[0].ffn_up.bias.is_none());
+    }
+}
@@ -9,7 +9,12 @@
 //! aligns the imported model with Burn's model and converts tensor data into a format compatible with
 //! Burn.
 
-#[cfg(any(feature = "pytorch", feature = "onnx"))]
+#[cfg(any(
+    feature = "pytorch",
+    feature = "onnx",
+    feature = "safetensors",
+    feature = "gguf"
+))]
 #[macro_use]
 extern crate derive_new;
 
@@ -29,5 +34,17 @@ pub mod burn;
 #[cfg(feature = "pytorch")]
 pub mod pytorch;
 
+/// The safetensors module for recorder.
+#[cfg(feature = "safetensors")]
+pub mod safetensors;
+
+/// The GGUF module for recorder.
+#[cfg(feature = "gguf")]
+pub mod gguf;
+
+// Shared by the recorders.
+#[cfg(any(feature = "pytorch", feature = "safetensors", feature = "gguf"))]
+mod common;
+
 mod formatter;
 pub use formatter::*;
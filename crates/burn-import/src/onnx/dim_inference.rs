@@ -29,12 +29,14 @@ pub fn dim_inference(node: &mut Node, graph_io: &mut OnnxGraphIO) {
         NodeType::Exp => same_as_input(node),
         NodeType::Flatten => flatten_update_outputs(node),
         NodeType::Gelu => same_as_input(node),
+        NodeType::Gather => gather_update_outputs(node),
         NodeType::GatherElements => same_as_input(node),
         NodeType::GlobalAveragePool => same_as_input(node),
         NodeType::ConvTranspose2d => conv_transpose2d_update_outputs(node),
         NodeType::Linear => linear_update_outputs(node),
         NodeType::Log => same_as_input(node),
         NodeType::LogSoftmax => same_as_input(node),
+        NodeType::LSTM => lstm_update_outputs(node),
         NodeType::MaxPool2d => same_as_input(node),
         NodeType::Mul => same_as_input(node),
         NodeType::Neg => same_as_input(node),
@@ -42,8 +44,11 @@ pub fn dim_inference(node: &mut Node, graph_io: &mut OnnxGraphIO) {
         NodeType::ReduceMean => mean_update_outputs(node),
         NodeType::Relu => same_as_input(node),
         NodeType::Reshape => reshape_update_outputs(node),
+        NodeType::Resize => same_rank_as_input(node),
+        NodeType::ScatterElements => same_as_input(node),
         NodeType::Shape => shape_update_outputs(node),
         NodeType::Sigmoid => same_as_input(node),
+        NodeType::Slice => same_rank_as_input(node),
         NodeType::Softmax => same_as_input(node),
         NodeType::Sqrt => same_as_input(node),
         NodeType::Sub => same_as_input(node),
@@ -106,21 +111,22 @@ fn linear_update_outputs(node: &mut Node) {
     let node_input = &node.inputs[0];
     let weight = &node.inputs[1];
 
-    // Calculate the output shape. Usually we do not use shapes, but since the input shape is
+    // Calculate the output shape. Usually we do not use shapes, but when the input shape is
     // known, we can calculate the output shape.
     if let ArgType::Tensor(tensor) = node_input.clone().ty {
         let mut tensor = tensor.clone();
-        let mut shape = tensor.shape.clone().unwrap();
 
         if let ArgType::Tensor(weight_tensor) = weight.clone().ty {
-            let last = shape.last_mut().unwrap();
-            *last = *weight_tensor.shape.unwrap().first().unwrap();
+            // The other dimensions are kept, so a dynamic batch size stays unknown.
+            let features = *weight_tensor.shape.unwrap().first().unwrap();
+            tensor.shape = tensor.shape.map(|mut shape| {
+                *shape.last_mut().unwrap() = features;
+                shape
+            });
         } else {
             panic!("Weight must be a tensor");
         }
 
-        tensor.shape = Some(shape);
-
         // Update the output tensor
         node.outputs[0].ty = ArgType::Tensor(tensor);
     } else {
@@ -195,12 +201,17 @@ fn reshape_update_outputs(node: &mut Node) {
         _ => panic!("Reshape: invalid input type"),
     };
 
-    let shape = shape.iter().map(|&dim| dim as usize).collect();
+    // The dimensions copied from the input (0) or inferred (-1) are only known at runtime, e.g.
+    // when the batch size is dynamic.
+    let shape = shape
+        .iter()
+        .map(|&dim| (dim > 0).then_some(dim as usize))
+        .collect();
 
     node.outputs[0].ty = ArgType::Tensor(TensorType {
         elem_type,
         dim,
-        shape: Some(shape),
+        shape,
     });
 }
 
@@ -277,7 +288,15 @@ fn unsqueeze_update_output(node: &mut Node) {
             let mut new_dims = vec![1; output_rank as usize];
 
             tensor.dim = output_rank as usize;
-            let old_dims = input.shape.unwrap();
+            // With dynamic input dimensions, only the rank of the output is known.
+            let old_dims = match input.shape {
+                Some(shape) => shape,
+                None => {
+                    tensor.shape = None;
+                    node.outputs[0].ty = ArgType::Tensor(tensor);
+                    return;
+                }
+            };
             //Now use this to copy the chunks of the dims
             let mut prev_idx: usize = 0;
             let mut current_left_b: usize = 0;
@@ -325,6 +344,19 @@ fn same_as_input(node: &mut Node) {
     node.outputs[0].ty = node.inputs[0].ty.clone();
 }
 
+/// The output has the type and rank of the input, but not its shape.
+fn same_rank_as_input(node: &mut Node) {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => {
+            node.outputs[0].ty = ArgType::Tensor(TensorType {
+                shape: None,
+                ..tensor.clone()
+            });
+        }
+        _ => panic!("{:?}: only tensor input is valid", node.node_type),
+    }
+}
+
 /// Temporary pass-through stub for dimension inference so that we can export the IR model.
 fn temporary_pass_through_stub(node: &Node) {
     log::warn!(
@@ -351,6 +383,42 @@ fn equal_update_outputs(node: &mut Node) {
     }
 }
 
+/// Infers the shape of a LSTM node: the hidden states of every element of the sequence have an
+/// additional dimension for the directions, the final states have the same rank as the input.
+fn lstm_update_outputs(node: &mut Node) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("LSTM: only tensor input is valid"),
+    };
+
+    for (output, dim) in node.outputs.iter_mut().zip([4, 3, 3]) {
+        output.ty = ArgType::Tensor(TensorType {
+            elem_type: tensor.elem_type.clone(),
+            dim,
+            shape: None,
+        });
+    }
+}
+
+/// Infers the shape of a Gather node: the indexed dimension of the input is replaced by the
+/// dimensions of the index tensor.
+fn gather_update_outputs(node: &mut Node) {
+    if node.inputs.len() != 2 {
+        panic!("Gather: index tensor must be present");
+    }
+
+    match (&node.inputs[0].ty, &node.inputs[1].ty) {
+        (ArgType::Tensor(input), ArgType::Tensor(index)) => {
+            node.outputs[0].ty = ArgType::Tensor(TensorType {
+                elem_type: input.elem_type.clone(),
+                dim: input.dim + index.dim - 1,
+                shape: None,
+            });
+        }
+        _ => panic!("Gather: only tensor inputs are valid"),
+    }
+}
+
 fn shape_update_outputs(node: &mut Node) {
     if node.inputs.len() != 1 {
         panic!("Gather: multiple inputs are not supported: {:?}", node);
@@ -387,8 +455,17 @@ fn flatten_update_outputs(node: &mut Node) {
     let collapsed_dims = end_dim - start_dim;
     let output_dim = input_dim - collapsed_dims;
 
+    // The collapsed dimensions are multiplied, the others (e.g. a dynamic batch) are kept.
+    let shape = tensor.shape.as_ref().map(|shape| {
+        let mut output_shape = shape[..start_dim].to_vec();
+        output_shape.push(shape[start_dim..=end_dim].iter().product());
+        output_shape.extend_from_slice(&shape[end_dim + 1..]);
+        output_shape
+    });
+
     node.outputs[0].ty = ArgType::Tensor(TensorType {
         dim: output_dim,
+        shape,
         ..tensor.clone()
     });
 }
@@ -17,13 +17,15 @@ use super::ir::{ArgType, Argument, Node, NodeType};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 7] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 9] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
     NodeType::Dropout,
     NodeType::Reshape,
+    NodeType::Resize,
+    NodeType::Slice,
     NodeType::Unsqueeze,
 ];
 
@@ -362,7 +364,10 @@ impl OnnxGraphBuilder {
     fn handle_unsqueeze(&mut self, node: &mut Node, graph_io: &OnnxGraphIO) {
         if node.node_type == NodeType::Unsqueeze && node.inputs[1].value.is_none() {
             if let Some(in_arg) = graph_io.get_node_output(&node.outputs[0].name) {
-                remap_unsqueeze_to_reshape(node, in_arg);
+                // The reshape needs a static output shape, which is unknown with dynamic dims.
+                if let ArgType::Tensor(TensorType { shape: Some(_), .. }) = &in_arg.ty {
+                    remap_unsqueeze_to_reshape(node, in_arg);
+                }
             }
         }
     }
@@ -2,10 +2,15 @@ use burn::nn::{
     conv::Conv1dConfig,
     conv::{Conv2dConfig, ConvTranspose2dConfig},
     pool::{AvgPool2dConfig, MaxPool2dConfig},
-    BatchNormConfig, DropoutConfig, LinearConfig, PaddingConfig1d, PaddingConfig2d,
+    BatchNormConfig, DropoutConfig, LinearConfig, LstmConfig, PaddingConfig1d, PaddingConfig2d,
+};
+use burn::tensor::{
+    ops::{InterpolateMode, InterpolateOptions},
+    ScatterReduction,
 };
 
 use super::ir::{ArgType, AttributeValue, Data, Node};
+use crate::burn::node::{resize::ResizeSize, slice::SliceAxis};
 
 /// Create a Conv1dConfig from the attributes of the node
 pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
@@ -283,6 +288,47 @@ pub fn gather_config(curr: &Node) -> usize {
     dim as usize
 }
 
+/// Create a ScatterConfig (dimension and reduction) from the attributes of the node
+pub fn scatter_config(curr: &Node) -> (usize, ScatterReduction) {
+    // Default: 0 and no reduction per ONNX spec
+    let mut dim: i64 = 0;
+    let mut reduction = ScatterReduction::Overwrite;
+
+    // check if the node has the index and updates inputs
+    if curr.inputs.len() != 3 {
+        panic!("ScatterElements: index and updates tensors must be present");
+    }
+
+    // extract the shape of the input tensor
+    let tensor = match curr.inputs.first().unwrap().clone().ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Only tensor input is valid"),
+    };
+
+    // extract the attributes
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "axis" => dim = value.clone().into_i64(),
+            "reduction" => {
+                reduction = match value.clone().into_string().as_str() {
+                    "none" => ScatterReduction::Overwrite,
+                    "add" => ScatterReduction::Add,
+                    "mul" => ScatterReduction::Multiply,
+                    other => panic!("ScatterElements: unsupported reduction {other}"),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // if dim is negative, it is counted from the end
+    if dim < 0 {
+        dim += tensor.dim as i64;
+    }
+
+    (dim as usize, reduction)
+}
+
 /// Create a LinearConfig from the attributes of the node
 pub fn linear_config(node: &Node) -> LinearConfig {
     if node.inputs.len() < 2 {
@@ -313,6 +359,178 @@ pub fn linear_config(node: &Node) -> LinearConfig {
     LinearConfig::new(in_size, out_size).with_bias(bias)
 }
 
+/// Create a LstmConfig from the attributes of the node, along with if the sequence is processed
+/// in reverse and if the batch comes first in the inputs and outputs.
+pub fn lstm_config(node: &Node) -> (LstmConfig, bool, bool) {
+    let mut hidden_size = None;
+    let mut direction = "forward".to_string();
+    let mut layout = 0;
+
+    // extract the weight tensor's shape [num_directions, 4 * hidden_size, input_size]
+    let weight = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) if node.inputs[1].value.is_some() => tensor.clone(),
+        _ => panic!("LSTM: weight tensor must be present"),
+    };
+    let shape = weight.shape.unwrap();
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "hidden_size" => hidden_size = Some(value.clone().into_i64() as usize),
+            "direction" => direction = value.clone().into_string(),
+            "layout" => layout = value.clone().into_i64(),
+            "activations" => {
+                let activations = value.clone().into_strings();
+                let default = ["sigmoid", "tanh", "tanh"];
+                if activations
+                    .iter()
+                    .zip(default.iter().cycle())
+                    .any(|(activation, default)| !activation.eq_ignore_ascii_case(default))
+                {
+                    panic!("LSTM: only the default activations are supported: {activations:?}")
+                }
+            }
+            "clip" => panic!("LSTM: clip is not supported"),
+            "input_forget" if value.clone().into_i64() != 0 => {
+                panic!("LSTM: input_forget is not supported")
+            }
+            _ => {}
+        }
+    }
+
+    let has_input = |index: usize| {
+        node.inputs
+            .get(index)
+            .map(|input| !input.name.is_empty() || input.value.is_some())
+            .unwrap_or(false)
+    };
+    if has_input(4) {
+        panic!("LSTM: sequence_lens is not supported");
+    }
+    if has_input(7) {
+        panic!("LSTM: peephole weights are not supported");
+    }
+
+    let (bidirectional, reverse) = match direction.as_str() {
+        "forward" => (false, false),
+        "reverse" => (false, true),
+        "bidirectional" => (true, false),
+        _ => panic!("LSTM: unsupported direction {direction}"),
+    };
+
+    let config = LstmConfig::new(
+        shape[2],
+        hidden_size.unwrap_or(shape[1] / 4),
+        node.inputs
+            .get(3)
+            .and_then(|bias| bias.value.as_ref())
+            .is_some(),
+    )
+    .with_bidirectional(bidirectional);
+
+    (config, reverse, layout == 1)
+}
+
+/// Create the interpolate options and the output size of a resize from the node
+pub fn resize_config(node: &Node) -> (InterpolateOptions, ResizeSize) {
+    let mut mode = "nearest".to_string();
+    let mut coordinate_transformation_mode = "half_pixel".to_string();
+    let mut nearest_mode = "round_prefer_floor".to_string();
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "mode" => mode = value.clone().into_string(),
+            "coordinate_transformation_mode" => {
+                coordinate_transformation_mode = value.clone().into_string()
+            }
+            "nearest_mode" => nearest_mode = value.clone().into_string(),
+            "antialias" if value.clone().into_i64() != 0 => {
+                panic!("Resize: antialias is not supported")
+            }
+            "axes" => panic!("Resize: axes is not supported"),
+            "exclude_outside" if value.clone().into_i64() != 0 => {
+                panic!("Resize: exclude_outside is not supported")
+            }
+            "keep_aspect_ratio_policy" if value.clone().into_string() != "stretch" => {
+                panic!("Resize: only the stretch keep_aspect_ratio_policy is supported")
+            }
+            _ => {}
+        }
+    }
+
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => assert_eq!(tensor.dim, 4, "Resize: input must be 4D"),
+        _ => panic!("Resize: only tensor input is valid"),
+    }
+
+    // The inputs are the data, roi, scales and sizes, where only one of scales and sizes is set
+    let value = |index: usize| {
+        node.inputs
+            .get(index)
+            .and_then(|input| input.value.clone())
+            .filter(|value| !matches!(value, Data::Float32s(elems) if elems.is_empty()))
+            .filter(|value| !matches!(value, Data::Int64s(elems) if elems.is_empty()))
+    };
+    let size = match (value(2), value(3)) {
+        (Some(scales), _) => {
+            let scales = scales.into_f32s();
+            assert_eq!(scales.len(), 4, "Resize: scales must have 4 values");
+            if scales[0] != 1.0 || scales[1] != 1.0 {
+                panic!("Resize: only the height and width can be scaled, got {scales:?}");
+            }
+
+            ResizeSize::Scales([scales[2] as f64, scales[3] as f64])
+        }
+        (None, Some(sizes)) => {
+            let sizes = sizes.into_i64s();
+            assert_eq!(sizes.len(), 4, "Resize: sizes must have 4 values");
+
+            ResizeSize::Sizes([sizes[2] as usize, sizes[3] as usize])
+        }
+        (None, None) => panic!("Resize: constant scales or sizes must be present"),
+    };
+
+    let options = match mode.as_str() {
+        // The nearest neighbor is the floor of the scaled output index
+        "nearest" => match (coordinate_transformation_mode.as_str(), nearest_mode.as_str()) {
+            ("asymmetric", "floor") => InterpolateOptions::new(InterpolateMode::Nearest),
+            ("asymmetric", _) => panic!(
+                "Resize: the {nearest_mode} nearest mode is not supported, only floor is supported"
+            ),
+            _ => panic!(
+                "Resize: only the asymmetric coordinate transformation is supported in nearest \
+                 mode, got {coordinate_transformation_mode}"
+            ),
+        },
+        "linear" => match coordinate_transformation_mode.as_str() {
+            "align_corners" => InterpolateOptions::new(InterpolateMode::Bilinear),
+            "half_pixel" => InterpolateOptions {
+                mode: InterpolateMode::Bilinear,
+                align_corners: false,
+            },
+            // The coordinates are the half_pixel ones, except for an output dimension of size 1,
+            // whose coordinate is always 0, so it's only supported with constant sizes above 1
+            "pytorch_half_pixel" => match &size {
+                ResizeSize::Sizes(sizes) if sizes.iter().all(|dim| *dim > 1) => {
+                    InterpolateOptions {
+                        mode: InterpolateMode::Bilinear,
+                        align_corners: false,
+                    }
+                }
+                _ => panic!(
+                    "Resize: the pytorch_half_pixel coordinate transformation is only supported \
+                     with sizes larger than 1"
+                ),
+            },
+            _ => panic!(
+                "Resize: unsupported coordinate transformation mode {coordinate_transformation_mode}"
+            ),
+        },
+        _ => panic!("Resize: unsupported mode {mode}"),
+    };
+
+    (options, size)
+}
+
 /// Create a DropoutConfig from an attribute and state of the node
 pub fn dropout_config(node: &Node) -> DropoutConfig {
     // Opset 7 and older store probability as an attribute
@@ -556,6 +774,64 @@ pub fn unsqueeze_config(node: &Node) -> Vec<i64> {
     }
 }
 
+/// Create the slices of each axis from the attributes or the constant inputs of the node
+pub fn slice_config(node: &Node) -> Vec<SliceAxis> {
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("Slice: only tensor input is valid"),
+    };
+
+    // Opset 1 to 9 have attributes, the newer ones have the inputs starts, ends, axes and steps
+    let mut starts = node.attrs.get("starts").map(|v| v.clone().into_i64s());
+    let mut ends = node.attrs.get("ends").map(|v| v.clone().into_i64s());
+    let mut axes = node.attrs.get("axes").map(|v| v.clone().into_i64s());
+    let mut steps = None;
+
+    let value = |index: usize| {
+        node.inputs.get(index).and_then(|input| match &input.value {
+            Some(Data::Int64s(values)) => Some(values.clone()),
+            Some(Data::Int32s(values)) => Some(values.iter().map(|v| *v as i64).collect()),
+            Some(value) => panic!("Slice: indices must be integers, got {value:?}"),
+            None if input.passed => panic!("Slice: only constant indices are supported"),
+            None => None,
+        })
+    };
+    if node.inputs.len() > 1 {
+        starts = value(1);
+        ends = value(2);
+        axes = value(3);
+        steps = value(4);
+    }
+
+    let starts = starts.expect("Slice: starts must be present");
+    let ends = ends.expect("Slice: ends must be present");
+    // Default: all the axes in order, with a step of 1 per ONNX spec
+    let axes = axes.unwrap_or_else(|| (0..starts.len() as i64).collect());
+    let steps = steps.unwrap_or_else(|| vec![1; starts.len()]);
+
+    if ends.len() != starts.len() || axes.len() != starts.len() || steps.len() != starts.len() {
+        panic!("Slice: starts, ends, axes and steps must have the same length");
+    }
+
+    starts
+        .into_iter()
+        .zip(ends)
+        .zip(axes)
+        .zip(steps)
+        .map(|(((start, end), axis), step)| {
+            if step == 0 {
+                panic!("Slice: step can't be 0");
+            }
+            let axis = match axis < 0 {
+                true => (axis + rank as i64) as usize,
+                false => axis as usize,
+            };
+
+            SliceAxis::new(axis, start, end, step)
+        })
+        .collect()
+}
+
 pub fn clip_config(node: &Node) -> (Option<f64>, Option<f64>) {
     let mut min_result: Option<f64> = None;
     let mut max_result: Option<f64> = None;
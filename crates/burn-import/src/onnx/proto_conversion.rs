@@ -3,7 +3,6 @@ use std::str::{from_utf8, FromStr};
 use crate::onnx::ir::TensorType;
 
 use super::from_onnx::OnnxGraphIO;
-use super::ir::Dim;
 use super::ir::{
     ArgType, Argument, AttributeValue, Attributes, Data, ElementType, Node, NodeType, Tensor,
 };
@@ -86,19 +85,17 @@ impl TryFrom<TensorProto> for Tensor {
     }
 }
 
-impl TryFrom<TensorShapeProto> for Vec<usize> {
-    type Error = ParseError;
-    fn try_from(shape: TensorShapeProto) -> Result<Vec<usize>, Self::Error> {
-        let mut result = Vec::new();
-
-        for dim in shape.dim {
-            if let Value::DimValue(value) = dim.value.unwrap() {
-                result.push(value as usize);
-            }
-        }
-
-        Ok(result)
-    }
+/// Convert a shape proto to a static shape, or `None` when a dimension is symbolic (e.g. a named
+/// `batch` dimension) or unknown, in which case only the rank of the tensor is known.
+fn convert_shape_proto(shape: &TensorShapeProto) -> Option<Vec<usize>> {
+    shape
+        .dim
+        .iter()
+        .map(|dim| match dim.value {
+            Some(Value::DimValue(value)) if value >= 0 => Some(value as usize),
+            _ => None,
+        })
+        .collect()
 }
 
 /// Convert a vector of AttributeProto to a HashMap of AttributeValue
@@ -119,12 +116,11 @@ impl TryFrom<&type_proto::Tensor> for Tensor {
         };
 
         let shape_proto = tensor.shape.clone().unwrap();
-        let shape: Vec<usize> = shape_proto.try_into().unwrap();
 
         Ok(Tensor {
             elem_type,
-            dim: shape.len(),
-            shape: Some(shape),
+            dim: shape_proto.dim.len(),
+            shape: convert_shape_proto(&shape_proto),
             data: None,
         })
     }
@@ -246,14 +242,7 @@ impl TryFrom<ValueInfoProto> for Argument {
         let tensor_type = TensorType {
             dim: tensor_proto.shape.dim.len(),
             elem_type,
-            shape: Some(
-                tensor_proto
-                    .shape
-                    .dim
-                    .iter()
-                    .map(|x| x.dim_value() as Dim)
-                    .collect(),
-            ),
+            shape: convert_shape_proto(&tensor_proto.shape),
         };
 
         let ty = ArgType::Tensor(tensor_type);
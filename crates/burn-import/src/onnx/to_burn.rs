@@ -26,9 +26,14 @@ use crate::{
             gather::GatherNode,
             global_avg_pool::GlobalAvgPoolNode,
             linear::LinearNode,
+            lstm::{LstmCellData, LstmGateData, LstmNode},
             matmul::MatmulNode,
             max_pool2d::MaxPool2dNode,
             reshape::ReshapeNode,
+            resize::ResizeNode,
+            scatter::ScatterNode,
+            select::SelectNode,
+            slice::SliceNode,
             unary::UnaryNode,
             unsqueeze::UnsqueezeNode,
         },
@@ -241,13 +246,16 @@ impl OnnxGraph {
                 NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
                 NodeType::Neg => graph.register(Self::neg_conversion(node)),
                 NodeType::Linear => graph.register(Self::linear_conversion::<PS>(node)),
+                NodeType::LSTM => graph.register(Self::lstm_conversion::<PS>(node)),
                 NodeType::BatchNormalization => {
                     graph.register(Self::batch_norm_conversion::<PS>(node))
                 }
                 NodeType::Relu => graph.register(Self::relu_conversion(node)),
                 NodeType::Gelu => graph.register(Self::gelu_conversion(node)),
                 NodeType::Flatten => graph.register(Self::flatten_conversion(node)),
+                NodeType::Gather => graph.register(Self::select_conversion(node)),
                 NodeType::GatherElements => graph.register(Self::gather_conversion(node)),
+                NodeType::ScatterElements => graph.register(Self::scatter_conversion(node)),
                 NodeType::Log => graph.register(Self::log_conversion(node)),
                 NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
                 NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
@@ -255,8 +263,10 @@ impl OnnxGraph {
                 NodeType::Tanh => graph.register(Self::tanh_conversion(node)),
                 NodeType::Constant => graph.register(Self::constant_conversion::<PS>(node)),
                 NodeType::Reshape => graph.register(Self::reshape_conversion(node)),
+                NodeType::Resize => graph.register(Self::resize_conversion(node)),
                 NodeType::Reciprocal => graph.register(Self::reciprocal_conversion(node)),
                 NodeType::Sigmoid => graph.register(Self::sigmoid_conversion(node)),
+                NodeType::Slice => graph.register(Self::slice_conversion(node)),
                 NodeType::Transpose => graph.register(Self::transpose_conversion(node)),
                 NodeType::Concat => graph.register(Self::concat_conversion(node)),
                 NodeType::Cast => graph.register(Self::cast_conversion(node)),
@@ -435,6 +445,32 @@ impl OnnxGraph {
         GatherNode::new(input, index, output, dim)
     }
 
+    fn select_conversion(node: Node) -> SelectNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let index = node.inputs.get(1).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let dim = gather_config(&node);
+
+        if index.dim != 1 {
+            panic!(
+                "Gather: only index tensors of rank 1 are supported: {:?}",
+                node
+            );
+        }
+
+        SelectNode::new(input, index, output, dim)
+    }
+
+    fn scatter_conversion(node: Node) -> ScatterNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let index = node.inputs.get(1).unwrap().to_tensor_type();
+        let updates = node.inputs.get(2).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (dim, reduction) = scatter_config(&node);
+
+        ScatterNode::new(input, index, updates, output, dim, reduction)
+    }
+
     fn transpose_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -456,6 +492,20 @@ impl OnnxGraph {
 
         ReshapeNode::new(input, output, shape)
     }
+    fn resize_conversion(node: Node) -> ResizeNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (options, size) = resize_config(&node);
+
+        ResizeNode::new(input, output, size, options)
+    }
+    fn slice_conversion(node: Node) -> SliceNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let axes = slice_config(&node);
+
+        SliceNode::new(input, output, axes)
+    }
     fn unsqueeze_conversion(node: Node) -> UnsqueezeNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
@@ -542,6 +592,61 @@ impl OnnxGraph {
         LinearNode::new(name, input, output, weight, bias, config)
     }
 
+    fn lstm_conversion<PS: PrecisionSettings>(node: Node) -> LstmNode<PS> {
+        let name = &node.name;
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let (config, reverse, batch_first) = lstm_config(&node);
+
+        let weight = extract_data_serialize::<PS::FloatElem>(1, &node).expect("W is required");
+        let recurrence = extract_data_serialize::<PS::FloatElem>(2, &node).expect("R is required");
+        let bias = extract_data_serialize::<PS::FloatElem>(3, &node);
+
+        // The optional inputs and outputs have an empty name when they are omitted.
+        let optional = |args: &[Argument], index: usize| {
+            args.get(index)
+                .filter(|arg| !arg.name.is_empty())
+                .map(Argument::to_tensor_type)
+        };
+
+        let num_directions = weight.shape[0];
+        let cells = (0..num_directions)
+            .map(|direction| {
+                let gate = |index: usize| LstmGateData {
+                    input_weight: lstm_gate_weight(&weight, direction, index),
+                    hidden_weight: lstm_gate_weight(&recurrence, direction, index),
+                    input_bias: bias
+                        .as_ref()
+                        .map(|bias| lstm_gate_bias(bias, direction, index)),
+                    hidden_bias: bias
+                        .as_ref()
+                        .map(|bias| lstm_gate_bias(bias, direction, index + 4)),
+                };
+
+                // ONNX orders the gates as input, output, forget and cell.
+                LstmCellData {
+                    input_gate: gate(0),
+                    output_gate: gate(1),
+                    forget_gate: gate(2),
+                    cell_gate: gate(3),
+                }
+            })
+            .collect();
+
+        LstmNode::new(
+            name,
+            input,
+            optional(&node.inputs, 5),
+            optional(&node.inputs, 6),
+            optional(&node.outputs, 0),
+            optional(&node.outputs, 1),
+            optional(&node.outputs, 2),
+            cells,
+            config,
+            reverse,
+            batch_first,
+        )
+    }
+
     fn dropout_conversion(node: Node) -> DropoutNode {
         let name = &node.name;
         let input = node.inputs.first().unwrap().to_tensor_type();
@@ -724,6 +829,45 @@ fn extract_data_serialize<E: Element>(input_index: usize, node: &Node) -> Option
     }
 }
 
+/// Extract the weights of a gate from the ONNX LSTM weights, with the shape
+/// `[num_directions, 4 * hidden_size, d_input]`, as a burn weight with the shape
+/// `[d_input, hidden_size]`.
+fn lstm_gate_weight<E: Element>(
+    weights: &DataSerialize<E>,
+    direction: usize,
+    gate: usize,
+) -> DataSerialize<E> {
+    let (rows, cols) = (weights.shape[1], weights.shape[2]);
+    let hidden_size = rows / 4;
+    let offset = (direction * rows + gate * hidden_size) * cols;
+
+    let mut value = Vec::with_capacity(hidden_size * cols);
+    for col in 0..cols {
+        for row in 0..hidden_size {
+            value.push(weights.value[offset + row * cols + col]);
+        }
+    }
+
+    DataSerialize::new(value, vec![cols, hidden_size])
+}
+
+/// Extract a bias from the ONNX LSTM biases, with the shape `[num_directions, 8 * hidden_size]`,
+/// the input biases of the gates coming before their hidden biases.
+fn lstm_gate_bias<E: Element>(
+    biases: &DataSerialize<E>,
+    direction: usize,
+    index: usize,
+) -> DataSerialize<E> {
+    let size = biases.shape[1];
+    let hidden_size = size / 8;
+    let offset = direction * size + index * hidden_size;
+
+    DataSerialize::new(
+        biases.value[offset..offset + hidden_size].to_vec(),
+        vec![hidden_size],
+    )
+}
+
 /// Convert data to `DataSerialize`.
 fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> DataSerialize<E> {
     match data {
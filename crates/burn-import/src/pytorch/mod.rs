@@ -1,4 +1,3 @@
-mod adapter;
 mod config;
 mod error;
 mod reader;
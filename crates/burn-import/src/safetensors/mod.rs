@@ -0,0 +1,5 @@
+mod error;
+mod reader;
+mod recorder;
+mod writer;
+pub use recorder::{AdapterType, SafetensorsFileRecorder};
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::error::Error;
+use crate::common::candle::CandleTensor;
+
+use burn::record::{
+    serde::{adapter::BurnModuleAdapter, data::unflatten, de::Deserializer},
+    PrecisionSettings,
+};
+
+use candle_core::{safetensors, Device};
+use serde::de::DeserializeOwned;
+
+/// Deserializes a safetensors file, the modules being adapted with the adapter `A`.
+///
+/// # Arguments
+///
+/// * `path` - A string slice that holds the path of the file to read.
+pub fn from_file<PS, D, A>(path: &Path) -> Result<D, Error>
+where
+    D: DeserializeOwned,
+    PS: PrecisionSettings,
+    A: BurnModuleAdapter,
+{
+    // Read the safetensors file and return a map of Candle tensors
+    let tensors: HashMap<String, CandleTensor> = safetensors::load(path, &Device::Cpu)?
+        .into_iter()
+        .map(|(key, tensor)| (key, CandleTensor(tensor)))
+        .collect();
+
+    // Convert the map of Candle tensors to a nested value data structure
+    let nested_value = unflatten::<PS, _>(tensors)?;
+
+    // The fields that aren't tensors are not saved in the file, so they get their default value
+    let deserializer = Deserializer::<A>::new(nested_value, true);
+
+    // Deserialize the nested value into a record type
+    let value = D::deserialize(deserializer)?;
+    Ok(value)
+}
@@ -0,0 +1,126 @@
+use core::marker::PhantomData;
+use std::path::PathBuf;
+
+use burn::{
+    record::{FileRecorder, PrecisionSettings, Record, Recorder, RecorderError},
+    tensor::backend::Backend,
+};
+
+use burn::record::serde::adapter::DefaultAdapter;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{reader::from_file, writer::to_file};
+use crate::common::adapter::PyTorchAdapter;
+
+/// The layout of the tensors of a safetensors file, used to adapt them to the Burn modules when
+/// loading.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterType {
+    /// The layout of the Burn modules, as saved by the [recorder](SafetensorsFileRecorder).
+    #[default]
+    Burn,
+    /// The layout of the PyTorch modules, e.g. a file saved with `safetensors.torch.save_file`.
+    ///
+    /// The weights of the linear modules are transposed, and the `weight` and `bias` of the
+    /// normalization modules are loaded as their `gamma` and `beta`, like with the
+    /// PyTorch recorder.
+    PyTorch,
+}
+
+/// A recorder that saves and loads Burn records as [safetensors](https://huggingface.co/docs/safetensors)
+/// files (`.safetensors`).
+///
+/// # Tensor names
+///
+/// Each tensor is named with the path of its field in the module, the names of the fields being
+/// joined with dots and the items of a vector being named by their index, e.g.
+/// `blocks.0.linear.weight`. The tensors keep the layout of the Burn modules, so the weight of a
+/// [linear](burn::nn::Linear) module has the shape `[d_input, d_output]`, transposed compared to
+/// PyTorch. The files with the PyTorch layout can be loaded with the
+/// [PyTorch adapter](AdapterType::PyTorch).
+///
+/// Only the tensors are saved: the parameter ids are regenerated when loading, and the fields that
+/// aren't tensors get their default value.
+///
+/// # Element types
+///
+/// The tensors are saved with the float and int element types of the precision settings, e.g.
+/// `F16` with [HalfPrecisionSettings](burn::record::HalfPrecisionSettings), and boolean tensors
+/// are saved as `U8`. When loading, the tensors of any type are converted to the precision
+/// settings.
+#[derive(new, Debug, Default, Clone)]
+pub struct SafetensorsFileRecorder<PS: PrecisionSettings> {
+    #[new(default)]
+    adapter: AdapterType,
+    _settings: PhantomData<PS>,
+}
+
+impl<PS: PrecisionSettings> SafetensorsFileRecorder<PS> {
+    /// Set the [adapter](AdapterType) used to load the tensors.
+    ///
+    /// The records are always saved with the layout of the Burn modules, so saving fails with the
+    /// [PyTorch adapter](AdapterType::PyTorch).
+    pub fn with_adapter(mut self, adapter: AdapterType) -> Self {
+        self.adapter = adapter;
+        self
+    }
+}
+
+impl<PS: PrecisionSettings, B: Backend> Recorder<B> for SafetensorsFileRecorder<PS> {
+    type Settings = PS;
+    type RecordArgs = PathBuf;
+    type RecordOutput = ();
+    type LoadArgs = PathBuf;
+
+    fn save_item<I: Serialize>(
+        &self,
+        item: I,
+        mut file: Self::RecordArgs,
+    ) -> Result<(), RecorderError> {
+        if self.adapter != AdapterType::Burn {
+            return Err(RecorderError::Unknown(format!(
+                "Records can't be saved with the {:?} adapter",
+                self.adapter
+            )));
+        }
+
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+
+        to_file::<PS, _>(&item, &file).map_err(|err| RecorderError::Unknown(err.to_string()))
+    }
+
+    fn load_item<I: DeserializeOwned>(&self, mut file: Self::LoadArgs) -> Result<I, RecorderError> {
+        file.set_extension(<Self as FileRecorder<B>>::file_extension());
+
+        if !file.exists() {
+            return Err(RecorderError::FileNotFound(file.display().to_string()));
+        }
+
+        let item = match self.adapter {
+            AdapterType::Burn => from_file::<PS, I, DefaultAdapter>(&file)?,
+            AdapterType::PyTorch => from_file::<PS, I, PyTorchAdapter<PS, B>>(&file)?,
+        };
+
+        Ok(item)
+    }
+
+    // The record is saved without the metadata, which can't be stored as tensors.
+    fn record<R: Record<B>>(&self, record: R, args: Self::RecordArgs) -> Result<(), RecorderError> {
+        self.save_item(record.into_item::<PS>(), args)
+    }
+
+    fn load<R: Record<B>>(
+        &self,
+        args: Self::LoadArgs,
+        device: &B::Device,
+    ) -> Result<R, RecorderError> {
+        let item = self.load_item::<R::Item<PS>>(args)?;
+        Ok(R::from_item(item, device))
+    }
+}
+
+impl<PS: PrecisionSettings, B: Backend> FileRecorder<B> for SafetensorsFileRecorder<PS> {
+    fn file_extension() -> &'static str {
+        "safetensors"
+    }
+}
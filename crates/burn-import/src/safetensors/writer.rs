@@ -0,0 +1,151 @@
+use core::any::TypeId;
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::error::Error;
+
+use burn::record::{
+    serde::{data::NestedValue, ser::Serializer},
+    PrecisionSettings,
+};
+
+use candle_core::{safetensors, Device, Tensor};
+use half::{bf16, f16};
+use serde::Serialize;
+
+/// Serializes the tensors of an item to a safetensors file.
+///
+/// # Arguments
+///
+/// * `item` - The item to serialize.
+/// * `path` - The path of the file to write.
+pub fn to_file<PS, I>(item: &I, path: &Path) -> Result<(), Error>
+where
+    PS: PrecisionSettings,
+    I: Serialize,
+{
+    // Convert the item to a nested value data structure
+    let nested_value = item.serialize(Serializer::new())?;
+
+    // Collect the tensors with the path of their field as name
+    let mut tensors = HashMap::new();
+    flatten::<PS>(nested_value, String::new(), &mut tensors)?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    safetensors::save(&tensors, path)?;
+
+    Ok(())
+}
+
+/// Collects the tensors of a nested value, naming them with the keys leading to them joined with
+/// dots.
+fn flatten<PS: PrecisionSettings>(
+    value: NestedValue,
+    name: String,
+    tensors: &mut HashMap<String, Tensor>,
+) -> Result<(), Error> {
+    let child = |key: &str| match name.is_empty() {
+        true => key.to_string(),
+        false => format!("{name}.{key}"),
+    };
+
+    match value {
+        NestedValue::Map(map) if is_param(&map) => {
+            let tensor = param_to_tensor::<PS>(map)?;
+            tensors.insert(name, tensor);
+        }
+        NestedValue::Map(map) => {
+            for (key, value) in map {
+                flatten::<PS>(value, child(&key), tensors)?;
+            }
+        }
+        NestedValue::Vec(values) => {
+            for (index, value) in values.into_iter().enumerate() {
+                flatten::<PS>(value, child(&index.to_string()), tensors)?;
+            }
+        }
+        // Constants and other values that aren't tensors are not saved.
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Whether the map is a serialized parameter, with an id and the values and shape of a tensor.
+fn is_param(map: &HashMap<String, NestedValue>) -> bool {
+    let param = match (map.get("id"), map.get("param")) {
+        (Some(NestedValue::String(_)), Some(NestedValue::Map(param))) => param,
+        _ => return false,
+    };
+
+    matches!(
+        (param.get("value"), param.get("shape")),
+        (Some(NestedValue::Vec(_)), Some(NestedValue::Vec(_)))
+    )
+}
+
+/// Converts a serialized parameter to a Candle tensor with the element type of the values.
+fn param_to_tensor<PS: PrecisionSettings>(
+    mut map: HashMap<String, NestedValue>,
+) -> Result<Tensor, Error> {
+    let mut param = map
+        .remove("param")
+        .and_then(NestedValue::as_map)
+        .ok_or_else(|| Error::Other("Missing parameter data".to_string()))?;
+
+    let (values, shape) = match (param.remove("value"), param.remove("shape")) {
+        (Some(NestedValue::Vec(values)), Some(NestedValue::Vec(shape))) => (values, shape),
+        _ => return Err(Error::Other("Invalid parameter data".to_string())),
+    };
+    let shape = convert(shape, |value| value.as_u64().map(|dim| dim as usize))?;
+    let device = Device::Cpu;
+
+    let tensor = match values.first() {
+        Some(NestedValue::F32(_)) => {
+            Tensor::from_vec(convert(values, NestedValue::as_f32)?, shape, &device)?
+        }
+        Some(NestedValue::F64(_)) => {
+            Tensor::from_vec(convert(values, NestedValue::as_f64)?, shape, &device)?
+        }
+        // Half precision floats are serialized as their bits.
+        Some(NestedValue::U16(_)) if TypeId::of::<PS::FloatElem>() == TypeId::of::<bf16>() => {
+            let values = convert(values, |value| value.as_u16().map(bf16::from_bits))?;
+            Tensor::from_vec(values, shape, &device)?
+        }
+        Some(NestedValue::U16(_)) => {
+            let values = convert(values, |value| value.as_u16().map(f16::from_bits))?;
+            Tensor::from_vec(values, shape, &device)?
+        }
+        Some(NestedValue::I16(_) | NestedValue::I32(_) | NestedValue::I64(_)) => {
+            Tensor::from_vec(convert(values, NestedValue::as_i64)?, shape, &device)?
+        }
+        // Boolean tensors are saved as bytes, like PyTorch does.
+        Some(NestedValue::Bool(_)) => {
+            let values = convert(values, |value| value.as_bool().map(u8::from))?;
+            Tensor::from_vec(values, shape, &device)?
+        }
+        Some(value) => {
+            return Err(Error::Other(format!(
+                "Unsupported tensor element: {value:?}"
+            )))
+        }
+        None => Tensor::from_vec(Vec::<f32>::new(), shape, &device)?,
+    };
+
+    Ok(tensor)
+}
+
+/// Converts the nested values to elements, failing if one of them can't be converted.
+fn convert<T>(
+    values: Vec<NestedValue>,
+    element: impl Fn(NestedValue) -> Option<T>,
+) -> Result<Vec<T>, Error> {
+    values
+        .into_iter()
+        .map(|value| {
+            element(value).ok_or_else(|| Error::Other("Unexpected tensor element".to_string()))
+        })
+        .collect()
+}
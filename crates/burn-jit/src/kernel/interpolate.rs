@@ -32,7 +32,8 @@ pub(crate) fn interpolate<R: Runtime, E: JitElement + Element>(
     let shape_out = Shape::new([batch_size, channels, out_height, out_width]);
     let output = empty_device(input.client.clone(), input.device.clone(), shape_out);
 
-    let info = build_info(&[&input, &output]);
+    let mut info = build_info(&[&input, &output]);
+    info.push(options.align_corners as u32);
 
     let info_handle = input.client.create(bytemuck::cast_slice(&info));
 
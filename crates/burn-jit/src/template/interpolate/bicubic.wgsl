@@ -8,7 +8,7 @@ var<storage, read_write> output: array<{{ elem }}>;
 
 @group(0)
 @binding(2)
-var<storage, read> info: array<u32, 17>;
+var<storage, read> info: array<u32, 18>;
 
 const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
 
@@ -28,6 +28,14 @@ fn cubic_interp1d(x0: f32, x1: f32, x2: f32, x3: f32, t: f32) -> f32 {
     return x0 * coeffs0 + x1 * coeffs1 + x2 * coeffs2 + x3 * coeffs3;
 }
 
+fn source_index(index: u32, input_size: u32, output_size: u32, align_corners: u32) -> f32 {
+    if align_corners == 1u {
+        return f32(index) * f32(input_size - 1u) / f32(output_size - 1u);
+    }
+
+    return (f32(index) + 0.5) * f32(input_size) / f32(output_size) - 0.5;
+}
+
 @compute
 @workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
 fn main(
@@ -59,25 +67,27 @@ fn main(
     let h = id / output_stride_2 % output_shape_2;
     let w = id / output_stride_3 % output_shape_3;
 
+    let align_corners = info[17];
+
     let input_height = f32(input_shape_2 - 1u);
-    let y_frac = f32(h) * input_height / f32(output_shape_2 - 1u);
+    let y_frac = source_index(h, input_shape_2, output_shape_2, align_corners);
     let y_in = floor(y_frac);
     let yw = y_frac - y_in;
 
-    let y0 = u32(max(y_in - 1.0, 0.0));
-    let y1 = u32(y_in);
-    let y2 = u32(min(y_in + 1.0, input_height));
-    let y3 = u32(min(y_in + 2.0, input_height));
+    let y0 = u32(clamp(y_in - 1.0, 0.0, input_height));
+    let y1 = u32(clamp(y_in, 0.0, input_height));
+    let y2 = u32(clamp(y_in + 1.0, 0.0, input_height));
+    let y3 = u32(clamp(y_in + 2.0, 0.0, input_height));
 
     let input_width = f32(input_shape_3 - 1u);
-    let x_frac = f32(w) * input_width / f32(output_shape_3 - 1u);
+    let x_frac = source_index(w, input_shape_3, output_shape_3, align_corners);
     let x_in = floor(x_frac);
     let xw = x_frac - x_in;
 
-    let x0 = u32(max(x_in - 1.0, 0.0));
-    let x1 = u32(x_in);
-    let x2 = u32(min(x_in + 1.0, input_width));
-    let x3 = u32(min(x_in + 2.0, input_width));
+    let x0 = u32(clamp(x_in - 1.0, 0.0, input_width));
+    let x1 = u32(clamp(x_in, 0.0, input_width));
+    let x2 = u32(clamp(x_in + 1.0, 0.0, input_width));
+    let x3 = u32(clamp(x_in + 2.0, 0.0, input_width));
 
     let coefficients0 = cubic_interp1d(
         input[b * input_stride_0 + c * input_stride_1 + y0 * input_stride_2 + x0 * input_stride_3],
@@ -8,10 +8,18 @@ var<storage, read_write> output: array<{{ elem }}>;
 
 @group(0)
 @binding(2)
-var<storage, read> info: array<u32, 17>;
+var<storage, read> info: array<u32, 18>;
 
 const WORKGROUP_SIZE_X = {{ workgroup_size_x }}u;
 
+fn source_index(index: u32, input_size: u32, output_size: u32, align_corners: u32) -> f32 {
+    if align_corners == 1u {
+        return f32(index) * f32(input_size - 1u) / f32(output_size - 1u);
+    }
+
+    return (f32(index) + 0.5) * f32(input_size) / f32(output_size) - 0.5;
+}
+
 @compute
 @workgroup_size({{ workgroup_size_x }}, {{ workgroup_size_y }}, 1)
 fn main(
@@ -43,14 +51,17 @@ fn main(
     let h = id / output_stride_2 % output_shape_2;
     let w = id / output_stride_3 % output_shape_3;
 
-    let y_frac = f32(h) * f32(input_shape_2 - 1u) / f32(output_shape_2 - 1u);
+    let align_corners = info[17];
+
+    // The positions before the first pixel center are clamped to the border.
+    let y_frac = max(source_index(h, input_shape_2, output_shape_2, align_corners), 0.0);
     let y0 = floor(y_frac);
-    let y1 = ceil(y_frac);
+    let y1 = min(y0 + 1.0, f32(input_shape_2 - 1u));
     let yw = y_frac - y0;
 
-    let x_frac = f32(w) * f32(input_shape_3 - 1u) / f32(output_shape_3 - 1u);
+    let x_frac = max(source_index(w, input_shape_3, output_shape_3, align_corners), 0.0);
     let x0 = floor(x_frac);
-    let x1 = ceil(x_frac);
+    let x1 = min(x0 + 1.0, f32(input_shape_3 - 1u));
     let xw = x_frac - x0;
 
     let x0u = u32(x0);
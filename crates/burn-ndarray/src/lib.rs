@@ -22,6 +22,7 @@ mod tensor;
 
 pub use backend::*;
 pub use element::FloatNdArrayElement;
+pub use parallel::set_parallel_reduction_threshold;
 pub(crate) use sharing::*;
 pub use tensor::*;
 
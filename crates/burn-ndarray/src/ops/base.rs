@@ -16,6 +16,7 @@ use ndarray::SliceInfoElem;
 
 use crate::element::NdArrayElement;
 use crate::ops::macros::{keepdim, mean_dim, prod_dim, sum_dim};
+use crate::ops::reduce;
 use crate::{reshape, tensor::NdArrayTensor};
 
 pub struct NdArrayOps<E> {
@@ -150,84 +151,68 @@ where
         lhs: NdArrayTensor<E, D>,
         rhs: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let array = &lhs.array + &rhs.array;
-        let array = array.into_shared();
-
-        NdArrayTensor { array }
+        Self::zip_inplace(lhs, rhs, |lhs, rhs| lhs + rhs)
     }
 
     pub fn add_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
-        let array = lhs.array + rhs;
-        let array = array.into_shared();
-
-        NdArrayTensor { array }
+        Self::map_inplace(lhs, |lhs| lhs + rhs)
     }
 
     pub fn sub<const D: usize>(
         lhs: NdArrayTensor<E, D>,
         rhs: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let array = lhs.array - rhs.array;
-        let array = array.into_shared();
-
-        NdArrayTensor { array }
+        Self::zip_inplace(lhs, rhs, |lhs, rhs| lhs - rhs)
     }
 
     pub fn sub_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
-        let array = lhs.array - rhs;
-        let array = array.into_shared();
-
-        NdArrayTensor { array }
+        Self::map_inplace(lhs, |lhs| lhs - rhs)
     }
 
     pub fn mul<const D: usize>(
         lhs: NdArrayTensor<E, D>,
         rhs: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let array = lhs.array * rhs.array;
-        let array = array.into_shared();
-
-        NdArrayTensor { array }
+        Self::zip_inplace(lhs, rhs, |lhs, rhs| lhs * rhs)
     }
 
     pub fn mul_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
-        let array = lhs.array * rhs;
-        let array = array.into_shared();
-
-        NdArrayTensor { array }
+        Self::map_inplace(lhs, |lhs| lhs * rhs)
     }
 
     pub fn div<const D: usize>(
         lhs: NdArrayTensor<E, D>,
         rhs: NdArrayTensor<E, D>,
     ) -> NdArrayTensor<E, D> {
-        let array = lhs.array / rhs.array;
-        let array = array.into_shared();
-
-        NdArrayTensor { array }
+        Self::zip_inplace(lhs, rhs, |lhs, rhs| lhs / rhs)
     }
 
     pub fn div_scalar<const D: usize>(lhs: NdArrayTensor<E, D>, rhs: E) -> NdArrayTensor<E, D> {
-        let array = lhs.array / rhs;
-        let array = array.into_shared();
-
-        NdArrayTensor { array }
+        Self::map_inplace(lhs, |lhs| lhs / rhs)
     }
 
     pub fn recip<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.map(|x| 1.elem::<E>() / *x);
-        let array = array.into_shared();
-
-        NdArrayTensor { array }
+        Self::map_inplace(tensor, |x| 1.elem::<E>() / x)
     }
 
     pub fn mean<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
-        let data = Data::from([tensor.array.mean().unwrap()]);
+        let length = E::from_usize(tensor.array.len()).unwrap();
+        let data = Data::from([reduce::sum(tensor.array.view()) / length]);
         NdArrayTensor::from_data(data)
     }
 
     pub fn sum<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
-        let data = Data::from([tensor.array.sum()]);
+        let data = Data::from([reduce::sum(tensor.array.view())]);
+        NdArrayTensor::from_data(data)
+    }
+
+    pub fn max<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        let data = Data::from([reduce::max(tensor.array.view())]);
+        NdArrayTensor::from_data(data)
+    }
+
+    pub fn min<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        let data = Data::from([reduce::min(tensor.array.view())]);
         NdArrayTensor::from_data(data)
     }
 
@@ -471,44 +456,32 @@ where
         arg(tensor, dim, CmpType::Min)
     }
 
-    pub fn clamp_min<const D: usize>(
-        mut tensor: NdArrayTensor<E, D>,
-        min: E,
-    ) -> NdArrayTensor<E, D> {
-        tensor.array.mapv_inplace(|x| match x < min {
+    pub fn clamp_min<const D: usize>(tensor: NdArrayTensor<E, D>, min: E) -> NdArrayTensor<E, D> {
+        Self::map_inplace(tensor, |x| match x < min {
             true => min,
             false => x,
-        });
-
-        tensor
+        })
     }
 
-    pub fn clamp_max<const D: usize>(
-        mut tensor: NdArrayTensor<E, D>,
-        max: E,
-    ) -> NdArrayTensor<E, D> {
-        tensor.array.mapv_inplace(|x| match x > max {
+    pub fn clamp_max<const D: usize>(tensor: NdArrayTensor<E, D>, max: E) -> NdArrayTensor<E, D> {
+        Self::map_inplace(tensor, |x| match x > max {
             true => max,
             false => x,
-        });
-
-        tensor
+        })
     }
 
     pub fn clamp<const D: usize>(
-        mut tensor: NdArrayTensor<E, D>,
+        tensor: NdArrayTensor<E, D>,
         min: E,
         max: E,
     ) -> NdArrayTensor<E, D> {
-        tensor.array.mapv_inplace(|x| match x < min {
+        Self::map_inplace(tensor, |x| match x < min {
             true => min,
             false => match x > max {
                 true => max,
                 false => x,
             },
-        });
-
-        tensor
+        })
     }
 
     pub(crate) fn elementwise_op<const D: usize, OtherE>(
@@ -528,7 +501,74 @@ where
         lhs: NdArrayTensor<E, D>,
         var_name: impl FnMut(E) -> E,
     ) -> NdArrayTensor<E, D> {
-        NdArrayTensor::new(lhs.array.mapv(var_name).into_shared())
+        Self::map_inplace(lhs, var_name)
+    }
+
+    /// Applies an elementwise operation, in place when the tensor is the only owner of its buffer.
+    pub(crate) fn map_inplace<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        op: impl FnMut(E) -> E,
+    ) -> NdArrayTensor<E, D> {
+        let array = match tensor.try_into_unique() {
+            Ok(mut array) => {
+                array.mapv_inplace(op);
+                array.into_shared()
+            }
+            Err(tensor) => tensor.array.mapv(op).into_shared(),
+        };
+
+        NdArrayTensor::new(array)
+    }
+
+    /// Applies an elementwise operation on two broadcastable tensors, in place in the buffer of
+    /// an input when it is the only owner of that buffer and has the shape of the output.
+    pub(crate) fn zip_inplace<const D: usize>(
+        lhs: NdArrayTensor<E, D>,
+        rhs: NdArrayTensor<E, D>,
+        op: impl Fn(E, E) -> E,
+    ) -> NdArrayTensor<E, D> {
+        let shape = lhs
+            .array
+            .shape()
+            .iter()
+            .zip(rhs.array.shape())
+            .map(|(&lhs, &rhs)| if lhs == 1 { rhs } else { lhs })
+            .collect::<Vec<_>>();
+
+        let lhs = match lhs.array.shape() == shape.as_slice() {
+            true => match lhs.try_into_unique() {
+                Ok(mut array) => {
+                    array.zip_mut_with(&rhs.array, |lhs, &rhs| *lhs = op(*lhs, rhs));
+                    return NdArrayTensor::new(array.into_shared());
+                }
+                Err(lhs) => lhs,
+            },
+            false => lhs,
+        };
+        let rhs = match rhs.array.shape() == shape.as_slice() {
+            true => match rhs.try_into_unique() {
+                Ok(mut array) => {
+                    array.zip_mut_with(&lhs.array, |rhs, &lhs| *rhs = op(lhs, *rhs));
+                    return NdArrayTensor::new(array.into_shared());
+                }
+                Err(rhs) => rhs,
+            },
+            false => rhs,
+        };
+
+        let lhs = lhs
+            .array
+            .broadcast(shape.as_slice())
+            .expect("The lhs tensor should be broadcastable to the output shape");
+        let rhs = rhs
+            .array
+            .broadcast(shape.as_slice())
+            .expect("The rhs tensor should be broadcastable to the output shape");
+        let array = Zip::from(lhs)
+            .and(rhs)
+            .map_collect(|&lhs, &rhs| op(lhs, rhs));
+
+        NdArrayTensor::new(array.into_shared())
     }
 
     pub(crate) fn sign_op<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D>
@@ -593,6 +633,56 @@ fn arg<E: NdArrayElement, const D: usize>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::NdArray;
+    use burn_tensor::ops::ActivationOps;
+
+    fn chain(lhs: NdArrayTensor<f32, 2>, rhs: NdArrayTensor<f32, 2>) -> NdArrayTensor<f32, 2> {
+        let tensor = NdArrayMathOps::add(lhs, rhs.clone());
+        let tensor = NdArrayMathOps::mul_scalar(tensor, 2.0);
+        let tensor = NdArray::<f32>::relu(tensor);
+        let tensor = NdArrayMathOps::sub(tensor, rhs);
+        NdArrayMathOps::clamp_max(tensor, 8.0)
+    }
+
+    #[test]
+    fn inplace_chain_should_match_the_out_of_place_chain() {
+        let lhs =
+            NdArrayTensor::<f32, 2>::from_data(Data::from([[1.0, -2.0, 3.0], [-4.0, 5.0, -6.0]]));
+        let rhs = NdArrayTensor::<f32, 2>::from_data(Data::from([[0.5, 1.5, -2.5]]));
+
+        // The input is shared with `lhs`, so each operation allocates a new buffer.
+        let expected = chain(lhs.clone(), rhs.clone());
+        assert_eq!(
+            lhs.array.iter().copied().collect::<Vec<_>>(),
+            vec![1.0, -2.0, 3.0, -4.0, 5.0, -6.0]
+        );
+
+        // The input is uniquely owned, so its buffer is updated in place by each operation.
+        let buffer = lhs.array.as_ptr();
+        let output = chain(lhs, rhs);
+
+        assert_eq!(output.array.as_ptr(), buffer);
+        assert_eq!(output.array, expected.array);
+        assert_eq!(
+            output.array.iter().copied().collect::<Vec<_>>(),
+            vec![2.5, -1.5, 3.5, -0.5, 8.0, 2.5]
+        );
+    }
+
+    #[test]
+    fn inplace_binary_op_should_reuse_the_rhs_when_the_lhs_is_broadcasted() {
+        let lhs = NdArrayTensor::<f32, 2>::from_data(Data::from([[10.0, 20.0]]));
+        let rhs = NdArrayTensor::<f32, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]));
+        let buffer = rhs.array.as_ptr();
+
+        let output = NdArrayMathOps::sub(lhs, rhs);
+
+        assert_eq!(output.array.as_ptr(), buffer);
+        assert_eq!(
+            output.array.iter().copied().collect::<Vec<_>>(),
+            vec![9.0, 18.0, 7.0, 16.0]
+        );
+    }
 
     #[test]
     fn should_generate_row_major_layout_for_cat() {
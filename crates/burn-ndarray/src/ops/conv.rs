@@ -1,8 +1,11 @@
 use burn_tensor::{
-    ops::{conv::calculate_conv_output_size, ConvOptions, ConvTransposeOptions},
+    ops::{
+        conv::{calculate_conv_output_size, calculate_conv_transpose_output_size},
+        ConvOptions, ConvTransposeOptions,
+    },
     ElementConversion,
 };
-use ndarray::{s, Array3, Array4, ArrayView2, ArrayViewMut2, Axis, Dim};
+use ndarray::{s, Array3, Array4, Array5, ArrayView2, ArrayViewMut2, Axis, Dim};
 
 use crate::{
     element::FloatNdArrayElement, iter_par, iter_range_par, ops::padding::apply_padding_4d,
@@ -203,7 +206,7 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
         iter_range_par!(0, batch_size * out_channels * options.groups).for_each(|k| unsafe {
             let b = k / (out_channels * options.groups);
             let oc = k % out_channels;
-            let g = k % options.groups;
+            let g = (k / out_channels) % options.groups;
 
             let output = unsafe_shared_out.get();
 
@@ -250,3 +253,115 @@ pub(crate) fn conv_transpose2d<E: FloatNdArrayElement>(
 
     NdArrayTensor::new(output.into_dyn().into_shared())
 }
+
+pub(crate) fn conv_transpose3d<E: FloatNdArrayElement>(
+    x: NdArrayTensor<E, 5>,
+    weight: NdArrayTensor<E, 5>,
+    bias: Option<NdArrayTensor<E, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> NdArrayTensor<E, 5> {
+    let [dilation_depth, dilation_height, dilation_width] = options.dilation;
+    let [padding_depth, padding_height, padding_width] = options.padding;
+    let [stride_depth, stride_height, stride_width] = options.stride;
+    let [out_padding_depth, out_padding_height, out_padding_width] = options.padding_out;
+    let [batch_size, _in_channels, in_depth, in_height, in_width] = x.shape().dims;
+    let [in_channels, out_channels, kernel_depth, kernel_height, kernel_width] =
+        weight.shape().dims;
+
+    let out_depth = calculate_conv_transpose_output_size(
+        kernel_depth,
+        stride_depth,
+        padding_depth,
+        out_padding_depth,
+        dilation_depth,
+        in_depth,
+    );
+    let out_height = calculate_conv_transpose_output_size(
+        kernel_height,
+        stride_height,
+        padding_height,
+        out_padding_height,
+        dilation_height,
+        in_height,
+    );
+    let out_width = calculate_conv_transpose_output_size(
+        kernel_width,
+        stride_width,
+        padding_width,
+        out_padding_width,
+        dilation_width,
+        in_width,
+    );
+
+    let x = x.array;
+    let mut output = Array5::zeros(Dim([
+        batch_size,
+        out_channels * options.groups,
+        out_depth,
+        out_height,
+        out_width,
+    ]));
+
+    let unsafe_shared_out = UnsafeSharedRef::new(&mut output);
+
+    run_par!(|| {
+        iter_range_par!(0, batch_size * out_channels * options.groups).for_each(|k| unsafe {
+            let b = k / (out_channels * options.groups);
+            let oc = k % out_channels;
+            let g = (k / out_channels) % options.groups;
+
+            let output = unsafe_shared_out.get();
+
+            let oc_out = oc + (out_channels * g);
+            let ic_start = g * (in_channels / options.groups);
+            let ic_end = ic_start + in_channels / options.groups;
+
+            for ic in ic_start..ic_end {
+                for id in 0..in_depth {
+                    for ih in 0..in_height {
+                        for iw in 0..in_width {
+                            for kd in 0..kernel_depth {
+                                for kh in 0..kernel_height {
+                                    for kw in 0..kernel_width {
+                                        let od = id * stride_depth + kd * dilation_depth;
+                                        let oh = ih * stride_height + kh * dilation_height;
+                                        let ow = iw * stride_width + kw * dilation_width;
+
+                                        if od >= out_depth + padding_depth
+                                            || oh >= out_height + padding_height
+                                            || ow >= out_width + padding_width
+                                            || od < padding_depth
+                                            || oh < padding_height
+                                            || ow < padding_width
+                                        {
+                                            continue;
+                                        }
+
+                                        let od = od - padding_depth;
+                                        let oh = oh - padding_height;
+                                        let ow = ow - padding_width;
+
+                                        output[[b, oc_out, od, oh, ow]] += x[[b, ic, id, ih, iw]]
+                                            * weight.array[[ic, oc, kd, kh, kw]];
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(bias) = &bias {
+                for od in 0..out_depth {
+                    for oh in 0..out_height {
+                        for ow in 0..out_width {
+                            output[[b, oc_out, od, oh, ow]] += bias.array[oc_out];
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    NdArrayTensor::new(output.into_dyn().into_shared())
+}
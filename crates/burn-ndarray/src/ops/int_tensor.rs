@@ -334,6 +334,14 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
     ) -> NdArrayTensor<i64, D> {
         NdArrayMathOps::select_assign(tensor, dim, indices, value)
     }
+    fn int_max<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, 1> {
+        NdArrayMathOps::max(tensor)
+    }
+
+    fn int_min<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, 1> {
+        NdArrayMathOps::min(tensor)
+    }
+
     fn int_argmax<const D: usize>(
         tensor: NdArrayTensor<i64, D>,
         dim: usize,
@@ -371,9 +379,7 @@ impl<E: FloatNdArrayElement> IntTensorOps<Self> for NdArray<E> {
     }
 
     fn int_abs<const D: usize>(tensor: NdArrayTensor<i64, D>) -> NdArrayTensor<i64, D> {
-        let array = tensor.array.mapv_into(|a| a.int_abs_elem()).into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| a.int_abs_elem())
     }
 
     fn int_into_float<const D: usize>(
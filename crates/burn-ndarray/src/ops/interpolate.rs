@@ -86,18 +86,25 @@ fn start_index(output_size_index: usize, output_size: usize, input_size: usize)
     libm::floorf((output_size_index as f32 * input_size as f32) / output_size as f32) as usize
 }
 
+/// The position in the input of the output index, with the corners aligned or with the centers of
+/// the pixels aligned (`half_pixel`).
+fn source_index(index: usize, input_size: usize, output_size: usize, align_corners: bool) -> f64 {
+    match align_corners {
+        true => index as f64 * (input_size - 1) as f64 / (output_size - 1) as f64,
+        false => (index as f64 + 0.5) * input_size as f64 / output_size as f64 - 0.5,
+    }
+}
+
 pub(crate) fn bilinear_interpolate<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
     output_size: [usize; 2],
+    align_corners: bool,
 ) -> NdArrayTensor<E, 4> {
     let x = x.array.into_dimensionality::<ndarray::Ix4>().unwrap();
 
     let (batch_size, channels, in_height, in_width) = x.dim();
     let [out_height, out_width] = output_size;
 
-    let y_ratio = ((in_height - 1) as f64) / ((out_height - 1) as f64);
-    let x_ratio = ((in_width - 1) as f64) / ((out_width - 1) as f64);
-
     let out_element_num = batch_size * channels * out_height * out_width;
     let strides = (
         channels * out_height * out_width,
@@ -118,17 +125,17 @@ pub(crate) fn bilinear_interpolate<E: FloatNdArrayElement>(
             );
 
             // We convert everything to `f64` for calculations and then back to `E` at the end.
-            let y_frac = y_ratio * h as f64;
+            // The positions before the first pixel center are clamped to the border.
+            let y_frac = source_index(h, in_height, out_height, align_corners).max(0.0);
             let y0 = y_frac.floor();
-            let y1 = y_frac.ceil();
             let yw = y_frac - y0;
 
-            let x_frac = x_ratio * w as f64;
+            let x_frac = source_index(w, in_width, out_width, align_corners).max(0.0);
             let x0 = x_frac.floor();
-            let x1 = x_frac.ceil();
             let xw = x_frac - x0;
 
-            let (x0, x1, y0, y1) = (x0 as usize, x1 as usize, y0 as usize, y1 as usize);
+            let (x0, y0) = (x0 as usize, y0 as usize);
+            let (x1, y1) = ((x0 + 1).min(in_width - 1), (y0 + 1).min(in_height - 1));
 
             let p_a = x[(b, c, y0, x0)].elem::<f64>() * (1.0 - xw) * (1.0 - yw);
             let p_b = x[(b, c, y0, x1)].elem::<f64>() * xw * (1.0 - yw);
@@ -148,6 +155,7 @@ pub(crate) fn bilinear_interpolate<E: FloatNdArrayElement>(
 pub(crate) fn bicubic_interpolate<E: FloatNdArrayElement>(
     x: NdArrayTensor<E, 4>,
     output_size: [usize; 2],
+    align_corners: bool,
 ) -> NdArrayTensor<E, 4> {
     fn cubic_interp1d(x0: f64, x1: f64, x2: f64, x3: f64, t: f64) -> f64 {
         fn cubic_convolution1(x: f64, a: f64) -> f64 {
@@ -173,9 +181,6 @@ pub(crate) fn bicubic_interpolate<E: FloatNdArrayElement>(
     let (batch_size, channels, in_height, in_width) = x.dim();
     let [out_height, out_width] = output_size;
 
-    let y_ratio = ((in_height - 1) as f64) / ((out_height - 1) as f64);
-    let x_ratio = ((in_width - 1) as f64) / ((out_width - 1) as f64);
-
     let out_element_num = batch_size * channels * out_height * out_width;
     let strides = (
         channels * out_height * out_width,
@@ -195,31 +200,21 @@ pub(crate) fn bicubic_interpolate<E: FloatNdArrayElement>(
                 id % strides.2,
             );
 
-            let y_frac = y_ratio * h as f64;
+            let y_frac = source_index(h, in_height, out_height, align_corners);
             let y0 = y_frac.floor();
             let yw = y_frac - y0;
-            let y_in = y0 as usize;
+            let y_in = y0 as i64;
 
-            let x_frac = x_ratio * w as f64;
+            let x_frac = source_index(w, in_width, out_width, align_corners);
             let x0 = x_frac.floor();
             let xw = x_frac - x0;
-            let x_in = x0 as usize;
-
-            let ys_in = [
-                if y_in == 0 { 0 } else { y_in - 1 },
-                y_in,
-                y_in + 1,
-                y_in + 2,
-            ]
-            .map(|y| y.min(in_height - 1));
-
-            let xs_in = [
-                if x_in == 0 { 0 } else { x_in - 1 },
-                x_in,
-                x_in + 1,
-                x_in + 2,
-            ]
-            .map(|x| x.min(in_width - 1));
+            let x_in = x0 as i64;
+
+            // The neighbors outside of the input are replaced by the border pixels.
+            let ys_in = [y_in - 1, y_in, y_in + 1, y_in + 2]
+                .map(|y| y.clamp(0, in_height as i64 - 1) as usize);
+            let xs_in = [x_in - 1, x_in, x_in + 1, x_in + 2]
+                .map(|x| x.clamp(0, in_width as i64 - 1) as usize);
 
             let coefficients = ys_in.map(|y| {
                 cubic_interp1d(
@@ -37,13 +37,16 @@ macro_rules! keepdim {
 pub(crate) use keepdim;
 use ndarray::Axis;
 
-use crate::{element::NdArrayElement, tensor::NdArrayTensor};
+use crate::{element::NdArrayElement, ops::reduce, tensor::NdArrayTensor};
 
 pub(crate) fn mean_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
     tensor: NdArrayTensor<E, D1>,
     dim: usize,
 ) -> NdArrayTensor<E, D2> {
-    let array = tensor.array.mean_axis(Axis(dim)).unwrap().into_shared();
+    let length = E::from_usize(tensor.array.len_of(Axis(dim))).unwrap();
+    let array = reduce::sum_axis(tensor.array.view(), dim)
+        .mapv_into(|sum| sum / length)
+        .into_shared();
 
     NdArrayTensor { array }
 }
@@ -52,7 +55,7 @@ pub(crate) fn sum_dim<E: NdArrayElement, const D1: usize, const D2: usize>(
     tensor: NdArrayTensor<E, D1>,
     dim: usize,
 ) -> NdArrayTensor<E, D2> {
-    let array = tensor.array.sum_axis(Axis(dim)).into_shared();
+    let array = reduce::sum_axis(tensor.array.view(), dim).into_shared();
 
     NdArrayTensor { array }
 }
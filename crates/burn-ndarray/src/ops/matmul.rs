@@ -1,7 +1,7 @@
 use crate::{element::FloatNdArrayElement, tensor::NdArrayTensor, NdArray};
 use crate::{iter_range_par, run_par, UnsafeSharedRef};
-use burn_tensor::ElementConversion;
 use burn_tensor::{ops::FloatTensorOps, Shape};
+use burn_tensor::{ElementConversion, ElementPrecision, Precision};
 use ndarray::s;
 
 pub(crate) fn matmul<E, const D: usize>(
@@ -27,7 +27,19 @@ where
     shape_out.dims[D - 2] = m;
     shape_out.dims[D - 1] = n;
 
-    let out = general_matmul(lhs, rhs);
+    let out = match E::precision() {
+        // There is no half precision kernel, so the product is computed in f32 and the result is
+        // stored back in half precision.
+        Precision::Half => {
+            let to_f32 = |tensor: NdArrayTensor<E, 3>| {
+                NdArrayTensor::<f32, 3>::new(tensor.array.mapv(|a| a.elem()).into_shared())
+            };
+            let out = general_matmul(to_f32(lhs), to_f32(rhs));
+
+            NdArrayTensor::new(out.array.mapv(|a| a.elem()).into_shared())
+        }
+        _ => general_matmul(lhs, rhs),
+    };
 
     NdArray::<E>::float_reshape(out, shape_out)
 }
@@ -71,6 +83,9 @@ fn general_matmul<E: FloatNdArrayElement>(
             unsafe {
                 let mut out_slice = unsafe_shared_out_array.get().slice_mut(s!(b, .., ..));
 
+                // Without BLAS, ndarray already dispatches f32 and f64 to the blocked kernels of
+                // matrixmultiply, and only uses a naive loop for the other element types.
+
                 ndarray::linalg::general_mat_mul(
                     alpha,
                     &lhs_slice,
@@ -109,3 +124,107 @@ fn batch_size<const D: usize>(shape: &Shape<D>) -> usize {
 
     num_batch
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::{bf16, Data};
+
+    /// Deterministic values in [-0.5, 0.5).
+    fn values(num_elements: usize, seed: usize) -> Vec<f64> {
+        (0..num_elements)
+            .map(|i| ((i * 7919 + seed * 104729) % 1000) as f64 / 1000.0 - 0.5)
+            .collect()
+    }
+
+    /// Naive batched matmul, broadcasting a batch of size 1.
+    fn reference_matmul(
+        lhs: &[f64],
+        rhs: &[f64],
+        [batch_lhs, batch_rhs, m, k, n]: [usize; 5],
+    ) -> Vec<f64> {
+        let batch_size = usize::max(batch_lhs, batch_rhs);
+        let mut out = vec![0.0; batch_size * m * n];
+
+        for b in 0..batch_size {
+            let lhs = &lhs[(b % batch_lhs) * m * k..];
+            let rhs = &rhs[(b % batch_rhs) * k * n..];
+
+            for i in 0..m {
+                for j in 0..n {
+                    out[b * m * n + i * n + j] =
+                        (0..k).map(|l| lhs[i * k + l] * rhs[l * n + j]).sum();
+                }
+            }
+        }
+
+        out
+    }
+
+    fn assert_matmul<E: FloatNdArrayElement>(shape_lhs: [usize; 3], shape_rhs: [usize; 3]) {
+        let [batch_lhs, m, k] = shape_lhs;
+        let [batch_rhs, _, n] = shape_rhs;
+        let (lhs, rhs) = (values(batch_lhs * m * k, 1), values(batch_rhs * k * n, 2));
+        let expected = reference_matmul(&lhs, &rhs, [batch_lhs, batch_rhs, m, k, n]);
+
+        let tensor = |values: &[f64], shape: [usize; 3]| {
+            NdArrayTensor::<E, 3>::from_data(
+                Data::new(values.to_vec(), Shape::new(shape)).convert(),
+            )
+        };
+        let out = matmul(tensor(&lhs, shape_lhs), tensor(&rhs, shape_rhs));
+
+        let batch_size = usize::max(batch_lhs, batch_rhs);
+        let expected = Data::new(expected, Shape::new([batch_size, m, n])).convert::<f32>();
+        out.into_data()
+            .convert::<f32>()
+            .assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_matmul_large_matrices_f32() {
+        assert_matmul::<f32>([1, 512, 512], [1, 512, 512]);
+    }
+
+    #[test]
+    fn should_matmul_large_matrices_f64() {
+        assert_matmul::<f64>([1, 512, 512], [1, 512, 512]);
+    }
+
+    #[test]
+    fn should_matmul_bf16() {
+        let lhs =
+            NdArrayTensor::<bf16, 2>::from_data(Data::from([[1.0, 2.0], [3.0, 4.0]]).convert());
+        let rhs =
+            NdArrayTensor::<bf16, 2>::from_data(Data::from([[5.0, 6.0], [7.0, -8.0]]).convert());
+
+        let out = matmul(lhs, rhs);
+
+        assert_eq!(
+            out.into_data().convert::<f32>(),
+            Data::from([[19.0, -10.0], [43.0, -14.0]])
+        );
+    }
+
+    #[test]
+    fn should_accumulate_bf16_matmul_in_f32() {
+        // Accumulated in bf16, the sum would stop increasing at 256, where the step between two
+        // values becomes 2.
+        let lhs = NdArrayTensor::<bf16, 2>::from_data(
+            Data::<f32, 2>::ones(Shape::new([1, 300])).convert(),
+        );
+        let rhs = NdArrayTensor::<bf16, 2>::from_data(
+            Data::<f32, 2>::ones(Shape::new([300, 1])).convert(),
+        );
+
+        let out = matmul(lhs, rhs);
+
+        assert_eq!(out.into_data().convert::<f32>(), Data::from([[300.0]]));
+    }
+
+    #[test]
+    fn should_matmul_broadcasted_batches() {
+        assert_matmul::<f32>([4, 64, 32], [1, 32, 48]);
+        assert_matmul::<f32>([1, 64, 32], [4, 32, 48]);
+    }
+}
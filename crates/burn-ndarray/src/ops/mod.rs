@@ -13,5 +13,6 @@ pub(crate) mod macros;
 pub(crate) mod matmul;
 pub(crate) mod maxpool;
 pub(crate) mod padding;
+pub(crate) mod reduce;
 
 pub(crate) use base::*;
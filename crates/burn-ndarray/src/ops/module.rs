@@ -1,7 +1,7 @@
 use super::{
     adaptive_avgpool::{adaptive_avg_pool2d, adaptive_avg_pool2d_backward},
     avgpool::{avg_pool2d, avg_pool2d_backward},
-    conv::{conv2d, conv_transpose2d},
+    conv::{conv2d, conv_transpose2d, conv_transpose3d},
     interpolate::{bicubic_interpolate, bilinear_interpolate, nearest_interpolate},
     maxpool::{max_pool2d, max_pool2d_backward, max_pool2d_with_indices},
 };
@@ -28,6 +28,15 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
         conv_transpose2d(x, weight, bias, options)
     }
 
+    fn conv_transpose3d(
+        x: NdArrayTensor<E, 5>,
+        weight: NdArrayTensor<E, 5>,
+        bias: Option<NdArrayTensor<E, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> NdArrayTensor<E, 5> {
+        conv_transpose3d(x, weight, bias, options)
+    }
+
     fn avg_pool2d(
         x: NdArrayTensor<E, 4>,
         kernel_size: [usize; 2],
@@ -109,8 +118,10 @@ impl<E: FloatNdArrayElement> ModuleOps<Self> for NdArray<E> {
     ) -> NdArrayTensor<E, 4> {
         match options.mode {
             InterpolateMode::Nearest => nearest_interpolate(x, output_size),
-            InterpolateMode::Bilinear => bilinear_interpolate(x, output_size),
-            InterpolateMode::Bicubic => bicubic_interpolate(x, output_size),
+            InterpolateMode::Bilinear => {
+                bilinear_interpolate(x, output_size, options.align_corners)
+            }
+            InterpolateMode::Bicubic => bicubic_interpolate(x, output_size, options.align_corners),
         }
     }
 
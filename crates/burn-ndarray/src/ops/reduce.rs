@@ -0,0 +1,276 @@
+//! Reductions parallelized over chunks of the reduced elements.
+//!
+//! The chunks have a fixed size, so the results don't depend on the number of threads and are
+//! deterministic. The float sums of the parallel reductions may still differ slightly from the
+//! serial ones, since the additions are grouped differently.
+
+use alloc::vec::Vec;
+use ndarray::{ArrayD, ArrayView1, ArrayViewD, Axis, Slice};
+use num_traits::Zero;
+
+use crate::{element::NdArrayElement, iter_range_par, parallel::parallel_reduction_threshold};
+
+#[cfg(feature = "std")]
+use rayon::prelude::*;
+
+/// The number of elements reduced by each task.
+const CHUNK_SIZE: usize = 1 << 14;
+
+/// Whether the reduction of the given number of elements is parallelized.
+fn is_parallel(num_elements: usize, threshold: usize) -> bool {
+    cfg!(feature = "std") && num_elements >= threshold
+}
+
+/// Reduces each chunk of the values, in parallel.
+fn reduce_chunks<E, F>(values: &[E], reduce: F) -> Vec<E>
+where
+    E: NdArrayElement,
+    F: Fn(&[E]) -> E + Send + Sync,
+{
+    #[cfg(feature = "std")]
+    let chunks = values.par_chunks(CHUNK_SIZE);
+
+    #[cfg(not(feature = "std"))]
+    let chunks = values.chunks(CHUNK_SIZE);
+
+    chunks.map(reduce).collect()
+}
+
+fn is_nan<E: NdArrayElement>(value: E) -> bool {
+    value.partial_cmp(&value).is_none()
+}
+
+/// The maximum of two values, which is NaN when any of them is NaN.
+fn max_elem<E: NdArrayElement>(lhs: E, rhs: E) -> E {
+    match is_nan(lhs) || lhs > rhs {
+        true => lhs,
+        false => rhs,
+    }
+}
+
+/// The minimum of two values, which is NaN when any of them is NaN.
+fn min_elem<E: NdArrayElement>(lhs: E, rhs: E) -> E {
+    match is_nan(lhs) || lhs < rhs {
+        true => lhs,
+        false => rhs,
+    }
+}
+
+pub(crate) fn sum<E: NdArrayElement>(array: ArrayViewD<'_, E>) -> E {
+    sum_with_threshold(array, parallel_reduction_threshold())
+}
+
+/// The maximum of the values, which is NaN when any of them is NaN.
+///
+/// # Panics
+///
+/// If the array is empty.
+pub(crate) fn max<E: NdArrayElement>(array: ArrayViewD<'_, E>) -> E {
+    fold_chunks(array, parallel_reduction_threshold(), max_elem)
+        .expect("Can't compute the maximum of an empty tensor")
+}
+
+/// The minimum of the values, which is NaN when any of them is NaN.
+///
+/// # Panics
+///
+/// If the array is empty.
+pub(crate) fn min<E: NdArrayElement>(array: ArrayViewD<'_, E>) -> E {
+    fold_chunks(array, parallel_reduction_threshold(), min_elem)
+        .expect("Can't compute the minimum of an empty tensor")
+}
+
+pub(crate) fn sum_axis<E: NdArrayElement>(array: ArrayViewD<'_, E>, dim: usize) -> ArrayD<E> {
+    sum_axis_with_threshold(array, dim, parallel_reduction_threshold())
+}
+
+fn sum_with_threshold<E: NdArrayElement>(array: ArrayViewD<'_, E>, threshold: usize) -> E {
+    if !is_parallel(array.len(), threshold) {
+        return array.sum();
+    }
+
+    let values = array.as_standard_layout();
+    let values = values
+        .as_slice()
+        .expect("Standard layout array should be contiguous");
+
+    reduce_chunks(values, |chunk| ArrayView1::from(chunk).sum())
+        .into_iter()
+        .fold(E::zero(), |acc, sum| acc + sum)
+}
+
+/// Folds the values with the given function, returning `None` when the array is empty.
+fn fold_chunks<E, F>(array: ArrayViewD<'_, E>, threshold: usize, fold: F) -> Option<E>
+where
+    E: NdArrayElement,
+    F: Fn(E, E) -> E + Send + Sync + Copy,
+{
+    // The values are only copied to a contiguous buffer when the reduction is parallelized.
+    if !is_parallel(array.len(), threshold) {
+        return array.iter().copied().reduce(fold);
+    }
+
+    let values = array.as_standard_layout();
+    let values = values
+        .as_slice()
+        .expect("Standard layout array should be contiguous");
+
+    reduce_chunks(values, |chunk| {
+        chunk
+            .iter()
+            .copied()
+            .reduce(fold)
+            .expect("Chunks should not be empty")
+    })
+    .into_iter()
+    .reduce(fold)
+}
+
+fn sum_axis_with_threshold<E: NdArrayElement>(
+    array: ArrayViewD<'_, E>,
+    dim: usize,
+    threshold: usize,
+) -> ArrayD<E> {
+    let axis_len = array.len_of(Axis(dim));
+
+    if !is_parallel(array.len(), threshold) || axis_len < 2 || array.is_empty() {
+        return array.sum_axis(Axis(dim));
+    }
+
+    // Each chunk of the axis has about `CHUNK_SIZE` elements.
+    let chunk_len = usize::max(1, CHUNK_SIZE * axis_len / array.len());
+    let num_chunks = axis_len.div_ceil(chunk_len);
+
+    let sums: Vec<ArrayD<E>> = iter_range_par!(0, num_chunks)
+        .map(|chunk| {
+            let start = chunk * chunk_len;
+            let end = usize::min(start + chunk_len, axis_len);
+
+            array
+                .slice_axis(Axis(dim), Slice::from(start..end))
+                .sum_axis(Axis(dim))
+        })
+        .collect();
+
+    sums.into_iter()
+        .reduce(|acc, sum| acc + sum)
+        .expect("The axis should have at least one chunk")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parallel::DEFAULT_PARALLEL_REDUCTION_THRESHOLD;
+    use ndarray::IxDyn;
+
+    /// Deterministic values in [-0.5, 0.5).
+    fn array(shape: &[usize]) -> ArrayD<f32> {
+        let num_elements = shape.iter().product();
+        let values = (0..num_elements)
+            .map(|i| ((i * 7919) % 1000) as f32 / 1000.0 - 0.5)
+            .collect();
+
+        ArrayD::from_shape_vec(IxDyn(shape), values).unwrap()
+    }
+
+    fn assert_close(parallel: f32, serial: f32) {
+        assert!(
+            (parallel - serial).abs() <= 1e-3 * serial.abs().max(1.0),
+            "Parallel {parallel} and serial {serial} reductions differ"
+        );
+    }
+
+    #[test]
+    fn parallel_sum_should_match_serial_sum() {
+        let array = array(&[300, 1000]);
+
+        let parallel = sum_with_threshold(array.view(), 0);
+        let serial = sum_with_threshold(array.view(), usize::MAX);
+
+        assert_close(parallel, serial);
+        // The chunks don't depend on the number of threads.
+        assert_eq!(parallel, sum_with_threshold(array.view(), 0));
+    }
+
+    #[test]
+    fn parallel_sum_axis_should_match_serial_sum_axis() {
+        let array = array(&[40, 3000, 3]);
+
+        for dim in 0..3 {
+            let parallel = sum_axis_with_threshold(array.view(), dim, 0);
+            let serial = sum_axis_with_threshold(array.view(), dim, usize::MAX);
+
+            assert_eq!(parallel.shape(), serial.shape());
+            for (parallel, serial) in parallel.iter().zip(serial.iter()) {
+                assert_close(*parallel, *serial);
+            }
+        }
+    }
+
+    #[test]
+    fn parallel_max_and_min_should_match_serial_ones() {
+        let mut array = array(&[300, 1000]);
+        array[IxDyn(&[123, 456])] = 2.0;
+        array[IxDyn(&[299, 999])] = -2.0;
+
+        assert_eq!(fold_chunks(array.view(), 0, max_elem), Some(2.0));
+        assert_eq!(fold_chunks(array.view(), usize::MAX, max_elem), Some(2.0));
+        assert_eq!(fold_chunks(array.view(), 0, min_elem), Some(-2.0));
+        assert_eq!(fold_chunks(array.view(), usize::MAX, min_elem), Some(-2.0));
+    }
+
+    #[test]
+    fn max_and_min_should_propagate_nan() {
+        let mut array = array(&[300, 1000]);
+        array[IxDyn(&[0, 0])] = f32::NAN;
+        array[IxDyn(&[299, 999])] = f32::NAN;
+
+        for threshold in [0, usize::MAX] {
+            assert!(fold_chunks(array.view(), threshold, max_elem)
+                .unwrap()
+                .is_nan());
+            assert!(fold_chunks(array.view(), threshold, min_elem)
+                .unwrap()
+                .is_nan());
+        }
+    }
+
+    #[test]
+    fn max_and_min_of_empty_arrays_should_be_none() {
+        let array = array(&[0, 3]);
+
+        for threshold in [0, usize::MAX] {
+            assert_eq!(fold_chunks(array.view(), threshold, max_elem), None);
+            assert_eq!(fold_chunks(array.view(), threshold, min_elem), None);
+        }
+    }
+
+    #[test]
+    fn max_and_min_of_non_contiguous_arrays_should_match() {
+        let array = array(&[300, 1000]);
+        let transposed = array.t();
+
+        for threshold in [0, usize::MAX] {
+            assert_eq!(
+                fold_chunks(transposed.view(), threshold, max_elem),
+                fold_chunks(array.view(), threshold, max_elem)
+            );
+        }
+    }
+
+    #[test]
+    fn small_reductions_should_stay_serial() {
+        assert!(!is_parallel(1000, DEFAULT_PARALLEL_REDUCTION_THRESHOLD));
+        assert!(!is_parallel(
+            DEFAULT_PARALLEL_REDUCTION_THRESHOLD - 1,
+            DEFAULT_PARALLEL_REDUCTION_THRESHOLD
+        ));
+        assert_eq!(
+            is_parallel(
+                DEFAULT_PARALLEL_REDUCTION_THRESHOLD,
+                DEFAULT_PARALLEL_REDUCTION_THRESHOLD
+            ),
+            cfg!(feature = "std")
+        );
+    }
+}
@@ -348,6 +348,14 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayTensor::new(array)
     }
 
+    fn float_max<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        NdArrayMathOps::max(tensor)
+    }
+
+    fn float_min<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, 1> {
+        NdArrayMathOps::min(tensor)
+    }
+
     fn float_argmax<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
@@ -363,90 +371,55 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
     }
 
     fn float_exp<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv_into(|a| a.exp_elem()).into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| a.exp_elem())
     }
 
     fn float_log<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv_into(|a| a.log_elem()).into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| a.log_elem())
     }
 
     fn float_log1p<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv_into(|a| a.log1p_elem()).into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| a.log1p_elem())
     }
 
     fn float_powf_scalar<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         value: f32,
     ) -> NdArrayTensor<E, D> {
-        let array = if value == 2.0 {
+        if value == 2.0 {
             // Happens often and is faster.
-            tensor.array.mapv_into(|a| a * a).into_shared()
+            NdArrayMathOps::map_inplace(tensor, |a| a * a)
         } else if value.floor() == value {
             // Is faster then powf
-            tensor
-                .array
-                .mapv_into(|a| a.powi_elem(value as i32))
-                .into_shared()
+            NdArrayMathOps::map_inplace(tensor, |a| a.powi_elem(value as i32))
         } else {
             // Default
-            tensor.array.mapv_into(|a| a.powf_elem(value)).into_shared()
-        };
-
-        NdArrayTensor::new(array)
+            NdArrayMathOps::map_inplace(tensor, |a| a.powf_elem(value))
+        }
     }
 
     fn float_sqrt<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv_into(|a| a.sqrt_elem()).into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| a.sqrt_elem())
     }
 
     fn float_abs<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor.array.mapv_into(|a| a.abs_elem()).into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| a.abs_elem())
     }
 
     fn float_cos<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor
-            .array
-            .mapv_into(|a| cos(a.to_f64().unwrap()).elem())
-            .into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| cos(a.to_f64().unwrap()).elem())
     }
 
     fn float_sin<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor
-            .array
-            .mapv_into(|a| sin(a.to_f64().unwrap()).elem())
-            .into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| sin(a.to_f64().unwrap()).elem())
     }
 
     fn float_tanh<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor
-            .array
-            .mapv_into(|a| tanh(a.to_f64().unwrap()).elem())
-            .into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| tanh(a.to_f64().unwrap()).elem())
     }
 
     fn float_erf<const D: usize>(tensor: NdArrayTensor<E, D>) -> NdArrayTensor<E, D> {
-        let array = tensor
-            .array
-            .mapv_into(|a| erf(a.to_f64().unwrap()).elem())
-            .into_shared();
-
-        NdArrayTensor::new(array)
+        NdArrayMathOps::map_inplace(tensor, |a| erf(a.to_f64().unwrap()).elem())
     }
 
     fn float_cat<const D: usize>(
@@ -1,3 +1,26 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The default minimum number of elements of a reduction to parallelize it.
+pub(crate) const DEFAULT_PARALLEL_REDUCTION_THRESHOLD: usize = 1 << 16;
+
+static PARALLEL_REDUCTION_THRESHOLD: AtomicUsize =
+    AtomicUsize::new(DEFAULT_PARALLEL_REDUCTION_THRESHOLD);
+
+/// Sets the minimum number of elements of a reduction (sum, mean, max and min) to parallelize it.
+///
+/// The smaller reductions stay serial, since the overhead of the threads would outweigh the gain.
+/// The reductions are split in chunks of a fixed size, so their results are deterministic, but the
+/// float sums may differ slightly from the serial ones since the additions are grouped
+/// differently. The reductions are always serial without the `std` feature.
+pub fn set_parallel_reduction_threshold(num_elements: usize) {
+    // The threshold doesn't synchronize any other memory, so a relaxed ordering is enough.
+    PARALLEL_REDUCTION_THRESHOLD.store(num_elements, Ordering::Relaxed);
+}
+
+pub(crate) fn parallel_reduction_threshold() -> usize {
+    PARALLEL_REDUCTION_THRESHOLD.load(Ordering::Relaxed)
+}
+
 /// Macro for running a function in parallel.
 #[macro_export(local_inner_macros)]
 macro_rules! run_par {
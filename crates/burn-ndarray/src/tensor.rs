@@ -13,6 +13,14 @@ impl<E, const D: usize> NdArrayTensor<E, D> {
     pub(crate) fn shape(&self) -> Shape<D> {
         Shape::from(self.array.shape().to_vec())
     }
+
+    /// Returns the array to update in place when the tensor is the only owner of its buffer, or
+    /// the tensor itself when the buffer is shared, e.g. with a cloned tensor.
+    pub(crate) fn try_into_unique(self) -> Result<Array<E, IxDyn>, Self> {
+        self.array
+            .try_into_owned_nocopy()
+            .map_err(NdArrayTensor::new)
+    }
 }
 
 #[cfg(test)]
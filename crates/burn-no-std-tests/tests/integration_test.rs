@@ -3,7 +3,7 @@
 use burn_no_std_tests::mlp::*;
 use burn_no_std_tests::model::*;
 
-use burn::tensor::{backend::Backend, Distribution, Tensor};
+use burn::tensor::{backend::Backend, Distribution, SeedableRng, StdRng, Tensor};
 use burn_ndarray::NdArray;
 
 #[test]
@@ -29,3 +29,18 @@ fn test_mnist_model_with_random_input() {
     assert_eq!(output.shape().dims, [1, 10]);
     assert!(output.to_data().value.into_iter().all(|x| x <= 1.0));
 }
+
+#[test]
+fn test_random_tensors_with_a_seeded_rng() {
+    type Backend = NdArray<f32>;
+
+    let device = Default::default();
+    let tensor = |seed: u64| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        Tensor::<Backend, 2>::random_with_rng([3, 4], Distribution::Default, &mut rng, &device)
+            .into_data()
+    };
+
+    assert_eq!(tensor(7), tensor(7));
+    assert_ne!(tensor(7), tensor(8));
+}
@@ -295,12 +295,20 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
             InterpolateMode::Nearest => {
                 tch::Tensor::upsample_nearest2d(&x.tensor, output_size, None, None)
             }
-            InterpolateMode::Bilinear => {
-                tch::Tensor::upsample_bilinear2d(&x.tensor, output_size, true, None, None)
-            }
-            InterpolateMode::Bicubic => {
-                tch::Tensor::upsample_bicubic2d(&x.tensor, output_size, true, None, None)
-            }
+            InterpolateMode::Bilinear => tch::Tensor::upsample_bilinear2d(
+                &x.tensor,
+                output_size,
+                options.align_corners,
+                None,
+                None,
+            ),
+            InterpolateMode::Bicubic => tch::Tensor::upsample_bicubic2d(
+                &x.tensor,
+                output_size,
+                options.align_corners,
+                None,
+                None,
+            ),
         };
 
         TchTensor::new(tensor)
@@ -328,7 +336,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
                 &grad.tensor,
                 output_size,
                 input_size,
-                true,
+                options.align_corners,
                 None,
                 None,
             ),
@@ -336,7 +344,7 @@ impl<E: TchElement> ModuleOps<Self> for LibTorch<E> {
                 &grad.tensor,
                 output_size,
                 input_size,
-                true,
+                options.align_corners,
                 None,
                 None,
             ),
@@ -20,3 +20,7 @@ pub(crate) use tensor::check::macros::check;
 pub use tensor::*;
 
 pub use burn_common::reader::Reader; // Useful so that backends don't have to add `burn_common` as
+
+// Useful to create reproducible random tensors with `Tensor::random_with_rng`, including on
+// `no_std` targets, without adding `rand` as a dependency.
+pub use rand::{rngs::StdRng, RngCore, SeedableRng};
@@ -13,6 +13,18 @@ pub fn gelu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     Tensor::from_primitive(B::gelu(tensor.primitive))
 }
 
+/// Applies the tanh approximation of the Gaussian Error Linear Units function, as used by the
+/// GPT family of models.
+///
+/// `gelu_tanh(x) = 0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`
+pub fn gelu_tanh<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    let inner = tensor.clone() + tensor.clone().powf_scalar(3.0).mul_scalar(0.044715);
+    let inner =
+        inner.mul_scalar(core::f64::consts::FRAC_2_SQRT_PI * core::f64::consts::FRAC_1_SQRT_2);
+
+    tensor.mul(inner.tanh().add_scalar(1.0)).mul_scalar(0.5)
+}
+
 /// Applies Parametric ReLu activation
 /// ` PReLu(x) = max(0,x) + \alpha * min(0,x)`
 /// tensor is assumed to be of shape \[batch_size, channels, ...\]
@@ -197,6 +197,45 @@ where
         Tensor::new(K::flip(self.primitive, &transformed_axes))
     }
 
+    /// Roll the elements of the tensor along the given dimensions, wrapping around.
+    ///
+    /// # Arguments
+    ///
+    /// * `shifts` - The number of positions to shift the elements by, for each dimension.
+    ///              Negative shifts roll the elements towards the start.
+    /// * `dims` - The dimensions to roll, in the range of the number of dimensions.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the elements rolled, where the element at index `i` moves to the index
+    /// `(i + shift) % size` of each rolled dimension.
+    pub fn roll(self, shifts: &[i64], dims: &[usize]) -> Self {
+        check!(TensorCheck::roll::<D>(shifts, dims));
+
+        let mut tensor = self;
+
+        for (&shift, &dim) in shifts.iter().zip(dims) {
+            let size = tensor.dims()[dim];
+
+            if size == 0 {
+                continue;
+            }
+
+            let shift = shift.rem_euclid(size as i64) as usize;
+
+            if shift == 0 {
+                continue;
+            }
+
+            // The last `shift` elements are moved in front of the others.
+            let end = tensor.clone().narrow(dim, size - shift, shift);
+            let start = tensor.narrow(dim, 0, size - shift);
+            tensor = Tensor::cat(alloc::vec![end, start], dim);
+        }
+
+        tensor
+    }
+
     /// Flatten the tensor along a given range of dimensions.
     ///
     /// This function collapses the specified range of dimensions into a single dimension,
@@ -571,6 +610,34 @@ where
         Self::new(K::repeat(self.primitive, dim, times))
     }
 
+    /// Repeat each element of the tensor along the given dimension, before moving to the next
+    /// element.
+    ///
+    /// Unlike [repeat](Tensor::repeat), which tiles the tensor, `[1, 2]` repeated twice becomes
+    /// `[1, 1, 2, 2]`.
+    ///
+    /// # Arguments
+    ///
+    /// * `repeats` - The number of times each element is repeated.
+    /// * `dim` - The dimension along which to repeat the elements.
+    pub fn repeat_interleave(self, repeats: usize, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("repeat_interleave", dim));
+
+        let mut dims = self.dims();
+        let size_before = dims[..=dim].iter().product::<usize>();
+        let size_after = dims[dim + 1..].iter().product::<usize>();
+        dims[dim] *= repeats;
+
+        if repeats == 0 {
+            return Self::empty(dims, &self.device());
+        }
+
+        // Each element along `dim` becomes a row, which is repeated along a new dimension.
+        self.reshape([size_before, 1, size_after])
+            .repeat(1, repeats)
+            .reshape(dims)
+    }
+
     /// Applies element-wise equal comparison and returns a boolean tensor.
     ///
     /// # Panics
@@ -1,4 +1,5 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use super::einsum::EinsumLabels;
+use crate::{backend::Backend, BasicOps, PadMode, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -307,6 +308,140 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn complex_parts<const D: usize>(real: &Shape<D>, imag: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if real != imag {
+            check = check.register(
+                "From parts",
+                TensorError::new("The real and imaginary parts should have the same shape.")
+                    .details(format!(
+                        "Real part shape {:?}, imaginary part shape {:?}.",
+                        real.dims, imag.dims
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn histogram(bins: usize, range: (f32, f32)) -> Self {
+        let mut check = Self::Ok;
+
+        if bins == 0 {
+            check = check.register(
+                "Histogram",
+                TensorError::new("The number of bins should be greater than 0."),
+            );
+        }
+
+        // Also rejects NaN bounds.
+        if range.0.is_nan() || range.1.is_nan() || range.0 >= range.1 {
+            check = check.register(
+                "Histogram",
+                TensorError::new(
+                    "The lower bound of the range should be smaller than the upper bound.",
+                )
+                .details(format!("Got the range ({}, {}).", range.0, range.1)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn pad<const D: usize>(
+        padding: &[(usize, usize)],
+        shape: &Shape<D>,
+        mode: PadMode,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if padding.len() != D {
+            return check.register(
+                "Pad",
+                TensorError::new("The padding must have one pair per dimension.").details(format!(
+                    "Got {} pairs for a tensor with {D} dimensions.",
+                    padding.len()
+                )),
+            );
+        }
+
+        for (dim, (&(before, after), &size)) in padding.iter().zip(shape.dims.iter()).enumerate() {
+            if before == 0 && after == 0 {
+                continue;
+            }
+
+            match mode {
+                PadMode::Constant => {}
+                PadMode::Replicate if size == 0 => {
+                    check = check.register(
+                        "Pad",
+                        TensorError::new("Can't replicate the edge of an empty dimension.")
+                            .details(format!("The dimension {dim} is empty.")),
+                    );
+                }
+                PadMode::Replicate => {}
+                PadMode::Reflect if before >= size || after >= size => {
+                    check = check.register(
+                        "Pad",
+                        TensorError::new(
+                            "The reflect padding must be smaller than the size of the dimension.",
+                        )
+                        .details(format!(
+                            "Got a padding of ({before}, {after}) for the dimension {dim} of size \
+                             {size}."
+                        )),
+                    );
+                }
+                PadMode::Reflect => {}
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn quantile<const D: usize>(q: f32, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Quantile", dim);
+
+        if !(0.0..=1.0).contains(&q) {
+            check = check.register(
+                "Quantile",
+                TensorError::new("The quantile must be in the range [0, 1].")
+                    .details(format!("Got a quantile of {q}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn topk<const D: usize>(k: usize, dim: usize) -> Self {
+        let mut check = Self::dim_ops::<D>("Topk", dim);
+
+        if k == 0 {
+            check = check.register(
+                "Topk",
+                TensorError::new("Can't select the top k elements with k equal to zero."),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn one_hot_tensor<const D1: usize, const D2: usize>() -> Self {
+        let mut check = Self::Ok;
+        if D2 != D1 + 1 {
+            check = check.register(
+                "One Hot",
+                TensorError::new(format!(
+                    "Can't create a one hot tensor of rank ({D2}) from a tensor of rank ({D1}), the rank should be ({}).",
+                    D1 + 1
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn swap_dims<const D: usize>(dim1: usize, dim2: usize) -> Self {
         let mut check = Self::Ok;
 
@@ -377,6 +512,172 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn repeat_interleave(repeats: &[i64], size: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if repeats.len() != size {
+            check = check.register(
+                "repeat_interleave",
+                TensorError::new("The number of repeats must match the size of the dimension.")
+                    .details(format!(
+                        "Got {} repeats for a dimension of size {size}.",
+                        repeats.len()
+                    )),
+            );
+        }
+
+        if let Some(times) = repeats.iter().find(|&&times| times < 0) {
+            check = check.register(
+                "repeat_interleave",
+                TensorError::new("The repeats must be positive.")
+                    .details(format!("Got a repeat of {times}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn roll<const D: usize>(shifts: &[i64], dims: &[usize]) -> Self {
+        let check = Self::Ok;
+
+        if shifts.len() != dims.len() {
+            return check.register(
+                "roll",
+                TensorError::new("The number of shifts must match the number of dimensions.")
+                    .details(format!(
+                        "Got {} shifts for {} dimensions.",
+                        shifts.len(),
+                        dims.len()
+                    )),
+            );
+        }
+
+        if let Some(dim) = dims.iter().find(|&x| *x >= D) {
+            return check.register(
+                "roll",
+                TensorError::new("The dimensions must be smaller than the tensor rank.").details(
+                    format!("The '{dim}' dimension is greater than {D} dimensions."),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn einsum<const D: usize, const D2: usize>(
+        equation: &str,
+        labels: &Result<EinsumLabels, String>,
+        shapes: &[Shape<D>],
+    ) -> Self {
+        let ops = "Einsum";
+        let mut check = Self::Ok;
+        let error = |description: String| {
+            TensorError::new(description).details(format!("Equation: '{equation}'."))
+        };
+
+        let (inputs, output) = match labels {
+            Ok(labels) => labels,
+            Err(description) => return check.register(ops, error(description.clone())),
+        };
+
+        if inputs.len() != shapes.len() {
+            return check.register(
+                ops,
+                error(format!(
+                    "The equation has {} operands, but {} tensors were given.",
+                    inputs.len(),
+                    shapes.len()
+                )),
+            );
+        }
+
+        if inputs.len() > 2 {
+            check = check.register(
+                ops,
+                error("Only equations with one or two operands are supported.".to_string()),
+            );
+        }
+
+        if output.len() != D2 {
+            check = check.register(
+                ops,
+                error(format!(
+                    "The output has {} labels, but the output rank is {D2}.",
+                    output.len()
+                )),
+            );
+        }
+
+        for (i, labels) in inputs.iter().enumerate() {
+            if labels.len() != D {
+                check = check.register(
+                    ops,
+                    error(format!(
+                        "The operand {i} has {} labels, but the tensors have {D} dimensions.",
+                        labels.len()
+                    )),
+                );
+            }
+
+            if let Some(label) = labels
+                .iter()
+                .enumerate()
+                .find_map(|(j, label)| labels[j + 1..].contains(label).then_some(label))
+            {
+                check = check.register(
+                    ops,
+                    error(format!(
+                        "The label '{label}' is repeated in the operand {i}, which isn't supported."
+                    )),
+                );
+            }
+        }
+
+        for (i, label) in output.iter().enumerate() {
+            if output[i + 1..].contains(label) {
+                check = check.register(
+                    ops,
+                    error(format!("The label '{label}' is repeated in the output.")),
+                );
+            }
+
+            if !inputs.iter().any(|labels| labels.contains(label)) {
+                check = check.register(
+                    ops,
+                    error(format!(
+                        "The output label '{label}' isn't part of any operand."
+                    )),
+                );
+            }
+        }
+
+        if let Self::Failed(_) = check {
+            return check;
+        }
+
+        // The dimensions sharing a label must have the same size.
+        let mut sizes: Vec<(char, usize, usize)> = Vec::new();
+        for (i, (labels, shape)) in inputs.iter().zip(shapes).enumerate() {
+            for (label, size) in labels.iter().zip(shape.dims) {
+                match sizes.iter().find(|(l, _, _)| l == label) {
+                    Some((_, size_other, j)) if *size_other != size => {
+                        check = check.register(
+                            ops,
+                            error(format!(
+                                "The label '{label}' has a size of {size_other} in the operand \
+                                 {j}, but a size of {size} in the operand {i}."
+                            )),
+                        );
+                    }
+                    Some(_) => {}
+                    None => sizes.push((*label, size, i)),
+                }
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn matmul<B: Backend, const D: usize>(
         lhs: &Tensor<B, D>,
         rhs: &Tensor<B, D>,
@@ -412,6 +713,92 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn square_matrix<const D: usize>(ops: &str, shape: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            return check.register(
+                ops,
+                TensorError::new("The tensor should have at least two dimensions.")
+                    .details(format!("Tensor rank: '{D}'.")),
+            );
+        }
+
+        let rows = shape.dims[D - 2];
+        let cols = shape.dims[D - 1];
+
+        if rows != cols {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The matrices should be square, but got {rows} rows and {cols} columns."
+                ))
+                .details(format!("Tensor shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn matrix_values<const D: usize, const D2: usize>(ops: &str) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                ops,
+                TensorError::new("The tensor should have at least two dimensions.")
+                    .details(format!("Tensor rank: '{D}'.")),
+            );
+        }
+
+        if D2 + 1 != D {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The values should have one dimension less than the matrices, the last two \
+                     dimensions being replaced by one.",
+                )
+                .details(format!(
+                    "Matrices rank: '{D}', values rank: '{D2}', expected values rank: '{}'.",
+                    D.saturating_sub(1)
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn solve<B: Backend, const D: usize>(
+        lhs: &Tensor<B, D>,
+        rhs: &Tensor<B, D>,
+    ) -> Self {
+        let shape_lhs = lhs.shape();
+        let shape_rhs = rhs.shape();
+
+        let mut check = Self::square_matrix("Solve", &shape_lhs);
+        check = check.binary_ops_device("Solve", &lhs.device(), &rhs.device());
+
+        if D < 2 {
+            return check;
+        }
+
+        if shape_lhs.dims[..D - 1] != shape_rhs.dims[..D - 1] {
+            check = check.register(
+                "Solve",
+                TensorError::new(
+                    "The right hand side should have the same batch dimensions and number of rows \
+                     as the matrices.",
+                )
+                .details(format!(
+                    "Lhs shape {:?}, rhs shape {:?}.",
+                    shape_lhs.dims, shape_rhs.dims
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn stack<B: Backend, const D: usize, K: BasicOps<B>>(
         tensors: &[Tensor<B, D, K>],
         dim: usize,
@@ -688,6 +1075,58 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn masked_select<const D: usize>(shape: &Shape<D>, shape_mask: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape != shape_mask {
+            check = check.register(
+                "Masked Select",
+                TensorError::new("The mask should have the same shape as the tensor.").details(
+                    format!(
+                        "Tensor shape {:?}, mask shape {:?}.",
+                        shape.dims, shape_mask.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn masked_scatter(num_selected: usize, num_source: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if num_source < num_selected {
+            check = check.register(
+                "Masked Scatter",
+                TensorError::new("The source doesn't have enough elements.").details(format!(
+                    "The mask selects {num_selected} elements, but the source only has {num_source}."
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn indices_bounds(ops: &str, dim: usize, size: usize, min: i64, max: i64) -> Self {
+        let mut check = Self::Ok;
+
+        for index in [min, max] {
+            if index < 0 || index >= size as i64 {
+                check = check.register(
+                    ops,
+                    TensorError::new(format!(
+                        "Index ({index}) is out of bounds for dimension ({dim}) of size ({size})."
+                    ))
+                    .details("Indices should be in the range [0, size)."),
+                );
+                break;
+            }
+        }
+
+        check
+    }
+
     pub(crate) fn select<const D: usize>(dim: usize) -> Self {
         Self::check_select_basic::<D>(Self::Ok, "select", dim)
     }
@@ -946,6 +1385,23 @@ mod tests {
     use super::*;
     use macros::check;
 
+    #[test]
+    #[should_panic(expected = "out of bounds for dimension (1)")]
+    fn indices_bounds_above_size() {
+        check!(TensorCheck::indices_bounds("Gather", 1, 3, 0, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for dimension (0)")]
+    fn indices_bounds_negative() {
+        check!(TensorCheck::indices_bounds("Scatter", 0, 3, -1, 0));
+    }
+
+    #[test]
+    fn indices_bounds_valid() {
+        check!(TensorCheck::indices_bounds("Gather", 1, 3, 0, 2));
+    }
+
     #[test]
     #[should_panic]
     fn reshape_invalid_shape() {
@@ -0,0 +1,336 @@
+use crate::{
+    backend::Backend, check, check::TensorCheck, ops::BoolTensor, ops::FloatTensor, BasicOps, Bool,
+    Complex, Data, ElementConversion, Shape, Tensor,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use burn_common::reader::Reader;
+use core::ops::Range;
+
+// The complex tensors are float tensors where the real and imaginary parts are interleaved in
+// the last dimension, so a complex tensor of shape `[..., n]` is stored as a float tensor of
+// shape `[..., 2 * n]`. The operations that preserve the pairs are applied directly on the float
+// tensor, the others are applied on the real and imaginary parts separately.
+impl<B: Backend> BasicOps<B> for Complex {
+    /// The real and imaginary parts of the element.
+    type Elem = [B::FloatElem; 2];
+
+    fn empty<const D: usize>(shape: Shape<D>, device: &B::Device) -> Self::Primitive<D> {
+        B::float_empty(interleaved_shape(shape), device)
+    }
+
+    fn shape<const D: usize>(tensor: &Self::Primitive<D>) -> Shape<D> {
+        complex_shape(B::float_shape(tensor))
+    }
+
+    fn reshape<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        shape: Shape<D2>,
+    ) -> Self::Primitive<D2> {
+        // The pairs are contiguous, so they are kept by any reshape.
+        B::float_reshape(tensor, interleaved_shape(shape))
+    }
+
+    fn transpose<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        map_parts::<B, D, D>(tensor, B::float_transpose)
+    }
+
+    fn swap_dims<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim1: usize,
+        dim2: usize,
+    ) -> Self::Primitive<D> {
+        check!(TensorCheck::swap_dims::<D>(dim1, dim2));
+
+        match dim1 == D - 1 || dim2 == D - 1 {
+            true => map_parts::<B, D, D>(tensor, |tensor| B::float_swap_dims(tensor, dim1, dim2)),
+            false => B::float_swap_dims(tensor, dim1, dim2),
+        }
+    }
+
+    fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
+        match axes[D - 1] == D - 1 {
+            true => B::float_permute(tensor, axes),
+            false => map_parts::<B, D, D>(tensor, |tensor| B::float_permute(tensor, axes)),
+        }
+    }
+
+    fn flip<const D: usize>(tensor: Self::Primitive<D>, axes: &[usize]) -> Self::Primitive<D> {
+        match axes.contains(&(D - 1)) {
+            true => map_parts::<B, D, D>(tensor, |tensor| B::float_flip(tensor, axes)),
+            false => B::float_flip(tensor, axes),
+        }
+    }
+
+    fn slice<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+    ) -> Self::Primitive<D1> {
+        B::float_slice(tensor, interleaved_ranges::<D1, D2>(ranges))
+    }
+
+    fn slice_assign<const D1: usize, const D2: usize>(
+        tensor: Self::Primitive<D1>,
+        ranges: [Range<usize>; D2],
+        value: Self::Primitive<D1>,
+    ) -> Self::Primitive<D1> {
+        B::float_slice_assign(tensor, interleaved_ranges::<D1, D2>(ranges), value)
+    }
+
+    fn device<const D: usize>(tensor: &Self::Primitive<D>) -> <B as Backend>::Device {
+        B::float_device(tensor)
+    }
+
+    fn to_device<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Self::Primitive<D> {
+        B::float_to_device(tensor, device)
+    }
+
+    fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<Data<Self::Elem, D>> {
+        B::float_into_data(tensor).map(|data| {
+            let value = data
+                .value
+                .chunks(2)
+                .map(|pair| [pair[0], pair[1]])
+                .collect();
+
+            Data::new(value, complex_shape(data.shape))
+        })
+    }
+
+    fn from_data<const D: usize>(
+        data: Data<Self::Elem, D>,
+        device: &B::Device,
+    ) -> Self::Primitive<D> {
+        let value = data.value.into_iter().flatten().collect();
+
+        B::float_from_data(Data::new(value, interleaved_shape(data.shape)), device)
+    }
+
+    fn repeat<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        times: usize,
+    ) -> Self::Primitive<D> {
+        match dim == D - 1 {
+            true => map_parts::<B, D, D>(tensor, |tensor| B::float_repeat(tensor, dim, times)),
+            false => B::float_repeat(tensor, dim, times),
+        }
+    }
+
+    fn cat<const D: usize>(vectors: Vec<Self::Primitive<D>>, dim: usize) -> Self::Primitive<D> {
+        B::float_cat(vectors, dim)
+    }
+
+    fn equal<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Tensor<B, D, Bool> {
+        let (lhs_real, lhs_imag) = split::<B, D>(lhs);
+        let (rhs_real, rhs_imag) = split::<B, D>(rhs);
+
+        let equal_real = B::bool_into_int(B::float_equal(lhs_real, rhs_real));
+        let equal_imag = B::bool_into_int(B::float_equal(lhs_imag, rhs_imag));
+        let equal = B::int_mul(equal_real, equal_imag);
+
+        Tensor::new(B::int_equal_elem(equal, 1i32.elem()))
+    }
+
+    fn not_equal<const D: usize>(
+        lhs: Self::Primitive<D>,
+        rhs: Self::Primitive<D>,
+    ) -> Tensor<B, D, Bool> {
+        Tensor::new(B::bool_not(Self::equal(lhs, rhs).primitive))
+    }
+
+    fn any<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, 1, Bool> {
+        <Bool as BasicOps<B>>::any(non_zero::<B, D>(tensor))
+    }
+
+    fn any_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool> {
+        <Bool as BasicOps<B>>::any_dim(non_zero::<B, D>(tensor), dim)
+    }
+
+    fn all<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, 1, Bool> {
+        <Bool as BasicOps<B>>::all(non_zero::<B, D>(tensor))
+    }
+
+    fn all_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool> {
+        <Bool as BasicOps<B>>::all_dim(non_zero::<B, D>(tensor), dim)
+    }
+}
+
+impl<B, const D: usize> Tensor<B, D, Complex>
+where
+    B: Backend,
+{
+    /// Create a complex tensor from its real and imaginary parts, which must have the same shape.
+    pub fn from_parts(real: Tensor<B, D>, imag: Tensor<B, D>) -> Self {
+        check!(TensorCheck::complex_parts(&real.shape(), &imag.shape()));
+
+        Self::new(merge::<B, D>(real.primitive, imag.primitive))
+    }
+
+    /// Create a complex tensor with the given real part and a null imaginary part.
+    pub fn from_real(real: Tensor<B, D>) -> Self {
+        let imag = real.zeros_like();
+
+        Self::from_parts(real, imag)
+    }
+
+    /// Returns the real and imaginary parts of the complex tensor.
+    pub fn into_parts(self) -> (Tensor<B, D>, Tensor<B, D>) {
+        let (real, imag) = split::<B, D>(self.primitive);
+
+        (Tensor::new(real), Tensor::new(imag))
+    }
+
+    /// Returns the real part of the complex tensor.
+    pub fn real(self) -> Tensor<B, D> {
+        self.into_parts().0
+    }
+
+    /// Returns the imaginary part of the complex tensor.
+    pub fn imag(self) -> Tensor<B, D> {
+        self.into_parts().1
+    }
+
+    /// Applies element wise complex addition, with broadcasting.
+    ///
+    /// `y = x1 + x2`
+    ///
+    /// # Notes
+    ///
+    /// The arithmetic of complex tensors is exposed with distinct names, since the methods of
+    /// [numeric](crate::Numeric) tensors, like [add](Tensor::add), can't be reused.
+    pub fn add_complex(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Add complex", &self, &other));
+
+        let (lhs_real, lhs_imag) = self.into_parts();
+        let (rhs_real, rhs_imag) = other.into_parts();
+
+        Self::from_parts(lhs_real + rhs_real, lhs_imag + rhs_imag)
+    }
+
+    /// Applies element wise complex subtraction, with broadcasting.
+    ///
+    /// `y = x1 - x2`
+    pub fn sub_complex(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Sub complex", &self, &other));
+
+        let (lhs_real, lhs_imag) = self.into_parts();
+        let (rhs_real, rhs_imag) = other.into_parts();
+
+        Self::from_parts(lhs_real - rhs_real, lhs_imag - rhs_imag)
+    }
+
+    /// Applies element wise complex multiplication, with broadcasting.
+    ///
+    /// `(a + bi)(c + di) = (ac - bd) + (ad + bc)i`
+    pub fn mul_complex(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("Mul complex", &self, &other));
+
+        let (a, b) = self.into_parts();
+        let (c, d) = other.into_parts();
+
+        let real = a.clone() * c.clone() - b.clone() * d.clone();
+        let imag = a * d + b * c;
+
+        Self::from_parts(real, imag)
+    }
+
+    /// Returns the complex conjugate of each element.
+    ///
+    /// `conj(a + bi) = a - bi`
+    pub fn conj(self) -> Self {
+        let (real, imag) = self.into_parts();
+
+        Self::from_parts(real, imag.neg())
+    }
+
+    /// Returns the magnitude of each element.
+    ///
+    /// `|a + bi| = sqrt(a^2 + b^2)`
+    pub fn magnitude(self) -> Tensor<B, D> {
+        let (real, imag) = self.into_parts();
+
+        (real.powf_scalar(2.0) + imag.powf_scalar(2.0)).sqrt()
+    }
+}
+
+/// The shape of the float tensor storing a complex tensor of the given shape.
+fn interleaved_shape<const D: usize>(mut shape: Shape<D>) -> Shape<D> {
+    shape.dims[D - 1] *= 2;
+    shape
+}
+
+/// The shape of the complex tensor stored in a float tensor of the given shape.
+fn complex_shape<const D: usize>(mut shape: Shape<D>) -> Shape<D> {
+    shape.dims[D - 1] /= 2;
+    shape
+}
+
+/// The ranges of the float tensor storing the given ranges of a complex tensor of rank `D1`.
+fn interleaved_ranges<const D1: usize, const D2: usize>(
+    mut ranges: [Range<usize>; D2],
+) -> [Range<usize>; D2] {
+    // Only the last dimension is interleaved, which is sliced only when all dimensions are.
+    if D2 > 0 && D2 == D1 {
+        let range = &ranges[D2 - 1];
+        ranges[D2 - 1] = range.start * 2..range.end * 2;
+    }
+
+    ranges
+}
+
+fn split<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D>, FloatTensor<B, D>) {
+    let shape = complex_shape(B::float_shape(&tensor));
+    let num_elements = shape.num_elements();
+
+    let pairs = B::float_reshape(tensor, Shape::new([num_elements, 2]));
+    let real = B::float_slice(pairs.clone(), [0..num_elements, 0..1]);
+    let imag = B::float_slice(pairs, [0..num_elements, 1..2]);
+
+    (
+        B::float_reshape(real, shape.clone()),
+        B::float_reshape(imag, shape),
+    )
+}
+
+fn merge<B: Backend, const D: usize>(
+    real: FloatTensor<B, D>,
+    imag: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let shape = B::float_shape(&real);
+    let num_elements = shape.num_elements();
+
+    let real = B::float_reshape(real, Shape::new([num_elements, 1]));
+    let imag = B::float_reshape(imag, Shape::new([num_elements, 1]));
+    let pairs = B::float_cat(vec![real, imag], 1);
+
+    B::float_reshape(pairs, interleaved_shape(shape))
+}
+
+/// Apply the operation on the real and imaginary parts separately.
+fn map_parts<B: Backend, const D: usize, const D2: usize>(
+    tensor: FloatTensor<B, D>,
+    func: impl Fn(FloatTensor<B, D>) -> FloatTensor<B, D2>,
+) -> FloatTensor<B, D2> {
+    let (real, imag) = split::<B, D>(tensor);
+
+    merge::<B, D2>(func(real), func(imag))
+}
+
+/// Whether each complex element isn't zero, which is when any of its parts isn't zero.
+fn non_zero<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> BoolTensor<B, D> {
+    let shape = complex_shape(B::float_shape(&tensor));
+    let num_elements = shape.num_elements();
+
+    let pairs = B::float_reshape(tensor, Shape::new([num_elements, 2]));
+
+    B::bool_reshape(B::float_any_dim(pairs, 1), shape)
+}
@@ -0,0 +1,229 @@
+use crate::{backend::Backend, check, check::TensorCheck, Tensor};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The labels of the operands and of the output of an einsum equation.
+pub(crate) type EinsumLabels = (Vec<Vec<char>>, Vec<char>);
+
+/// Compute a tensor contraction described with the Einstein summation notation.
+///
+/// The equation lists the labels of the dimensions of each operand, separated by commas, and the
+/// labels of the output after an arrow, e.g. `"bij,bjk->bik"` for a batched matrix multiplication.
+/// Labels that are not part of the output are summed.
+///
+/// # Arguments
+///
+/// * `equation` - The equation, with one or two operands, where each label is a letter.
+/// * `tensors` - The operands.
+///
+/// # Returns
+///
+/// The result of the contraction, whose dimensions are ordered like the output labels.
+///
+/// # Notes
+///
+/// Only explicit outputs are supported, the ellipsis and the repetition of a label within an
+/// operand aren't. The equation is lowered to `sum_dim`, `permute`, `reshape` and `matmul`.
+///
+/// # Panics
+///
+/// If the equation is invalid, doesn't match the tensors and `D2`, or if the dimensions sharing a
+/// label don't have the same size.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{einsum, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let lhs = Tensor::<B, 3>::ones([2, 3, 4], &device);
+///     let rhs = Tensor::<B, 3>::ones([2, 4, 5], &device);
+///
+///     let output: Tensor<B, 3> = einsum("bij,bjk->bik", &[lhs, rhs]);
+///     // Shape { dims: [2, 3, 5] }
+/// }
+/// ```
+pub fn einsum<B: Backend, const D: usize, const D2: usize>(
+    equation: &str,
+    tensors: &[Tensor<B, D>],
+) -> Tensor<B, D2> {
+    let labels = parse(equation);
+    let shapes = tensors
+        .iter()
+        .map(|tensor| tensor.shape())
+        .collect::<Vec<_>>();
+    check!(TensorCheck::einsum::<D, D2>(equation, &labels, &shapes));
+
+    let (inputs, output) = labels.unwrap();
+
+    match tensors {
+        [tensor] => einsum_single(tensor.clone(), &inputs[0], &output),
+        [lhs, rhs] => einsum_pair(lhs.clone(), rhs.clone(), &inputs[0], &inputs[1], &output),
+        _ => unreachable!("The number of operands is checked"),
+    }
+}
+
+fn einsum_single<B: Backend, const D: usize, const D2: usize>(
+    tensor: Tensor<B, D>,
+    labels: &[char],
+    output: &[char],
+) -> Tensor<B, D2> {
+    let tensor = sum_labels(tensor, labels, &[], output);
+    let dims = tensor.dims();
+
+    // The summed dimensions have a size of one, so they can be moved last and reshaped away.
+    let axes = ordered_axes(labels, &[output]);
+    let shape = output.iter().map(|label| dims[axis(labels, *label)]);
+
+    tensor
+        .permute(array(axes.into_iter().map(|a| a as isize)))
+        .reshape(array(shape))
+}
+
+fn einsum_pair<B: Backend, const D: usize, const D2: usize>(
+    lhs: Tensor<B, D>,
+    rhs: Tensor<B, D>,
+    labels_lhs: &[char],
+    labels_rhs: &[char],
+    output: &[char],
+) -> Tensor<B, D2> {
+    let lhs = sum_labels(lhs, labels_lhs, labels_rhs, output);
+    let rhs = sum_labels(rhs, labels_rhs, labels_lhs, output);
+    let (dims_lhs, dims_rhs) = (lhs.dims(), rhs.dims());
+
+    let filter = |labels: &[char], f: &dyn Fn(char) -> bool| -> Vec<char> {
+        labels.iter().copied().filter(|label| f(*label)).collect()
+    };
+    let batch = filter(output, &|l| {
+        labels_lhs.contains(&l) && labels_rhs.contains(&l)
+    });
+    let left = filter(output, &|l| {
+        labels_lhs.contains(&l) && !labels_rhs.contains(&l)
+    });
+    let right = filter(output, &|l| {
+        labels_rhs.contains(&l) && !labels_lhs.contains(&l)
+    });
+    let contracted = filter(labels_lhs, &|l| {
+        labels_rhs.contains(&l) && !output.contains(&l)
+    });
+
+    let size_lhs = |labels: &[char]| -> usize {
+        labels
+            .iter()
+            .map(|l| dims_lhs[axis(labels_lhs, *l)])
+            .product()
+    };
+    let size_rhs = |labels: &[char]| -> usize {
+        labels
+            .iter()
+            .map(|l| dims_rhs[axis(labels_rhs, *l)])
+            .product()
+    };
+
+    // Both operands are lowered to a batched matrix multiplication, the summed dimensions having
+    // a size of one.
+    let axes_lhs = ordered_axes(labels_lhs, &[batch.as_slice(), &left, &contracted]);
+    let lhs: Tensor<B, 3> = lhs
+        .permute(array(axes_lhs.into_iter().map(|a| a as isize)))
+        .reshape([size_lhs(&batch), size_lhs(&left), size_lhs(&contracted)]);
+
+    let axes_rhs = ordered_axes(labels_rhs, &[batch.as_slice(), &contracted, &right]);
+    let rhs: Tensor<B, 3> = rhs
+        .permute(array(axes_rhs.into_iter().map(|a| a as isize)))
+        .reshape([size_rhs(&batch), size_rhs(&contracted), size_rhs(&right)]);
+
+    let labels_result = [batch, left, right].concat();
+    let shape = labels_result
+        .iter()
+        .map(|label| match labels_lhs.contains(label) {
+            true => dims_lhs[axis(labels_lhs, *label)],
+            false => dims_rhs[axis(labels_rhs, *label)],
+        });
+    let axes = output
+        .iter()
+        .map(|label| axis(&labels_result, *label) as isize);
+
+    lhs.matmul(rhs).reshape(array(shape)).permute(array(axes))
+}
+
+/// Sum the dimensions whose label is neither in the other operand nor in the output, keeping them
+/// with a size of one.
+fn sum_labels<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    labels: &[char],
+    labels_other: &[char],
+    output: &[char],
+) -> Tensor<B, D> {
+    labels
+        .iter()
+        .enumerate()
+        .filter(|(_, label)| !labels_other.contains(label) && !output.contains(label))
+        .fold(tensor, |tensor, (dim, _)| tensor.sum_dim(dim))
+}
+
+/// The axes of the labels of each group in order, followed by the axes of the remaining labels.
+fn ordered_axes(labels: &[char], groups: &[&[char]]) -> Vec<usize> {
+    let grouped = groups.concat();
+    let mut axes = grouped
+        .iter()
+        .map(|label| axis(labels, *label))
+        .collect::<Vec<_>>();
+
+    axes.extend((0..labels.len()).filter(|axis| !grouped.contains(&labels[*axis])));
+    axes
+}
+
+fn axis(labels: &[char], label: char) -> usize {
+    labels
+        .iter()
+        .position(|l| *l == label)
+        .expect("The label should be part of the labels")
+}
+
+fn array<T: Default + Copy, const N: usize>(values: impl Iterator<Item = T>) -> [T; N] {
+    let mut array = [T::default(); N];
+    let mut count = 0;
+
+    for (item, value) in array.iter_mut().zip(values) {
+        *item = value;
+        count += 1;
+    }
+
+    assert_eq!(count, N, "The number of values should match the rank");
+    array
+}
+
+/// Parse an einsum equation into the labels of the operands and of the output.
+pub(crate) fn parse(equation: &str) -> Result<EinsumLabels, String> {
+    let equation = equation
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+
+    let (inputs, output) = match equation.split_once("->") {
+        Some(split) => split,
+        None => return Err("The equation must have an explicit output, e.g. 'ij,jk->ik'.".into()),
+    };
+
+    if equation.contains('.') {
+        return Err("The ellipsis isn't supported.".into());
+    }
+
+    let labels = |labels: &str| -> Result<Vec<char>, String> {
+        match labels.chars().find(|c| !c.is_ascii_alphabetic()) {
+            Some(c) => Err(alloc::format!(
+                "Invalid label '{c}', labels must be letters."
+            )),
+            None => Ok(labels.chars().collect()),
+        }
+    };
+
+    let inputs = inputs
+        .split(',')
+        .map(labels)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((inputs, labels(output)?))
+}
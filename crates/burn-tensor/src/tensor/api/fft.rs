@@ -0,0 +1,223 @@
+use crate::{backend::Backend, check, check::TensorCheck, Complex, Data, Shape, Tensor};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+
+/// The real and imaginary parts of a batch of signals, with a shape of `[batch, length]`.
+type Signal<B> = (Tensor<B, 2>, Tensor<B, 2>);
+
+impl<B, const D: usize> Tensor<B, D, Complex>
+where
+    B: Backend,
+{
+    /// Computes the discrete Fourier transform along the given dimension.
+    ///
+    /// `X[k] = sum_j x[j] * exp(-2 * pi * i * j * k / n)`
+    ///
+    /// # Notes
+    ///
+    /// The transform is composed of tensor operations, using the radix-2 Cooley-Tukey algorithm
+    /// when the size of the dimension is a power of two, and the Bluestein algorithm otherwise,
+    /// which is a few times slower.
+    pub fn fft(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("FFT", dim));
+
+        transform(self, dim, -1.0)
+    }
+
+    /// Computes the inverse discrete Fourier transform along the given dimension.
+    ///
+    /// `x[j] = 1 / n * sum_k X[k] * exp(2 * pi * i * j * k / n)`
+    ///
+    /// # Notes
+    ///
+    /// See [fft](Tensor::fft) for the algorithms.
+    pub fn ifft(self, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("IFFT", dim));
+
+        let size = self.dims()[dim];
+        let (real, imag) = transform(self, dim, 1.0).into_parts();
+        let scale = 1.0 / usize::max(size, 1) as f32;
+
+        Self::from_parts(real.mul_scalar(scale), imag.mul_scalar(scale))
+    }
+}
+
+impl<B, const D: usize> Tensor<B, D>
+where
+    B: Backend,
+{
+    /// Computes the discrete Fourier transform of the real tensor along the given dimension.
+    ///
+    /// The spectrum of a real signal is symmetric, so only the `n / 2 + 1` non-negative
+    /// frequencies are returned.
+    ///
+    /// # Notes
+    ///
+    /// See [fft](Tensor::fft) for the algorithms.
+    pub fn rfft(self, dim: usize) -> Tensor<B, D, Complex> {
+        check!(TensorCheck::dim_ops::<D>("RFFT", dim));
+
+        let size = self.dims()[dim];
+        let spectrum = Tensor::<B, D, Complex>::from_real(self).fft(dim);
+
+        match size {
+            0 => spectrum,
+            _ => spectrum.narrow(dim, 0, size / 2 + 1),
+        }
+    }
+}
+
+fn transform<B: Backend, const D: usize>(
+    tensor: Tensor<B, D, Complex>,
+    dim: usize,
+    sign: f64,
+) -> Tensor<B, D, Complex> {
+    let (real, imag) = tensor.into_parts();
+
+    // The signals are moved to the last dimension and flattened in a batch.
+    let real = real.swap_dims(dim, D - 1);
+    let imag = imag.swap_dims(dim, D - 1);
+    let shape = real.shape();
+    let size = shape.dims[D - 1];
+
+    if size <= 1 {
+        return Tensor::from_parts(real.swap_dims(dim, D - 1), imag.swap_dims(dim, D - 1));
+    }
+
+    let batch = shape.num_elements() / size;
+    let signal = (real.reshape([batch, size]), imag.reshape([batch, size]));
+
+    let (real, imag) = match size.is_power_of_two() {
+        true => radix2(signal, sign),
+        false => bluestein(signal, sign),
+    };
+
+    let real = real.reshape(shape.clone()).swap_dims(dim, D - 1);
+    let imag = imag.reshape(shape).swap_dims(dim, D - 1);
+
+    Tensor::from_parts(real, imag)
+}
+
+/// The radix-2 Cooley-Tukey algorithm, for signals with a length that is a power of two.
+fn radix2<B: Backend>((real, imag): Signal<B>, sign: f64) -> Signal<B> {
+    let [batch, size] = real.dims();
+
+    if size <= 1 {
+        return (real, imag);
+    }
+
+    let half = size / 2;
+
+    // The even and odd elements are transformed together, as separate signals of the batch.
+    let deinterleave = |tensor: Tensor<B, 2>| {
+        tensor
+            .reshape([batch, half, 2])
+            .swap_dims(1, 2)
+            .reshape([2 * batch, half])
+    };
+    let (real, imag) = radix2((deinterleave(real), deinterleave(imag)), sign);
+
+    let part = |tensor: &Tensor<B, 2>, index: usize| {
+        tensor
+            .clone()
+            .reshape([batch, 2, half])
+            .narrow(1, index, 1)
+            .reshape([batch, half])
+    };
+    let (even_real, even_imag) = (part(&real, 0), part(&imag, 0));
+    let (odd_real, odd_imag) = (part(&real, 1), part(&imag, 1));
+
+    let device = real.device();
+    let twiddles = signal_from_fn(
+        half,
+        |k| polar(sign * 2.0 * PI * k as f64 / size as f64),
+        &device,
+    );
+    let (odd_real, odd_imag) = mul((odd_real, odd_imag), twiddles);
+
+    let real = Tensor::cat(
+        vec![even_real.clone() + odd_real.clone(), even_real - odd_real],
+        1,
+    );
+    let imag = Tensor::cat(
+        vec![even_imag.clone() + odd_imag.clone(), even_imag - odd_imag],
+        1,
+    );
+
+    (real, imag)
+}
+
+/// The Bluestein algorithm, expressing the transform of any length as a convolution computed
+/// with radix-2 transforms of a larger length.
+fn bluestein<B: Backend>((real, imag): Signal<B>, sign: f64) -> Signal<B> {
+    let [batch, size] = real.dims();
+    let device = real.device();
+    let size_padded = (2 * size - 1).next_power_of_two();
+
+    // With jk = (j² + k² - (k - j)²) / 2, the transform is the convolution of the signal
+    // multiplied by the chirp c[j] = exp(sign * pi * i * j² / n) with the conjugate chirp,
+    // multiplied again by the chirp. The squares are reduced modulo 2n to keep the precision.
+    let chirp_phase = |j: usize| sign * PI * ((j * j) % (2 * size)) as f64 / size as f64;
+    let chirp = signal_from_fn(size, |j| polar(chirp_phase(j)), &device);
+
+    let (real, imag) = mul((real, imag), chirp.clone());
+    let zeros = Tensor::zeros([batch, size_padded - size], &device);
+    let signal = (
+        Tensor::cat(vec![real, zeros.clone()], 1),
+        Tensor::cat(vec![imag, zeros], 1),
+    );
+
+    // The conjugate chirp is wrapped around, so that it is also indexed by negative offsets.
+    let kernel = signal_from_fn(
+        size_padded,
+        |m| match m {
+            m if m < size => polar(-chirp_phase(m)),
+            m if m > size_padded - size => polar(-chirp_phase(size_padded - m)),
+            _ => (0.0, 0.0),
+        },
+        &device,
+    );
+
+    let spectrum = mul(radix2(signal, -1.0), radix2(kernel, -1.0));
+    let (real, imag) = radix2(spectrum, 1.0);
+
+    let scale = 1.0 / size_padded as f32;
+    let real = real.narrow(1, 0, size).mul_scalar(scale);
+    let imag = imag.narrow(1, 0, size).mul_scalar(scale);
+
+    mul((real, imag), chirp)
+}
+
+/// Element wise complex multiplication, with broadcasting.
+fn mul<B: Backend>((a, b): Signal<B>, (c, d): Signal<B>) -> Signal<B> {
+    let real = a.clone() * c.clone() - b.clone() * d.clone();
+    let imag = a * d + b * c;
+
+    (real, imag)
+}
+
+/// The signal of shape `[1, length]` with the given real and imaginary parts.
+fn signal_from_fn<B: Backend, F: Fn(usize) -> (f64, f64)>(
+    length: usize,
+    func: F,
+    device: &B::Device,
+) -> Signal<B> {
+    let (real, imag): (Vec<f32>, Vec<f32>) = (0..length)
+        .map(|index| {
+            let (real, imag) = func(index);
+            (real as f32, imag as f32)
+        })
+        .unzip();
+    let shape = Shape::new([1, length]);
+
+    (
+        Tensor::from_data(Data::new(real, shape.clone()).convert(), device),
+        Tensor::from_data(Data::new(imag, shape).convert(), device),
+    )
+}
+
+/// The real and imaginary parts of `exp(i * phase)`.
+fn polar(phase: f64) -> (f64, f64) {
+    (libm::cos(phase), libm::sin(phase))
+}
@@ -4,10 +4,15 @@ use core::convert::TryInto;
 use crate::check;
 use crate::check::TensorCheck;
 use crate::tensor::backend::Backend;
+use crate::tensor::ops::InterpolateOptions;
 use crate::tensor::stats;
 use crate::tensor::{Data, Distribution, Shape};
+use crate::ElementConversion;
 use crate::Int;
+use crate::LinalgError;
 use crate::Tensor;
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+use crate::{cholesky, inverse, solve, svd, symeig};
 
 impl<const D: usize, B> Tensor<B, D>
 where
@@ -59,6 +64,136 @@ where
         Self::new(B::float_erf(self.primitive))
     }
 
+    /// Computes `log(sum(exp(x)))` along the given dimension, in a numerically stable way.
+    ///
+    /// The maximum is subtracted before applying the exponential and added back after the
+    /// logarithm, so large values don't overflow. Like the other reductions, the reduced dimension
+    /// is kept with a size of one, see [logsumexp_squeeze](Tensor::logsumexp_squeeze) to remove it.
+    pub fn logsumexp(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("Logsumexp", dim));
+
+        let max = self.clone().detach().max_dim(dim);
+        // When all the elements are -inf, subtracting the max would produce NaN.
+        let max = max.clone().mask_fill(max.equal_elem(f32::NEG_INFINITY), 0);
+
+        (self - max.clone()).exp().sum_dim(dim).log() + max
+    }
+
+    /// Computes `log(sum(exp(x)))` along the given dimension, in a numerically stable way, and
+    /// removes the reduced dimension.
+    ///
+    /// See [logsumexp](Tensor::logsumexp) for more details.
+    pub fn logsumexp_squeeze<const D2: usize>(self, dim: usize) -> Tensor<B, D2> {
+        self.logsumexp(dim).squeeze(dim)
+    }
+
+    /// Computes the `q`-th quantile of the elements along the given dimension.
+    ///
+    /// The quantile is interpolated linearly between the two closest ranks: with the `n` elements
+    /// sorted in ascending order, the quantile at the position `q * (n - 1)` is computed from the
+    /// elements at the floor and the ceil of that position, weighted by the fractional part. This
+    /// is the `linear` method of NumPy and PyTorch.
+    ///
+    /// Like the other reductions, the reduced dimension is kept with a size of one. The quantile of
+    /// an empty dimension is NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The quantile to compute, in the range `[0, 1]`.
+    /// * `dim` - The dimension along which to compute the quantile.
+    ///
+    /// # Notes
+    ///
+    /// The elements are sorted with [topk](Tensor::topk), which reads the data on backends
+    /// without a sort implementation.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn quantile(self, q: f32, dim: usize) -> Self {
+        check!(TensorCheck::quantile::<D>(q, dim));
+
+        let size = self.dims()[dim];
+        if size == 0 {
+            return self.quantile_empty(dim);
+        }
+        let sorted = self.topk(size, dim, false);
+
+        Self::quantile_sorted(sorted, q, dim)
+    }
+
+    /// Computes the `q`-th quantile of the elements along the given dimension.
+    ///
+    /// The quantile is interpolated linearly between the two closest ranks: with the `n` elements
+    /// sorted in ascending order, the quantile at the position `q * (n - 1)` is computed from the
+    /// elements at the floor and the ceil of that position, weighted by the fractional part. This
+    /// is the `linear` method of NumPy and PyTorch.
+    ///
+    /// Like the other reductions, the reduced dimension is kept with a size of one. The quantile of
+    /// an empty dimension is NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The quantile to compute, in the range `[0, 1]`.
+    /// * `dim` - The dimension along which to compute the quantile.
+    ///
+    /// # Notes
+    ///
+    /// The elements are sorted with [topk](Tensor::topk), which reads the data on backends
+    /// without a sort implementation.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn quantile(self, q: f32, dim: usize) -> Self {
+        check!(TensorCheck::quantile::<D>(q, dim));
+
+        let size = self.dims()[dim];
+        if size == 0 {
+            return self.quantile_empty(dim);
+        }
+        let sorted = self.topk(size, dim, false).await;
+
+        Self::quantile_sorted(sorted, q, dim)
+    }
+
+    /// Computes the median of the elements along the given dimension.
+    ///
+    /// When the dimension has an even size, the median is the average of the two middle elements.
+    /// This is the quantile `0.5`, see [quantile](Tensor::quantile) for more details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn median(self, dim: usize) -> Self {
+        self.quantile(0.5, dim)
+    }
+
+    /// Computes the median of the elements along the given dimension.
+    ///
+    /// When the dimension has an even size, the median is the average of the two middle elements.
+    /// This is the quantile `0.5`, see [quantile](Tensor::quantile) for more details.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn median(self, dim: usize) -> Self {
+        self.quantile(0.5, dim).await
+    }
+
+    /// The quantile of no element is undefined, so it is NaN like in NumPy and PyTorch.
+    fn quantile_empty(self, dim: usize) -> Self {
+        let mut dims = self.dims();
+        dims[dim] = 1;
+
+        Self::full(dims, f32::NAN, &self.device())
+    }
+
+    fn quantile_sorted(sorted: Self, q: f32, dim: usize) -> Self {
+        let position = q as f64 * (sorted.dims()[dim] - 1) as f64;
+        let lower = libm::floor(position);
+        let weight = position - lower;
+        let lower = lower as usize;
+
+        let value_lower = sorted.clone().narrow(dim, lower, 1);
+
+        if weight == 0.0 {
+            return value_lower;
+        }
+
+        let value_upper = sorted.narrow(dim, lower + 1, 1);
+
+        value_lower.mul_scalar(1.0 - weight) + value_upper.mul_scalar(weight)
+    }
+
     /// Applies element wise reciprocal operation.
     pub fn recip(self) -> Self {
         Self::new(B::float_recip(self.primitive))
@@ -84,6 +219,113 @@ where
         Self::new(B::float_tanh(self.primitive))
     }
 
+    /// Replaces NaN, positive infinity and negative infinity element wise with the given values.
+    ///
+    /// # Arguments
+    ///
+    /// * `nan` - The value replacing NaN.
+    /// * `posinf` - The value replacing positive infinity.
+    /// * `neginf` - The value replacing negative infinity.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with only finite values, assuming the given values are finite.
+    pub fn nan_to_num(self, nan: f32, posinf: f32, neginf: f32) -> Self {
+        Self::new(B::float_nan_to_num(
+            self.primitive,
+            nan.elem(),
+            posinf.elem(),
+            neginf.elem(),
+        ))
+    }
+
+    /// Finds the indices where the elements should be inserted into the sorted boundaries to
+    /// keep them sorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundaries` - The boundaries, sorted in ascending order.
+    /// * `right` - Whether an element equal to a boundary is inserted after it instead of before.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape containing the insertion indices, which range from 0 for the
+    /// elements before all the boundaries to the number of boundaries for the elements after all
+    /// of them. With `right`, the index is the number of boundaries smaller or equal to the
+    /// element instead of strictly smaller.
+    ///
+    /// # Notes
+    ///
+    /// The elements are compared with all the boundaries, which uses a memory proportional to
+    /// their product.
+    pub fn searchsorted(self, boundaries: Tensor<B, 1>, right: bool) -> Tensor<B, D, Int> {
+        let shape = self.shape();
+        let device = self.device();
+        let num_elements = shape.num_elements();
+        let [num_boundaries] = boundaries.dims();
+
+        if num_elements == 0 || num_boundaries == 0 {
+            return Tensor::zeros(shape, &device);
+        }
+
+        let elements = self.reshape([num_elements, 1]).repeat(1, num_boundaries);
+        let boundaries = boundaries
+            .reshape([1, num_boundaries])
+            .repeat(0, num_elements);
+
+        let is_before = match right {
+            true => boundaries.lower_equal(elements),
+            false => boundaries.lower(elements),
+        };
+
+        is_before.int().sum_dim(1).reshape(shape)
+    }
+
+    /// Counts the elements falling in each of the bins of equal width covering the given range.
+    ///
+    /// # Arguments
+    ///
+    /// * `bins` - The number of bins.
+    /// * `range` - The lower and upper bounds of the range.
+    /// * `clamp` - Whether the elements outside the range are counted in the first or the last bin
+    ///   instead of being dropped.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[bins]` with the number of elements in each bin. A bin includes its
+    /// lower edge, and the last bin also includes the upper bound of the range. NaN elements are
+    /// always dropped.
+    pub fn histogram(self, bins: usize, range: (f32, f32), clamp: bool) -> Tensor<B, 1, Int> {
+        check!(TensorCheck::histogram(bins, range));
+
+        let (min, max) = range;
+        let device = self.device();
+        let num_elements = self.shape().num_elements();
+
+        if num_elements == 0 {
+            return Tensor::zeros([bins], &device);
+        }
+
+        let tensor = self.reshape([num_elements]);
+
+        // With the inner edges, the elements at the upper bound of the range are in the last bin.
+        let edges = (1..bins)
+            .map(|i| min + (max - min) * i as f32 / bins as f32)
+            .collect::<Vec<_>>();
+        let edges = Tensor::from_data(Data::from(edges.as_slice()).convert(), &device);
+        let indices = tensor.clone().searchsorted(edges, true);
+
+        let mut counts = Tensor::ones([num_elements], &device);
+        if !clamp {
+            counts = counts
+                .mask_fill(tensor.clone().lower_elem(min), 0)
+                .mask_fill(tensor.clone().greater_elem(max), 0);
+        }
+        counts = counts.mask_fill(tensor.clone().not_equal(tensor), 0);
+
+        Tensor::zeros([bins], &device).scatter(0, indices, counts)
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -221,6 +463,18 @@ where
         Self::new(B::float_detach(self.primitive))
     }
 
+    /// Stop the gradients from flowing through the current tensor.
+    ///
+    /// Unlike [detach](Tensor::detach), the returned tensor never requires gradients, even when
+    /// the current tensor is a parameter, so the tensor is used as a constant during the backward
+    /// pass. This is useful for target networks and straight-through estimators, e.g.
+    /// `x.clone() + (f(x.clone()) - x).stop_gradient()` computes `f(x)` with the gradient of `x`.
+    ///
+    /// This function does nothing when autodiff is not enabled.
+    pub fn stop_gradient(self) -> Self {
+        self.detach().set_require_grad(false)
+    }
+
     /// Mark the tensor to keep gradients during the backward pass.
     ///
     /// This function does nothing when autodiff is not enabled.
@@ -261,4 +515,244 @@ where
             .matmul(centered)
             .div_scalar(n as f32 - correction_factor as f32)
     }
+
+    /// Computes the inverse of the square matrices in the last two dimensions, batched over the
+    /// leading dimensions.
+    ///
+    /// Singular matrices produce non-finite values.
+    ///
+    /// # Notes
+    ///
+    /// Backends without a native implementation read the data and invert the matrices on the
+    /// host, which is slow and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn inverse(self) -> Self {
+        check!(TensorCheck::square_matrix("Inverse", &self.shape()));
+        Self::new(B::float_inverse(self.primitive))
+    }
+
+    /// Computes the inverse of the square matrices in the last two dimensions, batched over the
+    /// leading dimensions.
+    ///
+    /// Singular matrices produce non-finite values.
+    ///
+    /// # Notes
+    ///
+    /// The data is read and the matrices are inverted on the host, which is slow and not
+    /// differentiable.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn inverse(self) -> Self {
+        check!(TensorCheck::square_matrix("Inverse", &self.shape()));
+        Self::new(inverse::<B, D>(self.primitive).await)
+    }
+
+    /// Computes the Cholesky decomposition of the positive definite matrices in the last two
+    /// dimensions, batched over the leading dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Whether to return the upper triangular factor `U` with `A = U^T U` instead of
+    ///   the lower triangular factor `L` with `A = L L^T`.
+    ///
+    /// # Returns
+    ///
+    /// The triangular factors, or [LinalgError::NotPositiveDefinite] if a matrix isn't positive
+    /// definite, which commonly happens when a covariance matrix drifts during training. Only the
+    /// lower triangle of the matrices is read.
+    ///
+    /// # Notes
+    ///
+    /// Backends without a native implementation read the data and decompose the matrices on the
+    /// host, which is slow and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn cholesky(self, upper: bool) -> Result<Self, LinalgError> {
+        check!(TensorCheck::square_matrix("Cholesky", &self.shape()));
+        B::float_cholesky(self.primitive, upper).map(Self::new)
+    }
+
+    /// Computes the Cholesky decomposition of the positive definite matrices in the last two
+    /// dimensions, batched over the leading dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `upper` - Whether to return the upper triangular factor `U` with `A = U^T U` instead of
+    ///   the lower triangular factor `L` with `A = L L^T`.
+    ///
+    /// # Returns
+    ///
+    /// The triangular factors, or [LinalgError::NotPositiveDefinite] if a matrix isn't positive
+    /// definite, which commonly happens when a covariance matrix drifts during training. Only the
+    /// lower triangle of the matrices is read.
+    ///
+    /// # Notes
+    ///
+    /// The data is read and the matrices are decomposed on the host, which is slow and not
+    /// differentiable.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn cholesky(self, upper: bool) -> Result<Self, LinalgError> {
+        check!(TensorCheck::square_matrix("Cholesky", &self.shape()));
+        cholesky::<B, D>(self.primitive, upper).await.map(Self::new)
+    }
+
+    /// Solves the linear systems `self * x = rhs`, batched over the leading dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The right hand sides of shape `[..., n, k]`, where the matrices have a shape of
+    ///   `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `x` of shape `[..., n, k]`. Singular systems produce non-finite values.
+    ///
+    /// # Notes
+    ///
+    /// Backends without a native implementation read the data and solve the systems on the host,
+    /// which is slow and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn solve(self, rhs: Self) -> Self {
+        check!(TensorCheck::solve(&self, &rhs));
+        Self::new(B::float_solve(self.primitive, rhs.primitive))
+    }
+
+    /// Solves the linear systems `self * x = rhs`, batched over the leading dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` - The right hand sides of shape `[..., n, k]`, where the matrices have a shape of
+    ///   `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// The solutions `x` of shape `[..., n, k]`. Singular systems produce non-finite values.
+    ///
+    /// # Notes
+    ///
+    /// The data is read and the systems are solved on the host, which is slow and not
+    /// differentiable.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn solve(self, rhs: Self) -> Self {
+        check!(TensorCheck::solve(&self, &rhs));
+        Self::new(solve::<B, D>(self.primitive, rhs.primitive).await)
+    }
+
+    /// Computes the singular value decomposition `A = U diag(S) V^T` of the matrices in the last
+    /// two dimensions, batched over the leading dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `full_matrices` - Whether to return the full orthogonal matrices `U` of shape
+    ///   `[..., m, m]` and `V` of shape `[..., n, n]`, instead of only their first
+    ///   `k = min(m, n)` columns.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with `U`, the singular values `S` of shape `[..., k]` in descending order, and `V`.
+    /// The rank `D2` of the singular values is `D - 1`.
+    ///
+    /// # Notes
+    ///
+    /// Backends without a native implementation read the data and decompose the matrices on the
+    /// host, which is slow and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn svd<const D2: usize>(self, full_matrices: bool) -> (Self, Tensor<B, D2>, Self) {
+        check!(TensorCheck::matrix_values::<D, D2>("Svd"));
+
+        let (u, s, v) = B::float_svd::<D, D2>(self.primitive, full_matrices);
+
+        (Self::new(u), Tensor::new(s), Self::new(v))
+    }
+
+    /// Computes the eigen decomposition of the symmetric matrices in the last two dimensions,
+    /// batched over the leading dimensions.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the eigenvalues of shape `[..., n]` in ascending order, and the corresponding
+    /// eigenvectors as the columns of a tensor of shape `[..., n, n]`. The rank `D2` of the
+    /// eigenvalues is `D - 1`. Only symmetric matrices are supported, the result is unspecified
+    /// otherwise.
+    ///
+    /// # Notes
+    ///
+    /// Backends without a native implementation read the data and decompose the matrices on the
+    /// host, which is slow and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn symeig<const D2: usize>(self) -> (Tensor<B, D2>, Self) {
+        check!(TensorCheck::matrix_values::<D, D2>("Symeig"));
+        check!(TensorCheck::square_matrix("Symeig", &self.shape()));
+
+        let (values, vectors) = B::float_symeig::<D, D2>(self.primitive);
+
+        (Tensor::new(values), Self::new(vectors))
+    }
+
+    /// Computes the singular value decomposition `A = U diag(S) V^T` of the matrices in the last
+    /// two dimensions, batched over the leading dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `full_matrices` - Whether to return the full orthogonal matrices `U` of shape
+    ///   `[..., m, m]` and `V` of shape `[..., n, n]`, instead of only their first
+    ///   `k = min(m, n)` columns.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with `U`, the singular values `S` of shape `[..., k]` in descending order, and `V`.
+    /// The rank `D2` of the singular values is `D - 1`.
+    ///
+    /// # Notes
+    ///
+    /// The data is read and the matrices are decomposed on the host, which is slow and not
+    /// differentiable.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn svd<const D2: usize>(self, full_matrices: bool) -> (Self, Tensor<B, D2>, Self) {
+        check!(TensorCheck::matrix_values::<D, D2>("Svd"));
+
+        let (u, s, v) = svd::<B, D, D2>(self.primitive, full_matrices).await;
+
+        (Self::new(u), Tensor::new(s), Self::new(v))
+    }
+
+    /// Computes the eigen decomposition of the symmetric matrices in the last two dimensions,
+    /// batched over the leading dimensions.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the eigenvalues of shape `[..., n]` in ascending order, and the corresponding
+    /// eigenvectors as the columns of a tensor of shape `[..., n, n]`. The rank `D2` of the
+    /// eigenvalues is `D - 1`. Only symmetric matrices are supported, the result is unspecified
+    /// otherwise.
+    ///
+    /// # Notes
+    ///
+    /// The data is read and the matrices are decomposed on the host, which is slow and not
+    /// differentiable.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn symeig<const D2: usize>(self) -> (Tensor<B, D2>, Self) {
+        check!(TensorCheck::matrix_values::<D, D2>("Symeig"));
+        check!(TensorCheck::square_matrix("Symeig", &self.shape()));
+
+        let (values, vectors) = symeig::<B, D, D2>(self.primitive).await;
+
+        (Tensor::new(values), Self::new(vectors))
+    }
+}
+
+impl<B> Tensor<B, 4>
+where
+    B: Backend,
+{
+    /// Resizes the images of shape `[batch_size, channels, height, width]` to the output size
+    /// `[height_out, width_out]`.
+    ///
+    /// # Notes
+    ///
+    /// The [mode](InterpolateOptions::mode) selects the algorithm, and
+    /// [align_corners](InterpolateOptions::align_corners) how the output pixels are mapped on the
+    /// input for the bilinear and bicubic modes. The nearest pixel is found by rounding down the
+    /// scaled output index.
+    pub fn interpolate(self, output_size: [usize; 2], options: InterpolateOptions) -> Self {
+        Self::new(B::interpolate(self.primitive, output_size, options))
+    }
 }
@@ -0,0 +1,64 @@
+use crate::{backend::AutodiffBackend, Tensor};
+
+/// Computes the value of a scalar function and its gradients with respect to the given inputs.
+///
+/// The inputs are detached from any previous graph and tracked, so the returned gradients only
+/// account for the computation of the function. The tensors captured by the function are
+/// considered constants. An input that isn't used by the function has a gradient of zeros.
+///
+/// # Arguments
+///
+/// * `func` - The scalar function, receiving the inputs.
+/// * `inputs` - The inputs of the function.
+///
+/// # Returns
+///
+/// The value of the function and the gradient of each input.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let (value, [grad_x, grad_y]) = value_and_grad(|[x, y]| (x * y).sum(), [x, y]);
+/// ```
+pub fn value_and_grad<B, const D: usize, const N: usize, F>(
+    func: F,
+    inputs: [Tensor<B, D>; N],
+) -> (Tensor<B::InnerBackend, 1>, [Tensor<B::InnerBackend, D>; N])
+where
+    B: AutodiffBackend,
+    F: FnOnce([Tensor<B, D>; N]) -> Tensor<B, 1>,
+{
+    let inputs = inputs.map(|input| input.detach().require_grad());
+
+    let value = func(inputs.clone());
+    let grads = value.backward();
+
+    let grads = inputs.map(|input| {
+        input
+            .grad(&grads)
+            .unwrap_or_else(|| Tensor::zeros(input.shape(), &input.device()))
+    });
+
+    (value.inner(), grads)
+}
+
+/// Transforms a scalar function into the function computing its gradients with respect to its
+/// inputs.
+///
+/// See [value_and_grad] for more details.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let grad_f = grad(|[x, y]| (x * y).sum());
+/// let [grad_x, grad_y] = grad_f([x, y]);
+/// ```
+pub fn grad<B, const D: usize, const N: usize, F>(
+    func: F,
+) -> impl Fn([Tensor<B, D>; N]) -> [Tensor<B::InnerBackend, D>; N]
+where
+    B: AutodiffBackend,
+    F: Fn([Tensor<B, D>; N]) -> Tensor<B, 1>,
+{
+    move |inputs| value_and_grad(&func, inputs).1
+}
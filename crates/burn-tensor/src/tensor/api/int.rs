@@ -1,4 +1,8 @@
-use crate::{backend::Backend, Data, Float, Int, Tensor};
+use crate::check;
+use crate::check::TensorCheck;
+use crate::{backend::Backend, Data, Element, Float, Int, Numeric, Tensor};
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+use crate::{unique, unique_with_counts};
 use core::ops::Range;
 
 impl<B> Tensor<B, 1, Int>
@@ -24,6 +28,66 @@ where
     pub fn arange_step(range: Range<i64>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::int_arange_step(range, step, device))
     }
+
+    /// Returns the distinct values of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor containing the distinct values, sorted in ascending order.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unique(self) -> Self {
+        Tensor::new(B::int_unique(self.primitive))
+    }
+
+    /// Returns the distinct values of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A tensor containing the distinct values, sorted in ascending order.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn unique(self) -> Self {
+        Tensor::new(unique::<B>(self.primitive).await)
+    }
+
+    /// Returns the distinct values of the tensor, along with the inverse indices and the counts.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - the distinct values, sorted in ascending order;
+    /// - for each element of the tensor, the index of its value in the distinct values, so that
+    ///   `values.select(0, inverse)` rebuilds the tensor;
+    /// - the number of occurrences of each distinct value.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn unique_with_counts(self) -> (Self, Self, Self) {
+        let (values, inverse, counts) = B::int_unique_with_counts(self.primitive);
+
+        (
+            Tensor::new(values),
+            Tensor::new(inverse),
+            Tensor::new(counts),
+        )
+    }
+
+    /// Returns the distinct values of the tensor, along with the inverse indices and the counts.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// - the distinct values, sorted in ascending order;
+    /// - for each element of the tensor, the index of its value in the distinct values, so that
+    ///   `values.select(0, inverse)` rebuilds the tensor;
+    /// - the number of occurrences of each distinct value.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn unique_with_counts(self) -> (Self, Self, Self) {
+        let (values, inverse, counts) = unique_with_counts::<B>(self.primitive).await;
+
+        (
+            Tensor::new(values),
+            Tensor::new(inverse),
+            Tensor::new(counts),
+        )
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -66,4 +130,42 @@ where
     pub fn float(self) -> Tensor<B, D, Float> {
         Tensor::new(B::int_into_float(self.primitive))
     }
+
+    /// Create a one hot tensor from the class indices, appending a new last dimension of size
+    /// `num_classes`.
+    ///
+    /// The output rank `D2` must be `D + 1` and the output kind can be [Float] or [Int]. Negative
+    /// or out of range indices, such as `-1` often used to denote an ignored element, produce a
+    /// row of zeros.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Float, Int, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let indices = Tensor::<B, 1, Int>::from_ints([2, 0, -1], &device);
+    ///     let one_hot: Tensor<B, 2, Float> = indices.one_hot(3);
+    ///     println!("{}", one_hot.to_data());
+    ///     // [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 0.0, 0.0]]
+    /// }
+    /// ```
+    pub fn one_hot<const D2: usize, K: Numeric<B>>(self, num_classes: usize) -> Tensor<B, D2, K>
+    where
+        K::Elem: Element,
+    {
+        check!(TensorCheck::one_hot_tensor::<D, D2>());
+
+        let device = self.device();
+        let mut dims = [1; D2];
+        dims[D] = num_classes;
+
+        let classes = Tensor::<B, 1, Int>::arange(0..num_classes as i64, &device).reshape(dims);
+        // The indices are compared with every class by broadcasting the last dimension.
+        let mask = self.unsqueeze_dim::<D2>(D).sub(classes).equal_elem(0);
+
+        Tensor::zeros(mask.shape(), &device).mask_fill(mask, 1)
+    }
 }
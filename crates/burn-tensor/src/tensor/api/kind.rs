@@ -12,6 +12,12 @@ pub struct Int;
 #[derive(Clone, Debug)]
 pub struct Bool;
 
+/// A type-level representation of the kind of a complex tensor.
+///
+/// The real and imaginary parts are interleaved in the last dimension of a float tensor.
+#[derive(Clone, Debug)]
+pub struct Complex;
+
 /// A type-level representation of the kind of a tensor.
 pub trait TensorKind<B: Backend>: Clone + core::fmt::Debug {
     /// The primitive type of the tensor.
@@ -41,3 +47,10 @@ impl<B: Backend> TensorKind<B> for Bool {
         "Bool"
     }
 }
+
+impl<B: Backend> TensorKind<B> for Complex {
+    type Primitive<const D: usize> = B::FloatTensorPrimitive<D>;
+    fn name() -> &'static str {
+        "Complex"
+    }
+}
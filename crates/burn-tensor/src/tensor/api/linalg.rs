@@ -0,0 +1,792 @@
+use crate::{backend::Backend, ops::FloatTensor, Data, Element, ElementConversion, Shape};
+use alloc::format;
+use alloc::vec::Vec;
+
+/// Error that can occur with the linear algebra operations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinalgError {
+    /// The matrix isn't positive definite, so it has no Cholesky decomposition.
+    NotPositiveDefinite {
+        /// The index of the first matrix that isn't positive definite, with the leading
+        /// dimensions flattened.
+        matrix: usize,
+    },
+}
+
+impl core::fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotPositiveDefinite { matrix } => f.write_str(
+                format!("Linalg error => The matrix {matrix} isn't positive definite.").as_str(),
+            ),
+        }
+    }
+}
+
+// TODO: Move from std to core after Error is core (see https://github.com/rust-lang/rust/issues/103765)
+#[cfg(feature = "std")]
+impl std::error::Error for LinalgError {}
+
+/// Compute the inverse of the square matrices in the last two dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// A tensor with the same shape containing the inverse of each matrix. Singular matrices produce
+/// non-finite values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the matrices are inverted sequentially on the host,
+/// which is slow.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn inverse<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor).read();
+
+    B::float_from_data(inverse_data(data), &device)
+}
+
+/// Compute the inverse of the square matrices in the last two dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor of shape `[..., n, n]`.
+///
+/// # Returns
+///
+/// A tensor with the same shape containing the inverse of each matrix. Singular matrices produce
+/// non-finite values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the matrices are inverted sequentially on the host,
+/// which is slow.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn inverse<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor).read().await;
+
+    B::float_from_data(inverse_data(data), &device)
+}
+
+/// Solve the linear systems `lhs * x = rhs` batched over the leading dimensions.
+///
+/// # Arguments
+///
+/// * `lhs` - The square matrices of the systems, with a shape of `[..., n, n]`.
+/// * `rhs` - The right hand sides of the systems, with a shape of `[..., n, k]`.
+///
+/// # Returns
+///
+/// A tensor of shape `[..., n, k]` containing the solutions. Singular systems produce non-finite
+/// values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the systems are solved sequentially on the host,
+/// which is slow.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn solve<B: Backend, const D: usize>(
+    lhs: FloatTensor<B, D>,
+    rhs: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let device = B::float_device(&lhs);
+    let lhs = B::float_into_data(lhs).read();
+    let rhs = B::float_into_data(rhs).read();
+
+    B::float_from_data(solve_data(lhs, rhs), &device)
+}
+
+/// Solve the linear systems `lhs * x = rhs` batched over the leading dimensions.
+///
+/// # Arguments
+///
+/// * `lhs` - The square matrices of the systems, with a shape of `[..., n, n]`.
+/// * `rhs` - The right hand sides of the systems, with a shape of `[..., n, k]`.
+///
+/// # Returns
+///
+/// A tensor of shape `[..., n, k]` containing the solutions. Singular systems produce non-finite
+/// values.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the systems are solved sequentially on the host,
+/// which is slow.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn solve<B: Backend, const D: usize>(
+    lhs: FloatTensor<B, D>,
+    rhs: FloatTensor<B, D>,
+) -> FloatTensor<B, D> {
+    let device = B::float_device(&lhs);
+    let lhs = B::float_into_data(lhs).read().await;
+    let rhs = B::float_into_data(rhs).read().await;
+
+    B::float_from_data(solve_data(lhs, rhs), &device)
+}
+
+/// Compute the Cholesky decomposition of the positive definite matrices in the last two
+/// dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor of shape `[..., n, n]`. Only the lower triangle is read.
+/// * `upper` - Whether to return the upper triangular factor `U` with `A = U^T U` instead of the
+///   lower triangular factor `L` with `A = L L^T`.
+///
+/// # Returns
+///
+/// A tensor with the same shape containing the triangular factors, or an error if a matrix isn't
+/// positive definite.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the matrices are decomposed sequentially on the
+/// host, which is slow.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn cholesky<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    upper: bool,
+) -> Result<FloatTensor<B, D>, LinalgError> {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor).read();
+
+    Ok(B::float_from_data(cholesky_data(data, upper)?, &device))
+}
+
+/// Compute the Cholesky decomposition of the positive definite matrices in the last two
+/// dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor of shape `[..., n, n]`. Only the lower triangle is read.
+/// * `upper` - Whether to return the upper triangular factor `U` with `A = U^T U` instead of the
+///   lower triangular factor `L` with `A = L L^T`.
+///
+/// # Returns
+///
+/// A tensor with the same shape containing the triangular factors, or an error if a matrix isn't
+/// positive definite.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the matrices are decomposed sequentially on the
+/// host, which is slow.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn cholesky<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    upper: bool,
+) -> Result<FloatTensor<B, D>, LinalgError> {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor).read().await;
+
+    Ok(B::float_from_data(cholesky_data(data, upper)?, &device))
+}
+
+/// Compute the singular value decomposition `A = U diag(S) V^T` of the matrices in the last two
+/// dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor of shape `[..., m, n]`.
+/// * `full_matrices` - Whether to return the full orthogonal matrices `U` of shape `[..., m, m]`
+///   and `V` of shape `[..., n, n]`, instead of only their first `k = min(m, n)` columns.
+///
+/// # Returns
+///
+/// A tuple with `U`, the singular values `S` of shape `[..., k]` in descending order, and `V`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the matrices are decomposed sequentially on the
+/// host with the Jacobi method, which is slow.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn svd<B: Backend, const D: usize, const D2: usize>(
+    tensor: FloatTensor<B, D>,
+    full_matrices: bool,
+) -> (FloatTensor<B, D>, FloatTensor<B, D2>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor).read();
+    let (u, s, v) = svd_data(data, full_matrices);
+
+    (
+        B::float_from_data(u, &device),
+        B::float_from_data(s, &device),
+        B::float_from_data(v, &device),
+    )
+}
+
+/// Compute the singular value decomposition `A = U diag(S) V^T` of the matrices in the last two
+/// dimensions of the tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor of shape `[..., m, n]`.
+/// * `full_matrices` - Whether to return the full orthogonal matrices `U` of shape `[..., m, m]`
+///   and `V` of shape `[..., n, n]`, instead of only their first `k = min(m, n)` columns.
+///
+/// # Returns
+///
+/// A tuple with `U`, the singular values `S` of shape `[..., k]` in descending order, and `V`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the matrices are decomposed sequentially on the
+/// host with the Jacobi method, which is slow.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn svd<B: Backend, const D: usize, const D2: usize>(
+    tensor: FloatTensor<B, D>,
+    full_matrices: bool,
+) -> (FloatTensor<B, D>, FloatTensor<B, D2>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor).read().await;
+    let (u, s, v) = svd_data(data, full_matrices);
+
+    (
+        B::float_from_data(u, &device),
+        B::float_from_data(s, &device),
+        B::float_from_data(v, &device),
+    )
+}
+
+/// Compute the eigen decomposition of the symmetric matrices in the last two dimensions of the
+/// tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor of shape `[..., n, n]`, assumed to be symmetric.
+///
+/// # Returns
+///
+/// A tuple with the eigenvalues of shape `[..., n]` in ascending order, and the corresponding
+/// eigenvectors as the columns of a tensor of shape `[..., n, n]`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the matrices are decomposed sequentially on the
+/// host with the Jacobi method, which is slow.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn symeig<B: Backend, const D: usize, const D2: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D2>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor).read();
+    let (values, vectors) = symeig_data(data);
+
+    (
+        B::float_from_data(values, &device),
+        B::float_from_data(vectors, &device),
+    )
+}
+
+/// Compute the eigen decomposition of the symmetric matrices in the last two dimensions of the
+/// tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor of shape `[..., n, n]`, assumed to be symmetric.
+///
+/// # Returns
+///
+/// A tuple with the eigenvalues of shape `[..., n]` in ascending order, and the corresponding
+/// eigenvectors as the columns of a tensor of shape `[..., n, n]`.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+///
+/// The data is read back from the device and the matrices are decomposed sequentially on the
+/// host with the Jacobi method, which is slow.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn symeig<B: Backend, const D: usize, const D2: usize>(
+    tensor: FloatTensor<B, D>,
+) -> (FloatTensor<B, D2>, FloatTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let data = B::float_into_data(tensor).read().await;
+    let (values, vectors) = symeig_data(data);
+
+    (
+        B::float_from_data(values, &device),
+        B::float_from_data(vectors, &device),
+    )
+}
+
+fn inverse_data<E: Element, const D: usize>(data: Data<E, D>) -> Data<E, D> {
+    let n = data.shape.dims[D - 1];
+
+    if n == 0 {
+        return data;
+    }
+
+    let values = to_f64(&data.value);
+    let mut output = Vec::with_capacity(values.len());
+
+    for matrix in values.chunks(n * n) {
+        let mut inverse = identity(n);
+        gauss_jordan(matrix.to_vec(), &mut inverse, n, n);
+        output.extend(inverse);
+    }
+
+    Data::new(from_f64(output), data.shape)
+}
+
+fn solve_data<E: Element, const D: usize>(lhs: Data<E, D>, rhs: Data<E, D>) -> Data<E, D> {
+    let n = lhs.shape.dims[D - 1];
+    let k = rhs.shape.dims[D - 1];
+
+    if n == 0 || k == 0 {
+        return rhs;
+    }
+
+    let matrices = to_f64(&lhs.value);
+    let mut output = to_f64(&rhs.value);
+
+    for (matrix, solution) in matrices.chunks(n * n).zip(output.chunks_mut(n * k)) {
+        gauss_jordan(matrix.to_vec(), solution, n, k);
+    }
+
+    Data::new(from_f64(output), rhs.shape)
+}
+
+fn cholesky_data<E: Element, const D: usize>(
+    data: Data<E, D>,
+    upper: bool,
+) -> Result<Data<E, D>, LinalgError> {
+    let n = data.shape.dims[D - 1];
+
+    if n == 0 {
+        return Ok(data);
+    }
+
+    let values = to_f64(&data.value);
+    let mut output = alloc::vec![0.0; values.len()];
+
+    for (index, (matrix, factor)) in values
+        .chunks(n * n)
+        .zip(output.chunks_mut(n * n))
+        .enumerate()
+    {
+        // Cholesky-Banachiewicz, computing the lower factor row by row.
+        for i in 0..n {
+            for j in 0..=i {
+                let sum = (0..j)
+                    .map(|k| factor[i * n + k] * factor[j * n + k])
+                    .sum::<f64>();
+                let value = matrix[i * n + j] - sum;
+
+                if i != j {
+                    factor[i * n + j] = value / factor[j * n + j];
+                    continue;
+                }
+
+                // NaN is also rejected, it would otherwise propagate silently.
+                if value.is_nan() || value <= 0.0 {
+                    return Err(LinalgError::NotPositiveDefinite { matrix: index });
+                }
+
+                factor[i * n + i] = libm::sqrt(value);
+            }
+        }
+
+        if upper {
+            for i in 0..n {
+                for j in 0..i {
+                    factor.swap(i * n + j, j * n + i);
+                }
+            }
+        }
+    }
+
+    Ok(Data::new(from_f64(output), data.shape))
+}
+
+/// Reduce the `n x n` matrix to the identity with the Gauss-Jordan elimination, applying the same
+/// row operations to the `n x k` right hand side, which then contains the solution.
+fn gauss_jordan(mut matrix: Vec<f64>, rhs: &mut [f64], n: usize, k: usize) {
+    for col in 0..n {
+        // Partial pivoting keeps the elimination stable.
+        let pivot = (col..n)
+            .max_by(|a, b| {
+                libm::fabs(matrix[a * n + col]).total_cmp(&libm::fabs(matrix[b * n + col]))
+            })
+            .unwrap_or(col);
+
+        if pivot != col {
+            for j in 0..n {
+                matrix.swap(col * n + j, pivot * n + j);
+            }
+            for j in 0..k {
+                rhs.swap(col * k + j, pivot * k + j);
+            }
+        }
+
+        // A singular matrix has a zero pivot, which makes the solution non-finite.
+        let scale = matrix[col * n + col];
+        for j in 0..n {
+            matrix[col * n + j] /= scale;
+        }
+        for j in 0..k {
+            rhs[col * k + j] /= scale;
+        }
+
+        for row in (0..n).filter(|row| *row != col) {
+            let factor = matrix[row * n + col];
+
+            if factor == 0.0 {
+                continue;
+            }
+
+            for j in 0..n {
+                matrix[row * n + j] -= factor * matrix[col * n + j];
+            }
+            for j in 0..k {
+                rhs[row * k + j] -= factor * rhs[col * k + j];
+            }
+        }
+    }
+}
+
+fn svd_data<E: Element, const D: usize, const D2: usize>(
+    data: Data<E, D>,
+    full_matrices: bool,
+) -> (Data<E, D>, Data<E, D2>, Data<E, D>) {
+    let m = data.shape.dims[D - 2];
+    let n = data.shape.dims[D - 1];
+    let k = usize::min(m, n);
+    let (cols_u, cols_v) = match full_matrices {
+        true => (m, n),
+        false => (k, k),
+    };
+
+    let values = to_f64(&data.value);
+    let mut u = Vec::new();
+    let mut s = Vec::new();
+    let mut v = Vec::new();
+
+    for batch in 0..num_matrices(&data.shape) {
+        let matrix = &values[batch * m * n..(batch + 1) * m * n];
+        let (matrix_u, matrix_s, matrix_v) = svd_matrix(matrix, m, n, full_matrices);
+
+        u.extend(matrix_u);
+        s.extend(matrix_s);
+        v.extend(matrix_v);
+    }
+
+    (
+        Data::new(from_f64(u), matrices_shape(&data.shape, m, cols_u)),
+        Data::new(from_f64(s), values_shape(&data.shape, k)),
+        Data::new(from_f64(v), matrices_shape(&data.shape, n, cols_v)),
+    )
+}
+
+fn symeig_data<E: Element, const D: usize, const D2: usize>(
+    data: Data<E, D>,
+) -> (Data<E, D2>, Data<E, D>) {
+    let n = data.shape.dims[D - 1];
+
+    let values = to_f64(&data.value);
+    let mut eigenvalues = Vec::new();
+    let mut eigenvectors = Vec::new();
+
+    for batch in 0..num_matrices(&data.shape) {
+        let matrix = &values[batch * n * n..(batch + 1) * n * n];
+        let (matrix_values, matrix_vectors) = symeig_matrix(matrix, n);
+
+        eigenvalues.extend(matrix_values);
+        eigenvectors.extend(matrix_vectors);
+    }
+
+    (
+        Data::new(from_f64(eigenvalues), values_shape(&data.shape, n)),
+        Data::new(from_f64(eigenvectors), data.shape),
+    )
+}
+
+fn num_matrices<const D: usize>(shape: &Shape<D>) -> usize {
+    shape.dims[..D - 2].iter().product()
+}
+
+fn matrices_shape<const D: usize>(shape: &Shape<D>, rows: usize, cols: usize) -> Shape<D> {
+    let mut dims = shape.dims;
+    dims[D - 2] = rows;
+    dims[D - 1] = cols;
+
+    Shape::new(dims)
+}
+
+/// The shape of one value per matrix row, with the matrix dimensions replaced by `size`.
+fn values_shape<const D: usize, const D2: usize>(shape: &Shape<D>, size: usize) -> Shape<D2> {
+    let mut dims = [size; D2];
+    dims[..D2 - 1].copy_from_slice(&shape.dims[..D - 2]);
+
+    Shape::new(dims)
+}
+
+/// The maximum number of sweeps of the Jacobi method, which usually converges in less than ten.
+const MAX_SWEEPS: usize = 64;
+
+/// Eigen decomposition of the symmetric `n x n` matrix with the cyclic Jacobi method, returning
+/// the eigenvalues in ascending order and the row major matrix of the eigenvectors as columns.
+fn symeig_matrix(matrix: &[f64], n: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut a = columns(matrix, n, n);
+    let mut vectors = columns(&identity(n), n, n);
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal = (0..n)
+            .flat_map(|j| (0..n).filter(move |i| *i != j).map(move |i| (i, j)))
+            .map(|(i, j)| a[j][i] * a[j][i])
+            .sum::<f64>();
+        let total = a.iter().flatten().map(|v| v * v).sum::<f64>();
+
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * total {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[q][p];
+
+                if apq == 0.0 {
+                    continue;
+                }
+
+                let t = jacobi_tangent((a[q][q] - a[p][p]) / (2.0 * apq));
+                let cos = 1.0 / libm::sqrt(1.0 + t * t);
+                let sin = t * cos;
+
+                // A' = J^T A J, rotating the columns and then the rows.
+                rotate(&mut a, p, q, cos, sin);
+                for column in a.iter_mut() {
+                    let (xp, xq) = (column[p], column[q]);
+                    column[p] = cos * xp - sin * xq;
+                    column[q] = sin * xp + cos * xq;
+                }
+                rotate(&mut vectors, p, q, cos, sin);
+            }
+        }
+    }
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|i, j| a[*i][*i].total_cmp(&a[*j][*j]));
+
+    let values = order.iter().map(|i| a[*i][*i]).collect();
+    let vectors = order
+        .iter()
+        .map(|i| vectors[*i].clone())
+        .collect::<Vec<_>>();
+
+    (values, from_columns(&vectors, n))
+}
+
+/// Singular value decomposition of the `m x n` matrix with the one-sided Jacobi method, returning
+/// the row major `U`, the singular values in descending order and the row major `V`.
+fn svd_matrix(
+    matrix: &[f64],
+    m: usize,
+    n: usize,
+    full_matrices: bool,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    if m < n {
+        // The columns are orthogonalized, so a wide matrix is decomposed through its transpose:
+        // A^T = U S V^T, so A = V S U^T.
+        let (v, s, u) = svd_matrix(&transpose(matrix, m, n), n, m, full_matrices);
+        return (u, s, v);
+    }
+
+    let mut a = columns(matrix, m, n);
+    let mut v = columns(&identity(n), n, n);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = dot(&a[p], &a[p]);
+                let beta = dot(&a[q], &a[q]);
+                let gamma = dot(&a[p], &a[q]);
+
+                if libm::fabs(gamma) <= f64::EPSILON * libm::sqrt(alpha * beta) {
+                    continue;
+                }
+
+                rotated = true;
+                let t = jacobi_tangent((beta - alpha) / (2.0 * gamma));
+                let cos = 1.0 / libm::sqrt(1.0 + t * t);
+                let sin = t * cos;
+
+                rotate(&mut a, p, q, cos, sin);
+                rotate(&mut v, p, q, cos, sin);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms = a
+        .iter()
+        .map(|column| libm::sqrt(dot(column, column)))
+        .collect::<Vec<_>>();
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|i, j| norms[*j].total_cmp(&norms[*i]));
+
+    let tolerance = norms.iter().cloned().fold(0.0, f64::max) * f64::EPSILON * m as f64;
+
+    let values = order.iter().map(|i| norms[*i]).collect::<Vec<_>>();
+    let v = order.iter().map(|i| v[*i].clone()).collect::<Vec<_>>();
+    // The left vectors of the null singular values are undefined, the basis is completed instead.
+    let u = order
+        .iter()
+        .filter(|i| norms[**i] > tolerance)
+        .map(|i| a[*i].iter().map(|x| x / norms[*i]).collect())
+        .collect::<Vec<_>>();
+    let u = complete_basis(u, m, if full_matrices { m } else { n });
+
+    (from_columns(&u, m), values, from_columns(&v, n))
+}
+
+/// The tangent of the Jacobi rotation zeroing the off-diagonal element, using the smallest angle
+/// for stability.
+fn jacobi_tangent(zeta: f64) -> f64 {
+    let sign = if zeta >= 0.0 { 1.0 } else { -1.0 };
+    sign / (libm::fabs(zeta) + libm::sqrt(1.0 + zeta * zeta))
+}
+
+/// Rotate the columns `p` and `q`, where `p < q`.
+fn rotate(columns: &mut [Vec<f64>], p: usize, q: usize, cos: f64, sin: f64) {
+    let (left, right) = columns.split_at_mut(q);
+
+    for (xp, xq) in left[p].iter_mut().zip(right[0].iter_mut()) {
+        let (vp, vq) = (*xp, *xq);
+        *xp = cos * vp - sin * vq;
+        *xq = sin * vp + cos * vq;
+    }
+}
+
+/// Extend the orthonormal vectors of the given size to `count` vectors, orthogonalizing the
+/// standard basis against them with the Gram-Schmidt process.
+fn complete_basis(mut vectors: Vec<Vec<f64>>, size: usize, count: usize) -> Vec<Vec<f64>> {
+    for axis in 0..size {
+        if vectors.len() >= count {
+            break;
+        }
+
+        let mut candidate = alloc::vec![0.0; size];
+        candidate[axis] = 1.0;
+
+        // Orthogonalizing twice is enough to reach the working precision.
+        for _ in 0..2 {
+            for vector in vectors.iter() {
+                let projection = dot(&candidate, vector);
+                candidate
+                    .iter_mut()
+                    .zip(vector)
+                    .for_each(|(c, v)| *c -= projection * v);
+            }
+        }
+
+        // The axis is skipped when it is mostly spanned by the vectors.
+        let norm = libm::sqrt(dot(&candidate, &candidate));
+        if norm > 0.5 {
+            vectors.push(candidate.into_iter().map(|c| c / norm).collect());
+        }
+    }
+
+    vectors
+}
+
+/// The columns of the row major `rows x cols` matrix.
+fn columns(matrix: &[f64], rows: usize, cols: usize) -> Vec<Vec<f64>> {
+    (0..cols)
+        .map(|j| (0..rows).map(|i| matrix[i * cols + j]).collect())
+        .collect()
+}
+
+/// The row major matrix with the given columns of size `rows`.
+fn from_columns(columns: &[Vec<f64>], rows: usize) -> Vec<f64> {
+    let cols = columns.len();
+
+    (0..rows * cols)
+        .map(|index| columns[index % cols][index / cols])
+        .collect()
+}
+
+fn transpose(matrix: &[f64], rows: usize, cols: usize) -> Vec<f64> {
+    (0..rows * cols)
+        .map(|index| matrix[(index % rows) * cols + index / rows])
+        .collect()
+}
+
+fn identity(n: usize) -> Vec<f64> {
+    let mut matrix = alloc::vec![0.0; n * n];
+    for i in 0..n {
+        matrix[i * n + i] = 1.0;
+    }
+
+    matrix
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| a * b).sum()
+}
+
+fn to_f64<E: Element>(values: &[E]) -> Vec<f64> {
+    values.iter().map(|v| v.elem::<f64>()).collect()
+}
+
+fn from_f64<E: Element>(values: Vec<f64>) -> Vec<E> {
+    values.into_iter().map(|v| v.elem()).collect()
+}
@@ -5,16 +5,32 @@ mod autodiff;
 mod base;
 mod bool;
 mod chunk;
+mod complex;
+mod einsum;
+mod fft;
 mod float;
+mod grad;
 mod int;
 mod kind;
+mod linalg;
 mod narrow;
 mod numeric;
+mod quantization;
+mod topk;
+mod unique;
 
 pub use argwhere::argwhere;
 pub use autodiff::*;
 pub use base::*;
 pub use chunk::chunk;
+pub use einsum::einsum;
+pub use grad::{grad, value_and_grad};
 pub use kind::*;
+pub use linalg::{cholesky, inverse, solve, svd, symeig, LinalgError};
 pub use narrow::narrow;
 pub use numeric::*;
+pub use quantization::{
+    dequantize, quantize, quantize_per_channel, QuantizationParams, QuantizationScheme,
+};
+pub use topk::topk_indices;
+pub use unique::{unique, unique_with_counts};
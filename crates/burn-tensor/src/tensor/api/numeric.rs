@@ -1,10 +1,40 @@
 use crate::alloc::borrow::ToOwned;
 
 use crate::{
-    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Distribution, Element,
+    backend::Backend, check, check::TensorCheck, BasicOps, Bool, Data, Distribution, Element,
     ElementConversion, Float, Int, Shape, Tensor, TensorKind,
 };
+use alloc::vec::Vec;
 use num_traits::Zero;
+use rand::RngCore;
+
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+use crate::topk_indices;
+
+/// The reduction used to combine the scattered values with the elements of the tensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScatterReduction {
+    /// The values are added to the elements.
+    Add,
+    /// The elements are multiplied by the values.
+    Multiply,
+    /// The elements are replaced by the values. When an element is targeted multiple times, the
+    /// last value along the scattered dimension is kept.
+    Overwrite,
+}
+
+/// The mode used to fill the padded elements with [pad](Tensor::pad).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadMode {
+    /// The padded elements are filled with a constant value.
+    Constant,
+    /// The padded elements mirror the elements next to the edge, excluding the edge:
+    /// `[1, 2, 3]` padded by two on both sides becomes `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+    /// The padded elements repeat the edge: `[1, 2, 3]` padded by two on both sides becomes
+    /// `[1, 1, 1, 2, 3, 3, 3]`.
+    Replicate,
+}
 
 impl<B, const D: usize, K> Tensor<B, D, K>
 where
@@ -145,6 +175,132 @@ where
         Self::new(K::prod_dim(self.primitive, dim))
     }
 
+    /// Accumulate the elements along the given *dimension* or *axis* with the sum operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - When true, each element is excluded from its own sum, so the first sum is
+    ///   zero, otherwise it's included, so the last sum is the sum of the whole dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0], &device);
+    ///
+    ///     let inclusive = tensor.clone().cumsum(0, false); // [1.0, 3.0, 6.0]
+    ///     let exclusive = tensor.cumsum(0, true); // [0.0, 1.0, 3.0]
+    /// }
+    /// ```
+    pub fn cumsum(self, dim: usize, exclusive: bool) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumsum", dim));
+        Self::new(K::cumsum(self.primitive, dim, exclusive))
+    }
+
+    /// Accumulate the elements along the given *dimension* or *axis* with the product operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - When true, each element is excluded from its own product, so the first
+    ///   product is one, otherwise it's included, so the last product is the product of the whole
+    ///   dimension.
+    pub fn cumprod(self, dim: usize, exclusive: bool) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Cumprod", dim));
+        Self::new(K::cumprod(self.primitive, dim, exclusive))
+    }
+
+    /// Pad the tensor along each dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `padding` - The number of elements added (before, after) each dimension, with one pair
+    ///   per dimension.
+    /// * `mode` - How the padded elements are filled.
+    /// * `value` - The value of the padded elements with [PadMode::Constant], ignored otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the number of pairs doesn't match the number of dimensions, if a padded dimension is
+    /// empty with [PadMode::Reflect] or [PadMode::Replicate], or if the padding isn't smaller
+    /// than the size of the dimension with [PadMode::Reflect].
+    pub fn pad(self, padding: &[(usize, usize)], mode: PadMode, value: f32) -> Self {
+        check!(TensorCheck::pad::<D>(padding, &self.shape(), mode));
+
+        padding
+            .iter()
+            .enumerate()
+            .filter(|(_, (before, after))| *before > 0 || *after > 0)
+            .fold(self, |tensor, (dim, &(before, after))| {
+                let size = tensor.dims()[dim];
+                let device = tensor.device();
+
+                let edge = |tensor: &Self, length: usize, is_before: bool| -> Self {
+                    match mode {
+                        PadMode::Constant => {
+                            let mut shape = tensor.shape();
+                            shape.dims[dim] = length;
+                            Self::full(shape, value, &device)
+                        }
+                        PadMode::Reflect => {
+                            let start = if is_before { 1 } else { size - 1 - length };
+                            tensor
+                                .clone()
+                                .narrow(dim, start, length)
+                                .flip([dim as isize])
+                        }
+                        PadMode::Replicate => {
+                            let start = if is_before { 0 } else { size - 1 };
+                            tensor.clone().narrow(dim, start, 1).repeat(dim, length)
+                        }
+                    }
+                };
+
+                let mut tensors = Vec::with_capacity(3);
+                if before > 0 {
+                    tensors.push(edge(&tensor, before, true));
+                }
+                let padded_after = (after > 0).then(|| edge(&tensor, after, false));
+                tensors.push(tensor);
+                tensors.extend(padded_after);
+
+                Tensor::cat(tensors, dim)
+            })
+    }
+
+    /// Compute the n-th order discrete difference along the given *dimension* or *axis*.
+    ///
+    /// The first order difference is `output[i] = input[i + 1] - input[i]`, the higher orders are
+    /// computed by applying it repeatedly, so the size of the dimension shrinks by `n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of times the difference is computed. When it's larger than or equal to
+    ///   the size of the dimension, the output has a size of zero on that dimension.
+    /// * `dim` - The dimension along which to compute the difference.
+    pub fn diff(self, n: usize, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Diff", dim));
+
+        let size = self.dims()[dim];
+
+        if n >= size {
+            let mut shape = self.shape();
+            shape.dims[dim] = 0;
+            return Self::empty(shape, &self.device());
+        }
+
+        (0..n).fold(self, |tensor, i| {
+            let length = size - i - 1;
+            let next = tensor.clone().narrow(dim, 1, length);
+            next.sub(tensor.narrow(dim, 0, length))
+        })
+    }
+
     /// Applies element wise equal comparison and returns a boolean tensor.
     pub fn equal_elem<E: Element>(self, other: E) -> Tensor<B, D, Bool> {
         K::equal_elem::<D>(self.primitive, other.elem())
@@ -242,13 +398,21 @@ where
     /// # Notes
     ///
     /// The index tensor should have the same shape as the original tensor except for the dim
-    /// specified.
+    /// specified. The other dimensions of size one of the index tensor are broadcasted, so a
+    /// single index vector can be gathered across a batch.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes don't match, or in debug builds, if an index is out of bounds (see the
+    /// `check-indices-bounds` and `unchecked-indices` features).
     pub fn gather(self, dim: usize, indices: Tensor<B, D, Int>) -> Self {
+        let indices = broadcast_indices(dim, indices, &self.shape());
         check!(TensorCheck::gather::<D>(
             dim,
             &self.shape(),
             &indices.shape()
         ));
+        check_indices_bounds("Gather", dim, self.dims()[dim], &indices);
 
         Self::new(K::gather(dim, self.primitive, indices))
     }
@@ -265,18 +429,89 @@ where
     /// # Notes
     ///
     /// The index tensor should have the same shape as the original tensor except for the specified
-    /// dimension. The value and index tensors should have the same shape.
+    /// dimension. The value and index tensors should have the same shape, except for the
+    /// dimensions of size one of the index tensor other than `dim`, which are broadcasted.
     ///
     /// Other references to the input tensor will not be modified by this operation.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes don't match, or in debug builds, if an index is out of bounds (see the
+    /// `check-indices-bounds` and `unchecked-indices` features).
     pub fn scatter(self, dim: usize, indices: Tensor<B, D, Int>, values: Self) -> Self {
+        self.scatter_reduce(dim, indices, values, ScatterReduction::Add)
+    }
+
+    /// Assign the gathered elements corresponding to the given indices along the specified dimension
+    /// from the value tensor to the original tensor using the given reduction.
+    ///
+    /// Example using a 3D tensor with [ScatterReduction::Multiply]:
+    ///
+    /// `input[indices[i, j, k], j, k] *= values[i, j, k]; // dim = 0`
+    /// `input[i, indices[i, j, k], k] *= values[i, j, k]; // dim = 1`
+    /// `input[i, j, indices[i, j, k]] *= values[i, j, k]; // dim = 2`
+    ///
+    /// # Notes
+    ///
+    /// The shapes follow the same rules as [scatter](Tensor::scatter).
+    ///
+    /// Only [ScatterReduction::Add] maps to a single backend operation, the other reductions
+    /// scatter the values one slice along `dim` at a time.
+    ///
+    /// # Panics
+    ///
+    /// If the shapes don't match, or in debug builds, if an index is out of bounds (see the
+    /// `check-indices-bounds` and `unchecked-indices` features).
+    pub fn scatter_reduce(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        values: Self,
+        reduction: ScatterReduction,
+    ) -> Self {
+        let indices = broadcast_indices(dim, indices, &values.shape());
         check!(TensorCheck::scatter::<D>(
             dim,
             &self.shape(),
             &indices.shape(),
             &values.shape()
         ));
-
-        Self::new(K::scatter(dim, self.primitive, indices, values.primitive))
+        check_indices_bounds("Scatter", dim, self.dims()[dim], &indices);
+
+        if reduction == ScatterReduction::Add {
+            return Self::new(K::scatter(dim, self.primitive, indices, values.primitive));
+        }
+
+        let shape = self.shape();
+        let device = self.device();
+        let mut tensor = self.primitive;
+
+        // Each element of the tensor is targeted at most once by a slice of the indices along
+        // `dim`, so scattering the slices in order gives a well defined result with repeated
+        // indices.
+        for i in 0..indices.dims()[dim] {
+            let indices = indices.clone().narrow(dim, i, 1);
+            let values = values.clone().narrow(dim, i, 1).primitive;
+            let zeros = K::zeros(shape.clone(), &device);
+
+            tensor = match reduction {
+                ScatterReduction::Multiply => {
+                    // The factors are the values on the targeted elements and one elsewhere.
+                    let values = K::sub_scalar(values, 1);
+                    let factors = K::add_scalar(K::scatter(dim, zeros, indices, values), 1);
+                    K::mul(tensor, factors)
+                }
+                ScatterReduction::Overwrite => {
+                    let ones = K::ones(indices.shape(), &device);
+                    let mask = K::scatter(dim, zeros.clone(), indices.clone(), ones);
+                    let mask = K::greater_elem(mask, K::Elem::zero());
+                    K::mask_where(tensor, mask, K::scatter(dim, zeros, indices, values))
+                }
+                ScatterReduction::Add => unreachable!(),
+            };
+        }
+
+        Self::new(tensor)
     }
 
     /// Select the tensor elements along the given dimension corresponding to the given indices.
@@ -315,6 +550,220 @@ where
         ))
     }
 
+    /// Select the elements where the mask is true into a 1D tensor, in row-major order.
+    ///
+    /// # Notes
+    ///
+    /// The number of selected elements depends on the mask values, so the mask has to be read,
+    /// which syncs the device with the host.
+    ///
+    /// # Panics
+    ///
+    /// If the mask doesn't have the same shape as the tensor.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn masked_select(self, mask: Tensor<B, D, Bool>) -> Tensor<B, 1, K> {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let indices = masked_indices(mask.into_data(), &self.device());
+        self.masked_select_indices(indices)
+    }
+
+    /// Select the elements where the mask is true into a 1D tensor, in row-major order.
+    ///
+    /// # Notes
+    ///
+    /// The number of selected elements depends on the mask values, so the mask has to be read,
+    /// which syncs the device with the host.
+    ///
+    /// # Panics
+    ///
+    /// If the mask doesn't have the same shape as the tensor.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn masked_select(self, mask: Tensor<B, D, Bool>) -> Tensor<B, 1, K> {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let indices = masked_indices(mask.into_data().await, &self.device());
+        self.masked_select_indices(indices)
+    }
+
+    /// Copy the elements of the source into the positions of the tensor where the mask is true,
+    /// in row-major order.
+    ///
+    /// This is the reverse of [masked_select](Tensor::masked_select):
+    /// `tensor.masked_scatter(mask.clone(), tensor.masked_select(mask))` returns the same tensor.
+    ///
+    /// # Notes
+    ///
+    /// The number of selected elements depends on the mask values, so the mask has to be read,
+    /// which syncs the device with the host. Only the first elements of the source are used when
+    /// it has more elements than selected positions.
+    ///
+    /// # Panics
+    ///
+    /// If the mask doesn't have the same shape as the tensor, or if the source has fewer
+    /// elements than selected positions.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn masked_scatter(self, mask: Tensor<B, D, Bool>, source: Tensor<B, 1, K>) -> Self {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let indices = masked_indices(mask.clone().into_data(), &self.device());
+        self.masked_scatter_indices(mask, indices, source)
+    }
+
+    /// Copy the elements of the source into the positions of the tensor where the mask is true,
+    /// in row-major order.
+    ///
+    /// This is the reverse of [masked_select](Tensor::masked_select):
+    /// `tensor.masked_scatter(mask.clone(), tensor.masked_select(mask))` returns the same tensor.
+    ///
+    /// # Notes
+    ///
+    /// The number of selected elements depends on the mask values, so the mask has to be read,
+    /// which syncs the device with the host. Only the first elements of the source are used when
+    /// it has more elements than selected positions.
+    ///
+    /// # Panics
+    ///
+    /// If the mask doesn't have the same shape as the tensor, or if the source has fewer
+    /// elements than selected positions.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn masked_scatter(self, mask: Tensor<B, D, Bool>, source: Tensor<B, 1, K>) -> Self {
+        check!(TensorCheck::masked_select::<D>(
+            &self.shape(),
+            &mask.shape()
+        ));
+
+        let indices = masked_indices(mask.clone().into_data().await, &self.device());
+        self.masked_scatter_indices(mask, indices, source)
+    }
+
+    fn masked_select_indices(self, indices: Option<Tensor<B, 1, Int>>) -> Tensor<B, 1, K> {
+        let device = self.device();
+        let num_elements = self.shape().num_elements();
+
+        match indices {
+            Some(indices) => self.reshape([num_elements]).select(0, indices),
+            None => Tensor::empty([0], &device),
+        }
+    }
+
+    fn masked_scatter_indices(
+        self,
+        mask: Tensor<B, D, Bool>,
+        indices: Option<Tensor<B, 1, Int>>,
+        source: Tensor<B, 1, K>,
+    ) -> Self {
+        let indices = match indices {
+            Some(indices) => indices,
+            None => return self,
+        };
+
+        let shape = self.shape();
+        let num_elements = shape.num_elements();
+        let num_selected = indices.dims()[0];
+        check!(TensorCheck::masked_scatter(num_selected, source.dims()[0]));
+
+        // The selected elements are zeroed, so that assigning the values with a sum reduction
+        // overwrites them.
+        let tensor = self
+            .reshape([num_elements])
+            .mask_fill(mask.reshape([num_elements]), 0);
+        let values = source.narrow(0, 0, num_selected);
+
+        tensor.select_assign(0, indices, values).reshape(shape)
+    }
+
+    /// Repeat each element of the tensor along the given dimension the number of times given by
+    /// the repeats tensor, before moving to the next element.
+    ///
+    /// See [repeat_interleave](Tensor::repeat_interleave) when all the elements are repeated the
+    /// same number of times.
+    ///
+    /// # Arguments
+    ///
+    /// * `repeats` - The number of times each element is repeated, with one value per element of
+    ///   the dimension.
+    /// * `dim` - The dimension along which to repeat the elements.
+    ///
+    /// # Notes
+    ///
+    /// The size of the output depends on the repeats values, so the repeats have to be read,
+    /// which syncs the device with the host.
+    ///
+    /// # Panics
+    ///
+    /// If the number of repeats doesn't match the size of the dimension, or if a repeat is
+    /// negative.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn repeat_interleave_tensor(self, repeats: Tensor<B, 1, Int>, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("repeat_interleave", dim));
+
+        let repeats = repeats.into_data().convert::<i64>().value;
+        self.repeat_interleave_values(&repeats, dim)
+    }
+
+    /// Repeat each element of the tensor along the given dimension the number of times given by
+    /// the repeats tensor, before moving to the next element.
+    ///
+    /// See [repeat_interleave](Tensor::repeat_interleave) when all the elements are repeated the
+    /// same number of times.
+    ///
+    /// # Arguments
+    ///
+    /// * `repeats` - The number of times each element is repeated, with one value per element of
+    ///   the dimension.
+    /// * `dim` - The dimension along which to repeat the elements.
+    ///
+    /// # Notes
+    ///
+    /// The size of the output depends on the repeats values, so the repeats have to be read,
+    /// which syncs the device with the host.
+    ///
+    /// # Panics
+    ///
+    /// If the number of repeats doesn't match the size of the dimension, or if a repeat is
+    /// negative.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn repeat_interleave_tensor(self, repeats: Tensor<B, 1, Int>, dim: usize) -> Self {
+        check!(TensorCheck::dim_ops::<D>("repeat_interleave", dim));
+
+        let repeats = repeats.into_data().await.convert::<i64>().value;
+        self.repeat_interleave_values(&repeats, dim)
+    }
+
+    fn repeat_interleave_values(self, repeats: &[i64], dim: usize) -> Self {
+        check!(TensorCheck::repeat_interleave(repeats, self.dims()[dim]));
+
+        let device = self.device();
+        let indices = repeats
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &times)| {
+                core::iter::repeat((index as i64).elem()).take(times as usize)
+            })
+            .collect::<Vec<B::IntElem>>();
+
+        if indices.is_empty() {
+            let mut shape = self.shape();
+            shape.dims[dim] = 0;
+            return Self::empty(shape, &device);
+        }
+
+        let num_indices = indices.len();
+        let indices = Tensor::from_data(Data::new(indices, Shape::new([num_indices])), &device);
+
+        self.select(dim, indices)
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
     /// # Example
@@ -361,6 +810,216 @@ where
         (tensor, index)
     }
 
+    /// Find the `k` largest or smallest elements along the given dimension.
+    ///
+    /// Also returns the indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of elements to select. When larger than the size of the dimension, all
+    ///   the elements are selected and a warning is logged.
+    /// * `dim` - The dimension along which to select the elements.
+    /// * `largest` - Whether to select the largest or the smallest elements.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the selected elements and their indices, with the same shape as the
+    /// tensor except in the `dim` axis where the size is `k`. The elements are sorted from the
+    /// largest to the smallest, or the opposite when `largest` is false, and equal elements are
+    /// ordered by their index.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn topk_with_indices(
+        self,
+        k: usize,
+        dim: usize,
+        largest: bool,
+    ) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::topk::<D>(k, dim));
+        let k = Self::topk_clamp(k, self.dims()[dim]);
+
+        let (tensor, index) = K::topk_with_indices(self.primitive, k, dim, largest);
+
+        (Tensor::new(tensor), Tensor::new(index))
+    }
+
+    /// Find the `k` largest or smallest elements along the given dimension.
+    ///
+    /// Also returns the indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of elements to select. When larger than the size of the dimension, all
+    ///   the elements are selected and a warning is logged.
+    /// * `dim` - The dimension along which to select the elements.
+    /// * `largest` - Whether to select the largest or the smallest elements.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the selected elements and their indices, with the same shape as the
+    /// tensor except in the `dim` axis where the size is `k`. The elements are sorted from the
+    /// largest to the smallest, or the opposite when `largest` is false, and equal elements are
+    /// ordered by their index.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn topk_with_indices(
+        self,
+        k: usize,
+        dim: usize,
+        largest: bool,
+    ) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::topk::<D>(k, dim));
+        let k = Self::topk_clamp(k, self.dims()[dim]);
+
+        let index = topk_indices::<B, K, D>(self.primitive.clone(), k, dim, largest).await;
+        let tensor = K::gather(dim, self.primitive, Tensor::new(index.clone()));
+
+        (Tensor::new(tensor), Tensor::new(index))
+    }
+
+    /// Find the `k` largest or smallest elements along the given dimension.
+    ///
+    /// See [topk_with_indices](Tensor::topk_with_indices) for the details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn topk(self, k: usize, dim: usize, largest: bool) -> Tensor<B, D, K> {
+        self.topk_with_indices(k, dim, largest).0
+    }
+
+    /// Find the `k` largest or smallest elements along the given dimension.
+    ///
+    /// See [topk_with_indices](Tensor::topk_with_indices) for the details.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn topk(self, k: usize, dim: usize, largest: bool) -> Tensor<B, D, K> {
+        self.topk_with_indices(k, dim, largest).await.0
+    }
+
+    /// Find the indices of the `k` largest or smallest elements along the given dimension.
+    ///
+    /// See [topk_with_indices](Tensor::topk_with_indices) for the details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn topk_indices(self, k: usize, dim: usize, largest: bool) -> Tensor<B, D, Int> {
+        self.topk_with_indices(k, dim, largest).1
+    }
+
+    /// Find the indices of the `k` largest or smallest elements along the given dimension.
+    ///
+    /// See [topk_with_indices](Tensor::topk_with_indices) for the details.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn topk_indices(self, k: usize, dim: usize, largest: bool) -> Tensor<B, D, Int> {
+        self.topk_with_indices(k, dim, largest).await.1
+    }
+
+    fn topk_clamp(k: usize, size: usize) -> usize {
+        if k > size {
+            log::warn!(
+                "Can't select the top {k} elements of a dimension of size {size}, selecting all of them instead."
+            );
+            return size;
+        }
+
+        k
+    }
+
+    /// Sort the elements along the given dimension.
+    ///
+    /// Also returns the indices of the sorted elements in the tensor, so that
+    /// `tensor.gather(dim, indices)` gives the sorted elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension along which to sort the elements.
+    /// * `descending` - Whether to sort the elements from the largest to the smallest.
+    ///
+    /// # Notes
+    ///
+    /// The sort is stable: equal elements are ordered by their index, in both directions. NaN is
+    /// considered larger than any other value.
+    ///
+    /// The elements are sorted with [topk_with_indices](Tensor::topk_with_indices), which reads
+    /// the data on backends without a sort implementation.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn sort_with_indices(
+        self,
+        dim: usize,
+        descending: bool,
+    ) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        let size = self.dims()[dim];
+
+        if size == 0 {
+            let indices = Tensor::empty(self.shape(), &self.device());
+            return (self, indices);
+        }
+
+        self.topk_with_indices(size, dim, descending)
+    }
+
+    /// Sort the elements along the given dimension.
+    ///
+    /// See [sort_with_indices](Tensor::sort_with_indices) for the details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn sort(self, dim: usize, descending: bool) -> Tensor<B, D, K> {
+        self.sort_with_indices(dim, descending).0
+    }
+
+    /// Returns the indices that sort the elements along the given dimension.
+    ///
+    /// See [sort_with_indices](Tensor::sort_with_indices) for the details.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn argsort(self, dim: usize, descending: bool) -> Tensor<B, D, Int> {
+        self.sort_with_indices(dim, descending).1
+    }
+
+    /// Sort the elements along the given dimension.
+    ///
+    /// Also returns the indices of the sorted elements in the tensor, so that
+    /// `tensor.gather(dim, indices)` gives the sorted elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension along which to sort the elements.
+    /// * `descending` - Whether to sort the elements from the largest to the smallest.
+    ///
+    /// # Notes
+    ///
+    /// The sort is stable: equal elements are ordered by their index, in both directions. NaN is
+    /// considered larger than any other value.
+    ///
+    /// The elements are sorted with [topk_with_indices](Tensor::topk_with_indices), which reads
+    /// the data on backends without a sort implementation.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn sort_with_indices(
+        self,
+        dim: usize,
+        descending: bool,
+    ) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
+        check!(TensorCheck::dim_ops::<D>("Sort", dim));
+
+        let size = self.dims()[dim];
+
+        if size == 0 {
+            let indices = Tensor::empty(self.shape(), &self.device());
+            return (self, indices);
+        }
+
+        self.topk_with_indices(size, dim, descending).await
+    }
+
+    /// Sort the elements along the given dimension.
+    ///
+    /// See [sort_with_indices](Tensor::sort_with_indices) for the details.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn sort(self, dim: usize, descending: bool) -> Tensor<B, D, K> {
+        self.sort_with_indices(dim, descending).await.0
+    }
+
+    /// Returns the indices that sort the elements along the given dimension.
+    ///
+    /// See [sort_with_indices](Tensor::sort_with_indices) for the details.
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    pub async fn argsort(self, dim: usize, descending: bool) -> Tensor<B, D, Int> {
+        self.sort_with_indices(dim, descending).await.1
+    }
+
     /// Finds the maximum pair wise values with another Tensor
     ///
     /// # Arguments
@@ -658,6 +1317,36 @@ where
     ) -> Self {
         Self::new(K::random(shape.into(), distribution, device))
     }
+
+    /// Create a random tensor of the given shape on the given device where each element is
+    /// sampled from the given distribution with the given random number generator.
+    ///
+    /// Unlike [random](Tensor::random), which uses the generator of the backend, the values only
+    /// depend on the state of the generator, so a seeded one creates the same tensor on every
+    /// backend and target, including `no_std` ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Distribution, SeedableRng, StdRng, Tensor};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let mut rng = StdRng::seed_from_u64(42);
+    ///
+    ///     let distribution = Distribution::Normal(0.0, 1.0);
+    ///     let tensor = Tensor::<B, 2>::random_with_rng([2, 3], distribution, &mut rng, &device);
+    /// }
+    /// ```
+    pub fn random_with_rng<S: Into<Shape<D>>, R: RngCore>(
+        shape: S,
+        distribution: Distribution,
+        rng: &mut R,
+        device: &B::Device,
+    ) -> Self {
+        Self::from_data(Data::random(shape.into(), distribution, rng), device)
+    }
 }
 
 impl<B, K> Tensor<B, 2, K>
@@ -1062,6 +1751,58 @@ where
     ///
     fn prod_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
 
+    /// Computes the cumulative sum of the elements along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element is excluded from its own sum.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor containing the cumulative sums.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For the cumulative sum of a tensor, users should prefer the [Tensor::cumsum](Tensor::cumsum)
+    /// function, which is more high-level and designed for public use.
+    fn cumsum<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> Self::Primitive<D>;
+
+    /// Computes the cumulative product of the elements along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element is excluded from its own product.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as the input tensor containing the cumulative products.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For the cumulative product of a tensor, users should prefer the
+    /// [Tensor::cumprod](Tensor::cumprod) function, which is more high-level and designed for public use.
+    fn cumprod<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> Self::Primitive<D>;
+
     /// Computes the mean of all the elements of the tensor.
     ///
     /// # Arguments
@@ -1638,6 +2379,35 @@ where
         dim: usize,
     ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
 
+    /// Gets the `k` largest or smallest elements of a tensor along an axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the elements from.
+    /// * `k` - The number of elements to get, smaller or equal to the size of the axis.
+    /// * `dim` - The axis along which to get the elements.
+    /// * `largest` - Whether to get the largest or the smallest elements.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the selected elements and their indices.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For getting the top k elements of a tensor along an axis, users should prefer the
+    /// [Tensor::topk_with_indices](Tensor::topk_with_indices) function, which is more high-level and designed for public use.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn topk_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        k: usize,
+        dim: usize,
+        largest: bool,
+    ) -> (Self::Primitive<D>, B::IntTensorPrimitive<D>);
+
     /// Gets the minimum elements of a tensor along an axis.
     ///
     /// # Arguments
@@ -1936,6 +2706,22 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_prod_dim(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> Self::Primitive<D> {
+        B::int_cumsum(tensor, dim, exclusive)
+    }
+
+    fn cumprod<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> Self::Primitive<D> {
+        B::int_cumprod(tensor, dim, exclusive)
+    }
+
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::int_mean(tensor)
     }
@@ -2083,6 +2869,16 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_max_dim_with_indices(tensor, dim)
     }
 
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn topk_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        k: usize,
+        dim: usize,
+        largest: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::int_topk_with_indices(tensor, k, dim, largest)
+    }
+
     fn min<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::int_min(tensor)
     }
@@ -2248,6 +3044,22 @@ impl<B: Backend> Numeric<B> for Float {
         B::float_prod_dim(tensor, dim)
     }
 
+    fn cumsum<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> Self::Primitive<D> {
+        B::float_cumsum(tensor, dim, exclusive)
+    }
+
+    fn cumprod<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> Self::Primitive<D> {
+        B::float_cumprod(tensor, dim, exclusive)
+    }
+
     fn mean<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::float_mean(tensor)
     }
@@ -2397,6 +3209,16 @@ impl<B: Backend> Numeric<B> for Float {
         B::float_max_dim_with_indices(tensor, dim)
     }
 
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn topk_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        k: usize,
+        dim: usize,
+        largest: bool,
+    ) -> (Self::Primitive<D>, <B as Backend>::IntTensorPrimitive<D>) {
+        B::float_topk_with_indices(tensor, k, dim, largest)
+    }
+
     fn min<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::float_min(tensor)
     }
@@ -2599,3 +3421,76 @@ where
         Tensor::neg(self)
     }
 }
+
+/// Broadcast the dimensions of size one of the indices, except `dim`, to the given shape.
+fn broadcast_indices<B: Backend, const D: usize>(
+    dim: usize,
+    indices: Tensor<B, D, Int>,
+    shape: &Shape<D>,
+) -> Tensor<B, D, Int> {
+    let dims = indices.dims();
+
+    (0..D)
+        .filter(|&i| i != dim && dims[i] == 1 && shape.dims[i] != 1)
+        .fold(indices, |indices, i| indices.repeat(i, shape.dims[i]))
+}
+
+/// Reading the indices requires a sync with the device, so their bounds are only checked in debug
+/// builds, or in every build with the `check-indices-bounds` feature. The `unchecked-indices`
+/// feature disables the check, e.g. to avoid the syncs when debugging a GPU backend.
+#[cfg(all(
+    any(debug_assertions, feature = "check-indices-bounds"),
+    not(feature = "unchecked-indices"),
+    any(feature = "wasm-sync", not(target_family = "wasm"))
+))]
+fn check_indices_bounds<B: Backend, const D: usize>(
+    ops: &str,
+    dim: usize,
+    size: usize,
+    indices: &Tensor<B, D, Int>,
+) {
+    if indices.shape().num_elements() == 0 {
+        return;
+    }
+
+    let min = indices.clone().min().into_scalar().elem::<i64>();
+    let max = indices.clone().max().into_scalar().elem::<i64>();
+
+    check!(TensorCheck::indices_bounds(ops, dim, size, min, max));
+}
+
+#[cfg(not(all(
+    any(debug_assertions, feature = "check-indices-bounds"),
+    not(feature = "unchecked-indices"),
+    any(feature = "wasm-sync", not(target_family = "wasm"))
+)))]
+fn check_indices_bounds<B: Backend, const D: usize>(
+    _ops: &str,
+    _dim: usize,
+    _size: usize,
+    _indices: &Tensor<B, D, Int>,
+) {
+}
+
+/// The flat indices of the true elements of the mask, or none if no element is true.
+fn masked_indices<B: Backend, const D: usize>(
+    mask: Data<bool, D>,
+    device: &B::Device,
+) -> Option<Tensor<B, 1, Int>> {
+    let indices = mask
+        .value
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &value)| value.then_some((index as i64).elem()))
+        .collect::<Vec<B::IntElem>>();
+
+    if indices.is_empty() {
+        return None;
+    }
+
+    let num_indices = indices.len();
+    Some(Tensor::from_data(
+        Data::new(indices, Shape::new([num_indices])),
+        device,
+    ))
+}
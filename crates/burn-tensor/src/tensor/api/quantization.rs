@@ -0,0 +1,168 @@
+use crate::{backend::Backend, Int, Tensor};
+
+/// How the range of the values is mapped to the int8 range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantizationScheme {
+    /// The range is symmetric around zero, `[-max(|min|, |max|), max(|min|, |max|)]`, so the zero
+    /// point is always zero.
+    Symmetric,
+
+    /// The range spans from the minimum to the maximum value, extended to include zero, so the
+    /// whole int8 range is used even when the values are all positive.
+    Asymmetric,
+}
+
+/// The parameters mapping int8 values back to floats with
+/// `value = (quantized - zero_point) * scale`.
+#[derive(Debug, Clone)]
+pub struct QuantizationParams<B: Backend, const D: usize> {
+    /// The scale, which is the quantization step, broadcastable to the shape of the tensor: every
+    /// dimension has a size of 1, except the channel axis for a per-channel quantization.
+    pub scale: Tensor<B, D>,
+
+    /// The quantized value of zero, with the shape of the scale.
+    pub zero_point: Tensor<B, D, Int>,
+}
+
+/// Quantizes a tensor to int8 with a single scale and zero point for the whole tensor.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to quantize.
+/// * `scheme` - How the range of the values is mapped to the int8 range.
+///
+/// # Returns
+///
+/// The quantized values, in `[-128, 127]`, and the parameters to
+/// [dequantize](dequantize) them.
+///
+/// # Example
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{dequantize, quantize, QuantizationScheme, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     let tensor = Tensor::<B, 2>::from_floats([[-1.0, 0.5], [0.25, 2.0]], &device);
+///
+///     let (quantized, params) = quantize(tensor, QuantizationScheme::Symmetric);
+///     let dequantized = dequantize(quantized, &params);
+/// }
+/// ```
+pub fn quantize<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    scheme: QuantizationScheme,
+) -> (Tensor<B, D, Int>, QuantizationParams<B, D>) {
+    let (min, max) = (
+        tensor.clone().min().reshape([1; D]),
+        tensor.clone().max().reshape([1; D]),
+    );
+
+    quantize_with_range(tensor, min, max, scheme)
+}
+
+/// Quantizes a tensor to int8 with a scale and a zero point for each channel along the given axis,
+/// e.g. each output channel of the weight of a convolution.
+///
+/// # Arguments
+///
+/// * `tensor` - The tensor to quantize.
+/// * `axis` - The axis of the channels.
+/// * `scheme` - How the range of the values of each channel is mapped to the int8 range.
+///
+/// # Returns
+///
+/// The quantized values, in `[-128, 127]`, and the parameters to
+/// [dequantize](dequantize) them.
+///
+/// # Panics
+///
+/// If the axis is not a dimension of the tensor.
+pub fn quantize_per_channel<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    axis: usize,
+    scheme: QuantizationScheme,
+) -> (Tensor<B, D, Int>, QuantizationParams<B, D>) {
+    assert!(
+        axis < D,
+        "Can't quantize along the axis {axis} of a tensor with {D} dimensions"
+    );
+
+    let dims = tensor.dims();
+    let num_channels = dims[axis];
+    let num_elements: usize = dims.iter().product();
+
+    let mut shape = [1; D];
+    shape[axis] = num_channels;
+
+    // Each row holds the values of a channel.
+    let channels = tensor
+        .clone()
+        .swap_dims(0, axis)
+        .reshape([num_channels, num_elements / num_channels]);
+    let (min, max) = (
+        channels.clone().min_dim(1).reshape(shape),
+        channels.max_dim(1).reshape(shape),
+    );
+
+    quantize_with_range(tensor, min, max, scheme)
+}
+
+/// Maps int8 values back to floats, with the parameters returned by [quantize](quantize) or
+/// [quantize_per_channel](quantize_per_channel).
+///
+/// The dequantized values differ from the original ones by at most half of the scale.
+pub fn dequantize<B: Backend, const D: usize>(
+    tensor: Tensor<B, D, Int>,
+    params: &QuantizationParams<B, D>,
+) -> Tensor<B, D> {
+    tensor
+        .sub(params.zero_point.clone())
+        .float()
+        .mul(params.scale.clone())
+}
+
+fn quantize_with_range<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    min: Tensor<B, D>,
+    max: Tensor<B, D>,
+    scheme: QuantizationScheme,
+) -> (Tensor<B, D, Int>, QuantizationParams<B, D>) {
+    // A constant tensor would have a null scale.
+    let min_scale = f32::EPSILON;
+
+    let (scale, zero_point) = match scheme {
+        QuantizationScheme::Symmetric => {
+            let scale = max
+                .max_pair(min.neg())
+                .div_scalar(127.0)
+                .clamp_min(min_scale);
+            let zero_point = scale.zeros_like().int();
+
+            (scale, zero_point)
+        }
+        QuantizationScheme::Asymmetric => {
+            let (min, max) = (min.clamp_max(0.0), max.clamp_min(0.0));
+            let scale = max.sub(min.clone()).div_scalar(255.0).clamp_min(min_scale);
+            let zero_point = round_to_int8(min.div(scale.clone()).neg().sub_scalar(128.0));
+
+            (scale, zero_point)
+        }
+    };
+
+    let quantized = round_to_int8(tensor.div(scale.clone()).add(zero_point.clone().float()));
+
+    (quantized, QuantizationParams { scale, zero_point })
+}
+
+/// Rounds the values to the nearest integer in the int8 range.
+fn round_to_int8<B: Backend, const D: usize>(tensor: Tensor<B, D>) -> Tensor<B, D, Int> {
+    // The conversion to integers truncates, which rounds down the values once shifted to be
+    // positive.
+    tensor
+        .clamp(-128.0, 127.0)
+        .add_scalar(128.5)
+        .int()
+        .sub_scalar(128)
+}
@@ -0,0 +1,119 @@
+use crate::{
+    backend::Backend, ops::IntTensor, BasicOps, Data, Element, ElementComparison,
+    ElementConversion, Shape,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::Zero;
+
+/// Compute the indices of the `k` largest or smallest elements along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `k` - The number of elements to select, smaller or equal to the size of the dimension.
+/// * `dim` - The dimension along which to select the elements.
+/// * `largest` - Whether to select the largest or the smallest elements.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, except in the `dim` axis where the size is
+/// `k`, containing the indices of the selected elements ordered from the first selected to the
+/// last. Equal elements are ordered by their index.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn topk_indices<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: K::Primitive<D>,
+    k: usize,
+    dim: usize,
+    largest: bool,
+) -> IntTensor<B, D>
+where
+    K::Elem: Element,
+{
+    // The elements have to be sorted, which can't be done without reading the data.
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor).read();
+
+    B::int_from_data(topk_indices_data(data, k, dim, largest), &device)
+}
+
+/// Compute the indices of the `k` largest or smallest elements along the given dimension.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+/// * `k` - The number of elements to select, smaller or equal to the size of the dimension.
+/// * `dim` - The dimension along which to select the elements.
+/// * `largest` - Whether to select the largest or the smallest elements.
+///
+/// # Returns
+///
+/// A tensor with the same shape as the input tensor, except in the `dim` axis where the size is
+/// `k`, containing the indices of the selected elements ordered from the first selected to the
+/// last. Equal elements are ordered by their index.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn topk_indices<B: Backend, K: BasicOps<B>, const D: usize>(
+    tensor: K::Primitive<D>,
+    k: usize,
+    dim: usize,
+    largest: bool,
+) -> IntTensor<B, D>
+where
+    K::Elem: Element,
+{
+    // The elements have to be sorted, which can't be done without reading the data.
+    let device = K::device(&tensor);
+    let data = K::into_data(tensor).read().await;
+
+    B::int_from_data(topk_indices_data(data, k, dim, largest), &device)
+}
+
+fn topk_indices_data<E: Element, I: Element, const D: usize>(
+    data: Data<E, D>,
+    k: usize,
+    dim: usize,
+    largest: bool,
+) -> Data<I, D> {
+    let mut dims = data.shape.dims;
+    let size = dims[dim];
+    let stride = dims[dim + 1..].iter().product::<usize>();
+    let num_outer = dims[..dim].iter().product::<usize>();
+
+    // The elements are compared in their own type, so large integers don't lose precision.
+    let values = data.value;
+    let mut indices = vec![I::zero(); num_outer * k * stride];
+
+    for outer in 0..num_outer {
+        for inner in 0..stride {
+            let value = |index: usize| &values[(outer * size + index) * stride + inner];
+            let mut order = (0..size).collect::<Vec<_>>();
+
+            // The sort is stable, so equal elements keep the order of their indices.
+            order.sort_by(|a, b| match largest {
+                true => ElementComparison::cmp(value(*b), value(*a)),
+                false => ElementComparison::cmp(value(*a), value(*b)),
+            });
+
+            for (i, index) in order.iter().take(k).enumerate() {
+                indices[(outer * k + i) * stride + inner] = (*index as i64).elem();
+            }
+        }
+    }
+
+    dims[dim] = k;
+    Data::new(indices, Shape::new(dims))
+}
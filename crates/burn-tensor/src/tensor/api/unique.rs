@@ -0,0 +1,165 @@
+use crate::{
+    backend::Backend,
+    ops::{IntElem, IntTensor},
+    Data, Device, ElementConversion, Shape,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Compute the distinct values of the given tensor, sorted in ascending order.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+///
+/// # Returns
+///
+/// A tensor containing the distinct values of the given tensor in ascending order.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn unique<B: Backend>(tensor: IntTensor<B, 1>) -> IntTensor<B, 1> {
+    // Size of the output tensor is variable (= number of distinct values in the tensor).
+    // Reading the data to sort the values might cause sync but is required.
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+
+    unique_data::<B>(data, &device)
+}
+
+/// Compute the distinct values of the given tensor, sorted in ascending order.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+///
+/// # Returns
+///
+/// A tensor containing the distinct values of the given tensor in ascending order.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn unique<B: Backend>(tensor: IntTensor<B, 1>) -> IntTensor<B, 1> {
+    // Size of the output tensor is variable (= number of distinct values in the tensor).
+    // Reading the data to sort the values might cause sync but is required.
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read().await;
+
+    unique_data::<B>(data, &device)
+}
+
+/// Compute the distinct values of the given tensor, sorted in ascending order, along with the
+/// inverse indices and the number of occurrences of each value.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+///
+/// # Returns
+///
+/// A tuple containing the distinct values in ascending order, the index of each element of the
+/// given tensor in the distinct values, and the number of occurrences of each distinct value.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub fn unique_with_counts<B: Backend>(
+    tensor: IntTensor<B, 1>,
+) -> (IntTensor<B, 1>, IntTensor<B, 1>, IntTensor<B, 1>) {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read();
+
+    unique_with_counts_data::<B>(data, &device)
+}
+
+/// Compute the distinct values of the given tensor, sorted in ascending order, along with the
+/// inverse indices and the number of occurrences of each value.
+///
+/// # Arguments
+///
+/// * `tensor` - The input tensor.
+///
+/// # Returns
+///
+/// A tuple containing the distinct values in ascending order, the index of each element of the
+/// given tensor in the distinct values, and the number of occurrences of each distinct value.
+///
+/// # Remarks
+///
+/// This is a fallback solution that used only when the backend doesn't have the corresponding implementation.
+/// Ideally, it is supposed to be implemented by the backend and the backend implementation will be resolved
+/// by static dispatch. It is not designed for direct usage by users, and not recommended to import
+/// or use this function directly.
+#[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+pub async fn unique_with_counts<B: Backend>(
+    tensor: IntTensor<B, 1>,
+) -> (IntTensor<B, 1>, IntTensor<B, 1>, IntTensor<B, 1>) {
+    let device = B::int_device(&tensor);
+    let data = B::int_into_data(tensor).read().await;
+
+    unique_with_counts_data::<B>(data, &device)
+}
+
+fn sorted_values<B: Backend>(data: &Data<IntElem<B>, 1>) -> Vec<i64> {
+    let mut values = data
+        .value
+        .iter()
+        .map(|v| v.elem::<i64>())
+        .collect::<Vec<_>>();
+
+    values.sort_unstable();
+    values.dedup();
+    values
+}
+
+fn int_from_vec<B: Backend>(values: Vec<i64>, device: &Device<B>) -> IntTensor<B, 1> {
+    let num_values = values.len();
+    let values = values.into_iter().map(|v| v.elem()).collect();
+
+    B::int_from_data(Data::new(values, Shape::new([num_values])), device)
+}
+
+fn unique_data<B: Backend>(data: Data<IntElem<B>, 1>, device: &Device<B>) -> IntTensor<B, 1> {
+    int_from_vec::<B>(sorted_values::<B>(&data), device)
+}
+
+fn unique_with_counts_data<B: Backend>(
+    data: Data<IntElem<B>, 1>,
+    device: &Device<B>,
+) -> (IntTensor<B, 1>, IntTensor<B, 1>, IntTensor<B, 1>) {
+    let values = sorted_values::<B>(&data);
+    let mut counts = vec![0; values.len()];
+
+    let inverse = data
+        .value
+        .iter()
+        .map(|v| {
+            // The distinct values are sorted, so they can be searched with a binary search.
+            let index = values
+                .binary_search(&v.elem::<i64>())
+                .expect("Every value should be part of the distinct values");
+            counts[index] += 1;
+            index as i64
+        })
+        .collect::<Vec<_>>();
+
+    (
+        int_from_vec::<B>(values, device),
+        int_from_vec::<B>(inverse, device),
+        int_from_vec::<B>(counts, device),
+    )
+}
@@ -492,6 +492,27 @@ mod tests {
         assert_eq!(data.shape, Shape::new([3]));
     }
 
+    #[test]
+    fn should_convert_bf16_to_f32_and_back() {
+        // The values with at most 8 significant bits are exact in bf16.
+        let data =
+            Data::<f32, 1>::from([0.0, -1.0, 0.15625, 255.0, -65536.0, 1.430511474609375e-6]);
+
+        let converted = data.clone().convert::<half::bf16>().convert::<f32>();
+
+        assert_eq!(converted, data);
+    }
+
+    #[test]
+    fn should_round_f32_to_the_nearest_bf16() {
+        let data = Data::<f32, 1>::from([257.0, 1.0 + 1.0 / 256.0, 1.0 + 3.0 / 512.0]);
+
+        let converted = data.convert::<half::bf16>().convert::<f32>();
+
+        // The two first values are ties, rounded to the even significand.
+        assert_eq!(converted, Data::from([256.0, 1.0, 1.0 + 1.0 / 128.0]));
+    }
+
     #[test]
     fn should_assert_appox_eq_limit() {
         let data1 = Data::<f32, 2>::from([[3.0, 5.0, 6.0]]);
@@ -1,3 +1,5 @@
+use core::cmp::Ordering;
+
 use crate::Distribution;
 use half::{bf16, f16};
 use num_traits::{identities::Zero, One, ToPrimitive};
@@ -11,6 +13,7 @@ pub trait Element:
     + ElementRandom
     + ElementConversion
     + ElementPrecision
+    + ElementComparison
     + core::fmt::Debug
     + core::fmt::Display
     + Default
@@ -38,6 +41,15 @@ pub trait ElementConversion {
     fn elem<E: Element>(self) -> E;
 }
 
+/// Element ordering trait.
+pub trait ElementComparison {
+    /// Returns an [Ordering] between `self` and `other`.
+    ///
+    /// The ordering is total: NaN is considered larger than any other value, like PyTorch does,
+    /// and equal to itself.
+    fn cmp(&self, other: &Self) -> Ordering;
+}
+
 /// Element trait for random value of a tensor.
 pub trait ElementRandom {
     /// Returns a random value for the given distribution.
@@ -104,6 +116,16 @@ macro_rules! make_element {
             }
         }
 
+        impl ElementComparison for $type {
+            fn cmp(&self, other: &Self) -> Ordering {
+                #[allow(clippy::eq_op)]
+                let is_nan = |elem: &Self| elem != elem;
+
+                self.partial_cmp(other)
+                    .unwrap_or_else(|| is_nan(self).cmp(&is_nan(other)))
+            }
+        }
+
         impl ElementRandom for $type {
             fn random<R: RngCore>(distribution: Distribution, rng: &mut R) -> Self {
                 #[allow(clippy::redundant_closure_call)]
@@ -84,6 +84,24 @@ where
     ))
 }
 
+/// Applies a [3D transposed convolution](crate::ops::ModuleOps::conv_transpose3d).
+pub fn conv_transpose3d<B>(
+    x: Tensor<B, 5>,
+    weight: Tensor<B, 5>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    Tensor::new(B::conv_transpose3d(
+        x.primitive,
+        weight.primitive,
+        bias.map(|b| b.primitive),
+        options,
+    ))
+}
+
 /// Applies a [4D to 3D unfold](crate::ops::ModuleOps::unfold4d).
 pub fn unfold4d<B>(x: Tensor<B, 4>, kernel_size: [usize; 2], options: UnfoldOptions) -> Tensor<B, 3>
 where
@@ -220,6 +238,14 @@ where
     Tensor::new(B::adaptive_avg_pool1d(x.primitive, output_size))
 }
 
+/// Applies a [2D adaptive max pooling](crate::ops::ModuleOps::adaptive_max_pool2d).
+pub fn adaptive_max_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    Tensor::new(B::adaptive_max_pool2d(x.primitive, output_size))
+}
+
 /// Applies a [2D interpolation](crate::ops::ModuleOps::interpolate).
 pub fn interpolate<B>(
     x: Tensor<B, 4>,
@@ -1,8 +1,12 @@
 use super::cat::cat_with_slice_assign;
+use super::scan::{scan_with_slice, ScanOp};
 use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use crate::topk_indices;
 use crate::Tensor;
 use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Int};
 use crate::{tensor::api::chunk, tensor::api::narrow};
+use crate::{unique, unique_with_counts};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
@@ -791,6 +795,46 @@ pub trait IntTensorOps<B: Backend> {
     /// The product of all elements in the tensor along the dimension.
     fn int_prod_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
 
+    /// Cumulative sum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element is excluded from its own accumulation.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the cumulative sum along `dim`.
+    fn int_cumsum<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> IntTensor<B, D> {
+        scan_with_slice::<B, D, Int>(Tensor::from_primitive(tensor), dim, exclusive, ScanOp::Sum)
+            .into_primitive()
+    }
+
+    /// Cumulative product of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element is excluded from its own accumulation.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the cumulative product along `dim`.
+    fn int_cumprod<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> IntTensor<B, D> {
+        scan_with_slice::<B, D, Int>(Tensor::from_primitive(tensor), dim, exclusive, ScanOp::Prod)
+            .into_primitive()
+    }
+
     /// Computes the mean of all elements in the tensor.
     ///
     /// # Arguments
@@ -892,6 +936,32 @@ pub trait IntTensorOps<B: Backend> {
         (values, index)
     }
 
+    /// Gets the `k` largest or smallest elements of a tensor along an axis and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the elements of.
+    /// * `k` - The number of elements to get, smaller or equal to the size of `dim`.
+    /// * `dim` - The dimension along which to get the elements.
+    /// * `largest` - Whether to get the largest or the smallest elements.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the selected elements of `tensor` along `dim`, sorted from the first selected
+    /// to the last, and their indices.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_topk_with_indices<const D: usize>(
+        tensor: IntTensor<B, D>,
+        k: usize,
+        dim: usize,
+        largest: bool,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        let index = topk_indices::<B, Int, D>(tensor.clone(), k, dim, largest);
+        let values = B::int_gather(dim, tensor, index.clone());
+
+        (values, index)
+    }
+
     /// Gets the minimum element in the tensor.
     ///
     /// # Arguments
@@ -1197,4 +1267,36 @@ pub trait IntTensorOps<B: Backend> {
         result = B::int_mask_fill(result, greater_than_zero, 1.0f32.elem());
         result
     }
+
+    /// Returns the distinct values of the int `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to extract the distinct values from.
+    ///
+    /// # Returns
+    ///
+    /// A tensor containing the distinct values of `tensor`, sorted in ascending order.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_unique(tensor: IntTensor<B, 1>) -> IntTensor<B, 1> {
+        unique::<B>(tensor)
+    }
+
+    /// Returns the distinct values of the int `tensor` with their inverse indices and counts.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to extract the distinct values from.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the distinct values of `tensor` sorted in ascending order, the index of
+    /// each element of `tensor` in the distinct values, and the number of occurrences of each
+    /// distinct value.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn int_unique_with_counts(
+        tensor: IntTensor<B, 1>,
+    ) -> (IntTensor<B, 1>, IntTensor<B, 1>, IntTensor<B, 1>) {
+        unique_with_counts::<B>(tensor)
+    }
 }
@@ -3,6 +3,7 @@ mod alias;
 mod bool_tensor;
 mod int_tensor;
 mod modules;
+mod scan;
 mod tensor;
 
 pub use activation::*;
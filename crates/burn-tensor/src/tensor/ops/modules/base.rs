@@ -135,6 +135,12 @@ pub enum InterpolateMode {
 pub struct InterpolateOptions {
     /// Algorithm used for upsampling.
     pub mode: InterpolateMode,
+
+    /// If true, the corner pixels of the input and output are aligned, otherwise the pixels are
+    /// treated as squares and their centers are aligned (`half_pixel`). Only used by the bilinear
+    /// and bicubic modes.
+    #[new(value = "true")]
+    pub align_corners: bool,
 }
 
 /// Gradient computed during the backward pass for each tensor used by [interpolate](ModuleOps::interpolate).
@@ -291,6 +297,22 @@ pub trait ModuleOps<B: Backend> {
         conv::conv_transpose2d_backward(x, weight, bias, output_grad, options)
     }
 
+    /// Three dimensional transposed convolution.
+    ///
+    /// # Shapes
+    ///
+    /// x:      `[batch_size, channels_in, depth, height, width]`,
+    /// weight: `[channels_in, channels_out, kernel_size_1, kernel_size_2, kernel_size_3]`,
+    /// bias:   `[channels_out]`,
+    fn conv_transpose3d(
+        x: FloatTensor<B, 5>,
+        weight: FloatTensor<B, 5>,
+        bias: Option<FloatTensor<B, 1>>,
+        options: ConvTransposeOptions<3>,
+    ) -> FloatTensor<B, 5> {
+        conv::conv_transpose3d_from_conv_transpose2d::<B>(x, weight, bias, options)
+    }
+
     /// Four-dimensional unfolding.
     ///
     /// # Shapes
@@ -384,6 +406,18 @@ pub trait ModuleOps<B: Backend> {
     ) -> FloatTensor<B, 3> {
         pool::adaptive_avg_pool1d_backward_from_2d::<B>(x, grad)
     }
+    /// Two dimensional adaptive max pooling.
+    ///
+    /// The windows of the output cell `(i, j)` span the rows `floor(i * height / height_out)` to
+    /// `ceil((i + 1) * height / height_out)` and the same columns range for `j`, so they can overlap
+    /// when the input size isn't divisible by the output size.
+    ///
+    /// # Shapes
+    ///
+    /// x: [batch_size, channels, height, width],
+    fn adaptive_max_pool2d(x: FloatTensor<B, 4>, output_size: [usize; 2]) -> FloatTensor<B, 4> {
+        pool::adaptive_max_pool2d_from_max_dim::<B>(x, output_size)
+    }
     /// One dimensional max pooling.
     ///
     /// # Shapes
@@ -1,6 +1,7 @@
 #![allow(clippy::single_range_in_vec_init)]
 use super::{Conv1dBackward, Conv2dBackward, ConvOptions, ConvTransposeOptions};
 use crate::{backend::Backend, ops::FloatTensor, Shape};
+use alloc::vec;
 use libm::ceilf;
 
 /// Calculate the expected padding size required when applying a convolution.
@@ -360,6 +361,144 @@ pub(crate) fn conv_transpose1d_from_conv_transpose2d<B: Backend>(
     B::float_reshape(tensor, Shape::from([batch_size, channels_out, height_out]))
 }
 
+/// Execute a 3D transposed convolution using 2D transposed convolutions.
+///
+/// Each depth slice of the kernel is applied with a 2D transposed convolution on all the depth
+/// slices of the input at once, and the results are summed at their strided depth offsets.
+pub(crate) fn conv_transpose3d_from_conv_transpose2d<B: Backend>(
+    x: FloatTensor<B, 5>,
+    weight: FloatTensor<B, 5>,
+    bias: Option<FloatTensor<B, 1>>,
+    options: ConvTransposeOptions<3>,
+) -> FloatTensor<B, 5> {
+    let [channels_in, channels_out, kernel_depth, kernel_height, kernel_width] =
+        B::float_shape(&weight).dims;
+    let [batch_size, _channels_in, depth_in, height_in, width_in] = B::float_shape(&x).dims;
+    let [stride, padding, padding_out, dilation] = [
+        options.stride[0],
+        options.padding[0],
+        options.padding_out[0],
+        options.dilation[0],
+    ];
+    let device = B::float_device(&x);
+
+    let x = B::float_swap_dims(x, 1, 2);
+    let x = B::float_reshape(
+        x,
+        Shape::new([batch_size * depth_in, channels_in, height_in, width_in]),
+    );
+
+    // Length of the output depth before the padding is removed.
+    let depth_full = (depth_in - 1) * stride + dilation * (kernel_depth - 1) + padding_out + 1;
+    let depth_strided = (depth_in - 1) * stride + 1;
+    let height_out = calculate_conv_transpose_output_size(
+        kernel_height,
+        options.stride[1],
+        options.padding[1],
+        options.padding_out[1],
+        options.dilation[1],
+        height_in,
+    );
+    let width_out = calculate_conv_transpose_output_size(
+        kernel_width,
+        options.stride[2],
+        options.padding[2],
+        options.padding_out[2],
+        options.dilation[2],
+        width_in,
+    );
+    let channels = channels_out * options.groups;
+    let size = channels * height_out * width_out;
+    let zeros = |depth: usize| B::float_zeros(Shape::new([batch_size, depth, size]), &device);
+
+    let mut output = None;
+
+    for k in 0..kernel_depth {
+        let weight = B::float_slice(
+            weight.clone(),
+            [
+                0..channels_in,
+                0..channels_out,
+                k..k + 1,
+                0..kernel_height,
+                0..kernel_width,
+            ],
+        );
+        let weight = B::float_reshape(
+            weight,
+            Shape::new([channels_in, channels_out, kernel_height, kernel_width]),
+        );
+        let tensor = B::conv_transpose2d(
+            x.clone(),
+            weight,
+            None,
+            ConvTransposeOptions::new(
+                [options.stride[1], options.stride[2]],
+                [options.padding[1], options.padding[2]],
+                [options.padding_out[1], options.padding_out[2]],
+                [options.dilation[1], options.dilation[2]],
+                options.groups,
+            ),
+        );
+        let tensor = B::float_reshape(tensor, Shape::new([batch_size, depth_in, 1, size]));
+
+        // Zeros are inserted between the depth slices to account for the stride.
+        let tensor = match stride {
+            1 => B::float_reshape(tensor, Shape::new([batch_size, depth_in, size])),
+            _ => {
+                let tensor = B::float_cat(
+                    vec![
+                        tensor,
+                        B::float_zeros(
+                            Shape::new([batch_size, depth_in, stride - 1, size]),
+                            &device,
+                        ),
+                    ],
+                    2,
+                );
+                let tensor =
+                    B::float_reshape(tensor, Shape::new([batch_size, depth_in * stride, size]));
+                B::float_slice(tensor, [0..batch_size, 0..depth_strided, 0..size])
+            }
+        };
+
+        let offset = k * dilation;
+        let mut parts = vec![tensor];
+        if offset > 0 {
+            parts.insert(0, zeros(offset));
+        }
+        if depth_full > offset + depth_strided {
+            parts.push(zeros(depth_full - offset - depth_strided));
+        }
+        let tensor = B::float_cat(parts, 1);
+
+        output = Some(match output {
+            Some(output) => B::float_add(output, tensor),
+            None => tensor,
+        });
+    }
+
+    let output = output.expect("The kernel should not be empty.");
+    let depth_out = depth_full - 2 * padding;
+    let output = B::float_slice(
+        output,
+        [0..batch_size, padding..padding + depth_out, 0..size],
+    );
+    let output = B::float_reshape(
+        output,
+        Shape::new([batch_size, depth_out, channels, height_out, width_out]),
+    );
+    let output = B::float_swap_dims(output, 1, 2);
+
+    match bias {
+        Some(bias) => {
+            let bias = B::float_reshape(bias, Shape::new([1, channels, 1, 1, 1]));
+            B::float_add(output, bias)
+        }
+        None => output,
+    }
+}
+
 fn conv1d_weight_grad_groups<B: Backend>(
     x: FloatTensor<B, 3>,
     mut weight_grad: FloatTensor<B, 3>,
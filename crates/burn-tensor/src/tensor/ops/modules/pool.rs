@@ -5,6 +5,7 @@ use crate::{
 };
 
 use super::{MaxPool1dBackward, MaxPool1dWithIndices};
+use alloc::vec::Vec;
 
 pub(crate) fn avg_pool1d_from_2d<B: Backend>(
     x: FloatTensor<B, 3>,
@@ -165,3 +166,37 @@ pub(crate) fn max_pool1d_with_indices_backward_from_2d<B: Backend>(
         Shape::from([batch_size, channels, length_in]),
     ))
 }
+
+/// The maximum of a window is computed over its rows and then over its columns, which only takes
+/// `height_out + width_out` reductions.
+pub(crate) fn adaptive_max_pool2d_from_max_dim<B: Backend>(
+    x: FloatTensor<B, 4>,
+    output_size: [usize; 2],
+) -> FloatTensor<B, 4> {
+    let x = adaptive_max_pool_dim::<B>(x, 2, output_size[0]);
+
+    adaptive_max_pool_dim::<B>(x, 3, output_size[1])
+}
+
+fn adaptive_max_pool_dim<B: Backend>(
+    x: FloatTensor<B, 4>,
+    dim: usize,
+    output_size: usize,
+) -> FloatTensor<B, 4> {
+    let dims = B::float_shape(&x).dims;
+    let input_size = dims[dim];
+
+    let windows = (0..output_size)
+        .map(|index| {
+            let start = index * input_size / output_size;
+            let end = ((index + 1) * input_size + output_size - 1) / output_size;
+
+            let mut ranges = dims.map(|size| 0..size);
+            ranges[dim] = start..end;
+
+            B::float_max_dim(B::float_slice(x.clone(), ranges), dim)
+        })
+        .collect::<Vec<_>>();
+
+    B::float_cat(windows, dim)
+}
@@ -0,0 +1,68 @@
+use crate::{backend::Backend, Element, Numeric, Tensor};
+use alloc::vec;
+
+/// The operation accumulated by a scan.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ScanOp {
+    Sum,
+    Prod,
+}
+
+/// Compute the cumulative sum or product along a dimension with a log-step scan.
+///
+/// Each step combines every element with the one `offset` positions before it, doubling the
+/// offset, so only `log2(size)` steps made of slices, concatenations and element wise operations
+/// are needed.
+pub(crate) fn scan_with_slice<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+    exclusive: bool,
+    op: ScanOp,
+) -> Tensor<B, D, K>
+where
+    K::Elem: Element,
+{
+    let size = tensor.dims()[dim];
+
+    if size == 0 {
+        return tensor;
+    }
+
+    let mut output = tensor;
+    let mut offset = 1;
+
+    while offset < size {
+        let head = output.clone().narrow(dim, 0, offset);
+        let tail = output.clone().narrow(dim, offset, size - offset);
+        let previous = output.narrow(dim, 0, size - offset);
+
+        let tail = match op {
+            ScanOp::Sum => tail.add(previous),
+            ScanOp::Prod => tail.mul(previous),
+        };
+
+        output = Tensor::cat(vec![head, tail], dim);
+        offset *= 2;
+    }
+
+    if !exclusive {
+        return output;
+    }
+
+    // The exclusive scan is the inclusive one shifted by one position, starting with the identity
+    // of the operation.
+    let mut shape = output.shape();
+    shape.dims[dim] = 1;
+    let device = output.device();
+
+    let identity = match op {
+        ScanOp::Sum => Tensor::zeros(shape, &device),
+        ScanOp::Prod => Tensor::ones(shape, &device),
+    };
+
+    if size == 1 {
+        return identity;
+    }
+
+    Tensor::cat(vec![identity, output.narrow(dim, 0, size - 1)], dim)
+}
@@ -1,7 +1,10 @@
 use super::cat::cat_with_slice_assign;
+use super::scan::{scan_with_slice, ScanOp};
 use super::{BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor};
 use crate::Tensor;
 use crate::{backend::Backend, tensor::Shape, Data, Distribution, ElementConversion, Float};
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use crate::{cholesky, inverse, solve, svd, symeig, topk_indices, LinalgError};
 use crate::{tensor::api::chunk, tensor::api::narrow};
 use alloc::vec::Vec;
 use burn_common::reader::Reader;
@@ -871,6 +874,46 @@ pub trait FloatTensorOps<B: Backend> {
         B::float_exp(B::float_sum_dim(B::float_log(tensor), dim))
     }
 
+    /// Cumulative sum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element is excluded from its own accumulation.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the cumulative sum along `dim`.
+    fn float_cumsum<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> FloatTensor<B, D> {
+        scan_with_slice::<B, D, Float>(Tensor::from_primitive(tensor), dim, exclusive, ScanOp::Sum)
+            .into_primitive()
+    }
+
+    /// Cumulative product of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    /// * `exclusive` - Whether each element is excluded from its own accumulation.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` with the cumulative product along `dim`.
+    fn float_cumprod<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        exclusive: bool,
+    ) -> FloatTensor<B, D> {
+        scan_with_slice::<B, D, Float>(Tensor::from_primitive(tensor), dim, exclusive, ScanOp::Prod)
+            .into_primitive()
+    }
+
     /// Mean of all elements in a tensor.
     ///
     /// # Arguments
@@ -1183,6 +1226,32 @@ pub trait FloatTensorOps<B: Backend> {
         (values, index)
     }
 
+    /// Gets the `k` largest or smallest elements of a tensor along an axis and their indices.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to get the elements of.
+    /// * `k` - The number of elements to get, smaller or equal to the size of `dim`.
+    /// * `dim` - The dimension along which to get the elements.
+    /// * `largest` - Whether to get the largest or the smallest elements.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the selected elements of `tensor` along `dim`, sorted from the first selected
+    /// to the last, and their indices.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_topk_with_indices<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        k: usize,
+        dim: usize,
+        largest: bool,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        let index = topk_indices::<B, Float, D>(tensor.clone(), k, dim, largest);
+        let values = B::float_gather(dim, tensor, index.clone());
+
+        (values, index)
+    }
+
     /// Gets the minimum element of a tensor.
     ///
     /// # Arguments
@@ -1371,4 +1440,150 @@ pub trait FloatTensorOps<B: Backend> {
         result = B::float_mask_fill(result, greater_than_zero, 1.0f32.elem());
         result
     }
+
+    /// Replaces the non-finite values of the float `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sanitize.
+    /// * `nan` - The value replacing NaN.
+    /// * `posinf` - The value replacing positive infinity.
+    /// * `neginf` - The value replacing negative infinity.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where the non-finite values are replaced.
+    fn float_nan_to_num<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        nan: FloatElem<B>,
+        posinf: FloatElem<B>,
+        neginf: FloatElem<B>,
+    ) -> FloatTensor<B, D> {
+        // Default implementation
+        // NaN is the only value that isn't equal to itself.
+        let is_nan = B::float_not_equal(tensor.clone(), tensor.clone());
+        let is_posinf = B::float_equal_elem(tensor.clone(), f32::INFINITY.elem());
+        let is_neginf = B::float_equal_elem(tensor.clone(), f32::NEG_INFINITY.elem());
+
+        let mut result = B::float_mask_fill(tensor, is_nan, nan);
+        result = B::float_mask_fill(result, is_posinf, posinf);
+        B::float_mask_fill(result, is_neginf, neginf)
+    }
+
+    /// Computes the inverse of the square matrices in the last two dimensions of the float
+    /// `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor of shape `[..., n, n]`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape containing the inverse of each matrix.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the data and inverts the matrices on the host, which is
+    /// slow and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_inverse<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        inverse::<B, D>(tensor)
+    }
+
+    /// Computes the Cholesky decomposition of the positive definite matrices in the last two
+    /// dimensions of the float `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor of shape `[..., n, n]`. Only the lower triangle is read.
+    /// * `upper` - Whether to return the upper triangular factor `U` with `A = U^T U` instead of
+    ///   the lower triangular factor `L` with `A = L L^T`.
+    ///
+    /// # Returns
+    ///
+    /// The triangular factors, or an error if a matrix isn't positive definite.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the data and decomposes the matrices on the host, which
+    /// is slow and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_cholesky<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        upper: bool,
+    ) -> Result<FloatTensor<B, D>, LinalgError> {
+        cholesky::<B, D>(tensor, upper)
+    }
+
+    /// Solves the linear systems `lhs * x = rhs` batched over the leading dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The square matrices of the systems, with a shape of `[..., n, n]`.
+    /// * `rhs` - The right hand sides of the systems, with a shape of `[..., n, k]`.
+    ///
+    /// # Returns
+    ///
+    /// A tensor of shape `[..., n, k]` containing the solutions.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the data and solves the systems on the host, which is slow
+    /// and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_solve<const D: usize>(
+        lhs: FloatTensor<B, D>,
+        rhs: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        solve::<B, D>(lhs, rhs)
+    }
+
+    /// Computes the singular value decomposition `A = U diag(S) V^T` of the matrices in the last
+    /// two dimensions of the float `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor of shape `[..., m, n]`.
+    /// * `full_matrices` - Whether to return the full orthogonal matrices `U` of shape
+    ///   `[..., m, m]` and `V` of shape `[..., n, n]`, instead of only their first
+    ///   `k = min(m, n)` columns.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with `U`, the singular values `S` of shape `[..., k]` in descending order, and `V`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the data and decomposes the matrices on the host, which
+    /// is slow and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_svd<const D: usize, const D2: usize>(
+        tensor: FloatTensor<B, D>,
+        full_matrices: bool,
+    ) -> (FloatTensor<B, D>, FloatTensor<B, D2>, FloatTensor<B, D>) {
+        svd::<B, D, D2>(tensor, full_matrices)
+    }
+
+    /// Computes the eigen decomposition of the symmetric matrices in the last two dimensions of
+    /// the float `tensor`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor of shape `[..., n, n]`, assumed to be symmetric.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the eigenvalues of shape `[..., n]` in ascending order, and the corresponding
+    /// eigenvectors as the columns of a tensor of shape `[..., n, n]`.
+    ///
+    /// # Notes
+    ///
+    /// The default implementation reads the data and decomposes the matrices on the host, which
+    /// is slow and not differentiable.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_symeig<const D: usize, const D2: usize>(
+        tensor: FloatTensor<B, D>,
+    ) -> (FloatTensor<B, D2>, FloatTensor<B, D>) {
+        symeig::<B, D, D2>(tensor)
+    }
 }
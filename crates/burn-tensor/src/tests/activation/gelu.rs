@@ -16,4 +16,25 @@ mod tests {
         data_expected.assert_approx_eq(&data_actual, 2); // Low precision to allow approximation
                                                          // implementation using tanh
     }
+
+    #[test]
+    fn test_gelu_tanh() {
+        let tensor = TestTensor::from([[-3.0, -1.0, -0.5, 0.0, 0.5, 1.0, 3.0]]);
+        let data_actual = activation::gelu_tanh(tensor).into_data();
+
+        let data_expected = Data::from([[-0.0036, -0.1588, -0.1543, 0.0, 0.3457, 0.8412, 2.9964]]);
+        data_expected.assert_approx_eq(&data_actual, 3);
+    }
+
+    #[test]
+    fn test_gelu_erf_and_tanh_agree() {
+        let tensor = TestTensor::from([[-3.0, -1.0, -0.5, 0.0, 0.5, 1.0, 3.0]]);
+        let data_erf = activation::gelu(tensor.clone()).into_data();
+        let data_tanh = activation::gelu_tanh(tensor).into_data();
+
+        let data_expected = Data::from([[-0.0040, -0.1587, -0.1543, 0.0, 0.3457, 0.8413, 2.9960]]);
+        data_expected.assert_approx_eq(&data_erf, 3);
+        // The approximation error stays below 1e-3 on these points.
+        data_erf.assert_approx_eq(&data_tanh, 3);
+    }
 }
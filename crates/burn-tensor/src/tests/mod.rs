@@ -24,6 +24,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_conv2d!();
         burn_tensor::testgen_module_conv_transpose1d!();
         burn_tensor::testgen_module_conv_transpose2d!();
+        burn_tensor::testgen_module_conv_transpose3d!();
         burn_tensor::testgen_module_unfold4d!();
         burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
@@ -31,6 +32,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_avg_pool2d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
         burn_tensor::testgen_module_adaptive_avg_pool2d!();
+        burn_tensor::testgen_module_adaptive_max_pool2d!();
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
@@ -55,6 +57,7 @@ macro_rules! testgen_all {
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
         burn_tensor::testgen_init!();
+        burn_tensor::testgen_interpolate!();
         burn_tensor::testgen_iter_dim!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_log1p!();
@@ -91,6 +94,24 @@ macro_rules! testgen_all {
         burn_tensor::testgen_argwhere_nonzero!();
         burn_tensor::testgen_sign!();
         burn_tensor::testgen_tri_mask!();
+        burn_tensor::testgen_nan_to_num!();
+        burn_tensor::testgen_unique!();
+        burn_tensor::testgen_topk!();
+        burn_tensor::testgen_roll!();
+        burn_tensor::testgen_einsum!();
+        burn_tensor::testgen_cumulative!();
+        burn_tensor::testgen_repeat_interleave!();
+        burn_tensor::testgen_diff!();
+        burn_tensor::testgen_logsumexp!();
+        burn_tensor::testgen_quantile!();
+        burn_tensor::testgen_pad!();
+        burn_tensor::testgen_linalg!();
+        burn_tensor::testgen_complex!();
+        burn_tensor::testgen_fft!();
+        burn_tensor::testgen_sort!();
+        burn_tensor::testgen_searchsorted!();
+        burn_tensor::testgen_histogram!();
+        burn_tensor::testgen_quantization!();
 
         // test stats
         burn_tensor::testgen_var!();
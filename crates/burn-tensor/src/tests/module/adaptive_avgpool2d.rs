@@ -77,6 +77,20 @@ mod tests {
         ]]));
     }
 
+    #[test]
+    fn test_adaptive_avg_pool2d_global() {
+        let test = AdaptiveAvgPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 3,
+            width: 4,
+            height_out: 1,
+            width_out: 1,
+        };
+
+        test.assert_output(TestTensor::from([[[[5.5]], [[17.5]]]]));
+    }
+
     struct AdaptiveAvgPool2dTestCase {
         batch_size: usize,
         channels: usize,
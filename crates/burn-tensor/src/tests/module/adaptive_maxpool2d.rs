@@ -0,0 +1,79 @@
+#[burn_tensor_testgen::testgen(module_adaptive_max_pool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::adaptive_max_pool2d;
+    use burn_tensor::{Data, Shape};
+
+    #[test]
+    fn test_adaptive_max_pool2d_global() {
+        let test = AdaptiveMaxPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 3,
+            width: 4,
+            height_out: 1,
+            width_out: 1,
+        };
+
+        test.assert_output(TestTensor::from([[[[11.0]], [[23.0]]]]));
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_dyn_filter_size() {
+        let test = AdaptiveMaxPool2dTestCase {
+            batch_size: 1,
+            channels: 2,
+            height: 5,
+            width: 7,
+            height_out: 3,
+            width_out: 2,
+        };
+
+        test.assert_output(TestTensor::from([[
+            [[10.0, 13.0], [24.0, 27.0], [31.0, 34.0]],
+            [[45.0, 48.0], [59.0, 62.0], [66.0, 69.0]],
+        ]]));
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_overlapping_windows() {
+        let x = TestTensor::from([[[
+            [1.0, -2.0, 3.0, 0.5],
+            [4.0, 9.0, -1.0, 2.0],
+            [0.0, 6.0, 7.0, -3.0],
+            [5.0, -4.0, 8.0, 1.0],
+            [2.0, 3.0, -5.0, 6.0],
+        ]]]);
+
+        let output = adaptive_max_pool2d(x, [3, 3]);
+
+        output.into_data().assert_approx_eq(
+            &Data::from([[[[9.0, 9.0, 3.0], [9.0, 9.0, 8.0], [5.0, 8.0, 8.0]]]]),
+            3,
+        );
+    }
+
+    struct AdaptiveMaxPool2dTestCase {
+        batch_size: usize,
+        channels: usize,
+        height: usize,
+        width: usize,
+        height_out: usize,
+        width_out: usize,
+    }
+
+    impl AdaptiveMaxPool2dTestCase {
+        fn assert_output(self, y: TestTensor<4>) {
+            let shape_x = Shape::new([self.batch_size, self.channels, self.height, self.width]);
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &y.device())
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = adaptive_max_pool2d(x, [self.height_out, self.width_out]);
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
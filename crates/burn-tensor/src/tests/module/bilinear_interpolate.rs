@@ -85,6 +85,28 @@ mod tests {
         ]]]));
     }
 
+    #[test]
+    fn test_upsample_interpolation_half_pixel() {
+        let x = TestTensor::from([[[[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]]]);
+
+        let output = interpolate(
+            x,
+            [4, 5],
+            InterpolateOptions {
+                mode: InterpolateMode::Bilinear,
+                align_corners: false,
+            },
+        );
+
+        let expected = TestTensor::from([[[
+            [0.0, 0.4, 1.0, 1.6, 2.0],
+            [0.75, 1.15, 1.75, 2.35, 2.75],
+            [2.25, 2.65, 3.25, 3.85, 4.25],
+            [3.0, 3.4, 4.0, 4.6, 5.0],
+        ]]]);
+        expected.to_data().assert_approx_eq(&output.into_data(), 3);
+    }
+
     struct InterpolateTestCase {
         batch_size: usize,
         channels: usize,
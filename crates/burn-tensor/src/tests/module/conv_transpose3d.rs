@@ -0,0 +1,139 @@
+#[burn_tensor_testgen::testgen(module_conv_transpose3d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::conv_transpose3d;
+    use burn_tensor::ops::ConvTransposeOptions;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn test_conv_transpose3d_simple() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 1,
+            channels_out: 1,
+            kernel_size: [3, 3, 3],
+            padding: [1, 1, 1],
+            padding_out: [0, 0, 0],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 1,
+            size: [2, 2, 2],
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[96., 124.], [180., 208.]],
+            [[348., 376.], [432., 460.]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_stride_out_padding_dilation() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 1,
+            channels_out: 1,
+            kernel_size: [2, 2, 2],
+            padding: [0, 0, 0],
+            padding_out: [1, 0, 0],
+            stride: [2, 1, 1],
+            dilation: [1, 1, 2],
+            groups: 1,
+            size: [2, 2, 2],
+        };
+
+        test.assert_output(TestTensor::from([[[
+            [[0., 0., 0., 1.], [0., 2., 2., 6.], [4., 6., 6., 9.]],
+            [[0., 4., 0., 5.], [8., 18., 10., 22.], [12., 18., 14., 21.]],
+            [[0., 0., 4., 5.], [8., 10., 18., 22.], [12., 14., 18., 21.]],
+            [
+                [16., 20., 20., 25.],
+                [48., 58., 58., 70.],
+                [36., 42., 42., 49.],
+            ],
+            [[0., 0., 0., 0.], [0., 0., 0., 0.], [0., 0., 0., 0.]],
+        ]]]));
+    }
+
+    #[test]
+    fn test_conv_transpose3d_groups_different_channels() {
+        let test = ConvTranspose3dTestCase {
+            batch_size: 1,
+            channels_in: 2,
+            channels_out: 4,
+            kernel_size: [2, 1, 2],
+            padding: [0, 0, 0],
+            padding_out: [0, 0, 0],
+            stride: [1, 1, 1],
+            dilation: [1, 1, 1],
+            groups: 2,
+            size: [1, 1, 2],
+        };
+
+        test.assert_output(TestTensor::from([[
+            [[[0., 0., 1.]], [[0., 2., 3.]]],
+            [[[1., 5., 6.]], [[1., 7., 8.]]],
+            [[[18., 44., 29.]], [[22., 54., 35.]]],
+            [[[27., 65., 42.]], [[31., 75., 48.]]],
+        ]]));
+    }
+
+    struct ConvTranspose3dTestCase {
+        batch_size: usize,
+        channels_in: usize,
+        channels_out: usize,
+        kernel_size: [usize; 3],
+        padding: [usize; 3],
+        padding_out: [usize; 3],
+        stride: [usize; 3],
+        dilation: [usize; 3],
+        groups: usize,
+        size: [usize; 3],
+    }
+
+    impl ConvTranspose3dTestCase {
+        fn assert_output(self, y: TestTensor<5>) {
+            let [depth, height, width] = self.size;
+            let [kernel_size_1, kernel_size_2, kernel_size_3] = self.kernel_size;
+            let shape_x = Shape::new([self.batch_size, self.channels_in, depth, height, width]);
+            let shape_weights = Shape::new([
+                self.channels_in,
+                self.channels_out / self.groups,
+                kernel_size_1,
+                kernel_size_2,
+                kernel_size_3,
+            ]);
+            let device = Default::default();
+            let weights = TestTensor::from(
+                TestTensorInt::arange(0..shape_weights.num_elements() as i64, &device)
+                    .reshape(shape_weights)
+                    .into_data()
+                    .convert(),
+            );
+            let bias = TestTensor::from(
+                TestTensorInt::arange(0..self.channels_out as i64, &device)
+                    .into_data()
+                    .convert(),
+            );
+            let x = TestTensor::from(
+                TestTensorInt::arange(0..shape_x.num_elements() as i64, &device)
+                    .reshape(shape_x)
+                    .into_data()
+                    .convert(),
+            );
+            let output = conv_transpose3d(
+                x,
+                weights,
+                Some(bias),
+                ConvTransposeOptions::new(
+                    self.stride,
+                    self.padding,
+                    self.padding_out,
+                    self.dilation,
+                    self.groups,
+                ),
+            );
+
+            y.to_data().assert_approx_eq(&output.into_data(), 3);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod adaptive_maxpool2d;
 mod avgpool1d;
 mod avgpool2d;
 mod bicubic_interpolate;
@@ -8,6 +9,7 @@ mod conv1d;
 mod conv2d;
 mod conv_transpose1d;
 mod conv_transpose2d;
+mod conv_transpose3d;
 mod forward;
 mod maxpool1d;
 mod maxpool2d;
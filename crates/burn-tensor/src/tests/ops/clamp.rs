@@ -60,4 +60,38 @@ mod tests {
         let data_expected = Data::from([[1, 1, 2], [3, 4, 4]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn clamp_min_int_keeps_integer_precision() {
+        // 2^24 + 1 can't be represented by a f32, so a conversion would round the bound.
+        let tensor = TestTensorInt::from([16_777_216, 16_777_218, 0]);
+
+        let data_actual = tensor.clamp_min(16_777_217).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::from([16_777_217, 16_777_218, 16_777_217])
+        );
+    }
+
+    #[test]
+    fn clamp_max_int_keeps_integer_precision() {
+        let tensor = TestTensorInt::from([16_777_216, 16_777_218, 20_000_000]);
+
+        let data_actual = tensor.clamp_max(16_777_217).into_data();
+
+        assert_eq!(
+            data_actual,
+            Data::from([16_777_216, 16_777_217, 16_777_217])
+        );
+    }
+
+    #[test]
+    fn clamp_min_max_int_chained() {
+        let tensor = TestTensorInt::from([[-5, 0, 5], [10, 15, 20]]);
+
+        let data_actual = tensor.clamp_min(0).clamp_max(10).into_data();
+
+        assert_eq!(data_actual, Data::from([[0, 0, 5], [10, 10, 10]]));
+    }
 }
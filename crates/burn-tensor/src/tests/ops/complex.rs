@@ -0,0 +1,86 @@
+#[burn_tensor_testgen::testgen(complex)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, Data, Tensor};
+
+    type TestTensorComplex<const D: usize> = Tensor<TestBackend, D, Complex>;
+
+    #[test]
+    fn should_convert_from_and_into_parts() {
+        let real = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+        let imag = TestTensor::from([[5.0, 6.0], [7.0, 8.0]]);
+
+        let tensor = TestTensorComplex::from_parts(real.clone(), imag.clone());
+
+        assert_eq!(tensor.dims(), [2, 2]);
+        assert_eq!(
+            tensor.clone().into_data(),
+            Data::from([[[1.0, 5.0], [2.0, 6.0]], [[3.0, 7.0], [4.0, 8.0]]])
+        );
+        let (real_actual, imag_actual) = tensor.into_parts();
+        assert_eq!(real_actual.into_data(), real.into_data());
+        assert_eq!(imag_actual.into_data(), imag.into_data());
+    }
+
+    #[test]
+    fn should_support_complex_add_and_sub() {
+        let lhs = TestTensorComplex::<1>::from([[1.0, 2.0], [3.0, -1.0]]);
+        let rhs = TestTensorComplex::<1>::from([[0.5, 0.5], [-3.0, 1.0]]);
+
+        let sum = lhs.clone().add_complex(rhs.clone());
+        let difference = lhs.sub_complex(rhs);
+
+        assert_eq!(sum.into_data(), Data::from([[1.5, 2.5], [0.0, 0.0]]));
+        assert_eq!(
+            difference.into_data(),
+            Data::from([[0.5, 1.5], [6.0, -2.0]])
+        );
+    }
+
+    #[test]
+    fn should_support_complex_mul() {
+        // (1 + 2i)(3 - i) = 5 + 5i and i * i = -1.
+        let lhs = TestTensorComplex::<1>::from([[1.0, 2.0], [0.0, 1.0]]);
+        let rhs = TestTensorComplex::<1>::from([[3.0, -1.0], [0.0, 1.0]]);
+
+        let output = lhs.mul_complex(rhs);
+
+        assert_eq!(output.into_data(), Data::from([[5.0, 5.0], [-1.0, 0.0]]));
+    }
+
+    #[test]
+    fn should_support_conj_and_magnitude() {
+        let tensor = TestTensorComplex::<1>::from([[3.0, 4.0], [0.0, -2.0]]);
+
+        let conj = tensor.clone().conj();
+        let magnitude = tensor.magnitude();
+
+        assert_eq!(conj.into_data(), Data::from([[3.0, -4.0], [0.0, 2.0]]));
+        magnitude
+            .into_data()
+            .assert_approx_eq(&Data::from([5.0, 2.0]), 3);
+    }
+
+    #[test]
+    fn should_keep_pairs_with_basic_ops() {
+        let real = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let tensor = TestTensorComplex::from_parts(real.clone(), real.clone().neg());
+
+        let transposed = tensor.clone().transpose();
+        let sliced = tensor.slice([0..2, 1..3]);
+
+        let (real_actual, imag_actual) = transposed.into_parts();
+        assert_eq!(
+            real_actual.into_data(),
+            real.clone().transpose().into_data()
+        );
+        assert_eq!(
+            imag_actual.into_data(),
+            real.clone().transpose().neg().into_data()
+        );
+        assert_eq!(
+            sliced.real().into_data(),
+            real.slice([0..2, 1..3]).into_data()
+        );
+    }
+}
@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(cumulative)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_cumsum_inclusive() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0, 4.0, 5.0], [0.5, -1.0, 2.0, 0.0, 1.5]]);
+
+        let output = tensor.cumsum(1, false);
+
+        let expected = Data::from([[1.0, 3.0, 6.0, 10.0, 15.0], [0.5, -0.5, 1.5, 1.5, 3.0]]);
+        assert_eq!(output.into_data(), expected);
+    }
+
+    #[test]
+    fn should_support_cumsum_exclusive() {
+        let tensor = TestTensorInt::from([[1, 2, 3], [4, 5, 6]]);
+
+        let output = tensor.cumsum(0, true);
+
+        assert_eq!(output.into_data(), Data::from([[0, 0, 0], [1, 2, 3]]));
+    }
+
+    #[test]
+    fn should_support_cumprod_inclusive() {
+        let tensor = TestTensorInt::from([[1, 2, 3, 4], [2, -1, 3, 1]]);
+
+        let output = tensor.cumprod(1, false);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1, 2, 6, 24], [2, -2, -6, -6]])
+        );
+    }
+
+    #[test]
+    fn should_support_cumprod_exclusive() {
+        let tensor = TestTensor::from([2.0, 3.0, 0.5, 4.0]);
+
+        let output = tensor.cumprod(0, true);
+
+        assert_eq!(output.into_data(), Data::from([1.0, 2.0, 6.0, 3.0]));
+    }
+
+    #[test]
+    fn should_support_cumsum_single_element() {
+        let tensor = TestTensor::from([[3.0], [4.0]]);
+
+        let inclusive = tensor.clone().cumsum(1, false);
+        let exclusive = tensor.cumsum(1, true);
+
+        assert_eq!(inclusive.into_data(), Data::from([[3.0], [4.0]]));
+        assert_eq!(exclusive.into_data(), Data::from([[0.0], [0.0]]));
+    }
+}
@@ -0,0 +1,53 @@
+#[burn_tensor_testgen::testgen(diff)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_first_order_diff() {
+        let tensor = TestTensor::from([[1.0, 3.0, 6.0, 10.0], [2.0, 1.0, 4.0, 0.0]]);
+
+        let output = tensor.diff(1, 1);
+
+        let expected = Data::from([[2.0, 3.0, 4.0], [-1.0, 3.0, -4.0]]);
+        assert_eq!(output.into_data(), expected);
+    }
+
+    #[test]
+    fn should_support_higher_order_diff() {
+        let tensor = TestTensorInt::from([1, 4, 9, 16, 25]);
+
+        let second = tensor.clone().diff(2, 0);
+        let third = tensor.diff(3, 0);
+
+        assert_eq!(second.into_data(), Data::from([2, 2, 2]));
+        assert_eq!(third.into_data(), Data::from([0, 0]));
+    }
+
+    #[test]
+    fn should_support_diff_on_first_dim() {
+        let tensor = TestTensorInt::from([[1, 2], [4, 8], [9, 18]]);
+
+        let output = tensor.diff(1, 0);
+
+        assert_eq!(output.into_data(), Data::from([[3, 6], [5, 10]]));
+    }
+
+    #[test]
+    fn should_return_the_tensor_when_n_is_zero() {
+        let tensor = TestTensor::from([1.0, 2.0, 4.0]);
+
+        let output = tensor.clone().diff(0, 0);
+
+        assert_eq!(output.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    fn should_return_an_empty_dim_when_n_is_larger_than_the_dim() {
+        let tensor = TestTensor::from([[1.0, 2.0, 4.0], [3.0, 5.0, 8.0]]);
+
+        let output = tensor.diff(4, 1);
+
+        assert_eq!(output.dims(), [2, 0]);
+    }
+}
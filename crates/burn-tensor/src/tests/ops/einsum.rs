@@ -0,0 +1,107 @@
+#[burn_tensor_testgen::testgen(einsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::{einsum, Data, Tensor};
+
+    #[test]
+    fn should_support_matmul() {
+        let lhs = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]]);
+
+        let output: TestTensor<2> = einsum("ij,jk->ik", &[lhs.clone(), rhs.clone()]);
+
+        assert_eq!(output.into_data(), lhs.matmul(rhs).into_data());
+    }
+
+    #[test]
+    fn should_support_batched_matmul() {
+        let device = Default::default();
+        let lhs = TestTensorInt::arange(0..12, &device)
+            .reshape([2, 2, 3])
+            .float();
+        let rhs = TestTensorInt::arange(0..18, &device)
+            .reshape([2, 3, 3])
+            .float();
+
+        let output: TestTensor<3> = einsum("bij,bjk->bik", &[lhs.clone(), rhs.clone()]);
+
+        assert_eq!(output.into_data(), lhs.matmul(rhs).into_data());
+    }
+
+    #[test]
+    fn should_support_operands_with_transposed_labels() {
+        let lhs = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from([[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]);
+
+        let output: TestTensor<2> = einsum("ji,jk->ki", &[lhs.clone(), rhs.clone()]);
+
+        let expected = rhs.transpose().matmul(lhs);
+        assert_eq!(output.into_data(), expected.into_data());
+    }
+
+    #[test]
+    fn should_support_outer_product() {
+        let lhs = TestTensor::from([1.0, 2.0]);
+        let rhs = TestTensor::from([3.0, 4.0, 5.0]);
+
+        let output: TestTensor<2> = einsum("i,j->ij", &[lhs, rhs]);
+
+        let expected = Data::from([[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+        assert_eq!(output.into_data(), expected);
+    }
+
+    #[test]
+    fn should_support_batched_dot_product() {
+        let lhs = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from([[5.0, 6.0], [7.0, 8.0]]);
+
+        let output: TestTensor<1> = einsum("bi,bi->b", &[lhs, rhs]);
+
+        assert_eq!(output.into_data(), Data::from([17.0, 53.0]));
+    }
+
+    #[test]
+    fn should_support_single_operand_permute() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output: TestTensor<2> = einsum("ij->ji", &[tensor.clone()]);
+
+        assert_eq!(output.into_data(), tensor.transpose().into_data());
+    }
+
+    #[test]
+    fn should_support_single_operand_reduction() {
+        let tensor = TestTensor::from([[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]);
+
+        let output: TestTensor<2> = einsum("ijk->ki", &[tensor]);
+
+        assert_eq!(output.into_data(), Data::from([[4.0, 12.0], [6.0, 14.0]]));
+    }
+
+    #[test]
+    fn should_sum_labels_of_a_single_operand() {
+        let lhs = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::from([[1.0, 1.0], [2.0, 2.0]]);
+
+        let output: TestTensor<1> = einsum("ij,kl->i", &[lhs, rhs]);
+
+        assert_eq!(output.into_data(), Data::from([18.0, 42.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "The label 'j' has a size of 3")]
+    fn should_panic_when_label_sizes_mismatch() {
+        let lhs = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let rhs = TestTensor::from([[1.0, 0.0], [0.0, 1.0]]);
+
+        let _output: TestTensor<2> = einsum("ij,jk->ik", &[lhs, rhs]);
+    }
+
+    #[test]
+    #[should_panic(expected = "explicit output")]
+    fn should_panic_without_explicit_output() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let _output: TestTensor<2> = einsum("ij", &[tensor]);
+    }
+}
@@ -0,0 +1,103 @@
+#[burn_tensor_testgen::testgen(fft)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Complex, Data, Tensor};
+    use core::f32::consts::PI;
+
+    type TestTensorComplex<const D: usize> = Tensor<TestBackend, D, Complex>;
+
+    #[test]
+    fn ifft_should_invert_fft() {
+        let real = TestTensor::from([
+            [1.0, -2.0, 0.5, 3.0, 0.0, 1.5, -1.0, 2.0],
+            [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0],
+        ]);
+        let imag = TestTensor::from([
+            [0.5, 0.0, -1.0, 2.0, 1.0, 0.0, 0.0, -0.5],
+            [1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+        ]);
+        let tensor = TestTensorComplex::from_parts(real.clone(), imag.clone());
+
+        let (real_actual, imag_actual) = tensor.fft(1).ifft(1).into_parts();
+
+        real_actual
+            .into_data()
+            .assert_approx_eq(&real.into_data(), 3);
+        imag_actual
+            .into_data()
+            .assert_approx_eq(&imag.into_data(), 3);
+    }
+
+    #[test]
+    fn ifft_should_invert_fft_with_non_power_of_two_length() {
+        let real = TestTensor::from([
+            [1.0, 2.0],
+            [-3.0, 0.5],
+            [2.0, 0.0],
+            [0.0, 1.0],
+            [4.0, -1.0],
+            [1.0, 1.0],
+        ]);
+        let imag = real.clone().flip([0]);
+        let tensor = TestTensorComplex::from_parts(real.clone(), imag.clone());
+
+        let (real_actual, imag_actual) = tensor.fft(0).ifft(0).into_parts();
+
+        real_actual
+            .into_data()
+            .assert_approx_eq(&real.into_data(), 3);
+        imag_actual
+            .into_data()
+            .assert_approx_eq(&imag.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_fft_with_non_power_of_two_length() {
+        let tensor = TestTensorComplex::from_real(TestTensor::from([1.0, 2.0, 3.0, 4.0, 5.0]));
+
+        let (real, imag) = tensor.fft(0).into_parts();
+
+        real.into_data()
+            .assert_approx_eq(&Data::from([15.0, -2.5, -2.5, -2.5, -2.5]), 3);
+        imag.into_data()
+            .assert_approx_eq(&Data::from([0.0, 3.4410, 0.8123, -0.8123, -3.4410]), 3);
+    }
+
+    #[test]
+    fn sinusoid_should_produce_a_single_peak() {
+        let size = 8;
+        let phases = (0..size)
+            .map(|j| 2.0 * PI * 3.0 * j as f32 / size as f32)
+            .collect::<Vec<_>>();
+        let real = phases.iter().map(|p| p.cos()).collect::<Vec<_>>();
+        let imag = phases.iter().map(|p| p.sin()).collect::<Vec<_>>();
+        let tensor = TestTensorComplex::from_parts(
+            TestTensor::from_data(Data::from(real.as_slice()).convert(), &Default::default()),
+            TestTensor::from_data(Data::from(imag.as_slice()).convert(), &Default::default()),
+        );
+
+        let magnitude = tensor.fft(0).magnitude();
+
+        magnitude
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0, 0.0, 8.0, 0.0, 0.0, 0.0, 0.0]), 3);
+    }
+
+    #[test]
+    fn rfft_should_return_the_half_spectrum() {
+        let size = 10;
+        let values = (0..size)
+            .map(|j| (2.0 * PI * 2.0 * j as f32 / size as f32).cos())
+            .collect::<Vec<_>>();
+        let tensor =
+            TestTensor::from_data(Data::from(values.as_slice()).convert(), &Default::default());
+
+        let spectrum = tensor.rfft(0);
+
+        assert_eq!(spectrum.dims(), [6]);
+        spectrum
+            .magnitude()
+            .into_data()
+            .assert_approx_eq(&Data::from([0.0, 0.0, 5.0, 0.0, 0.0, 0.0]), 3);
+    }
+}
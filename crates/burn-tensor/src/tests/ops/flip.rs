@@ -102,4 +102,27 @@ mod tests {
         // Test with an out of bound axis
         let _ = tensor.clone().flip([3, 0, 1]);
     }
+
+    #[test]
+    fn flip_twice_is_identity() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..24, &device).reshape([2, 3, 4]);
+
+        let flipped = tensor.clone().flip([1]).flip([1]);
+
+        assert_eq!(tensor.into_data(), flipped.into_data());
+    }
+
+    #[test]
+    fn flip_multiple_axes_composes() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..24, &device)
+            .reshape([2, 3, 4])
+            .float();
+
+        let flipped = tensor.clone().flip([0, -1]);
+        let composed = tensor.flip([2]).flip([0]);
+
+        assert_eq!(flipped.into_data(), composed.into_data());
+    }
 }
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(gather_scatter)]
 mod tests {
     use super::*;
-    use burn_tensor::{Data, Tensor};
+    use burn_tensor::{Data, ScatterReduction, Tensor};
 
     #[test]
     fn should_gather_1d_dim0() {
@@ -198,4 +198,80 @@ mod tests {
 
         tensor.scatter(0, indices, values);
     }
+
+    #[test]
+    fn should_gather_2d_dim1_broadcast_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[2, 0]], &device);
+
+        let output = tensor.gather(1, indices);
+
+        assert_eq!(output.into_data(), Data::from([[2.0, 0.0], [5.0, 3.0]]));
+    }
+
+    #[test]
+    fn should_scatter_2d_dim1_broadcast_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]], &device);
+        let values = TestTensor::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+        let indices = TestTensorInt::from_ints([[2, 0]], &device);
+
+        let output = tensor.scatter(1, indices, values);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[2.0, 0.0, 1.0], [4.0, 0.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_multiply() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let values = TestTensor::from_floats([[2.0, 3.0], [-1.0, 0.5]], &device);
+        let indices = TestTensorInt::from_ints([[1, 1], [0, 2]], &device);
+
+        let output = tensor.scatter_reduce(1, indices, values, ScatterReduction::Multiply);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 12.0, 3.0], [-4.0, 5.0, 3.0]])
+        );
+    }
+
+    #[test]
+    fn should_scatter_reduce_overwrite() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+        let values = TestTensorInt::from_ints([[7, 8], [9, 10]], &device);
+        let indices = TestTensorInt::from_ints([[1, 1], [0, 2]], &device);
+
+        let output = tensor.scatter_reduce(1, indices, values, ScatterReduction::Overwrite);
+
+        assert_eq!(output.into_data(), Data::from([[1, 8, 3], [9, 5, 10]]));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "out of bounds for dimension (1)")]
+    fn gather_should_panic_on_out_of_bounds_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], &device);
+        let indices = TestTensorInt::from_ints([[0, 3], [1, 2]], &device);
+
+        tensor.gather(1, indices);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "out of bounds for dimension (0)")]
+    fn scatter_should_panic_on_negative_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::from_floats([0.0, 0.0, 0.0], &device);
+        let values = TestTensor::from_floats([5.0, 4.0], &device);
+        let indices = TestTensorInt::from_ints([-1, 0], &device);
+
+        tensor.scatter(0, indices, values);
+    }
 }
@@ -0,0 +1,43 @@
+#[burn_tensor_testgen::testgen(histogram)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn uniform_input_should_spread_evenly() {
+        let tensor = TestTensor::from([[0.5, 1.5, 2.5, 3.5, 4.5], [5.5, 6.5, 7.5, 8.5, 9.5]]);
+
+        let output = tensor.histogram(5, (0.0, 10.0), false);
+
+        assert_eq!(output.into_data(), Data::from([2, 2, 2, 2, 2]));
+    }
+
+    #[test]
+    fn edges_should_land_in_the_upper_bin() {
+        let tensor = TestTensor::from([0.0, 2.0, 4.0, 10.0]);
+
+        let output = tensor.histogram(5, (0.0, 10.0), false);
+
+        // The upper bound of the range is included in the last bin.
+        assert_eq!(output.into_data(), Data::from([1, 1, 1, 0, 1]));
+    }
+
+    #[test]
+    fn should_clamp_or_drop_values_outside_the_range() {
+        let tensor = TestTensor::from([-1.0, 11.0, 5.0, f32::NAN]);
+
+        let clamped = tensor.clone().histogram(5, (0.0, 10.0), true);
+        let dropped = tensor.histogram(5, (0.0, 10.0), false);
+
+        assert_eq!(clamped.into_data(), Data::from([1, 0, 1, 0, 1]));
+        assert_eq!(dropped.into_data(), Data::from([0, 0, 1, 0, 0]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_with_an_empty_range() {
+        let tensor = TestTensor::from([1.0, 2.0]);
+
+        let _output = tensor.histogram(2, (1.0, 1.0), false);
+    }
+}
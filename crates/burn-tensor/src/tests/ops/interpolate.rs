@@ -0,0 +1,61 @@
+#[burn_tensor_testgen::testgen(interpolate)]
+mod tests {
+    use super::*;
+    use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
+    use burn_tensor::Data;
+
+    #[test]
+    fn should_upsample_nearest_2x() {
+        let tensor = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = tensor.interpolate([4, 4], InterpolateOptions::new(InterpolateMode::Nearest));
+
+        let expected = Data::from([[[
+            [1.0, 1.0, 2.0, 2.0],
+            [1.0, 1.0, 2.0, 2.0],
+            [3.0, 3.0, 4.0, 4.0],
+            [3.0, 3.0, 4.0, 4.0],
+        ]]]);
+        assert_eq!(output.into_data(), expected);
+    }
+
+    #[test]
+    fn should_upsample_bilinear_align_corners() {
+        let tensor = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = tensor.interpolate(
+            [3, 3],
+            InterpolateOptions {
+                mode: InterpolateMode::Bilinear,
+                align_corners: true,
+            },
+        );
+
+        // The corners are the input ones, the center is the mean of the four pixels.
+        let expected = Data::from([[[[1.0, 1.5, 2.0], [2.0, 2.5, 3.0], [3.0, 3.5, 4.0]]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_upsample_bilinear_half_pixel() {
+        let tensor = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+
+        let output = tensor.interpolate(
+            [4, 4],
+            InterpolateOptions {
+                mode: InterpolateMode::Bilinear,
+                align_corners: false,
+            },
+        );
+
+        // The pixels outside of the input centers are clamped, so the corners are the input ones,
+        // and the four center pixels are a quarter away from the input centers.
+        let expected = Data::from([[[
+            [1.0, 1.25, 1.75, 2.0],
+            [1.5, 1.75, 2.25, 2.5],
+            [2.5, 2.75, 3.25, 3.5],
+            [3.0, 3.25, 3.75, 4.0],
+        ]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
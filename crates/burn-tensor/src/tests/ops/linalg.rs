@@ -0,0 +1,163 @@
+#[burn_tensor_testgen::testgen(linalg)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, LinalgError, Tensor};
+
+    #[test]
+    fn should_support_inverse() {
+        let tensor = TestTensor::from([[4.0, 7.0], [2.0, 6.0]]);
+
+        let output = tensor.inverse();
+
+        let expected = Data::from([[0.6, -0.7], [-0.2, 0.4]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn matmul_with_inverse_should_be_identity() {
+        let tensor = TestTensor::from([
+            [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]],
+            [[0.0, 1.0, 2.0], [1.0, 0.0, 3.0], [4.0, -3.0, 8.0]],
+        ]);
+        let device = tensor.device();
+
+        let output = tensor.clone().matmul(tensor.inverse());
+
+        let identity = TestTensor::<2>::eye(3, &device)
+            .unsqueeze::<3>()
+            .repeat(0, 2);
+        output
+            .into_data()
+            .assert_approx_eq(&identity.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_solve() {
+        let lhs = TestTensor::from([[3.0, 1.0], [1.0, 2.0]]);
+        let rhs = TestTensor::from([[9.0, 1.0], [8.0, 2.0]]);
+
+        let output = lhs.clone().solve(rhs.clone());
+
+        let expected = Data::from([[2.0, 0.0], [3.0, 1.0]]);
+        output.clone().into_data().assert_approx_eq(&expected, 3);
+        lhs.matmul(output)
+            .into_data()
+            .assert_approx_eq(&rhs.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_batched_solve() {
+        let lhs = TestTensor::from([[[2.0, 0.0], [0.0, 4.0]], [[0.0, 1.0], [1.0, 0.0]]]);
+        let rhs = TestTensor::from([[[2.0], [8.0]], [[5.0], [7.0]]]);
+
+        let output = lhs.solve(rhs);
+
+        let expected = Data::from([[[1.0], [2.0]], [[7.0], [5.0]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inverse_should_panic_on_non_square_matrices() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let _output = tensor.inverse();
+    }
+
+    #[test]
+    fn should_support_cholesky() {
+        let tensor = TestTensor::from([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+
+        let lower = tensor.clone().cholesky(false).unwrap();
+        let upper = tensor.clone().cholesky(true).unwrap();
+
+        let expected = Data::from([[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]]);
+        lower.clone().into_data().assert_approx_eq(&expected, 3);
+        upper
+            .clone()
+            .into_data()
+            .assert_approx_eq(&lower.clone().transpose().into_data(), 3);
+        lower
+            .matmul(upper)
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_batched_cholesky() {
+        let tensor = TestTensor::from([[[4.0, 2.0], [2.0, 5.0]], [[9.0, 0.0], [0.0, 1.0]]]);
+
+        let output = tensor.cholesky(false).unwrap();
+
+        let expected = Data::from([[[2.0, 0.0], [1.0, 2.0]], [[3.0, 0.0], [0.0, 1.0]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn cholesky_should_fail_on_non_positive_definite_matrices() {
+        let tensor = TestTensor::from([[[4.0, 2.0], [2.0, 5.0]], [[1.0, 2.0], [2.0, 1.0]]]);
+
+        let output = tensor.cholesky(false);
+
+        assert_eq!(
+            output.err(),
+            Some(LinalgError::NotPositiveDefinite { matrix: 1 })
+        );
+    }
+
+    #[test]
+    fn svd_should_reconstruct_the_input() {
+        let tensor = TestTensor::from([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[2.0, 0.0, 1.0], [-1.0, 3.0, 0.5]],
+        ]);
+
+        let (u, s, v) = tensor.clone().svd::<2>(false);
+
+        assert_eq!(u.dims(), [2, 2, 2]);
+        assert_eq!(s.dims(), [2, 2]);
+        assert_eq!(v.dims(), [2, 3, 2]);
+        let diag = TestTensor::<2>::eye(2, &s.device()).unsqueeze::<3>() * s.unsqueeze_dim(1);
+        u.matmul(diag)
+            .matmul(v.transpose())
+            .into_data()
+            .assert_approx_eq(&tensor.into_data(), 3);
+    }
+
+    #[test]
+    fn svd_should_return_descending_singular_values() {
+        let tensor = TestTensor::from([[3.0, 0.0], [0.0, -4.0], [0.0, 0.0]]);
+
+        let (u, s, v) = tensor.svd::<1>(true);
+
+        assert_eq!(u.dims(), [3, 3]);
+        assert_eq!(v.dims(), [2, 2]);
+        s.into_data().assert_approx_eq(&Data::from([4.0, 3.0]), 3);
+        u.clone()
+            .transpose()
+            .matmul(u)
+            .into_data()
+            .assert_approx_eq(&TestTensor::<2>::eye(3, &v.device()).into_data(), 3);
+    }
+
+    #[test]
+    fn should_support_symeig() {
+        let tensor = TestTensor::from([[2.0, 1.0], [1.0, 2.0]]);
+
+        let (values, vectors) = tensor.clone().symeig::<1>();
+
+        values
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([1.0, 3.0]), 3);
+        // A V = V diag(λ)
+        tensor
+            .matmul(vectors.clone())
+            .into_data()
+            .assert_approx_eq(&(vectors * values.unsqueeze()).into_data(), 3);
+    }
+}
@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(logsumexp)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_logsumexp() {
+        let tensor = TestTensor::from([[0.0, 0.0], [1.0, 2.0]]);
+
+        let output = tensor.logsumexp(1);
+
+        let expected = Data::from([[core::f32::consts::LN_2], [2.3132617]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_not_overflow_with_large_values() {
+        let tensor = TestTensor::from([[1e30, -1e30], [-1e30, -1e30]]);
+
+        let output = tensor.logsumexp(1).into_data();
+
+        assert!(output.value.iter().all(|value| value.is_finite()));
+        let expected = Data::from([[1e30], [-1e30 + core::f32::consts::LN_2]]);
+        output.assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_negative_infinity() {
+        let tensor = TestTensor::from([
+            [f32::NEG_INFINITY, 0.0],
+            [f32::NEG_INFINITY, f32::NEG_INFINITY],
+        ]);
+
+        let output = tensor.logsumexp(1).into_data();
+
+        assert_eq!(output.value[0], 0.0);
+        assert_eq!(output.value[1], f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn should_support_logsumexp_squeeze() {
+        let tensor = TestTensor::from([[0.0, 0.0], [1.0, 2.0]]);
+
+        let output: TestTensor<1> = tensor.logsumexp_squeeze(0);
+
+        let expected = Data::from([1.3132617, 2.126928]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+}
@@ -66,4 +66,63 @@ mod tests {
         let data_expected = Data::from([[9, 7], [2, 9]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn should_support_masked_select_ops() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0, 4.0], [2.0, 3.0, 5.0]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[true, false, true], [false, true, false]]),
+            &device,
+        );
+
+        let data_actual = tensor.masked_select(mask).into_data();
+
+        let data_expected = Data::from([1.0, 4.0, 3.0]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn should_support_masked_select_ops_without_selection() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_data([[1, 7], [2, 3]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[false, false], [false, false]]),
+            &device,
+        );
+
+        let output = tensor.masked_select(mask);
+
+        assert_eq!(output.dims(), [0]);
+    }
+
+    #[test]
+    fn should_support_masked_scatter_ops() {
+        let device = Default::default();
+        let tensor = TestTensorInt::from_data([[1, 7, 4], [2, 3, 5]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[false, true, false], [true, false, true]]),
+            &device,
+        );
+        let source = Tensor::<TestBackend, 1, Int>::from_data([10, 20, 30, 40], &device);
+
+        let data_actual = tensor.masked_scatter(mask, source).into_data();
+
+        let data_expected = Data::from([[1, 10, 4], [20, 3, 30]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    #[should_panic]
+    fn masked_scatter_should_panic_when_the_source_is_too_small() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            Data::from([[true, true], [false, true]]),
+            &device,
+        );
+        let source = TestTensor::from_data([1.0, 2.0], &device);
+
+        tensor.masked_scatter(mask, source);
+    }
 }
@@ -13,43 +13,62 @@ mod cat;
 mod chunk;
 mod clamp;
 mod close;
+mod complex;
 mod cos;
 mod create_like;
+mod cumulative;
+mod diff;
 mod div;
+mod einsum;
 mod erf;
 mod exp;
+mod fft;
 mod flatten;
 mod flip;
 mod full;
 mod gather_scatter;
+mod histogram;
 mod init;
+mod interpolate;
 mod iter_dim;
+mod linalg;
 mod log;
 mod log1p;
+mod logsumexp;
 mod map_comparison;
 mod mask;
 mod matmul;
 mod maxmin;
 mod mul;
+mod nan_to_num;
 mod narrow;
 mod neg;
 mod one_hot;
+mod pad;
 mod permute;
 mod powf;
 mod powf_scalar;
+mod quantile;
+mod quantization;
 mod random;
 mod recip;
 mod repeat;
+mod repeat_interleave;
 mod reshape;
+mod roll;
+mod searchsorted;
 mod select;
 mod sign;
 mod sin;
 mod slice;
+mod sort;
 mod sqrt;
 mod squeeze;
 mod stack;
 mod sub;
 mod tanh;
+mod topk;
 mod transpose;
 mod tri;
 mod tri_mask;
+mod unique;
@@ -0,0 +1,28 @@
+#[burn_tensor_testgen::testgen(nan_to_num)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_replace_non_finite_values() {
+        let tensor = TestTensor::from([
+            [f32::NAN, 1.0, f32::INFINITY],
+            [-2.0, f32::NEG_INFINITY, 0.5],
+        ]);
+
+        let data_actual = tensor.nan_to_num(0.0, 100.0, -100.0).into_data();
+
+        let data_expected = Data::from([[0.0, 1.0, 100.0], [-2.0, -100.0, 0.5]]);
+        assert_eq!(data_actual, data_expected);
+    }
+
+    #[test]
+    fn should_keep_finite_values() {
+        let tensor = TestTensor::from([[0.0, -1.0, 2.0], [3.0, 4.0, -5.0]]);
+
+        let data_actual = tensor.nan_to_num(9.0, 9.0, 9.0).into_data();
+
+        let data_expected = Data::from([[0.0, -1.0, 2.0], [3.0, 4.0, -5.0]]);
+        assert_eq!(data_actual, data_expected);
+    }
+}
@@ -33,4 +33,42 @@ mod tests {
         let device = Default::default();
         let tensor = TestTensor::<1>::one_hot(0, 0, &device);
     }
+
+    #[test]
+    fn should_support_one_hot_from_int_tensor() {
+        let tensor = TestTensorInt::from([2, 0, 1]);
+
+        let one_hot: TestTensor<2> = tensor.one_hot(3);
+
+        let expected = Data::from([[0., 0., 1.], [1., 0., 0.], [0., 1., 0.]]);
+        assert_eq!(one_hot.into_data(), expected);
+    }
+
+    #[test]
+    fn should_support_batched_one_hot_from_int_tensor() {
+        let tensor = TestTensorInt::from([[0, 3], [1, 1]]);
+
+        let one_hot: TestTensorInt<3> = tensor.one_hot(4);
+
+        let expected = Data::from([[[1, 0, 0, 0], [0, 0, 0, 1]], [[0, 1, 0, 0], [0, 1, 0, 0]]]);
+        assert_eq!(one_hot.into_data(), expected);
+    }
+
+    #[test]
+    fn should_produce_zeros_for_out_of_range_indices() {
+        let tensor = TestTensorInt::from([-1, 3, 1]);
+
+        let one_hot: TestTensor<2> = tensor.one_hot(3);
+
+        let expected = Data::from([[0., 0., 0.], [0., 0., 0.], [0., 1., 0.]]);
+        assert_eq!(one_hot.into_data(), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_output_rank_is_invalid() {
+        let tensor = TestTensorInt::from([0, 1]);
+
+        let _one_hot: TestTensor<3> = tensor.one_hot(2);
+    }
 }
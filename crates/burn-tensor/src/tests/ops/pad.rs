@@ -0,0 +1,65 @@
+#[burn_tensor_testgen::testgen(pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, PadMode, Tensor};
+
+    #[test]
+    fn should_support_reflect_pad_1d() {
+        let tensor = TestTensor::from([1.0, 2.0, 3.0]);
+
+        let output = tensor.pad(&[(2, 2)], PadMode::Reflect, 0.0);
+
+        let expected = Data::from([3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 1.0]);
+        assert_eq!(output.into_data(), expected);
+    }
+
+    #[test]
+    fn should_support_replicate_pad_1d() {
+        let tensor = TestTensorInt::from([1, 2, 3]);
+
+        let output = tensor.pad(&[(2, 1)], PadMode::Replicate, 0.0);
+
+        assert_eq!(output.into_data(), Data::from([1, 1, 1, 2, 3, 3]));
+    }
+
+    #[test]
+    fn should_support_constant_pad_with_fill_value() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = tensor.pad(&[(1, 0), (0, 2)], PadMode::Constant, -1.5);
+
+        let expected = Data::from([
+            [-1.5, -1.5, -1.5, -1.5],
+            [1.0, 2.0, -1.5, -1.5],
+            [3.0, 4.0, -1.5, -1.5],
+        ]);
+        assert_eq!(output.into_data(), expected);
+    }
+
+    #[test]
+    fn should_support_reflect_pad_2d() {
+        let tensor = TestTensorInt::from([[1, 2, 3], [4, 5, 6]]);
+
+        let output = tensor.pad(&[(1, 1), (1, 0)], PadMode::Reflect, 0.0);
+
+        let expected = Data::from([[5, 4, 5, 6], [2, 1, 2, 3], [5, 4, 5, 6], [2, 1, 2, 3]]);
+        assert_eq!(output.into_data(), expected);
+    }
+
+    #[test]
+    fn should_keep_tensor_without_padding() {
+        let tensor = TestTensor::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = tensor.clone().pad(&[(0, 0), (0, 0)], PadMode::Reflect, 0.0);
+
+        assert_eq!(output.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_reflect_padding_exceeds_dim() {
+        let tensor = TestTensor::from([1.0, 2.0, 3.0]);
+
+        let _output = tensor.pad(&[(3, 0)], PadMode::Reflect, 0.0);
+    }
+}
@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(quantile)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Shape, Tensor};
+
+    #[test]
+    fn should_support_median_odd_size() {
+        let tensor = TestTensor::from([[3.0, 1.0, 2.0], [-1.0, 5.0, 4.0]]);
+
+        let output = tensor.median(1);
+
+        assert_eq!(output.into_data(), Data::from([[2.0], [4.0]]));
+    }
+
+    #[test]
+    fn should_support_median_even_size() {
+        let tensor = TestTensor::from([[4.0, 1.0, 3.0, 2.0], [8.0, -2.0, 5.0, 1.0]]);
+
+        let output = tensor.median(1);
+
+        assert_eq!(output.into_data(), Data::from([[2.5], [3.0]]));
+    }
+
+    #[test]
+    fn should_support_median_on_first_dim() {
+        let tensor = TestTensor::from([[1.0, 6.0], [3.0, 2.0]]);
+
+        let output = tensor.median(0);
+
+        assert_eq!(output.into_data(), Data::from([[2.0, 4.0]]));
+    }
+
+    #[test]
+    fn should_support_quantile_with_interpolation() {
+        let tensor = TestTensor::from([0.0, 10.0, 20.0, 30.0, 40.0]);
+
+        let q_0 = tensor.clone().quantile(0.0, 0);
+        let q_1 = tensor.clone().quantile(1.0, 0);
+        let q_30 = tensor.quantile(0.3, 0);
+
+        assert_eq!(q_0.into_data(), Data::from([0.0]));
+        assert_eq!(q_1.into_data(), Data::from([40.0]));
+        q_30.into_data().assert_approx_eq(&Data::from([12.0]), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_quantile_is_out_of_range() {
+        let tensor = TestTensor::from([0.0, 10.0]);
+
+        tensor.quantile(1.5, 0);
+    }
+
+    #[test]
+    fn should_return_nan_for_an_empty_dim() {
+        let tensor = TestTensor::<2>::from_data(
+            Data::<f32, 2>::new(Vec::new(), Shape::new([2, 0])).convert(),
+            &Default::default(),
+        );
+
+        let output = tensor.quantile(0.5, 1).into_data().convert::<f32>();
+
+        assert_eq!(output.shape, Shape::new([2, 1]));
+        assert!(output.value.iter().all(|value| value.is_nan()));
+    }
+}
@@ -0,0 +1,133 @@
+#[burn_tensor_testgen::testgen(quantization)]
+mod tests {
+    use super::*;
+    use burn_tensor::{dequantize, quantize, quantize_per_channel, Data, QuantizationScheme};
+
+    fn values<const D: usize>(shape: [usize; D]) -> TestTensor<D> {
+        let num_elements = shape.iter().product::<usize>() as i64;
+
+        TestTensorInt::arange(0..num_elements, &Default::default())
+            .float()
+            .mul_scalar(0.37)
+            .sin()
+            .mul_scalar(3.0)
+            .reshape(shape)
+    }
+
+    fn to_vec<const D: usize>(tensor: TestTensor<D>) -> Vec<f32> {
+        tensor.into_data().convert::<f32>().value
+    }
+
+    /// Asserts the dequantized values are within half of the quantization step of the values.
+    fn assert_within_step<const D: usize>(
+        tensor: TestTensor<D>,
+        dequantized: TestTensor<D>,
+        scale: TestTensor<D>,
+    ) {
+        let error = to_vec(tensor.clone().sub(dequantized).abs());
+        let step = to_vec(tensor.zeros_like().add(scale));
+
+        for (error, step) in error.iter().zip(step) {
+            assert!(
+                *error <= step * 0.501,
+                "The error {error} exceeds half of the step {step}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_dequantize_per_tensor_symmetric() {
+        let tensor = values([4, 6]);
+
+        let (quantized, params) = quantize(tensor.clone(), QuantizationScheme::Symmetric);
+        let dequantized = dequantize(quantized.clone(), &params);
+
+        assert_eq!(params.scale.dims(), [1, 1]);
+        assert_eq!(params.zero_point.clone().into_data(), Data::from([[0]]));
+        let max = to_vec(tensor.clone().abs().max())[0];
+        params
+            .scale
+            .clone()
+            .into_data()
+            .convert::<f32>()
+            .assert_approx_eq(&Data::from([[max / 127.0]]), 5);
+        assert_within_step(tensor, dequantized, params.scale);
+    }
+
+    #[test]
+    fn should_dequantize_per_tensor_asymmetric() {
+        // All positive values, so the range starts at zero, which is the smallest quantized value.
+        let tensor = values([4, 6]).add_scalar(3.5);
+
+        let (quantized, params) = quantize(tensor.clone(), QuantizationScheme::Asymmetric);
+        let dequantized = dequantize(quantized.clone(), &params);
+
+        assert_eq!(params.zero_point.clone().into_data(), Data::from([[-128]]));
+        assert_eq!(quantized.max().into_data(), Data::from([127]));
+        assert_within_step(tensor, dequantized, params.scale);
+    }
+
+    #[test]
+    fn should_dequantize_zero_exactly() {
+        let tensor = TestTensor::from([[-1.0, 0.0, 0.3, 2.5]]);
+
+        for scheme in [
+            QuantizationScheme::Symmetric,
+            QuantizationScheme::Asymmetric,
+        ] {
+            let (quantized, params) = quantize(tensor.clone(), scheme);
+            let dequantized = to_vec(dequantize(quantized, &params));
+
+            assert_eq!(dequantized[1], 0.0);
+        }
+    }
+
+    #[test]
+    fn should_use_a_scale_per_channel() {
+        // The second channel has a range 100 times larger than the first one.
+        let tensor = TestTensor::from([[0.1, -0.2, 0.3], [10.0, -20.0, 30.0]]);
+
+        let (quantized, params) =
+            quantize_per_channel(tensor.clone(), 0, QuantizationScheme::Symmetric);
+        let dequantized = dequantize(quantized, &params);
+
+        assert_eq!(params.scale.dims(), [2, 1]);
+        let scale = to_vec(params.scale.clone());
+        assert!(scale[1] > scale[0] * 50.0);
+        params
+            .scale
+            .clone()
+            .into_data()
+            .assert_approx_eq(&Data::from([[0.3 / 127.0], [30.0 / 127.0]]), 5);
+        assert_within_step(tensor.clone(), dequantized, params.scale.clone());
+
+        // With a single scale, the first channel would be quantized to a few values.
+        let (_, params_per_tensor) = quantize(tensor, QuantizationScheme::Symmetric);
+        assert!(to_vec(params_per_tensor.scale)[0] > scale[0] * 50.0);
+    }
+
+    #[test]
+    fn should_quantize_per_channel_along_any_axis() {
+        let tensor = values([2, 3, 4]);
+
+        for axis in 0..3 {
+            let (quantized, params) =
+                quantize_per_channel(tensor.clone(), axis, QuantizationScheme::Asymmetric);
+            let dequantized = dequantize(quantized, &params);
+
+            let mut shape = [1; 3];
+            shape[axis] = tensor.dims()[axis];
+            assert_eq!(params.scale.dims(), shape);
+            assert_eq!(params.zero_point.dims(), shape);
+            assert_within_step(tensor.clone(), dequantized, params.scale);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_axis_is_out_of_bounds() {
+        let tensor: TestTensor<2> = values([2, 3]);
+
+        quantize_per_channel(tensor, 2, QuantizationScheme::Symmetric);
+    }
+}
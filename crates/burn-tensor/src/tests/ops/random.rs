@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(random)]
 mod tests {
     use super::*;
-    use burn_tensor::{Distribution, Tensor};
+    use burn_tensor::{Distribution, Int, SeedableRng, StdRng, Tensor};
 
     #[test]
     fn rand_default() {
@@ -33,4 +33,43 @@ mod tests {
 
         assert_eq!(tensor.into_data(), [1.; 20].into());
     }
+
+    #[test]
+    fn rand_with_rng_should_be_reproducible() {
+        let device = Default::default();
+        let tensor = |seed: u64| {
+            Tensor::<TestBackend, 2>::random_with_rng(
+                [4, 5],
+                Distribution::Normal(0.0, 1.0),
+                &mut StdRng::seed_from_u64(seed),
+                &device,
+            )
+            .into_data()
+        };
+
+        assert_eq!(tensor(42), tensor(42));
+        assert_ne!(tensor(42), tensor(43));
+    }
+
+    #[test]
+    fn rand_with_rng_should_continue_the_sequence_of_the_generator() {
+        let device = Default::default();
+        let mut rng = StdRng::seed_from_u64(0);
+
+        let first = Tensor::<TestBackend, 1, Int>::random_with_rng(
+            [20],
+            Distribution::Uniform(0.0, 100.0),
+            &mut rng,
+            &device,
+        );
+        let second = Tensor::<TestBackend, 1, Int>::random_with_rng(
+            [20],
+            Distribution::Uniform(0.0, 100.0),
+            &mut rng,
+            &device,
+        );
+
+        assert_ne!(first.clone().into_data(), second.into_data());
+        first.float().into_data().assert_within_range(0.0..100.0);
+    }
 }
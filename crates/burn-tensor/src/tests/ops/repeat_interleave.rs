@@ -0,0 +1,64 @@
+#[burn_tensor_testgen::testgen(repeat_interleave)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_repeat_interleave_1d() {
+        let tensor = TestTensor::from([1.0, 2.0, 3.0]);
+
+        let output = tensor.repeat_interleave(2, 0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([1.0, 1.0, 2.0, 2.0, 3.0, 3.0])
+        );
+    }
+
+    #[test]
+    fn should_repeat_interleave_on_first_dim() {
+        let tensor = TestTensorInt::from([[1, 2], [3, 4]]);
+
+        let output = tensor.repeat_interleave(2, 0);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1, 2], [1, 2], [3, 4], [3, 4]])
+        );
+    }
+
+    #[test]
+    fn should_repeat_interleave_on_middle_dim() {
+        let tensor = TestTensorInt::from([[[1, 2], [3, 4]], [[5, 6], [7, 8]]]);
+
+        let output = tensor.repeat_interleave(3, 1);
+
+        let expected = Data::from([
+            [[1, 2], [1, 2], [1, 2], [3, 4], [3, 4], [3, 4]],
+            [[5, 6], [5, 6], [5, 6], [7, 8], [7, 8], [7, 8]],
+        ]);
+        assert_eq!(output.into_data(), expected);
+    }
+
+    #[test]
+    fn should_repeat_interleave_with_repeats_tensor() {
+        let tensor = TestTensor::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let repeats = TestTensorInt::from([1, 0, 2]);
+
+        let output = tensor.repeat_interleave_tensor(repeats, 1);
+
+        assert_eq!(
+            output.into_data(),
+            Data::from([[1.0, 3.0, 3.0], [4.0, 6.0, 6.0]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_the_repeats_dont_match_the_dim() {
+        let tensor = TestTensor::from([1.0, 2.0, 3.0]);
+        let repeats = TestTensorInt::from([1, 2]);
+
+        tensor.repeat_interleave_tensor(repeats, 0);
+    }
+}
@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(roll)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Int, Tensor};
+
+    #[test]
+    fn should_roll_1d() {
+        let tensor = TestTensor::from([0.0, 1.0, 2.0, 3.0, 4.0]);
+
+        let output = tensor.roll(&[2], &[0]);
+
+        assert_eq!(output.into_data(), Data::from([3.0, 4.0, 0.0, 1.0, 2.0]));
+    }
+
+    #[test]
+    fn should_roll_1d_negative_shift() {
+        let tensor = TestTensor::from([0.0, 1.0, 2.0, 3.0, 4.0]);
+
+        let output = tensor.roll(&[-1], &[0]);
+
+        assert_eq!(output.into_data(), Data::from([1.0, 2.0, 3.0, 4.0, 0.0]));
+    }
+
+    #[test]
+    fn should_roll_multiple_dims() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..6, &device).reshape([2, 3]);
+
+        let output = tensor.clone().roll(&[1, -1], &[0, 1]);
+        let composed = tensor.roll(&[1], &[0]).roll(&[-1], &[1]);
+
+        // from pytorch:
+        // import torch; torch.arange(0, 6).reshape(2, 3).roll((1, -1), (0, 1))
+        let data_expected = Data::from([[4, 5, 3], [1, 2, 0]]);
+        assert_eq!(output.into_data(), data_expected);
+        assert_eq!(composed.into_data(), data_expected);
+    }
+
+    #[test]
+    fn should_return_the_same_tensor_when_rolling_the_dim_size() {
+        let device = Default::default();
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..6, &device).reshape([2, 3]);
+
+        let output = tensor.clone().roll(&[3, -4, 0], &[1, 0, 1]);
+
+        assert_eq!(output.into_data(), tensor.into_data());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_shifts_and_dims_mismatch() {
+        let tensor = TestTensor::from([0.0, 1.0, 2.0]);
+
+        tensor.roll(&[1, 2], &[0]);
+    }
+}
@@ -0,0 +1,39 @@
+#[burn_tensor_testgen::testgen(searchsorted)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_find_insertion_indices() {
+        let boundaries = TestTensor::from([1.0, 3.0, 5.0, 7.0]);
+        let tensor = TestTensor::from([[2.0, 6.0], [4.0, 8.0]]);
+
+        let output = tensor.searchsorted(boundaries, false);
+
+        assert_eq!(output.into_data(), Data::from([[1, 3], [2, 4]]));
+    }
+
+    #[test]
+    fn right_should_insert_ties_after_the_boundary() {
+        let boundaries = TestTensor::from([1.0, 3.0, 3.0, 5.0]);
+        let tensor = TestTensor::from([1.0, 3.0, 5.0]);
+
+        let left = tensor.clone().searchsorted(boundaries.clone(), false);
+        let right = tensor.searchsorted(boundaries, true);
+
+        assert_eq!(left.into_data(), Data::from([0, 1, 3]));
+        assert_eq!(right.into_data(), Data::from([1, 3, 4]));
+    }
+
+    #[test]
+    fn should_return_zero_and_len_outside_the_boundaries() {
+        let boundaries = TestTensor::from([0.0, 0.5, 1.0]);
+        let tensor = TestTensor::from([-10.0, 10.0]);
+
+        let left = tensor.clone().searchsorted(boundaries.clone(), false);
+        let right = tensor.searchsorted(boundaries, true);
+
+        assert_eq!(left.into_data(), Data::from([0, 3]));
+        assert_eq!(right.into_data(), Data::from([0, 3]));
+    }
+}
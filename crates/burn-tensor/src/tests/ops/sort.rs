@@ -0,0 +1,78 @@
+#[burn_tensor_testgen::testgen(sort)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Data, ElementPrecision, Precision, Tensor};
+
+    #[test]
+    fn should_sort_along_dim() {
+        let tensor = TestTensor::from([[3.0, 1.0, 2.0], [-1.0, 5.0, 0.0]]);
+
+        let ascending = tensor.clone().sort(1, false);
+        let descending = tensor.sort(1, true);
+
+        assert_eq!(
+            ascending.into_data(),
+            Data::from([[1.0, 2.0, 3.0], [-1.0, 0.0, 5.0]])
+        );
+        assert_eq!(
+            descending.into_data(),
+            Data::from([[3.0, 2.0, 1.0], [5.0, 0.0, -1.0]])
+        );
+    }
+
+    #[test]
+    fn should_sort_with_indices_along_first_dim() {
+        let tensor = TestTensorInt::from([[4, 1], [2, 3], [6, 0]]);
+
+        let (values, indices) = tensor.clone().sort_with_indices(0, false);
+
+        assert_eq!(
+            values.clone().into_data(),
+            Data::from([[2, 0], [4, 1], [6, 3]])
+        );
+        assert_eq!(
+            indices.clone().into_data(),
+            Data::from([[1, 2], [0, 0], [2, 1]])
+        );
+        assert_eq!(tensor.gather(0, indices).into_data(), values.into_data());
+    }
+
+    #[test]
+    fn should_keep_the_order_of_equal_elements() {
+        let tensor = TestTensor::from([2.0, 2.0, 2.0, 2.0]);
+
+        let (ascending, ascending_indices) = tensor.clone().sort_with_indices(0, false);
+        let descending_indices = tensor.argsort(0, true);
+
+        assert_eq!(ascending.into_data(), Data::from([2.0, 2.0, 2.0, 2.0]));
+        assert_eq!(ascending_indices.into_data(), Data::from([0, 1, 2, 3]));
+        assert_eq!(descending_indices.into_data(), Data::from([0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn should_keep_already_sorted_input() {
+        let tensor = TestTensorInt::from([-3, 0, 1, 1, 7]);
+
+        let (values, indices) = tensor.clone().sort_with_indices(0, false);
+        let reversed = tensor.argsort(0, true);
+
+        assert_eq!(values.into_data(), Data::from([-3, 0, 1, 1, 7]));
+        assert_eq!(indices.into_data(), Data::from([0, 1, 2, 3, 4]));
+        assert_eq!(reversed.into_data(), Data::from([4, 2, 3, 1, 0]));
+    }
+
+    #[test]
+    fn should_sort_large_integers_exactly() {
+        // The integers above 2^53 can't be represented exactly as f64.
+        if <<TestBackend as Backend>::IntElem as ElementPrecision>::precision() != Precision::Double
+        {
+            return;
+        }
+
+        let tensor = TestTensorInt::from([9007199254740993i64, 9007199254740992]);
+
+        let indices = tensor.argsort(0, false);
+
+        assert_eq!(indices.into_data(), Data::from([1, 0]));
+    }
+}
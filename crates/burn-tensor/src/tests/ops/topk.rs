@@ -0,0 +1,55 @@
+#[burn_tensor_testgen::testgen(topk)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_support_topk_largest() {
+        let tensor = TestTensor::from([[1.0, 5.0, 3.0, 4.0], [8.0, 6.0, 7.0, 2.0]]);
+
+        let (values, indices) = tensor.topk_with_indices(2, 1, true);
+
+        assert_eq!(values.into_data(), Data::from([[5.0, 4.0], [8.0, 7.0]]));
+        assert_eq!(indices.into_data(), Data::from([[1, 3], [0, 2]]));
+    }
+
+    #[test]
+    fn should_support_topk_smallest() {
+        let tensor = TestTensor::from([[1.0, 5.0, 3.0, 4.0], [8.0, 6.0, 7.0, 2.0]]);
+
+        let (values, indices) = tensor.topk_with_indices(2, 1, false);
+
+        assert_eq!(values.into_data(), Data::from([[1.0, 3.0], [2.0, 6.0]]));
+        assert_eq!(indices.into_data(), Data::from([[0, 2], [3, 1]]));
+    }
+
+    #[test]
+    fn should_support_topk_on_first_dim() {
+        let tensor = TestTensorInt::from([[1, 5], [8, 6], [7, 2]]);
+
+        let values = tensor.clone().topk(2, 0, true);
+        let indices = tensor.topk_indices(2, 0, true);
+
+        assert_eq!(values.into_data(), Data::from([[8, 6], [7, 5]]));
+        assert_eq!(indices.into_data(), Data::from([[1, 1], [2, 0]]));
+    }
+
+    #[test]
+    fn should_order_ties_by_index() {
+        let tensor = TestTensorInt::from([[2, 3, 3, 1, 3]]);
+
+        let indices = tensor.topk_indices(3, 1, true);
+
+        assert_eq!(indices.into_data(), Data::from([[1, 2, 4]]));
+    }
+
+    #[test]
+    fn should_clamp_k_to_the_dim_size() {
+        let tensor = TestTensor::from([[3.0, 1.0, 2.0]]);
+
+        let (values, indices) = tensor.topk_with_indices(5, 1, true);
+
+        assert_eq!(values.into_data(), Data::from([[3.0, 2.0, 1.0]]));
+        assert_eq!(indices.into_data(), Data::from([[0, 2, 1]]));
+    }
+}
@@ -0,0 +1,38 @@
+#[burn_tensor_testgen::testgen(unique)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Data, Tensor};
+
+    #[test]
+    fn should_return_sorted_distinct_values() {
+        let tensor = TestTensorInt::from([3, 1, 2, 3, -1, 1]);
+
+        let data_actual = tensor.unique().into_data();
+
+        let data_expected = Data::from([-1, 1, 2, 3]);
+        assert_eq!(data_actual, data_expected);
+    }
+
+    #[test]
+    fn should_return_inverse_indices_and_counts() {
+        let tensor = TestTensorInt::from([3, 1, 2, 3, -1, 1]);
+
+        let (values, inverse, counts) = tensor.clone().unique_with_counts();
+
+        assert_eq!(values.to_data(), Data::from([-1, 1, 2, 3]));
+        assert_eq!(inverse.to_data(), Data::from([3, 1, 2, 3, 0, 1]));
+        assert_eq!(counts.into_data(), Data::from([1, 2, 1, 2]));
+        assert_eq!(values.select(0, inverse).into_data(), tensor.into_data());
+    }
+
+    #[test]
+    fn should_support_single_value() {
+        let tensor = TestTensorInt::from([7, 7, 7]);
+
+        let (values, inverse, counts) = tensor.unique_with_counts();
+
+        assert_eq!(values.into_data(), Data::from([7]));
+        assert_eq!(inverse.into_data(), Data::from([0, 0, 0]));
+        assert_eq!(counts.into_data(), Data::from([3]));
+    }
+}
@@ -33,6 +33,39 @@ impl<FR> FileCheckpointer<FR> {
     }
 }
 
+/// The last epoch with a checkpoint saved in the directory for each of the given names.
+///
+/// Epochs where only some of the checkpoints were saved, for instance because the training was
+/// interrupted while saving them, are ignored.
+pub(crate) fn last_complete_checkpoint(directory: &str, names: &[&str]) -> Option<usize> {
+    let files = std::fs::read_dir(directory)
+        .ok()?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .collect::<Vec<_>>();
+
+    let epochs = |name: &str| {
+        files
+            .iter()
+            .filter_map(|file| {
+                let (stem, _extension) = file.rsplit_once('.')?;
+                let (prefix, epoch) = stem.rsplit_once('-')?;
+                match prefix == name {
+                    true => epoch.parse::<usize>().ok(),
+                    false => None,
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let (first, others) = names.split_first()?;
+    let others = others.iter().map(|name| epochs(name)).collect::<Vec<_>>();
+
+    epochs(first)
+        .into_iter()
+        .filter(|epoch| others.iter().all(|epochs| epochs.contains(epoch)))
+        .max()
+}
+
 impl<FR, R, B> Checkpointer<R, B> for FileCheckpointer<FR>
 where
     R: Record<B>,
@@ -72,3 +105,24 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_complete_checkpoint() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().to_str().unwrap();
+        let names = ["model", "optim"];
+        assert_eq!(last_complete_checkpoint(path, &names), None);
+
+        for file in ["model-2.bin", "optim-2.bin", "model-3.bin", "model-10.bin"] {
+            std::fs::write(directory.path().join(file), []).unwrap();
+        }
+        assert_eq!(last_complete_checkpoint(path, &names), Some(2));
+
+        std::fs::write(directory.path().join("optim-10.bin"), []).unwrap();
+        assert_eq!(last_complete_checkpoint(path, &names), Some(10));
+    }
+}
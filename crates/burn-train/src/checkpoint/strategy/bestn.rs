@@ -0,0 +1,150 @@
+use super::CheckpointingStrategy;
+use crate::{
+    checkpoint::CheckpointingAction,
+    metric::{
+        store::{Aggregate, Direction, EventStoreClient, Split},
+        Metric,
+    },
+};
+
+/// Keep the best N checkpoints based on a metric.
+///
+/// Compose it with [KeepLastNCheckpoints](super::KeepLastNCheckpoints) to also keep the most
+/// recent checkpoints, so the training can be resumed.
+pub struct KeepBestNCheckpoints {
+    num_keep: usize,
+    // The kept epochs with their metric value, from the best to the worst.
+    best: Vec<(usize, f64)>,
+    aggregate: Aggregate,
+    direction: Direction,
+    split: Split,
+    name: String,
+}
+
+impl KeepBestNCheckpoints {
+    /// Create a new strategy keeping the `num_keep` best checkpoints based on the metric `M`.
+    pub fn new<M>(num_keep: usize, aggregate: Aggregate, direction: Direction, split: Split) -> Self
+    where
+        M: Metric,
+    {
+        Self {
+            num_keep,
+            best: Vec::new(),
+            aggregate,
+            direction,
+            split,
+            name: M::NAME.to_string(),
+        }
+    }
+}
+
+impl CheckpointingStrategy for KeepBestNCheckpoints {
+    fn checkpointing(
+        &mut self,
+        epoch: usize,
+        store: &EventStoreClient,
+    ) -> Vec<CheckpointingAction> {
+        let value = match store.find_metric(&self.name, epoch, self.aggregate, self.split) {
+            Some(value) => value,
+            // Without a value to compare, the checkpoint is kept.
+            None => return vec![CheckpointingAction::Save],
+        };
+
+        self.best.push((epoch, value));
+        // The sort is stable, so the oldest epoch is kept on ties.
+        match self.direction {
+            Direction::Lowest => self.best.sort_by(|a, b| a.1.total_cmp(&b.1)),
+            Direction::Highest => self.best.sort_by(|a, b| b.1.total_cmp(&a.1)),
+        }
+
+        if self.best.len() <= self.num_keep {
+            return vec![CheckpointingAction::Save];
+        }
+
+        match self.best.pop() {
+            Some((worst, _)) if worst != epoch => vec![
+                CheckpointingAction::Delete(worst),
+                CheckpointingAction::Save,
+            ],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        checkpoint::{
+            Checkpointer, ComposedCheckpointingStrategy, FileCheckpointer, KeepLastNCheckpoints,
+        },
+        logger::InMemoryMetricLogger,
+        metric::{
+            processor::{
+                test_utils::{end_epoch, process_train},
+                Metrics, MinimalEventProcessor,
+            },
+            store::LogEventStore,
+            LossMetric,
+        },
+        TestBackend,
+    };
+    use burn_core::record::{BinFileRecorder, FullPrecisionSettings};
+    use std::sync::Arc;
+
+    #[test]
+    fn should_keep_the_best_and_last_checkpoints_on_disk() {
+        let mut store = LogEventStore::default();
+        let mut metrics = Metrics::<f64, f64>::default();
+        store.register_logger_train(InMemoryMetricLogger::default());
+        metrics.register_train_metric_numeric(LossMetric::<TestBackend>::new());
+        let store = Arc::new(EventStoreClient::new(store));
+        let mut processor = MinimalEventProcessor::new(metrics, store.clone());
+
+        let mut strategy = ComposedCheckpointingStrategy::builder()
+            .add(KeepLastNCheckpoints::new(2))
+            .add(KeepBestNCheckpoints::new::<LossMetric<TestBackend>>(
+                2,
+                Aggregate::Mean,
+                Direction::Lowest,
+                Split::Train,
+            ))
+            .build();
+
+        let directory = tempfile::tempdir().unwrap();
+        let checkpointer = FileCheckpointer::new(
+            BinFileRecorder::<FullPrecisionSettings>::new(),
+            directory.path().to_str().unwrap(),
+            "model",
+        );
+
+        // The best losses are at the epochs 4 and 7.
+        let losses = [0.9, 0.5, 0.7, 0.3, 0.8, 0.6, 0.4, 0.95, 0.85, 0.99];
+        for (epoch, loss) in (1..).zip(losses) {
+            process_train(&mut processor, loss, epoch);
+            end_epoch(&mut processor, epoch);
+
+            for action in strategy.checkpointing(epoch, &store) {
+                match action {
+                    CheckpointingAction::Save => {
+                        Checkpointer::<(), TestBackend>::save(&checkpointer, epoch, ()).unwrap()
+                    }
+                    CheckpointingAction::Delete(epoch) => {
+                        Checkpointer::<(), TestBackend>::delete(&checkpointer, epoch).unwrap()
+                    }
+                }
+            }
+        }
+
+        let mut files = std::fs::read_dir(directory.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        files.sort();
+
+        assert_eq!(
+            files,
+            ["model-10.bin", "model-4.bin", "model-7.bin", "model-9.bin"]
+        );
+    }
+}
@@ -1,9 +1,11 @@
 mod base;
+mod bestn;
 mod composed;
 mod lastn;
 mod metric;
 
 pub use base::*;
+pub use bestn::*;
 pub use composed::*;
 pub use lastn::*;
 pub use metric::*;
@@ -4,7 +4,7 @@ use crate::learner::EarlyStoppingStrategy;
 use crate::metric::store::EventStoreClient;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::Module;
-use burn_core::optim::Optimizer;
+use burn_core::optim::{LossScaler, Optimizer};
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::Device;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -20,6 +20,7 @@ pub struct Learner<LC: LearnerComponents> {
     pub(crate) num_epochs: usize,
     pub(crate) checkpoint: Option<usize>,
     pub(crate) grad_accumulation: Option<usize>,
+    pub(crate) loss_scaler: Option<LossScaler>,
     pub(crate) checkpointer: Option<LearnerCheckpointer<LC>>,
     pub(crate) devices: Vec<<LC::Backend as Backend>::Device>,
     pub(crate) interrupter: TrainingInterrupter,
@@ -34,6 +35,9 @@ pub(crate) struct LearnerCheckpointer<LC: LearnerComponents> {
     optim: LC::CheckpointerOptimizer,
     lr_scheduler: LC::CheckpointerLrScheduler,
     strategy: LC::CheckpointerStrategy,
+    // The checkpoint restored when resuming, kept until a newer one is saved.
+    #[new(default)]
+    resumed: Option<usize>,
 }
 
 impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
@@ -47,36 +51,45 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
     ) {
         let actions = self.strategy.checkpointing(epoch, store);
 
+        // Saving first ensures that there is always a checkpoint to resume from.
+        if actions.contains(&CheckpointingAction::Save) {
+            self.model
+                .save(epoch, model.clone().into_record())
+                .expect("Can save model checkpoint.");
+            self.optim
+                .save(epoch, optim.to_record())
+                .expect("Can save optimizer checkpoint.");
+            self.lr_scheduler
+                .save(epoch, scheduler.to_record())
+                .expect("Can save learning rate scheduler checkpoint.");
+            self.resumed = None;
+        }
+
         for action in actions {
-            match action {
-                CheckpointingAction::Delete(epoch) => {
-                    self.model
-                        .delete(epoch)
-                        .expect("Can delete model checkpoint.");
-                    self.optim
-                        .delete(epoch)
-                        .expect("Can delete optimizer checkpoint.");
-                    self.lr_scheduler
-                        .delete(epoch)
-                        .expect("Can delete learning rate scheduler checkpoint.");
-                }
-                CheckpointingAction::Save => {
-                    self.model
-                        .save(epoch, model.clone().into_record())
-                        .expect("Can save model checkpoint.");
-                    self.optim
-                        .save(epoch, optim.to_record())
-                        .expect("Can save optimizer checkpoint.");
-                    self.lr_scheduler
-                        .save(epoch, scheduler.to_record())
-                        .expect("Can save learning rate scheduler checkpoint.");
+            if let CheckpointingAction::Delete(epoch_delete) = action {
+                if self.resumed == Some(epoch_delete) {
+                    log::warn!(
+                        "Keeping the checkpoint {epoch_delete} used to resume the training until a \
+                         newer one is saved."
+                    );
+                    continue;
                 }
+
+                self.model
+                    .delete(epoch_delete)
+                    .expect("Can delete model checkpoint.");
+                self.optim
+                    .delete(epoch_delete)
+                    .expect("Can delete optimizer checkpoint.");
+                self.lr_scheduler
+                    .delete(epoch_delete)
+                    .expect("Can delete learning rate scheduler checkpoint.");
             }
         }
     }
 
     pub(crate) fn load_checkpoint(
-        &self,
+        &mut self,
         model: LC::Model,
         optim: LC::Optimizer,
         scheduler: LC::LrScheduler,
@@ -100,9 +113,27 @@ impl<LC: LearnerComponents> LearnerCheckpointer<LC> {
             .restore(epoch, device)
             .expect("Can load learning rate scheduler checkpoint.");
         let scheduler = scheduler.load_record(record);
+        self.resumed = Some(epoch);
 
         (model, optim, scheduler)
     }
+
+    /// Load the model of the given epoch, keeping the given model when the checkpoint can't be
+    /// restored, e.g. when it was deleted by the checkpointing strategy.
+    pub(crate) fn load_model(
+        &self,
+        model: LC::Model,
+        device: &Device<LC::Backend>,
+        epoch: usize,
+    ) -> LC::Model {
+        match self.model.restore(epoch, device) {
+            Ok(record) => model.load_record(record),
+            Err(err) => {
+                log::warn!("Can't restore the model checkpoint of epoch {epoch}: {err:?}");
+                model
+            }
+        }
+    }
 }
 
 #[derive(Clone, Default)]
@@ -3,13 +3,14 @@ use std::sync::Arc;
 use super::log::install_file_logger;
 use super::Learner;
 use crate::checkpoint::{
-    AsyncCheckpointer, CheckpointingStrategy, ComposedCheckpointingStrategy, FileCheckpointer,
-    KeepLastNCheckpoints, MetricCheckpointingStrategy,
+    last_complete_checkpoint, AsyncCheckpointer, CheckpointingStrategy,
+    ComposedCheckpointingStrategy, FileCheckpointer, KeepLastNCheckpoints,
+    MetricCheckpointingStrategy,
 };
 use crate::components::LearnerComponentsMarker;
 use crate::learner::base::TrainingInterrupter;
 use crate::learner::EarlyStoppingStrategy;
-use crate::logger::{FileMetricLogger, MetricLogger};
+use crate::logger::{FileMetricLogger, MetricLogger, TensorBoardMetricLogger};
 use crate::metric::processor::{FullEventProcessor, Metrics};
 use crate::metric::store::{Aggregate, Direction, EventStoreClient, LogEventStore, Split};
 use crate::metric::{Adaptor, LossMetric, Metric};
@@ -17,7 +18,7 @@ use crate::renderer::{default_renderer, MetricsRenderer};
 use crate::LearnerCheckpointer;
 use burn_core::lr_scheduler::LrScheduler;
 use burn_core::module::AutodiffModule;
-use burn_core::optim::Optimizer;
+use burn_core::optim::{LossScaler, LossScalerConfig, Optimizer};
 use burn_core::record::FileRecorder;
 use burn_core::tensor::backend::AutodiffBackend;
 
@@ -42,8 +43,10 @@ where
     )>,
     num_epochs: usize,
     checkpoint: Option<usize>,
+    resume: bool,
     directory: String,
     grad_accumulation: Option<usize>,
+    loss_scaler: Option<LossScaler>,
     devices: Vec<B::Device>,
     renderer: Option<Box<dyn MetricsRenderer + 'static>>,
     metrics: Metrics<T, V>,
@@ -73,9 +76,11 @@ where
         Self {
             num_epochs: 1,
             checkpoint: None,
+            resume: false,
             checkpointers: None,
             directory: directory.to_string(),
             grad_accumulation: None,
+            loss_scaler: None,
             devices: vec![B::Device::default()],
             metrics: Metrics::default(),
             event_store: LogEventStore::default(),
@@ -114,6 +119,27 @@ where
         self
     }
 
+    /// Also log the numeric metrics as TensorBoard events, in the `train` and `valid`
+    /// subdirectories of the given directory.
+    ///
+    /// The default metric loggers are kept, unless they are replaced with
+    /// [metric_loggers](Self::metric_loggers).
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The TensorBoard log directory.
+    pub fn tensorboard_loggers(mut self, directory: &str) -> Self {
+        self.event_store
+            .register_logger_train(TensorBoardMetricLogger::new(
+                format!("{directory}/train").as_str(),
+            ));
+        self.event_store
+            .register_logger_valid(TensorBoardMetricLogger::new(
+                format!("{directory}/valid").as_str(),
+            ));
+        self
+    }
+
     /// Update the checkpointing_strategy.
     pub fn with_checkpointing_strategy<CS>(&mut self, strategy: CS)
     where
@@ -168,6 +194,23 @@ where
         self
     }
 
+    /// Enable the [dynamic loss scaling](LossScaler) used to train with half precision
+    /// gradients.
+    ///
+    /// # Notes
+    ///
+    /// The training step is run with [step_scaled](crate::TrainStep::step_scaled), which should
+    /// be implemented to scale the loss before the backward pass. The gradients are unscaled
+    /// before the optimizer step, which is skipped when they overflow. Loss scaling isn't
+    /// supported with multiple devices.
+    ///
+    /// This is not a full automatic mixed precision mode: the model runs with the float element
+    /// of its backend, and no f32 master copy of the weights is kept.
+    pub fn loss_scaling(mut self, config: LossScalerConfig) -> Self {
+        self.loss_scaler = Some(config.init());
+        self
+    }
+
     /// Register a [numeric](crate::metric::Numeric) training [metric](Metric).
     pub fn metric_train_numeric<Me>(mut self, metric: Me) -> Self
     where
@@ -208,6 +251,21 @@ where
         self
     }
 
+    /// Resume the training from the last checkpoint saved in the directory of the builder, if any.
+    ///
+    /// The [file checkpointer](Self::with_file_checkpointer) of the interrupted training saved the
+    /// model, the optimizer and the scheduler of each epoch in that directory. When all three were
+    /// saved for an epoch, they are restored and the training continues from the next epoch,
+    /// otherwise it starts from scratch.
+    ///
+    /// # Notes
+    ///
+    /// A file checkpointer must be registered, otherwise the [build](Self::build) panics.
+    pub fn with_resume(mut self) -> Self {
+        self.resume = true;
+        self
+    }
+
     /// Provides a handle that can be used to interrupt training.
     pub fn interrupter(&self) -> TrainingInterrupter {
         self.interrupter.clone()
@@ -215,6 +273,9 @@ where
 
     /// Register an [early stopping strategy](EarlyStoppingStrategy) to stop the training when the
     /// conditions are meet.
+    ///
+    /// When a checkpointer is registered, the model of the best epoch found by the strategy is
+    /// restored once the training is stopped.
     pub fn early_stopping<Strategy>(mut self, strategy: Strategy) -> Self
     where
         Strategy: EarlyStoppingStrategy + 'static,
@@ -269,6 +330,11 @@ where
     /// Create the [learner](Learner) from a [model](AutodiffModule) and an [optimizer](Optimizer).
     /// The [learning rate scheduler](LrScheduler) can also be a simple
     /// [learning rate](burn_core::LearningRate).
+    ///
+    /// # Panics
+    ///
+    /// If the training is [resumed](Self::with_resume) without a
+    /// [file checkpointer](Self::with_file_checkpointer).
     #[allow(clippy::type_complexity)] // The goal for the builder is to handle all types and
                                       // creates a clean learner.
     pub fn build(
@@ -297,6 +363,25 @@ where
         if self.log_to_file {
             self.init_logger();
         }
+        if self.resume {
+            assert!(
+                self.checkpointers.is_some(),
+                "Can't resume the training without a file checkpointer, register one with \
+                 `with_file_checkpointer`"
+            );
+
+            // The same directory as the one of the file checkpointer.
+            let directory = format!("{}/checkpoint", self.directory);
+            let names = ["model", "optim", "scheduler"];
+
+            match last_complete_checkpoint(&directory, &names) {
+                Some(epoch) => {
+                    log::info!("Resuming the training from the checkpoint {epoch}");
+                    self.checkpoint = Some(epoch);
+                }
+                None => log::info!("No checkpoint found in {directory}, starting from scratch"),
+            }
+        }
         let renderer = self.renderer.unwrap_or_else(|| {
             Box::new(default_renderer(self.interrupter.clone(), self.checkpoint))
         });
@@ -330,6 +415,7 @@ where
             event_store,
             checkpoint: self.checkpoint,
             grad_accumulation: self.grad_accumulation,
+            loss_scaler: self.loss_scaler,
             devices: self.devices,
             interrupter: self.interrupter,
             early_stopping: self.early_stopping,
@@ -341,3 +427,217 @@ where
         install_file_logger(file_path.as_str());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::{MetricState, TrainingProgress};
+    use crate::{
+        RegressionOutput, TestAutodiffBackend, TestBackend, TrainOutput, TrainStep, ValidStep,
+    };
+    use burn_core as burn;
+    use burn_core::data::dataloader::batcher::Batcher;
+    use burn_core::data::dataloader::{DataLoader, DataLoaderBuilder};
+    use burn_core::data::dataset::InMemDataset;
+    use burn_core::lr_scheduler::exponential::ExponentialLrSchedulerConfig;
+    use burn_core::module::Module;
+    use burn_core::nn::loss::{MseLoss, Reduction};
+    use burn_core::nn::{Linear, LinearConfig};
+    use burn_core::optim::AdamConfig;
+    use burn_core::record::{BinFileRecorder, FullPrecisionSettings};
+    use burn_core::tensor::backend::Backend;
+    use burn_core::tensor::{Data, Tensor};
+
+    #[derive(Module, Debug)]
+    struct Regression<B: Backend> {
+        linear: Linear<B>,
+    }
+
+    #[derive(Clone, Debug)]
+    struct RegressionBatch<B: Backend> {
+        inputs: Tensor<B, 2>,
+        targets: Tensor<B, 2>,
+    }
+
+    impl<B: Backend> Regression<B> {
+        fn forward(&self, batch: RegressionBatch<B>) -> RegressionOutput<B> {
+            let output = self.linear.forward(batch.inputs);
+            let loss =
+                MseLoss::new().forward(output.clone(), batch.targets.clone(), Reduction::Mean);
+
+            RegressionOutput::new(loss, output, batch.targets)
+        }
+    }
+
+    impl TrainStep<RegressionBatch<TestAutodiffBackend>, RegressionOutput<TestAutodiffBackend>>
+        for Regression<TestAutodiffBackend>
+    {
+        fn step(
+            &self,
+            batch: RegressionBatch<TestAutodiffBackend>,
+        ) -> TrainOutput<RegressionOutput<TestAutodiffBackend>> {
+            let item = self.forward(batch);
+            TrainOutput::new(self, item.loss.backward(), item)
+        }
+    }
+
+    impl<B: Backend> ValidStep<RegressionBatch<B>, RegressionOutput<B>> for Regression<B> {
+        fn step(&self, batch: RegressionBatch<B>) -> RegressionOutput<B> {
+            self.forward(batch)
+        }
+    }
+
+    struct RegressionBatcher;
+
+    impl<B: Backend> Batcher<[f32; 3], RegressionBatch<B>> for RegressionBatcher {
+        fn batch(&self, items: Vec<[f32; 3]>) -> RegressionBatch<B> {
+            let device = Default::default();
+            let column = |i: usize| {
+                let values = items.iter().map(|item| item[i]).collect::<Vec<_>>();
+                Tensor::<B, 1>::from_floats(values.as_slice(), &device)
+            };
+            let inputs = Tensor::stack(vec![column(0), column(1)], 1);
+
+            RegressionBatch {
+                inputs,
+                targets: column(2).unsqueeze_dim(1),
+            }
+        }
+    }
+
+    struct SilentRenderer;
+
+    impl MetricsRenderer for SilentRenderer {
+        fn update_train(&mut self, _state: MetricState) {}
+        fn update_valid(&mut self, _state: MetricState) {}
+        fn render_train(&mut self, _item: TrainingProgress) {}
+        fn render_valid(&mut self, _item: TrainingProgress) {}
+    }
+
+    fn fit(
+        directory: &str,
+        model: Regression<TestAutodiffBackend>,
+        num_epochs: usize,
+        resume: bool,
+        loss_scaling: Option<LossScalerConfig>,
+    ) -> Data<f32, 2> {
+        // y = 2 * x1 - x2 + 0.5
+        let items = (0..16)
+            .map(|i| {
+                let (x1, x2) = (i as f32 / 8.0, (i % 4) as f32);
+                [x1, x2, 2.0 * x1 - x2 + 0.5]
+            })
+            .collect::<Vec<_>>();
+        let dataloader_train: Arc<dyn DataLoader<RegressionBatch<TestAutodiffBackend>>> =
+            DataLoaderBuilder::new(RegressionBatcher)
+                .batch_size(4)
+                .build(InMemDataset::new(items.clone()));
+        let dataloader_valid: Arc<dyn DataLoader<RegressionBatch<TestBackend>>> =
+            DataLoaderBuilder::new(RegressionBatcher)
+                .batch_size(4)
+                .build(InMemDataset::new(items));
+
+        let mut builder = LearnerBuilder::new(directory)
+            .with_file_checkpointer(BinFileRecorder::<FullPrecisionSettings>::new())
+            .renderer(SilentRenderer)
+            .log_to_file(false)
+            .num_epochs(num_epochs);
+        if resume {
+            builder = builder.with_resume();
+        }
+        if let Some(config) = loss_scaling {
+            builder = builder.loss_scaling(config);
+        }
+
+        let learner = builder.build(
+            model,
+            AdamConfig::new().init(),
+            ExponentialLrSchedulerConfig::new(0.1, 0.5).init(),
+        );
+
+        learner
+            .fit(dataloader_train, dataloader_valid)
+            .linear
+            .weight
+            .val()
+            .into_data()
+            .convert()
+    }
+
+    #[test]
+    fn test_resumed_training_matches_uninterrupted_training() {
+        let model = Regression {
+            linear: LinearConfig::new(2, 1).init(&Default::default()),
+        };
+        let directory_full = tempfile::tempdir().unwrap();
+        let directory_resumed = tempfile::tempdir().unwrap();
+        let directory_resumed = directory_resumed.path().to_str().unwrap();
+
+        let expected = fit(
+            directory_full.path().to_str().unwrap(),
+            model.clone(),
+            4,
+            false,
+            None,
+        );
+
+        // Without checkpoints, the resumed training starts from scratch.
+        let _weights = fit(directory_resumed, model.clone(), 2, true, None);
+        let weights = fit(directory_resumed, model, 4, true, None);
+
+        weights.assert_approx_eq(&expected, 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_resume_without_checkpointer_panics() {
+        let directory = tempfile::tempdir().unwrap();
+        let model: Regression<TestAutodiffBackend> = Regression {
+            linear: LinearConfig::new(2, 1).init(&Default::default()),
+        };
+
+        let _learner = LearnerBuilder::<
+            TestAutodiffBackend,
+            RegressionOutput<TestAutodiffBackend>,
+            RegressionOutput<TestBackend>,
+            _,
+            _,
+            _,
+        >::new(directory.path().to_str().unwrap())
+        .renderer(SilentRenderer)
+        .log_to_file(false)
+        .with_resume()
+        .build(
+            model,
+            AdamConfig::new().init(),
+            ExponentialLrSchedulerConfig::new(0.1, 0.5).init(),
+        );
+    }
+
+    #[test]
+    fn test_training_with_loss_scaling_matches_training_without() {
+        let model = Regression {
+            linear: LinearConfig::new(2, 1).init(&Default::default()),
+        };
+        let directory = tempfile::tempdir().unwrap();
+        let directory_scaled = tempfile::tempdir().unwrap();
+
+        let expected = fit(
+            directory.path().to_str().unwrap(),
+            model.clone(),
+            2,
+            false,
+            None,
+        );
+        // The scale is a power of two, so the unscaled gradients are exactly the same.
+        let weights = fit(
+            directory_scaled.path().to_str().unwrap(),
+            model,
+            2,
+            false,
+            Some(LossScalerConfig::new().with_init_scale(1024.0)),
+        );
+
+        weights.assert_approx_eq(&expected, 5);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::metric::{AccuracyInput, Adaptor, LossInput};
+use crate::metric::{
+    AccuracyInput, Adaptor, AurocInput, ClassificationInput, ConfusionMatrixInput, LossInput,
+};
 use burn_core::tensor::backend::Backend;
 use burn_core::tensor::{Int, Tensor};
 
@@ -26,3 +28,21 @@ impl<B: Backend> Adaptor<LossInput<B>> for ClassificationOutput<B> {
         LossInput::new(self.loss.clone())
     }
 }
+
+impl<B: Backend> Adaptor<AurocInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> AurocInput<B> {
+        AurocInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<ClassificationInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> ClassificationInput<B> {
+        ClassificationInput::new(self.output.clone(), self.targets.clone())
+    }
+}
+
+impl<B: Backend> Adaptor<ConfusionMatrixInput<B>> for ClassificationOutput<B> {
+    fn adapt(&self) -> ConfusionMatrixInput<B> {
+        ConfusionMatrixInput::new(self.output.clone(), self.targets.clone())
+    }
+}
@@ -16,6 +16,14 @@ pub enum StoppingCondition {
 pub trait EarlyStoppingStrategy {
     /// Update its current state and returns if the training should be stopped.
     fn should_stop(&mut self, epoch: usize, store: &EventStoreClient) -> bool;
+
+    /// The best epoch found so far, if any.
+    ///
+    /// When the training is stopped and checkpointing is enabled, the model is restored from the
+    /// checkpoint of that epoch.
+    fn best_epoch(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// An [early stopping strategy](EarlyStoppingStrategy) based on a metrics collected
@@ -26,7 +34,8 @@ pub struct MetricEarlyStoppingStrategy {
     aggregate: Aggregate,
     direction: Direction,
     split: Split,
-    best_epoch: usize,
+    min_delta: f64,
+    best_epoch: Option<usize>,
     best_value: f64,
 }
 
@@ -41,9 +50,12 @@ impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
                 }
             };
 
-        let is_best = match self.direction {
-            Direction::Lowest => current_value < self.best_value,
-            Direction::Highest => current_value > self.best_value,
+        let is_best = match self.best_epoch {
+            None => true,
+            Some(_) => match self.direction {
+                Direction::Lowest => current_value < self.best_value - self.min_delta,
+                Direction::Highest => current_value > self.best_value + self.min_delta,
+            },
         };
 
         if is_best {
@@ -54,19 +66,21 @@ impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
                 current_value
             );
             self.best_value = current_value;
-            self.best_epoch = epoch;
+            self.best_epoch = Some(epoch);
             return false;
         }
 
+        let best_epoch = self.best_epoch.unwrap_or(epoch);
+
         match self.condition {
             StoppingCondition::NoImprovementSince { n_epochs } => {
-                let should_stop = epoch - self.best_epoch >= n_epochs;
+                let should_stop = epoch - best_epoch >= n_epochs;
 
                 if should_stop {
                     log::info!(
                         "Stopping training loop, no improvement since epoch {}, {}: {},  current \
                          epoch {}, {}: {}",
-                        self.best_epoch,
+                        best_epoch,
                         self.metric_name,
                         self.best_value,
                         epoch,
@@ -79,6 +93,10 @@ impl EarlyStoppingStrategy for MetricEarlyStoppingStrategy {
             }
         }
     }
+
+    fn best_epoch(&self) -> Option<usize> {
+        self.best_epoch
+    }
 }
 
 impl MetricEarlyStoppingStrategy {
@@ -105,10 +123,17 @@ impl MetricEarlyStoppingStrategy {
             aggregate,
             direction,
             split,
-            best_epoch: 1,
+            min_delta: 0.0,
+            best_epoch: None,
             best_value: init_value,
         }
     }
+
+    /// Set the minimum change of the metric to count as an improvement.
+    pub fn with_min_delta(mut self, min_delta: f64) -> Self {
+        self.min_delta = min_delta;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -184,13 +209,48 @@ mod tests {
         );
     }
 
-    fn test_early_stopping(n_epochs: usize, data: &[(&[f64], bool, &str)]) {
-        let mut early_stopping = MetricEarlyStoppingStrategy::new::<LossMetric<TestBackend>>(
+    #[test]
+    fn early_stop_when_improvements_are_below_min_delta() {
+        let mut early_stopping = strategy(2).with_min_delta(0.1);
+
+        test_early_stopping_with(
+            &mut early_stopping,
+            &[
+                (&[1.0, 1.0], false, "Should not stop first epoch"),
+                (&[0.7, 0.7], false, "Should not stop when improving"),
+                (
+                    &[0.65, 0.65],
+                    false,
+                    "Should not stop first small improvement",
+                ),
+                (
+                    &[0.62, 0.62],
+                    true,
+                    "Should stop since two following epochs improved less than min delta",
+                ),
+            ],
+        );
+
+        assert_eq!(early_stopping.best_epoch(), Some(2));
+    }
+
+    fn strategy(n_epochs: usize) -> MetricEarlyStoppingStrategy {
+        MetricEarlyStoppingStrategy::new::<LossMetric<TestBackend>>(
             Aggregate::Mean,
             Direction::Lowest,
             Split::Train,
             StoppingCondition::NoImprovementSince { n_epochs },
-        );
+        )
+    }
+
+    fn test_early_stopping(n_epochs: usize, data: &[(&[f64], bool, &str)]) {
+        test_early_stopping_with(&mut strategy(n_epochs), data);
+    }
+
+    fn test_early_stopping_with(
+        early_stopping: &mut MetricEarlyStoppingStrategy,
+        data: &[(&[f64], bool, &str)],
+    ) {
         let mut store = LogEventStore::default();
         let mut metrics = Metrics::<f64, f64>::default();
 
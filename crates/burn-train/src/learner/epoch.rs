@@ -1,6 +1,9 @@
 use burn_core::{
-    data::dataloader::DataLoader, lr_scheduler::LrScheduler, module::AutodiffModule,
-    optim::GradientsAccumulator, tensor::backend::Backend,
+    data::dataloader::DataLoader,
+    lr_scheduler::LrScheduler,
+    module::AutodiffModule,
+    optim::{GradientsAccumulator, LossScaler, Optimizer},
+    tensor::backend::Backend,
 };
 use std::sync::Arc;
 
@@ -59,6 +62,7 @@ impl<VI> ValidEpoch<VI> {
                 self.epoch_total,
                 iteration,
                 None,
+                Vec::new(),
             );
 
             processor.process_valid(Event::ProcessedItem(item));
@@ -81,6 +85,8 @@ impl<TI> TrainEpoch<TI> {
     /// * `optim` - The optimizer to use.
     /// * `scheduler` - The learning rate scheduler to use.
     /// * `processor` - The event processor to use.
+    /// * `loss_scaler` - The loss scaler to use, if any. The optimizer step of the items whose
+    ///   gradients overflow is skipped.
     ///
     /// # Returns
     ///
@@ -91,6 +97,7 @@ impl<TI> TrainEpoch<TI> {
         mut optim: LC::Optimizer,
         scheduler: &mut LC::LrScheduler,
         processor: &mut LC::EventProcessor,
+        mut loss_scaler: Option<&mut LossScaler>,
         interrupter: &TrainingInterrupter,
     ) -> (LC::Model, LC::Optimizer)
     where
@@ -103,37 +110,70 @@ impl<TI> TrainEpoch<TI> {
         let mut iteration = 0;
         let mut accumulator = GradientsAccumulator::new();
         let mut accumulation_current = 0;
+        // The items of the accumulation window, including the skipped ones.
+        let mut accumulated = 0;
 
         while let Some(item) = iterator.next() {
             iteration += 1;
+            accumulated += 1;
             let lr = scheduler.step();
             log::info!("Iteration {}", iteration);
 
             let progress = iterator.progress();
-            let item = model.step(item);
+            let (item, grads) = match loss_scaler.as_deref_mut() {
+                Some(scaler) => {
+                    let item = model.step_scaled::<LC::Backend>(item, scaler);
+                    let grads = scaler.unscale(&model, item.grads);
+
+                    if grads.is_none() {
+                        log::info!(
+                            "Skipping the step of iteration {iteration}, the gradients overflowed"
+                        );
+                    }
+
+                    (item.item, grads)
+                }
+                None => {
+                    let item = model.step(item);
+                    (item.item, Some(item.grads))
+                }
+            };
 
-            match self.grad_accumulation {
-                Some(accumulation) => {
-                    accumulator.accumulate(&model, item.grads);
+            let window = accumulated;
+            match (self.grad_accumulation, grads) {
+                (Some(_), None) => {}
+                (Some(accumulation), Some(grads)) => {
+                    accumulator.accumulate(&model, grads);
                     accumulation_current += 1;
 
                     if accumulation <= accumulation_current {
                         let grads = accumulator.grads();
                         model = model.optimize(&mut optim, lr, grads);
                         accumulation_current = 0;
+                        accumulated = 0;
                     }
                 }
-                None => model = model.optimize(&mut optim, lr, item.grads),
+                (None, grads) => {
+                    if let Some(grads) = grads {
+                        model = model.optimize(&mut optim, lr, grads);
+                    }
+                    accumulated = 0;
+                }
             }
 
-            let item = LearnerItem::new(
-                item.item,
+            let mut item = LearnerItem::new(
+                item,
                 progress,
                 self.epoch,
                 self.epoch_total,
                 iteration,
                 Some(lr),
+                optim
+                    .param_groups()
+                    .map(|groups| groups.lrs(lr))
+                    .unwrap_or_default(),
             );
+            item.accumulated = window;
 
             processor.process_train(Event::ProcessedItem(item));
 
@@ -210,6 +250,7 @@ impl<TI> TrainEpoch<TI> {
 
                 accumulator.accumulate(&model, grads);
                 accumulation_current += 1;
+                let window = accumulation_current;
 
                 if accumulation <= accumulation_current {
                     let grads = accumulator.grads();
@@ -217,14 +258,19 @@ impl<TI> TrainEpoch<TI> {
                     accumulation_current = 0;
                 }
 
-                let item = LearnerItem::new(
+                let mut item = LearnerItem::new(
                     item.item,
                     progress,
                     self.epoch,
                     self.epoch_total,
                     iteration,
                     Some(lr),
+                    optim
+                        .param_groups()
+                        .map(|groups| groups.lrs(lr))
+                        .unwrap_or_default(),
                 );
+                item.accumulated = window;
 
                 processor.process_train(Event::ProcessedItem(item));
 
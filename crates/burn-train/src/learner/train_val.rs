@@ -3,7 +3,7 @@ use crate::metric::processor::EventProcessor;
 use crate::{Learner, TrainEpoch, ValidEpoch};
 use burn_core::data::dataloader::DataLoader;
 use burn_core::module::{AutodiffModule, Module};
-use burn_core::optim::{GradientsParams, Optimizer};
+use burn_core::optim::{GradientsParams, LossScaler, Optimizer};
 use burn_core::tensor::backend::AutodiffBackend;
 use std::sync::Arc;
 
@@ -62,6 +62,35 @@ pub trait TrainStep<TI, TO> {
     ///
     /// The training output containing the model output and the gradients.
     fn step(&self, item: TI) -> TrainOutput<TO>;
+    /// Runs the training step with the loss [scaled](LossScaler::scale) before the backward pass,
+    /// which is used when [loss scaling](crate::learner::LearnerBuilder::loss_scaling) is enabled.
+    ///
+    /// By default, the gradients of the [step](TrainStep::step) are
+    /// [scaled](LossScaler::scale_gradients) after the backward pass instead. The overflows are
+    /// still detected, but the gradients that underflow in half precision aren't recovered, so
+    /// this method should be implemented to scale the loss itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The training input for the model.
+    /// * `scaler` - The loss scaler, the gradients are unscaled by the learner.
+    ///
+    /// # Returns
+    ///
+    /// The training output containing the model output and the gradients of the scaled loss.
+    fn step_scaled<B>(&self, item: TI, scaler: &LossScaler) -> TrainOutput<TO>
+    where
+        B: AutodiffBackend,
+        Self: AutodiffModule<B>,
+    {
+        let output = self.step(item);
+        let grads = scaler.scale_gradients::<B, Self>(self, output.grads);
+
+        TrainOutput {
+            grads,
+            item: output.item,
+        }
+    }
     /// Optimize the current module with the provided gradients and learning rate.
     ///
     /// # Arguments
@@ -108,6 +137,11 @@ impl<LC: LearnerComponents> Learner<LC> {
     /// # Returns
     ///
     /// The fitted model.
+    ///
+    /// # Panics
+    ///
+    /// If [loss scaling](crate::learner::LearnerBuilder::loss_scaling) is enabled with multiple
+    /// devices.
     pub fn fit<InputTrain, InputValid, OutputTrain, OutputValid>(
         mut self,
         dataloader_train: Arc<dyn DataLoader<InputTrain>>,
@@ -123,6 +157,10 @@ impl<LC: LearnerComponents> Learner<LC> {
         LC::EventProcessor: EventProcessor<ItemTrain = OutputTrain, ItemValid = OutputValid>,
     {
         log::info!("Fitting {}", self.model.to_string());
+        assert!(
+            self.loss_scaler.is_none() || self.devices.len() <= 1,
+            "Loss scaling isn't supported with multiple devices"
+        );
         // The reference model is always on the first device provided.
         if let Some(device) = self.devices.first() {
             self.model = self.model.fork(device);
@@ -194,6 +232,13 @@ impl<LC: LearnerComponents> Learner<LC> {
 
             if let Some(early_stopping) = &mut self.early_stopping {
                 if early_stopping.should_stop(epoch, &self.event_store) {
+                    if let (Some(checkpointer), Some(best_epoch)) =
+                        (&self.checkpointer, early_stopping.best_epoch())
+                    {
+                        log::info!("Restoring the model of the best epoch {best_epoch}");
+                        let device = self.devices.first().cloned().unwrap_or_default();
+                        self.model = checkpointer.load_model(self.model, &device, best_epoch);
+                    }
                     break;
                 }
             }
@@ -1,6 +1,8 @@
 use super::{AsyncLogger, FileLogger, InMemoryLogger, Logger};
 use crate::metric::MetricEntry;
 use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
 
 /// Metric logger.
 pub trait MetricLogger: Send {
@@ -11,6 +13,18 @@ pub trait MetricLogger: Send {
     /// * `item` - The item.
     fn log(&mut self, item: &MetricEntry);
 
+    /// Logs an item updated at the given iteration of the current epoch.
+    ///
+    /// By default, the iteration is ignored and the item is [logged](MetricLogger::log).
+    ///
+    /// # Arguments
+    ///
+    /// * `item` - The item.
+    /// * `iteration` - The iteration, counted from 1 at the start of each epoch.
+    fn log_iteration(&mut self, item: &MetricEntry, _iteration: usize) {
+        self.log(item)
+    }
+
     /// Logs an epoch.
     ///
     /// # Arguments
@@ -124,6 +138,178 @@ impl MetricLogger for FileMetricLogger {
     }
 }
 
+/// The CSV metric logger.
+///
+/// Every entry is written as a row `epoch,step,metric,value` of a single `metrics.csv` file in the
+/// given directory, where `step` is the position of the entry among the entries of the same
+/// metric during the epoch. Rows are buffered and flushed every `flush_interval` rows and at the
+/// end of each epoch, so the logs survive a crash.
+///
+/// An existing file is appended to, e.g. when the training is resumed, and its rows can still be
+/// read with [read_numeric](MetricLogger::read_numeric). The values are kept in memory, so the
+/// file is only read once.
+pub struct CsvMetricLogger {
+    writer: BufWriter<File>,
+    // The logged values of each metric, by epoch.
+    values: HashMap<String, HashMap<usize, Vec<String>>>,
+    steps: HashMap<String, usize>,
+    epoch: usize,
+    flush_interval: usize,
+    pending: usize,
+}
+
+impl CsvMetricLogger {
+    /// The header of the CSV file.
+    pub const HEADER: &'static str = "epoch,step,metric,value";
+
+    /// Create a new CSV metric logger.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory where the `metrics.csv` file is created, or appended to if
+    ///   it already exists.
+    ///
+    /// # Returns
+    ///
+    /// The CSV metric logger.
+    pub fn new(directory: &str) -> Self {
+        std::fs::create_dir_all(directory).ok();
+
+        let path = format!("{directory}/metrics.csv");
+        let existing = std::fs::read_to_string(&path).unwrap_or_default();
+        let file = OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(&path)
+            .unwrap_or_else(|err| panic!("Should be able to open the file '{path}': {err}"));
+        let mut writer = BufWriter::new(file);
+
+        if existing.is_empty() {
+            writeln!(writer, "{}", Self::HEADER).expect("Can log the header.");
+        }
+
+        let mut values: HashMap<String, HashMap<usize, Vec<String>>> = HashMap::new();
+        for row in existing.lines().skip(1).map(parse_csv_row) {
+            match (
+                row.as_slice(),
+                row.first().map(|epoch| epoch.parse::<usize>()),
+            ) {
+                ([_, _, name, value], Some(Ok(epoch))) => values
+                    .entry(name.clone())
+                    .or_default()
+                    .entry(epoch)
+                    .or_default()
+                    .push(value.clone()),
+                _ => log::warn!("Ignoring the malformed row {row:?} of '{path}'"),
+            }
+        }
+
+        Self {
+            writer,
+            values,
+            steps: HashMap::new(),
+            epoch: 1,
+            flush_interval: 100,
+            pending: 0,
+        }
+    }
+
+    /// Set the number of rows written between two flushes, 100 by default.
+    pub fn with_flush_interval(mut self, flush_interval: usize) -> Self {
+        self.flush_interval = usize::max(flush_interval, 1);
+        self
+    }
+
+    fn flush(&mut self) {
+        self.writer.flush().expect("Can flush the logged rows.");
+        self.pending = 0;
+    }
+}
+
+impl MetricLogger for CsvMetricLogger {
+    fn log(&mut self, item: &MetricEntry) {
+        let step = self.steps.entry(item.name.clone()).or_insert(0);
+        *step += 1;
+
+        writeln!(
+            self.writer,
+            "{},{},{},{}",
+            self.epoch,
+            step,
+            csv_field(&item.name),
+            csv_field(&item.serialize)
+        )
+        .expect("Can log an item.");
+
+        self.values
+            .entry(item.name.clone())
+            .or_default()
+            .entry(self.epoch)
+            .or_default()
+            .push(item.serialize.clone());
+
+        self.pending += 1;
+        if self.pending >= self.flush_interval {
+            self.flush();
+        }
+    }
+
+    fn end_epoch(&mut self, epoch: usize) {
+        self.flush();
+        self.steps.clear();
+        self.epoch = epoch + 1;
+    }
+
+    fn read_numeric(&mut self, name: &str, epoch: usize) -> Result<Vec<f64>, String> {
+        let values = match self.values.get(name).and_then(|values| values.get(&epoch)) {
+            Some(values) => values,
+            None => return Ok(Vec::new()),
+        };
+
+        values
+            .iter()
+            .map(|value| value.parse::<f64>().map_err(|err| err.to_string()))
+            .collect()
+    }
+}
+
+impl Drop for CsvMetricLogger {
+    fn drop(&mut self) {
+        self.writer.flush().ok();
+    }
+}
+
+/// Quote a CSV field when it contains a separator, a quote or a line break.
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n', '\r']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+/// Split a CSV row written by the [CSV metric logger](CsvMetricLogger) into its fields.
+fn parse_csv_row(row: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = row.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match (char, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => fields.push(core::mem::take(&mut field)),
+            (char, _) => field.push(char),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
+
 /// In memory metric logger, useful when testing and debugging.
 #[derive(Default)]
 pub struct InMemoryMetricLogger {
@@ -170,3 +356,79 @@ impl MetricLogger for InMemoryMetricLogger {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_metric_logger() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path().to_str().unwrap();
+        let mut logger = CsvMetricLogger::new(directory).with_flush_interval(2);
+
+        for loss in ["0.5", "0.25"] {
+            logger.log(&MetricEntry::new(
+                "Loss".to_string(),
+                String::new(),
+                loss.to_string(),
+            ));
+        }
+        logger.log(&MetricEntry::new(
+            "Confusion Matrix".to_string(),
+            String::new(),
+            "1,0;0,1".to_string(),
+        ));
+        logger.end_epoch(1);
+        logger.log(&MetricEntry::new(
+            "Loss".to_string(),
+            String::new(),
+            "0.125".to_string(),
+        ));
+
+        assert_eq!(logger.read_numeric("Loss", 1), Ok(vec![0.5, 0.25]));
+        drop(logger);
+
+        let data = std::fs::read_to_string(format!("{directory}/metrics.csv")).unwrap();
+        let rows = data.lines().map(parse_csv_row).collect::<Vec<_>>();
+
+        assert_eq!(data.lines().next(), Some(CsvMetricLogger::HEADER));
+        assert_eq!(
+            rows[1..],
+            [
+                ["1", "1", "Loss", "0.5"],
+                ["1", "2", "Loss", "0.25"],
+                ["1", "1", "Confusion Matrix", "1,0;0,1"],
+                ["2", "1", "Loss", "0.125"],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_csv_metric_logger_appends_to_existing_file() {
+        let directory = tempfile::tempdir().unwrap();
+        let directory = directory.path().to_str().unwrap();
+        let loss = |value: &str| MetricEntry::new("Loss".to_string(), String::new(), value.into());
+
+        let mut logger = CsvMetricLogger::new(directory);
+        logger.log(&loss("0.5"));
+        logger.end_epoch(1);
+        drop(logger);
+
+        // The resumed training keeps the rows of the previous one.
+        let mut logger = CsvMetricLogger::new(directory);
+        logger.end_epoch(1);
+        logger.log(&loss("0.25"));
+
+        assert_eq!(logger.read_numeric("Loss", 1), Ok(vec![0.5]));
+        assert_eq!(logger.read_numeric("Loss", 2), Ok(vec![0.25]));
+        drop(logger);
+
+        let data = std::fs::read_to_string(format!("{directory}/metrics.csv")).unwrap();
+
+        assert_eq!(
+            data.lines().collect::<Vec<_>>(),
+            [CsvMetricLogger::HEADER, "1,1,Loss,0.5", "2,1,Loss,0.25"]
+        );
+    }
+}
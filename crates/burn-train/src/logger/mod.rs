@@ -3,9 +3,11 @@ mod base;
 mod file;
 mod in_memory;
 mod metric;
+mod tensorboard;
 
 pub use async_logger::*;
 pub use base::*;
 pub use file::*;
 pub use in_memory::*;
 pub use metric::*;
+pub use tensorboard::*;
@@ -0,0 +1,319 @@
+use super::MetricLogger;
+use crate::metric::MetricEntry;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The TensorBoard metric logger.
+///
+/// Numeric metrics are written as scalar summaries in an `events.out.tfevents.*` file of the given
+/// directory, which can be opened with `tensorboard --logdir <directory>`. The metric name is used
+/// as the tag and the step is the iteration counted since the first epoch logged. Entries that
+/// aren't numeric are ignored.
+///
+/// The values are only written to the event file, so they can't be
+/// [read](MetricLogger::read_numeric) back: another logger must be registered for the metrics
+/// used by the checkpointing and early stopping strategies.
+pub struct TensorBoardMetricLogger {
+    writer: BufWriter<File>,
+    // The number of iterations of the previous epochs.
+    step_offset: usize,
+    iteration: usize,
+}
+
+impl TensorBoardMetricLogger {
+    /// Create a new TensorBoard metric logger.
+    ///
+    /// # Arguments
+    ///
+    /// * `directory` - The directory where the event file is created.
+    ///
+    /// # Returns
+    ///
+    /// The TensorBoard metric logger.
+    pub fn new(directory: &str) -> Self {
+        std::fs::create_dir_all(directory).ok();
+
+        let path = format!(
+            "{directory}/events.out.tfevents.{}.burn",
+            wall_time() as u64
+        );
+        let file = File::create(&path)
+            .unwrap_or_else(|err| panic!("Should be able to create the new file '{path}': {err}"));
+
+        let mut logger = Self {
+            writer: BufWriter::new(file),
+            step_offset: 0,
+            iteration: 0,
+        };
+
+        // The first event of a file only contains its version.
+        let mut event = event_header(0);
+        encode_bytes(&mut event, 3, b"brain.Event:2");
+        logger.write_record(&event);
+        logger.flush();
+
+        logger
+    }
+
+    fn write_record(&mut self, data: &[u8]) {
+        let len = (data.len() as u64).to_le_bytes();
+
+        let mut record = Vec::with_capacity(data.len() + 16);
+        record.extend_from_slice(&len);
+        record.extend_from_slice(&masked_crc32c(&len).to_le_bytes());
+        record.extend_from_slice(data);
+        record.extend_from_slice(&masked_crc32c(data).to_le_bytes());
+
+        self.writer.write_all(&record).expect("Can log an event.");
+    }
+
+    fn flush(&mut self) {
+        self.writer.flush().expect("Can flush the logged events.");
+    }
+}
+
+impl MetricLogger for TensorBoardMetricLogger {
+    /// Logs the item at the step of the last iteration logged.
+    fn log(&mut self, item: &MetricEntry) {
+        self.log_iteration(item, self.iteration);
+    }
+
+    fn log_iteration(&mut self, item: &MetricEntry, iteration: usize) {
+        self.iteration = usize::max(self.iteration, iteration);
+
+        let value = match item.serialize.parse::<f64>() {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+
+        let mut event = event_header((self.step_offset + iteration) as i64);
+
+        // Summary { value: [Value { tag, simple_value }] }
+        let mut summary_value = Vec::new();
+        encode_bytes(&mut summary_value, 1, item.name.as_bytes());
+        encode_key(&mut summary_value, 2, WIRE_FIXED32);
+        summary_value.extend_from_slice(&(value as f32).to_le_bytes());
+        let mut summary = Vec::new();
+        encode_bytes(&mut summary, 1, &summary_value);
+        encode_bytes(&mut event, 5, &summary);
+
+        self.write_record(&event);
+    }
+
+    fn end_epoch(&mut self, _epoch: usize) {
+        self.flush();
+        self.step_offset += self.iteration;
+        self.iteration = 0;
+    }
+
+    fn read_numeric(&mut self, _name: &str, _epoch: usize) -> Result<Vec<f64>, String> {
+        Err("The TensorBoard metric logger can't read the logged values".to_string())
+    }
+}
+
+impl Drop for TensorBoardMetricLogger {
+    fn drop(&mut self) {
+        self.writer.flush().ok();
+    }
+}
+
+const WIRE_VARINT: u8 = 0;
+const WIRE_FIXED64: u8 = 1;
+const WIRE_BYTES: u8 = 2;
+const WIRE_FIXED32: u8 = 5;
+
+fn wall_time() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64())
+        .unwrap_or_default()
+}
+
+/// Encode the `wall_time` and `step` fields of an event.
+fn event_header(step: i64) -> Vec<u8> {
+    let mut event = Vec::new();
+    encode_key(&mut event, 1, WIRE_FIXED64);
+    event.extend_from_slice(&wall_time().to_le_bytes());
+    encode_key(&mut event, 2, WIRE_VARINT);
+    encode_varint(&mut event, step as u64);
+
+    event
+}
+
+fn encode_key(buffer: &mut Vec<u8>, field: u64, wire_type: u8) {
+    encode_varint(buffer, (field << 3) | wire_type as u64);
+}
+
+fn encode_bytes(buffer: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    encode_key(buffer, field, WIRE_BYTES);
+    encode_varint(buffer, bytes.len() as u64);
+    buffer.extend_from_slice(bytes);
+}
+
+fn encode_varint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+/// The CRC-32C checksum of the data, masked as required by the record format.
+fn masked_crc32c(data: &[u8]) -> u32 {
+    let crc = crc32c(data);
+    crc.rotate_right(15).wrapping_add(0xa282ead8)
+}
+
+fn crc32c(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0x82f63b78 & mask);
+        }
+    }
+
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crc32c() {
+        assert_eq!(crc32c(b"123456789"), 0xe3069283);
+    }
+
+    #[test]
+    fn test_event_file_contains_scalars() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut logger = TensorBoardMetricLogger::new(directory.path().to_str().unwrap());
+
+        logger.log_iteration(&entry("Loss", "0.5"), 1);
+        logger.log_iteration(&entry("Confusion Matrix", "1,0;0,1"), 1);
+        logger.log_iteration(&entry("Loss", "0.25"), 2);
+        logger.end_epoch(1);
+        logger.log_iteration(&entry("Accuracy", "75"), 1);
+        logger.log_iteration(&entry("Accuracy", "80"), 3);
+
+        assert!(logger.read_numeric("Accuracy", 2).is_err());
+        drop(logger);
+
+        let path = std::fs::read_dir(directory.path())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap()
+            .path();
+        assert!(path
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("events.out.tfevents."));
+
+        let events = read_records(&std::fs::read(path).unwrap())
+            .into_iter()
+            .map(|record| decode_event(&record))
+            .collect::<Vec<_>>();
+
+        assert_eq!(events[0], (0, None));
+        assert_eq!(
+            events[1..],
+            [
+                (1, Some(("Loss".to_string(), 0.5))),
+                (2, Some(("Loss".to_string(), 0.25))),
+                (3, Some(("Accuracy".to_string(), 75.0))),
+                (5, Some(("Accuracy".to_string(), 80.0))),
+            ]
+        );
+    }
+
+    fn entry(name: &str, value: &str) -> MetricEntry {
+        MetricEntry::new(name.to_string(), String::new(), value.to_string())
+    }
+
+    /// Split the records, checking their length and data checksums.
+    fn read_records(mut data: &[u8]) -> Vec<Vec<u8>> {
+        let mut records = Vec::new();
+        let u32_at = |bytes: &[u8]| u32::from_le_bytes(bytes.try_into().unwrap());
+
+        while !data.is_empty() {
+            let len_bytes = &data[..8];
+            assert_eq!(u32_at(&data[8..12]), masked_crc32c(len_bytes));
+
+            let len = u64::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+            let record = &data[12..12 + len];
+            assert_eq!(u32_at(&data[12 + len..16 + len]), masked_crc32c(record));
+
+            records.push(record.to_vec());
+            data = &data[16 + len..];
+        }
+
+        records
+    }
+
+    /// Decode the step and the scalar summary of an event.
+    fn decode_event(mut event: &[u8]) -> (i64, Option<(String, f64)>) {
+        let mut step = 0;
+        let mut scalar = None;
+
+        while !event.is_empty() {
+            let (field, wire_type) = read_key(&mut event);
+            match (field, wire_type) {
+                (1, WIRE_FIXED64) => event = &event[8..],
+                (2, WIRE_VARINT) => step = read_varint(&mut event) as i64,
+                (3, WIRE_BYTES) => {
+                    assert_eq!(read_bytes(&mut event), b"brain.Event:2");
+                }
+                (5, WIRE_BYTES) => {
+                    let mut summary = read_bytes(&mut event);
+                    assert_eq!(read_key(&mut summary), (1, WIRE_BYTES));
+                    let mut value = read_bytes(&mut summary);
+
+                    assert_eq!(read_key(&mut value), (1, WIRE_BYTES));
+                    let tag = String::from_utf8(read_bytes(&mut value).to_vec()).unwrap();
+                    assert_eq!(read_key(&mut value), (2, WIRE_FIXED32));
+                    let simple_value = f32::from_le_bytes(value[..4].try_into().unwrap());
+
+                    scalar = Some((tag, simple_value as f64));
+                }
+                field => panic!("Unexpected field {field:?}"),
+            }
+        }
+
+        (step, scalar)
+    }
+
+    fn read_key(data: &mut &[u8]) -> (u64, u8) {
+        let key = read_varint(data);
+        (key >> 3, (key & 0x7) as u8)
+    }
+
+    fn read_bytes<'a>(data: &mut &'a [u8]) -> &'a [u8] {
+        let len = read_varint(data) as usize;
+        let (bytes, rest) = data.split_at(len);
+        *data = rest;
+
+        bytes
+    }
+
+    fn read_varint(data: &mut &[u8]) -> u64 {
+        let mut value = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = data[0];
+            *data = &data[1..];
+            value |= ((byte & 0x7f) as u64) << shift;
+            shift += 7;
+
+            if byte < 0x80 {
+                return value;
+            }
+        }
+    }
+}
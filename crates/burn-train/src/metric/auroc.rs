@@ -0,0 +1,235 @@
+use super::{format_float, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{activation::softmax, Data, Int, Tensor};
+use core::marker::PhantomData;
+
+/// The area under the ROC curve metric, for binary classification.
+///
+/// The scores and labels are accumulated over the epoch, and the area is computed from the ranks
+/// of the scores with the Mann–Whitney U statistic: it is the probability that a positive item
+/// has a higher score than a negative one, with ties counting for half.
+pub struct AurocMetric<B: Backend> {
+    scores: Vec<f64>,
+    labels: Vec<bool>,
+    current: f64,
+    _b: PhantomData<B>,
+}
+
+/// The [AUROC metric](AurocMetric) input type.
+///
+/// The outputs are either the scores of the positive class with shape `[batch_size, 1]`, or the
+/// logits of the negative and positive classes with shape `[batch_size, 2]`. The targets are 1
+/// for positive items and 0 for negative ones.
+#[derive(new)]
+pub struct AurocInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> AurocMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The points `(false positive rate, true positive rate)` of the ROC curve, obtained by
+    /// lowering the decision threshold through each distinct score of the epoch.
+    ///
+    /// The curve starts at `(0, 0)` and ends at `(1, 1)`.
+    pub fn roc_curve(&self) -> Vec<(f64, f64)> {
+        let mut items = self.sorted_items();
+        items.reverse();
+
+        let num_pos = self.labels.iter().filter(|label| **label).count() as f64;
+        let num_neg = self.labels.len() as f64 - num_pos;
+        let rate = |count: usize, total: f64| match total > 0.0 {
+            true => count as f64 / total,
+            false => 0.0,
+        };
+
+        let (mut tp, mut fp) = (0, 0);
+        let mut points = vec![(0.0, 0.0)];
+
+        for (i, (score, label)) in items.iter().enumerate() {
+            match label {
+                true => tp += 1,
+                false => fp += 1,
+            }
+
+            // Items with the same score are on the same side of every threshold.
+            if items
+                .get(i + 1)
+                .map(|(next, _)| next != score)
+                .unwrap_or(true)
+            {
+                points.push((rate(fp, num_neg), rate(tp, num_pos)));
+            }
+        }
+
+        points
+    }
+
+    fn sorted_items(&self) -> Vec<(f64, bool)> {
+        let mut items = self
+            .scores
+            .iter()
+            .copied()
+            .zip(self.labels.iter().copied())
+            .collect::<Vec<_>>();
+        items.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        items
+    }
+
+    fn auroc(&self) -> f64 {
+        let items = self.sorted_items();
+        let num_pos = self.labels.iter().filter(|label| **label).count();
+        let num_neg = self.labels.len() - num_pos;
+
+        if num_pos == 0 || num_neg == 0 {
+            return f64::NAN;
+        }
+
+        // Sum of the ranks of the positive items, tied scores sharing their average rank.
+        let mut rank_sum = 0.0;
+        let mut start = 0;
+        while start < items.len() {
+            let mut end = start;
+            while end < items.len() && items[end].0 == items[start].0 {
+                end += 1;
+            }
+
+            let rank = (start + end + 1) as f64 / 2.0;
+            let num_pos_tied = items[start..end].iter().filter(|item| item.1).count();
+            rank_sum += rank * num_pos_tied as f64;
+            start = end;
+        }
+
+        let num_pos = num_pos as f64;
+        let u = rank_sum - num_pos * (num_pos + 1.0) / 2.0;
+
+        u / (num_pos * num_neg as f64)
+    }
+}
+
+impl<B: Backend> Default for AurocMetric<B> {
+    fn default() -> Self {
+        Self {
+            scores: Vec::new(),
+            labels: Vec::new(),
+            current: f64::NAN,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for AurocMetric<B> {
+    const NAME: &'static str = "AUROC";
+
+    type Input = AurocInput<B>;
+
+    fn update(&mut self, input: &AurocInput<B>, _metadata: &MetricMetadata) -> MetricEntry {
+        let [batch_size, n_classes] = input.outputs.dims();
+
+        let scores = match n_classes {
+            1 => input.outputs.clone(),
+            2 => softmax(input.outputs.clone(), 1).slice([0..batch_size, 1..2]),
+            _ => panic!("The AUROC metric only supports binary classification"),
+        };
+        let scores: Data<f64, 2> = scores.into_data().convert();
+        let targets: Data<i64, 1> = input.targets.clone().into_data().convert();
+
+        self.scores.extend(scores.value);
+        self.labels
+            .extend(targets.value.into_iter().map(|target| target == 1));
+        self.current = self.auroc();
+
+        let formatted = format!("epoch {}", format_float(self.current, 4));
+        MetricEntry::new(Self::NAME.to_string(), formatted, self.current.to_string())
+    }
+
+    fn clear(&mut self) {
+        self.scores.clear();
+        self.labels.clear();
+        self.current = f64::NAN;
+    }
+}
+
+impl<B: Backend> Numeric for AurocMetric<B> {
+    fn value(&self) -> f64 {
+        self.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_auroc_separable() {
+        let mut metric = AurocMetric::<TestBackend>::new();
+        let _entry = metric.update(
+            &input(&[0.1, 0.8, 0.3, 0.9, 0.7], &[0, 1, 0, 1, 1]),
+            &MetricMetadata::fake(),
+        );
+
+        assert_eq!(metric.value(), 1.0);
+        assert_eq!(
+            metric.roc_curve(),
+            vec![
+                (0.0, 0.0),
+                (0.0, 1.0 / 3.0),
+                (0.0, 2.0 / 3.0),
+                (0.0, 1.0),
+                (0.5, 1.0),
+                (1.0, 1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_auroc_with_ties_over_batches() {
+        let mut metric = AurocMetric::<TestBackend>::new();
+        let _entry = metric.update(&input(&[0.1, 0.4], &[0, 0]), &MetricMetadata::fake());
+        assert!(metric.value().is_nan());
+
+        // One of the four positive/negative pairs is tied.
+        let _entry = metric.update(&input(&[0.4, 0.8], &[1, 1]), &MetricMetadata::fake());
+        assert!((metric.value() - 0.875).abs() < 1e-9);
+
+        metric.clear();
+        assert!(metric.value().is_nan());
+    }
+
+    #[test]
+    fn test_auroc_random() {
+        // Scores and labels drawn independently with a linear congruential generator.
+        let mut state: u64 = 42;
+        let mut random = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) as f32 / (1u64 << 31) as f32
+        };
+        let scores = (0..1000).map(|_| random()).collect::<Vec<_>>();
+        let labels = (0..1000)
+            .map(|_| (random() < 0.3) as i32)
+            .collect::<Vec<_>>();
+
+        let mut metric = AurocMetric::<TestBackend>::new();
+        let _entry = metric.update(&input(&scores, &labels), &MetricMetadata::fake());
+
+        assert!((metric.value() - 0.5).abs() < 0.05, "{}", metric.value());
+    }
+
+    fn input(scores: &[f32], labels: &[i32]) -> AurocInput<TestBackend> {
+        let device = Default::default();
+
+        AurocInput::new(
+            Tensor::from_floats(scores, &device).reshape([scores.len(), 1]),
+            Tensor::from_ints(labels, &device),
+        )
+    }
+}
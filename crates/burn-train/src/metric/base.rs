@@ -16,6 +16,13 @@ pub struct MetricMetadata {
 
     /// The current learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The current learning rate of each parameter group, with the name of the group.
+    pub lr_groups: Vec<(String, LearningRate)>,
+
+    /// The number of items processed since the last optimizer step, including the current one,
+    /// which is 1 without gradient accumulation.
+    pub accumulated: usize,
 }
 
 impl MetricMetadata {
@@ -30,6 +37,8 @@ impl MetricMetadata {
             epoch_total: 1,
             iteration: 0,
             lr: None,
+            lr_groups: Vec::new(),
+            accumulated: 1,
         }
     }
 }
@@ -0,0 +1,296 @@
+use super::{format_float, MetricEntry};
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Data, Int, Tensor};
+
+/// How the scores of each class are reduced to a single value by the
+/// [precision](super::PrecisionMetric), [recall](super::RecallMetric) and [F1](super::F1Metric)
+/// metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassAverage {
+    /// Compute the score from the counts summed over all classes.
+    Micro,
+    /// Average the score of each class, giving the same weight to every class.
+    Macro,
+    /// Average the score of each class, weighted by the number of items of that class.
+    Weighted,
+    /// Only report the score of the positive class `1`, for binary classification.
+    Binary,
+}
+
+/// The input type of the [precision](super::PrecisionMetric), [recall](super::RecallMetric) and
+/// [F1](super::F1Metric) metrics.
+///
+/// The outputs have one column per class. With a single column, the output is the probability
+/// of the positive class, which is predicted when the output is at least 0.5.
+///
+/// The items with a negative target, e.g. `-1`, are ignored.
+#[derive(new)]
+pub struct ClassificationInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+/// The true positives, false positives and false negatives of each class.
+#[derive(Default)]
+struct ClassificationCounts {
+    true_positives: Vec<usize>,
+    false_positives: Vec<usize>,
+    false_negatives: Vec<usize>,
+}
+
+impl ClassificationCounts {
+    fn from_input<B: Backend>(input: &ClassificationInput<B>) -> Self {
+        let [batch_size, n_classes] = input.outputs.dims();
+
+        let predictions = match n_classes {
+            1 => input.outputs.clone().greater_equal_elem(0.5).int(),
+            _ => input.outputs.clone().argmax(1),
+        };
+        let predictions: Data<i64, 1> = predictions.reshape([batch_size]).into_data().convert();
+        let targets: Data<i64, 1> = input.targets.clone().into_data().convert();
+
+        let num_classes = usize::max(n_classes, 2);
+        let mut counts = Self {
+            true_positives: vec![0; num_classes],
+            false_positives: vec![0; num_classes],
+            false_negatives: vec![0; num_classes],
+        };
+
+        for (prediction, target) in predictions.value.into_iter().zip(targets.value) {
+            if target < 0 {
+                continue;
+            }
+            assert!(
+                (target as usize) < num_classes,
+                "The target {target} is out of range for {num_classes} classes"
+            );
+            let (prediction, target) = (prediction as usize, target as usize);
+
+            if prediction == target {
+                counts.true_positives[target] += 1;
+            } else {
+                counts.false_positives[prediction] += 1;
+                counts.false_negatives[target] += 1;
+            }
+        }
+
+        counts
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if self.true_positives.is_empty() {
+            self.true_positives = vec![0; other.true_positives.len()];
+            self.false_positives = vec![0; other.false_positives.len()];
+            self.false_negatives = vec![0; other.false_negatives.len()];
+        }
+
+        let counts = [
+            (&mut self.true_positives, &other.true_positives),
+            (&mut self.false_positives, &other.false_positives),
+            (&mut self.false_negatives, &other.false_negatives),
+        ];
+        for (counts, other) in counts {
+            counts
+                .iter_mut()
+                .zip(other)
+                .for_each(|(count, other)| *count += other);
+        }
+    }
+
+    fn reduce(&self, score: ClassScore, average: ClassAverage) -> f64 {
+        let num_classes = self.true_positives.len();
+        let class = |i: usize| {
+            (
+                self.true_positives[i],
+                self.false_positives[i],
+                self.false_negatives[i],
+            )
+        };
+
+        match average {
+            ClassAverage::Micro => {
+                let sum = |counts: &[usize]| counts.iter().sum::<usize>();
+                score(
+                    sum(&self.true_positives),
+                    sum(&self.false_positives),
+                    sum(&self.false_negatives),
+                )
+            }
+            ClassAverage::Macro => {
+                (0..num_classes)
+                    .map(|i| {
+                        let (tp, fp, fn_) = class(i);
+                        score(tp, fp, fn_)
+                    })
+                    .sum::<f64>()
+                    / num_classes as f64
+            }
+            ClassAverage::Weighted => {
+                let (scores, support) = (0..num_classes)
+                    .map(|i| {
+                        let (tp, fp, fn_) = class(i);
+                        let support = (tp + fn_) as f64;
+                        (score(tp, fp, fn_) * support, support)
+                    })
+                    .fold((0.0, 0.0), |acc, (score, support)| {
+                        (acc.0 + score, acc.1 + support)
+                    });
+
+                ratio(scores, support)
+            }
+            ClassAverage::Binary => {
+                assert_eq!(
+                    num_classes, 2,
+                    "Binary averaging is only supported with two classes"
+                );
+                let (tp, fp, fn_) = class(1);
+                score(tp, fp, fn_)
+            }
+        }
+    }
+}
+
+/// Compute a score from the true positives, false positives and false negatives of a class.
+pub(crate) type ClassScore = fn(usize, usize, usize) -> f64;
+
+pub(crate) fn precision(tp: usize, fp: usize, _fn_: usize) -> f64 {
+    ratio(tp as f64, (tp + fp) as f64)
+}
+
+pub(crate) fn recall(tp: usize, _fp: usize, fn_: usize) -> f64 {
+    ratio(tp as f64, (tp + fn_) as f64)
+}
+
+pub(crate) fn f1(tp: usize, fp: usize, fn_: usize) -> f64 {
+    ratio(2.0 * tp as f64, (2 * tp + fp + fn_) as f64)
+}
+
+/// Undefined scores, when a class is never predicted or never present, are reported as 0.
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    match denominator > 0.0 {
+        true => numerator / denominator,
+        false => 0.0,
+    }
+}
+
+/// Shared state of the metrics computed from the counts of each class.
+///
+/// The counts are accumulated over the epoch, so the reported value is the score of every item
+/// seen since the metric was last cleared, not the average of the batch scores.
+pub(crate) struct ClassificationMetricState {
+    counts: ClassificationCounts,
+    current: f64,
+}
+
+impl ClassificationMetricState {
+    pub(crate) fn new() -> Self {
+        Self {
+            counts: ClassificationCounts::default(),
+            current: f64::NAN,
+        }
+    }
+
+    pub(crate) fn update<B: Backend>(
+        &mut self,
+        input: &ClassificationInput<B>,
+        name: &str,
+        score: ClassScore,
+        average: ClassAverage,
+    ) -> MetricEntry {
+        let batch = ClassificationCounts::from_input(input);
+        self.counts.merge(&batch);
+
+        let value_batch = 100.0 * batch.reduce(score, average);
+        self.current = 100.0 * self.counts.reduce(score, average);
+
+        let formatted = format!(
+            "epoch {} % - batch {} %",
+            format_float(self.current, 2),
+            format_float(value_batch, 2),
+        );
+
+        MetricEntry::new(name.to_string(), formatted, self.current.to_string())
+    }
+
+    pub(crate) fn value(&self) -> f64 {
+        self.current
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.counts = ClassificationCounts::default();
+        self.current = f64::NAN;
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    /// Predictions on an imbalanced dataset of 3 classes with 6, 2 and 2 items.
+    ///
+    /// Class 0 is always recognized, class 1 only once, and class 2 never, all the errors being
+    /// predicted as class 0.
+    pub(crate) fn imbalanced_input() -> ClassificationInput<TestBackend> {
+        let device = Default::default();
+        let [zero, one, two] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+        ClassificationInput::new(
+            Tensor::from_data(
+                [zero, zero, zero, zero, zero, zero, one, zero, zero, zero],
+                &device,
+            ),
+            Tensor::from_data([0, 0, 0, 0, 0, 0, 1, 1, 2, 2], &device),
+        )
+    }
+
+    /// Binary predictions as probabilities of the positive class.
+    pub(crate) fn binary_input() -> ClassificationInput<TestBackend> {
+        let device = Default::default();
+
+        ClassificationInput::new(
+            Tensor::from_data([[0.9], [0.6], [0.2], [0.7], [0.1], [0.4]], &device),
+            Tensor::from_data([1, 0, 0, 1, 1, 1], &device),
+        )
+    }
+
+    #[test]
+    fn test_counts_accumulate_over_batches() {
+        let mut state = ClassificationMetricState::new();
+
+        state.update(&imbalanced_input(), "F1", f1, ClassAverage::Macro);
+        let value = state.value();
+        state.update(&imbalanced_input(), "F1", f1, ClassAverage::Macro);
+        assert!((state.value() - value).abs() < 1e-9);
+
+        state.reset();
+        assert!(state.value().is_nan());
+    }
+
+    #[test]
+    fn test_negative_targets_are_ignored() {
+        let device = Default::default();
+        let input = ClassificationInput::<TestBackend>::new(
+            Tensor::from_data([[1.0, 0.0], [0.0, 1.0], [1.0, 0.0]], &device),
+            Tensor::from_data([0, -1, 1], &device),
+        );
+
+        let counts = ClassificationCounts::from_input(&input);
+
+        assert_eq!(counts.true_positives, vec![1, 0]);
+        assert_eq!(counts.false_positives, vec![1, 0]);
+        assert_eq!(counts.false_negatives, vec![0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The target 2 is out of range for 2 classes")]
+    fn test_targets_out_of_range_panic() {
+        let device = Default::default();
+        let input = ClassificationInput::<TestBackend>::new(
+            Tensor::from_data([[1.0, 0.0], [0.0, 1.0]], &device),
+            Tensor::from_data([0, 2], &device),
+        );
+
+        ClassificationCounts::from_input(&input);
+    }
+}
@@ -0,0 +1,200 @@
+use super::{MetricEntry, MetricMetadata};
+use crate::metric::Metric;
+use burn_core::tensor::backend::Backend;
+use burn_core::tensor::{Data, Float, Int, Tensor};
+
+/// The confusion matrix metric.
+///
+/// Counts, over an epoch, how many items of each target class (rows) were predicted as each class
+/// (columns).
+pub struct ConfusionMatrixMetric<B: Backend> {
+    num_classes: usize,
+    counts: Option<Tensor<B, 2, Int>>,
+}
+
+/// The [confusion matrix metric](ConfusionMatrixMetric) input type.
+#[derive(new)]
+pub struct ConfusionMatrixInput<B: Backend> {
+    outputs: Tensor<B, 2>,
+    targets: Tensor<B, 1, Int>,
+}
+
+impl<B: Backend> ConfusionMatrixMetric<B> {
+    /// Creates the metric for the given number of classes.
+    pub fn new(num_classes: usize) -> Self {
+        Self {
+            num_classes,
+            counts: None,
+        }
+    }
+
+    /// The accumulated matrix of shape `[num_classes, num_classes]`, where the entry `[i, j]` is
+    /// the number of items of class `i` predicted as class `j`.
+    pub fn matrix(&self) -> Tensor<B, 2, Int> {
+        match &self.counts {
+            Some(counts) => counts.clone(),
+            None => Tensor::zeros([self.num_classes, self.num_classes], &B::Device::default()),
+        }
+    }
+
+    /// Render the accumulated matrix as a table, with one row per target class.
+    pub fn render(&self) -> String {
+        let counts = self.counts();
+        let width = counts
+            .iter()
+            .flatten()
+            .map(|count| count.to_string().len())
+            .chain([self.num_classes.to_string().len()])
+            .max()
+            .unwrap_or(1);
+
+        let mut text = format!("{:>width$} |", "");
+        for class in 0..self.num_classes {
+            text += &format!(" {class:>width$}");
+        }
+
+        for (class, row) in counts.iter().enumerate() {
+            text += &format!("\n{class:>width$} |");
+            for count in row {
+                text += &format!(" {count:>width$}");
+            }
+        }
+
+        text
+    }
+
+    fn counts(&self) -> Vec<Vec<i64>> {
+        let data: Data<i64, 2> = self.matrix().into_data().convert();
+
+        data.value
+            .chunks(self.num_classes)
+            .map(|row| row.to_vec())
+            .collect()
+    }
+}
+
+impl<B: Backend> Metric for ConfusionMatrixMetric<B> {
+    const NAME: &'static str = "Confusion Matrix";
+
+    type Input = ConfusionMatrixInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ConfusionMatrixInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        let [batch_size, n_classes] = input.outputs.dims();
+        assert_eq!(
+            n_classes, self.num_classes,
+            "The outputs should have one column per class"
+        );
+
+        let targets = input
+            .targets
+            .clone()
+            .to_device(&B::Device::default())
+            .one_hot::<2, Float>(self.num_classes);
+        let outputs = input
+            .outputs
+            .clone()
+            .argmax(1)
+            .to_device(&B::Device::default())
+            .reshape([batch_size])
+            .one_hot::<2, Float>(self.num_classes);
+
+        // [num_classes, batch_size] x [batch_size, num_classes]
+        let counts = targets.transpose().matmul(outputs).int();
+        let counts = match self.counts.take() {
+            Some(previous) => previous.add(counts),
+            None => counts,
+        };
+        self.counts = Some(counts);
+
+        let counts = self.counts();
+        let rows = |separator: &str| {
+            counts
+                .iter()
+                .map(|row| {
+                    let row = row.iter().map(|count| count.to_string());
+                    row.collect::<Vec<_>>().join(separator)
+                })
+                .collect::<Vec<_>>()
+        };
+        let formatted = rows(", ")
+            .iter()
+            .map(|row| format!("[{row}]"))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        MetricEntry::new(
+            Self::NAME.to_string(),
+            format!("[{formatted}]"),
+            rows(",").join(";"),
+        )
+    }
+
+    fn clear(&mut self) {
+        self.counts = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::tensor::ElementConversion;
+
+    #[test]
+    fn test_confusion_matrix() {
+        let device = Default::default();
+        let mut metric = ConfusionMatrixMetric::<TestBackend>::new(3);
+        let input = ConfusionMatrixInput::new(
+            Tensor::from_data(
+                [
+                    [0.0, 0.2, 0.8], // 2
+                    [1.0, 2.0, 0.5], // 1
+                    [0.4, 0.1, 0.2], // 0
+                    [0.6, 0.7, 0.2], // 1
+                ],
+                &device,
+            ),
+            Tensor::from_data([2, 2, 1, 1], &device),
+        );
+
+        let entry = metric.update(&input, &MetricMetadata::fake());
+        assert_eq!(entry.formatted, "[[0, 0, 0], [1, 1, 0], [0, 1, 1]]");
+        assert_eq!(entry.serialize, "0,0,0;1,1,0;0,1,1");
+
+        // The counts accumulate until the metric is cleared.
+        let input = ConfusionMatrixInput::new(
+            Tensor::from_data([[0.9, 0.0, 0.1], [0.0, 0.0, 1.0]], &device),
+            Tensor::from_data([0, 2], &device),
+        );
+        metric.update(&input, &MetricMetadata::fake());
+
+        let expected = [[1, 0, 0], [1, 1, 0], [0, 1, 2]];
+        for (row, counts) in expected.iter().enumerate() {
+            for (col, count) in counts.iter().enumerate() {
+                assert_eq!(count(&metric, row, col), *count, "Entry [{row}, {col}]");
+            }
+        }
+        assert_eq!(
+            metric.render(),
+            "  | 0 1 2\n0 | 1 0 0\n1 | 1 1 0\n2 | 0 1 2"
+        );
+
+        metric.clear();
+        assert_eq!(
+            metric.render(),
+            "  | 0 1 2\n0 | 0 0 0\n1 | 0 0 0\n2 | 0 0 0"
+        );
+    }
+
+    fn count(metric: &ConfusionMatrixMetric<TestBackend>, row: usize, col: usize) -> i64 {
+        metric
+            .matrix()
+            .slice([row..row + 1, col..col + 1])
+            .into_scalar()
+            .elem()
+    }
+}
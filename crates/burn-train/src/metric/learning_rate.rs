@@ -1,12 +1,18 @@
 use super::{
+    format_float,
     state::{FormatOptions, NumericMetricState},
     MetricMetadata, Numeric,
 };
 use crate::metric::{Metric, MetricEntry};
 
 /// Track the learning rate across iterations.
+///
+/// When the optimizer has [parameter groups](burn_core::optim::ParamGroups), the learning rate of
+/// each group is displayed after the base learning rate. Register a [group](Self::group) metric
+/// for each group to also log their learning rates.
 pub struct LearningRateMetric {
     state: NumericMetricState,
+    group: Option<String>,
 }
 
 impl LearningRateMetric {
@@ -14,6 +20,18 @@ impl LearningRateMetric {
     pub fn new() -> Self {
         Self {
             state: NumericMetricState::new(),
+            group: None,
+        }
+    }
+
+    /// Creates a new metric tracking the learning rate of the given parameter group, named
+    /// `Learning Rate - <group>`.
+    ///
+    /// The base learning rate is tracked when the optimizer doesn't have the group.
+    pub fn group(name: &str) -> Self {
+        Self {
+            state: NumericMetricState::new(),
+            group: Some(name.to_string()),
         }
     }
 }
@@ -32,8 +50,29 @@ impl Metric for LearningRateMetric {
     fn update(&mut self, _item: &(), metadata: &MetricMetadata) -> MetricEntry {
         let lr = metadata.lr.unwrap_or(0.0);
 
-        self.state
-            .update(lr, 1, FormatOptions::new("Learning Rate").precision(2))
+        if let Some(group) = &self.group {
+            let lr = metadata
+                .lr_groups
+                .iter()
+                .find(|(name, _)| name == group)
+                .map(|(_, lr)| *lr)
+                .unwrap_or(lr);
+            let name = format!("{} - {group}", Self::NAME);
+
+            return self
+                .state
+                .update(lr, 1, FormatOptions::new(&name).precision(2));
+        }
+
+        let mut entry = self
+            .state
+            .update(lr, 1, FormatOptions::new(Self::NAME).precision(2));
+
+        for (name, lr) in metadata.lr_groups.iter() {
+            entry.formatted += &format!(" - {name} {}", format_float(*lr, 2));
+        }
+
+        entry
     }
 
     fn clear(&mut self) {
@@ -46,3 +85,57 @@ impl Numeric for LearningRateMetric {
         self.state.value()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+    use burn_core::lr_scheduler::{exponential::ExponentialLrSchedulerConfig, LrScheduler};
+
+    #[test]
+    fn test_learning_rate_follows_scheduler() {
+        let mut scheduler = ExponentialLrSchedulerConfig::new(0.1, 0.5).init();
+        let mut metric = LearningRateMetric::new();
+
+        // The scheduler decays the learning rate before returning it.
+        for expected in [0.05, 0.025, 0.0125, 0.00625] {
+            let mut metadata = MetricMetadata::fake();
+            metadata.lr = Some(LrScheduler::<TestBackend>::step(&mut scheduler));
+
+            let entry = metric.update(&(), &metadata);
+            assert!((metric.value() - expected).abs() < 1e-12);
+            assert_eq!(entry.serialize, metric.value().to_string());
+        }
+    }
+
+    #[test]
+    fn test_learning_rate_of_each_group() {
+        let mut metric = LearningRateMetric::new();
+        let mut metadata = MetricMetadata::fake();
+        metadata.lr = Some(0.5);
+        metadata.lr_groups = vec![("backbone".to_string(), 0.05), ("head".to_string(), 0.5)];
+
+        let entry = metric.update(&(), &metadata);
+
+        assert_eq!(metric.value(), 0.5);
+        assert!(entry.formatted.ends_with(" - backbone 5.00e-2 - head 0.50"));
+    }
+
+    #[test]
+    fn test_learning_rate_of_a_group_is_logged() {
+        let mut metric = LearningRateMetric::group("backbone");
+        let mut metadata = MetricMetadata::fake();
+        metadata.lr = Some(0.5);
+        metadata.lr_groups = vec![("backbone".to_string(), 0.05), ("head".to_string(), 0.5)];
+
+        let entry = metric.update(&(), &metadata);
+
+        assert_eq!(entry.name, "Learning Rate - backbone");
+        assert_eq!(entry.serialize, 0.05.to_string());
+
+        // Without parameter groups, every parameter uses the base learning rate.
+        metadata.lr_groups.clear();
+        metric.update(&(), &metadata);
+        assert_eq!(metric.value(), 0.5);
+    }
+}
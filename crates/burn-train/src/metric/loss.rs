@@ -8,9 +8,13 @@ use burn_core::tensor::ElementConversion;
 use burn_core::tensor::Tensor;
 
 /// The loss metric.
+///
+/// With [gradient accumulation](crate::learner::LearnerBuilder::grads_accumulation), the current
+/// loss is the average over the items of the accumulation window.
 #[derive(Default)]
 pub struct LossMetric<B: Backend> {
     state: NumericMetricState,
+    window: Vec<f64>,
     _b: B,
 }
 
@@ -32,15 +36,26 @@ impl<B: Backend> Metric for LossMetric<B> {
 
     type Input = LossInput<B>;
 
-    fn update(&mut self, loss: &Self::Input, _metadata: &MetricMetadata) -> MetricEntry {
+    fn update(&mut self, loss: &Self::Input, metadata: &MetricMetadata) -> MetricEntry {
         let loss = f64::from_elem(loss.tensor.clone().mean().into_data().value[0]);
 
-        self.state
-            .update(loss, 1, FormatOptions::new(Self::NAME).precision(2))
+        if metadata.accumulated <= 1 {
+            self.window.clear();
+        }
+        self.window.push(loss);
+        let loss_window = self.window.iter().sum::<f64>() / self.window.len() as f64;
+
+        self.state.update_with_current(
+            loss,
+            loss_window,
+            1,
+            FormatOptions::new(Self::NAME).precision(2),
+        )
     }
 
     fn clear(&mut self) {
-        self.state.reset()
+        self.state.reset();
+        self.window.clear();
     }
 }
 
@@ -49,3 +64,26 @@ impl<B: Backend> Numeric for LossMetric<B> {
         self.state.value()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TestBackend;
+
+    #[test]
+    fn test_loss_is_averaged_over_the_accumulation_window() {
+        let device = Default::default();
+        let mut metric = LossMetric::<TestBackend>::new();
+        let mut metadata = MetricMetadata::fake();
+        let mut update = |loss: f32, accumulated: usize| {
+            metadata.accumulated = accumulated;
+            let input = LossInput::new(Tensor::from_floats([loss], &device));
+            metric.update(&input, &metadata);
+            metric.value()
+        };
+
+        assert_eq!(update(1.0, 1), 1.0);
+        assert_eq!(update(3.0, 2), 2.0);
+        assert_eq!(update(5.0, 1), 5.0);
+    }
+}
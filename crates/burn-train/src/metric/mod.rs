@@ -2,30 +2,42 @@
 pub mod state;
 
 mod acc;
+mod auroc;
 mod base;
+mod classification;
+mod confusion_matrix;
 #[cfg(feature = "metrics")]
 mod cpu_temp;
 #[cfg(feature = "metrics")]
 mod cpu_use;
 #[cfg(feature = "metrics")]
 mod cuda;
+mod f1;
 mod learning_rate;
 mod loss;
 #[cfg(feature = "metrics")]
 mod memory_use;
+mod precision;
+mod recall;
 
 pub use acc::*;
+pub use auroc::*;
 pub use base::*;
+pub use classification::{ClassAverage, ClassificationInput};
+pub use confusion_matrix::*;
 #[cfg(feature = "metrics")]
 pub use cpu_temp::*;
 #[cfg(feature = "metrics")]
 pub use cpu_use::*;
 #[cfg(feature = "metrics")]
 pub use cuda::*;
+pub use f1::*;
 pub use learning_rate::*;
 pub use loss::*;
 #[cfg(feature = "metrics")]
 pub use memory_use::*;
+pub use precision::*;
+pub use recall::*;
 
 pub(crate) mod processor;
 /// Module responsible to save and exposes data collected during training.
@@ -0,0 +1,96 @@
+use super::classification::{precision, ClassificationMetricState};
+use super::{ClassAverage, ClassificationInput, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// The precision metric, the fraction of the predictions of a class that are correct.
+///
+/// The counts of each class are accumulated over the epoch and reduced with the configured
+/// [averaging](ClassAverage), [macro](ClassAverage::Macro) by default.
+pub struct PrecisionMetric<B: Backend> {
+    state: ClassificationMetricState,
+    average: ClassAverage,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> PrecisionMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the scores of each class are averaged.
+    pub fn with_average(mut self, average: ClassAverage) -> Self {
+        self.average = average;
+        self
+    }
+}
+
+impl<B: Backend> Default for PrecisionMetric<B> {
+    fn default() -> Self {
+        Self {
+            state: ClassificationMetricState::new(),
+            average: ClassAverage::Macro,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for PrecisionMetric<B> {
+    const NAME: &'static str = "Precision";
+
+    type Input = ClassificationInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        self.state
+            .update(input, Self::NAME, precision, self.average)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for PrecisionMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::classification::tests::{binary_input, imbalanced_input};
+    use crate::TestBackend;
+
+    #[test]
+    fn test_precision_averages_on_imbalanced_classes() {
+        let cases = [
+            (ClassAverage::Micro, 70.0),
+            (ClassAverage::Macro, 55.555556),
+            (ClassAverage::Weighted, 60.0),
+        ];
+
+        for (average, expected) in cases {
+            let mut metric = PrecisionMetric::<TestBackend>::new().with_average(average);
+            let _entry = metric.update(&imbalanced_input(), &MetricMetadata::fake());
+            assert!(
+                (metric.value() - expected).abs() < 1e-4,
+                "{average:?}: {} != {expected}",
+                metric.value()
+            );
+        }
+    }
+
+    #[test]
+    fn test_precision_binary() {
+        let mut metric = PrecisionMetric::<TestBackend>::new().with_average(ClassAverage::Binary);
+        let _entry = metric.update(&binary_input(), &MetricMetadata::fake());
+        assert!((metric.value() - 66.666667).abs() < 1e-4);
+    }
+}
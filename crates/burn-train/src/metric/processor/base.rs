@@ -42,4 +42,12 @@ pub struct LearnerItem<T> {
 
     /// The learning rate.
     pub lr: Option<LearningRate>,
+
+    /// The name and the learning rate of each parameter group of the optimizer.
+    pub lr_groups: Vec<(String, LearningRate)>,
+
+    /// The position of the item in the current [gradient accumulation](crate::learner::LearnerBuilder::grads_accumulation)
+    /// window, starting at 1.
+    #[new(value = "1")]
+    pub accumulated: usize,
 }
@@ -82,7 +82,7 @@ impl<T, V> Metrics<T, V> {
             entries_numeric.push((state, value));
         }
 
-        MetricsUpdate::new(entries, entries_numeric)
+        MetricsUpdate::new(entries, entries_numeric, metadata.iteration)
     }
 
     /// Update the training information from the validation item.
@@ -104,7 +104,7 @@ impl<T, V> Metrics<T, V> {
             entries_numeric.push((state, value));
         }
 
-        MetricsUpdate::new(entries, entries_numeric)
+        MetricsUpdate::new(entries, entries_numeric, metadata.iteration)
     }
 
     /// Signal the end of a training epoch.
@@ -147,6 +147,8 @@ impl<T> From<&LearnerItem<T>> for MetricMetadata {
             epoch_total: item.epoch_total,
             iteration: item.iteration,
             lr: item.lr,
+            lr_groups: item.lr_groups.clone(),
+            accumulated: item.accumulated,
         }
     }
 }
@@ -44,6 +44,7 @@ pub(crate) mod test_utils {
             num_epochs,
             dummy_iteration,
             None,
+            Vec::new(),
         )));
     }
 
@@ -0,0 +1,95 @@
+use super::classification::{recall, ClassificationMetricState};
+use super::{ClassAverage, ClassificationInput, MetricEntry, MetricMetadata};
+use crate::metric::{Metric, Numeric};
+use burn_core::tensor::backend::Backend;
+use core::marker::PhantomData;
+
+/// The recall metric, the fraction of the items of a class that are predicted as such.
+///
+/// The counts of each class are accumulated over the epoch and reduced with the configured
+/// [averaging](ClassAverage), [macro](ClassAverage::Macro) by default.
+pub struct RecallMetric<B: Backend> {
+    state: ClassificationMetricState,
+    average: ClassAverage,
+    _b: PhantomData<B>,
+}
+
+impl<B: Backend> RecallMetric<B> {
+    /// Creates the metric.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how the scores of each class are averaged.
+    pub fn with_average(mut self, average: ClassAverage) -> Self {
+        self.average = average;
+        self
+    }
+}
+
+impl<B: Backend> Default for RecallMetric<B> {
+    fn default() -> Self {
+        Self {
+            state: ClassificationMetricState::new(),
+            average: ClassAverage::Macro,
+            _b: PhantomData,
+        }
+    }
+}
+
+impl<B: Backend> Metric for RecallMetric<B> {
+    const NAME: &'static str = "Recall";
+
+    type Input = ClassificationInput<B>;
+
+    fn update(
+        &mut self,
+        input: &ClassificationInput<B>,
+        _metadata: &MetricMetadata,
+    ) -> MetricEntry {
+        self.state.update(input, Self::NAME, recall, self.average)
+    }
+
+    fn clear(&mut self) {
+        self.state.reset()
+    }
+}
+
+impl<B: Backend> Numeric for RecallMetric<B> {
+    fn value(&self) -> f64 {
+        self.state.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::classification::tests::{binary_input, imbalanced_input};
+    use crate::TestBackend;
+
+    #[test]
+    fn test_recall_averages_on_imbalanced_classes() {
+        let cases = [
+            (ClassAverage::Micro, 70.0),
+            (ClassAverage::Macro, 50.0),
+            (ClassAverage::Weighted, 70.0),
+        ];
+
+        for (average, expected) in cases {
+            let mut metric = RecallMetric::<TestBackend>::new().with_average(average);
+            let _entry = metric.update(&imbalanced_input(), &MetricMetadata::fake());
+            assert!(
+                (metric.value() - expected).abs() < 1e-4,
+                "{average:?}: {} != {expected}",
+                metric.value()
+            );
+        }
+    }
+
+    #[test]
+    fn test_recall_binary() {
+        let mut metric = RecallMetric::<TestBackend>::new().with_average(ClassAverage::Binary);
+        let _entry = metric.update(&binary_input(), &MetricMetadata::fake());
+        assert!((metric.value() - 50.0).abs() < 1e-4);
+    }
+}
@@ -61,11 +61,22 @@ impl NumericMetricState {
 
     /// Update the state.
     pub fn update(&mut self, value: f64, batch_size: usize, format: FormatOptions) -> MetricEntry {
+        self.update_with_current(value, value, batch_size, format)
+    }
+
+    /// Update the state with a value, but report another one as the current value, e.g. the
+    /// average over the last batches.
+    pub fn update_with_current(
+        &mut self,
+        value: f64,
+        value_current: f64,
+        batch_size: usize,
+        format: FormatOptions,
+    ) -> MetricEntry {
         self.sum += value * batch_size as f64;
         self.count += batch_size;
-        self.current = value;
+        self.current = value_current;
 
-        let value_current = value;
         let value_running = self.sum / self.count as f64;
         let serialized = value_current.to_string();
 
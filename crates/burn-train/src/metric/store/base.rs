@@ -15,6 +15,8 @@ pub struct MetricsUpdate {
     pub entries: Vec<MetricEntry>,
     /// Metrics information related to numeric metrics.
     pub entries_numeric: Vec<(MetricEntry, f64)>,
+    /// The iteration of the current epoch the metrics were updated at.
+    pub iteration: usize,
 }
 
 /// Defines how training and validation events are collected and searched.
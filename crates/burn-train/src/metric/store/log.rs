@@ -21,7 +21,7 @@ impl EventStore for LogEventStore {
                         .for_each(|entry| {
                             self.loggers_train
                                 .iter_mut()
-                                .for_each(|logger| logger.log(entry));
+                                .for_each(|logger| logger.log_iteration(entry, update.iteration));
                         });
                 }
                 Split::Valid => {
@@ -32,7 +32,7 @@ impl EventStore for LogEventStore {
                         .for_each(|entry| {
                             self.loggers_valid
                                 .iter_mut()
-                                .for_each(|logger| logger.log(entry));
+                                .for_each(|logger| logger.log_iteration(entry, update.iteration));
                         });
                 }
             },
@@ -0,0 +1,362 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The version of the format of the cached entries, bumped when it changes.
+const FORMAT_VERSION: u32 = 2;
+
+/// The number of words of a digest.
+const DIGEST_WORDS: usize = 4;
+
+/// The magic number starting every SPIR-V module.
+const SPIRV_MAGIC_NUMBER: u32 = 0x0723_0203;
+
+/// Compiled kernels cached on disk, so that a new process reuses the kernels compiled by a
+/// previous one instead of compiling them again.
+///
+/// # Notes
+///
+/// The version of [wgpu] used doesn't expose its pipeline cache, so the kernels are compiled to
+/// SPIR-V with [naga] and the cached modules are given to the device as is, skipping the parsing,
+/// the validation and the translation of the WGSL sources. This requires the
+/// [SPIRV_SHADER_PASSTHROUGH](wgpu::Features::SPIRV_SHADER_PASSTHROUGH) feature, so the cache is
+/// only used with the Vulkan backend.
+///
+/// Entries are identified by the digest of the kernel source and stored in a sub-directory
+/// specific to the adapter and the driver version, so entries created on a different GPU or with a
+/// different driver are never reused. Each entry also stores the digest of its source and of its
+/// content, so an entry that was corrupted, truncated or written for another source is discarded
+/// rather than given to the device.
+#[derive(Debug)]
+pub struct PipelineCache {
+    directory: PathBuf,
+}
+
+/// A kernel compiled to SPIR-V, with the layout of its bindings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledKernel {
+    /// The bindings of the first bind group, sorted by binding index.
+    pub bindings: Vec<(u32, KernelBinding)>,
+    /// The SPIR-V module.
+    pub spirv: Vec<u32>,
+}
+
+/// The type of a binding of a [compiled kernel](CompiledKernel).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KernelBinding {
+    /// A storage buffer that is only read.
+    StorageReadOnly,
+    /// A storage buffer that is read and written.
+    Storage,
+    /// A uniform buffer.
+    Uniform,
+}
+
+impl PipelineCache {
+    /// Create a new cache in the given directory for the given adapter.
+    pub fn new<P: AsRef<Path>>(directory: P, info: &wgpu::AdapterInfo) -> Self {
+        // Another version may use a different compiler, generating different SPIR-V.
+        let key = format!(
+            "{}\n{}\n{}\n{}\n{}\n{}\n{}",
+            info.name,
+            info.vendor,
+            info.device,
+            info.driver,
+            info.driver_info,
+            info.backend.to_str(),
+            env!("CARGO_PKG_VERSION"),
+        );
+
+        let directory = directory
+            .as_ref()
+            .join(format!("{:x}", md5::compute(key.as_bytes())));
+
+        if let Err(err) = fs::create_dir_all(&directory) {
+            log::warn!("Unable to create the pipeline cache directory {directory:?}: {err}");
+        }
+
+        Self { directory }
+    }
+
+    /// Load the compiled kernel of the given source if it is cached.
+    pub fn load(&self, source: &str) -> Option<CompiledKernel> {
+        let bytes = fs::read(self.path(source)).ok()?;
+
+        if bytes.len() % 4 != 0 {
+            return None;
+        }
+
+        let words = bytes
+            .chunks_exact(4)
+            .map(|word| u32::from_le_bytes([word[0], word[1], word[2], word[3]]))
+            .collect::<Vec<_>>();
+
+        CompiledKernel::decode(&words, source)
+    }
+
+    /// Save the compiled kernel of the given source.
+    pub fn save(&self, source: &str, kernel: &CompiledKernel) {
+        let path = self.path(source);
+        let bytes = words_to_bytes(&kernel.encode(source));
+
+        // The entry is renamed once written, so a process never loads a partially written entry.
+        let path_tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        let result = fs::write(&path_tmp, bytes).and_then(|_| fs::rename(&path_tmp, &path));
+
+        if let Err(err) = result {
+            log::warn!("Unable to save the compiled kernel to {path:?}: {err}");
+            fs::remove_file(&path_tmp).ok();
+        }
+    }
+
+    fn path(&self, source: &str) -> PathBuf {
+        self.directory
+            .join(format!("{:x}.spv", md5::compute(source.as_bytes())))
+    }
+}
+
+impl CompiledKernel {
+    /// Compile the given WGSL source, returning `None` if it can't be compiled or if it uses
+    /// bindings other than buffers in the first bind group.
+    pub fn compile(source: &str) -> Option<Self> {
+        use naga::{
+            back::spv,
+            proc::{BoundsCheckPolicies, BoundsCheckPolicy},
+            valid::{Capabilities, ValidationFlags, Validator},
+            AddressSpace, StorageAccess,
+        };
+
+        let module = naga::front::wgsl::parse_str(source)
+            .map_err(|err| log::warn!("Unable to parse the kernel source: {err}"))
+            .ok()?;
+        let info = Validator::new(ValidationFlags::all(), Capabilities::all())
+            .validate(&module)
+            .map_err(|err| log::warn!("Unable to validate the kernel source: {err}"))
+            .ok()?;
+
+        let mut bindings = Vec::new();
+
+        for (_, variable) in module.global_variables.iter() {
+            let binding = match &variable.binding {
+                Some(binding) => binding,
+                None => continue,
+            };
+
+            let kind = match variable.space {
+                AddressSpace::Storage { access } if access.contains(StorageAccess::STORE) => {
+                    KernelBinding::Storage
+                }
+                AddressSpace::Storage { .. } => KernelBinding::StorageReadOnly,
+                AddressSpace::Uniform => KernelBinding::Uniform,
+                _ => return None,
+            };
+
+            if binding.group != 0 {
+                return None;
+            }
+
+            bindings.push((binding.binding, kind));
+        }
+
+        bindings.sort_by_key(|(binding, _)| *binding);
+
+        // The module is given to the device as is, so the accesses are bounds checked the same way
+        // wgpu does when it compiles the source itself.
+        let options = spv::Options {
+            bounds_check_policies: BoundsCheckPolicies {
+                index: BoundsCheckPolicy::Restrict,
+                buffer: BoundsCheckPolicy::Restrict,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let spirv = spv::write_vec(&module, &info, &options, None)
+            .map_err(|err| log::warn!("Unable to compile the kernel source to SPIR-V: {err}"))
+            .ok()?;
+
+        Some(Self { bindings, spirv })
+    }
+
+    /// Encode the kernel compiled from the given source, as the format version, the digest of
+    /// the source, the digest of the content, then the content: the bindings and the SPIR-V.
+    fn encode(&self, source: &str) -> Vec<u32> {
+        let mut content = Vec::with_capacity(1 + self.bindings.len() * 2 + self.spirv.len());
+
+        content.push(self.bindings.len() as u32);
+
+        for (binding, kind) in self.bindings.iter() {
+            content.push(*binding);
+            content.push(match kind {
+                KernelBinding::StorageReadOnly => 0,
+                KernelBinding::Storage => 1,
+                KernelBinding::Uniform => 2,
+            });
+        }
+
+        content.extend_from_slice(&self.spirv);
+
+        let mut words = Vec::with_capacity(1 + 2 * DIGEST_WORDS + content.len());
+        words.push(FORMAT_VERSION);
+        words.extend(digest(source.as_bytes()));
+        words.extend(digest(&words_to_bytes(&content)));
+        words.extend(content);
+        words
+    }
+
+    /// Decode a kernel encoded for the given source, returning `None` if the entry doesn't match.
+    fn decode(words: &[u32], source: &str) -> Option<Self> {
+        let header = 1 + 2 * DIGEST_WORDS;
+
+        if words.len() < header + 1 || words[0] != FORMAT_VERSION {
+            return None;
+        }
+
+        let content = &words[header..];
+
+        if words[1..1 + DIGEST_WORDS] != digest(source.as_bytes())
+            || words[1 + DIGEST_WORDS..header] != digest(&words_to_bytes(content))
+        {
+            return None;
+        }
+
+        let spirv_start = 1 + content[0] as usize * 2;
+        let bindings = content.get(1..spirv_start)?;
+        let spirv = &content[spirv_start..];
+        let bindings = bindings
+            .chunks_exact(2)
+            .map(|binding| {
+                let kind = match binding[1] {
+                    0 => KernelBinding::StorageReadOnly,
+                    1 => KernelBinding::Storage,
+                    2 => KernelBinding::Uniform,
+                    _ => return None,
+                };
+
+                Some((binding[0], kind))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        match spirv.first() == Some(&SPIRV_MAGIC_NUMBER) {
+            true => Some(Self {
+                bindings,
+                spirv: spirv.to_vec(),
+            }),
+            false => None,
+        }
+    }
+}
+
+/// The MD5 digest of the bytes, which is stable across platforms and compiler versions.
+fn digest(bytes: &[u8]) -> [u32; DIGEST_WORDS] {
+    let digest = md5::compute(bytes);
+    let mut words = [0; DIGEST_WORDS];
+
+    for (word, bytes) in words.iter_mut().zip(digest.0.chunks_exact(4)) {
+        *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+
+    words
+}
+
+fn words_to_bytes(words: &[u32]) -> Vec<u8> {
+    words.iter().copied().flat_map(u32::to_le_bytes).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "
+@group(0)
+@binding(0)
+var<storage, read> input: array<f32>;
+
+@group(0)
+@binding(1)
+var<storage, read_write> output: array<f32>;
+
+@compute
+@workgroup_size(32, 1, 1)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    output[id.x] = input[id.x] * 2.0;
+}
+";
+
+    #[test]
+    fn kernel_should_compile_to_spirv() {
+        let kernel = CompiledKernel::compile(SOURCE).unwrap();
+
+        assert_eq!(
+            kernel.bindings,
+            vec![
+                (0, KernelBinding::StorageReadOnly),
+                (1, KernelBinding::Storage)
+            ]
+        );
+        assert_eq!(kernel.spirv[0], SPIRV_MAGIC_NUMBER);
+    }
+
+    #[test]
+    fn saved_kernel_can_be_loaded() {
+        let directory = std::env::temp_dir().join("burn-wgpu-pipeline-cache-test");
+        let cache = PipelineCache::new(&directory, &adapter_info("adapter"));
+        let kernel = CompiledKernel::compile(SOURCE).unwrap();
+
+        cache.save(SOURCE, &kernel);
+
+        assert_eq!(cache.load(SOURCE), Some(kernel));
+        assert_eq!(cache.load("unknown"), None);
+    }
+
+    #[test]
+    fn entries_are_not_shared_between_adapters() {
+        let directory = std::env::temp_dir().join("burn-wgpu-pipeline-cache-test");
+        let cache_a = PipelineCache::new(&directory, &adapter_info("adapter a"));
+        let cache_b = PipelineCache::new(&directory, &adapter_info("adapter b"));
+
+        cache_a.save(SOURCE, &CompiledKernel::compile(SOURCE).unwrap());
+
+        assert_eq!(cache_b.load(SOURCE), None);
+    }
+
+    #[test]
+    fn corrupted_entries_are_not_loaded() {
+        let kernel = CompiledKernel::compile(SOURCE).unwrap();
+        let words = kernel.encode(SOURCE);
+        assert_eq!(CompiledKernel::decode(&words, SOURCE), Some(kernel.clone()));
+
+        // Any modified word of the SPIR-V is detected.
+        let mut words_corrupted = words.clone();
+        let last = words_corrupted.len() - 1;
+        words_corrupted[last] ^= 1;
+        assert_eq!(CompiledKernel::decode(&words_corrupted, SOURCE), None);
+
+        // As well as a truncated entry.
+        assert_eq!(
+            CompiledKernel::decode(&words[..words.len() - 1], SOURCE),
+            None
+        );
+        assert_eq!(CompiledKernel::decode(&words[..1], SOURCE), None);
+    }
+
+    #[test]
+    fn entries_of_another_source_are_not_loaded() {
+        let kernel = CompiledKernel::compile(SOURCE).unwrap();
+        let words = kernel.encode(SOURCE);
+
+        assert_eq!(CompiledKernel::decode(&words, "another source"), None);
+    }
+
+    fn adapter_info(name: &str) -> wgpu::AdapterInfo {
+        wgpu::AdapterInfo {
+            name: name.to_string(),
+            vendor: 0,
+            device: 0,
+            device_type: wgpu::DeviceType::Other,
+            driver: "driver".to_string(),
+            driver_info: "1.0".to_string(),
+            backend: wgpu::Backend::Empty,
+        }
+    }
+}
@@ -1,5 +1,9 @@
+#[cfg(not(target_family = "wasm"))]
+mod cache;
 mod server;
 mod storage;
 
+#[cfg(not(target_family = "wasm"))]
+pub use cache::*;
 pub use server::*;
 pub use storage::*;
@@ -1,12 +1,16 @@
 use super::WgpuStorage;
 use alloc::{borrow::Cow, sync::Arc};
 use burn_compute::{
-    memory_management::MemoryManagement,
-    server::{self, ComputeServer},
+    memory_management::{MemoryManagement, MemoryStats},
+    server::{self, ComputeServer, TimedRead, TimingMethod},
 };
 use burn_jit::compute::{JitAutotuneKey, Kernel, WorkGroup};
 use burn_tensor::Reader;
 use hashbrown::HashMap;
+#[cfg(not(target_family = "wasm"))]
+use std::time;
+#[cfg(target_family = "wasm")]
+use web_time as time;
 use wgpu::{
     util::{BufferInitDescriptor, DeviceExt},
     BindGroup, CommandEncoder, ComputePipeline, ShaderModuleDescriptor,
@@ -24,6 +28,8 @@ pub struct WgpuServer<MM: MemoryManagement<WgpuStorage>> {
     max_tasks: usize,
     manual_available: HashMap<usize, Vec<server::Handle<Self>>>,
     manual_taken: Vec<(usize, server::Handle<Self>)>,
+    #[cfg(not(target_family = "wasm"))]
+    pipeline_cache: Option<super::PipelineCache>,
 }
 
 #[derive(new, Debug)]
@@ -38,11 +44,16 @@ where
     MM: MemoryManagement<WgpuStorage>,
 {
     /// Create a new server.
+    ///
+    /// When a [pipeline cache](super::PipelineCache) is provided, the compiled kernels are loaded
+    /// from it and saved into it. It requires the
+    /// [SPIRV_SHADER_PASSTHROUGH](wgpu::Features::SPIRV_SHADER_PASSTHROUGH) feature on the device.
     pub fn new(
         memory_management: MM,
         device: Arc<wgpu::Device>,
         queue: wgpu::Queue,
         max_tasks: usize,
+        #[cfg(not(target_family = "wasm"))] pipeline_cache: Option<super::PipelineCache>,
     ) -> Self {
         let encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
             label: Some("Command Encoder"),
@@ -58,6 +69,8 @@ where
             max_tasks,
             manual_available: HashMap::new(),
             manual_taken: Vec::new(),
+            #[cfg(not(target_family = "wasm"))]
+            pipeline_cache,
         }
     }
 
@@ -117,7 +130,16 @@ where
     }
 
     fn register_tasks(&mut self) {
-        if self.tasks.is_empty() {
+        self.register_tasks_with(None)
+    }
+
+    /// Register the tasks in a compute pass, optionally writing the timestamps at the beginning
+    /// and the end of the pass.
+    ///
+    /// The compute pass is always created when timestamps are requested, even without any task,
+    /// so that the queries are always written.
+    fn register_tasks_with(&mut self, timestamp: Option<&TimestampQuery>) {
+        if self.tasks.is_empty() && timestamp.is_none() {
             return;
         }
 
@@ -125,7 +147,7 @@ where
             .encoder
             .begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: None,
-                timestamp_writes: None,
+                timestamp_writes: timestamp.map(|timestamp| timestamp.writes()),
             });
 
         for task in self.tasks.iter() {
@@ -152,6 +174,11 @@ where
     }
 
     fn compile_source(&self, source: &str) -> Arc<ComputePipeline> {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(pipeline) = self.compile_source_cached(source) {
+            return pipeline;
+        }
+
         let module = self.device.create_shader_module(ShaderModuleDescriptor {
             label: None,
             source: wgpu::ShaderSource::Wgsl(Cow::Borrowed(source)),
@@ -168,6 +195,84 @@ where
         )
     }
 
+    /// Create the pipeline from the kernel compiled to SPIR-V, loaded from the
+    /// [pipeline cache](super::PipelineCache) or saved into it.
+    ///
+    /// Returns `None` when no cache is used or when the source can't be compiled to SPIR-V, in
+    /// which case wgpu compiles the source itself.
+    #[cfg(not(target_family = "wasm"))]
+    fn compile_source_cached(&self, source: &str) -> Option<Arc<ComputePipeline>> {
+        use super::{CompiledKernel, KernelBinding};
+
+        let cache = self.pipeline_cache.as_ref()?;
+        let kernel = match cache.load(source) {
+            Some(kernel) => kernel,
+            None => {
+                let kernel = CompiledKernel::compile(source)?;
+                cache.save(source, &kernel);
+                kernel
+            }
+        };
+
+        // The layout can't be derived from a SPIR-V module given as is, so it is declared.
+        let entries = kernel
+            .bindings
+            .iter()
+            .map(|(binding, kind)| wgpu::BindGroupLayoutEntry {
+                binding: *binding,
+                visibility: wgpu::ShaderStages::COMPUTE,
+                ty: wgpu::BindingType::Buffer {
+                    ty: match kind {
+                        KernelBinding::StorageReadOnly => {
+                            wgpu::BufferBindingType::Storage { read_only: true }
+                        }
+                        KernelBinding::Storage => {
+                            wgpu::BufferBindingType::Storage { read_only: false }
+                        }
+                        KernelBinding::Uniform => wgpu::BufferBindingType::Uniform,
+                    },
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            })
+            .collect::<Vec<_>>();
+        let group_layout = self
+            .device
+            .create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: None,
+                entries: &entries,
+            });
+        let layout = self
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: None,
+                bind_group_layouts: &[&group_layout],
+                push_constant_ranges: &[],
+            });
+
+        // Safety: the module is generated by naga from a validated source, with the accesses
+        // bounds checked. A cached entry is only loaded when the digests of its source and of its
+        // content match, which rejects corrupted, truncated and colliding entries, but the module
+        // isn't validated again: the cache directory must only be writable by trusted processes.
+        let module = unsafe {
+            self.device
+                .create_shader_module_spirv(&wgpu::ShaderModuleDescriptorSpirV {
+                    label: None,
+                    source: Cow::Borrowed(&kernel.spirv),
+                })
+        };
+
+        Some(Arc::new(self.device.create_compute_pipeline(
+            &wgpu::ComputePipelineDescriptor {
+                label: None,
+                layout: Some(&layout),
+                module: &module,
+                entry_point: "main",
+            },
+        )))
+    }
+
     fn buffer_reader(&mut self, handle: &server::Handle<Self>) -> BufferReader {
         // Register previous tasks before reading the buffer so that it is up to date.
         self.register_tasks();
@@ -196,6 +301,80 @@ where
     }
 }
 
+/// Timestamp queries written at the beginning and the end of a compute pass.
+struct TimestampQuery {
+    query_set: wgpu::QuerySet,
+    resolve_buffer: wgpu::Buffer,
+    destination_buffer: wgpu::Buffer,
+}
+
+impl TimestampQuery {
+    const SIZE: u64 = 2 * core::mem::size_of::<u64>() as u64;
+
+    fn new(device: &wgpu::Device) -> Self {
+        let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("Timestamp Query Set"),
+            ty: wgpu::QueryType::Timestamp,
+            count: 2,
+        });
+        let resolve_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: Self::SIZE,
+            usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let destination_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: Self::SIZE,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            query_set,
+            resolve_buffer,
+            destination_buffer,
+        }
+    }
+
+    fn writes(&self) -> wgpu::ComputePassTimestampWrites {
+        wgpu::ComputePassTimestampWrites {
+            query_set: &self.query_set,
+            beginning_of_pass_write_index: Some(0),
+            end_of_pass_write_index: Some(1),
+        }
+    }
+
+    /// Resolve the queries and copy them into a buffer that can be read.
+    fn resolve(self, encoder: &mut CommandEncoder) -> BufferReader {
+        encoder.resolve_query_set(&self.query_set, 0..2, &self.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &self.destination_buffer,
+            0,
+            Self::SIZE,
+        );
+
+        BufferReader::new(self.destination_buffer)
+    }
+
+    /// Compute the elapsed nanoseconds from the resolved timestamps.
+    fn elapsed_nanos(timestamps: &[u8], period: f32) -> u64 {
+        let timestamp = |index: usize| {
+            let start = index * core::mem::size_of::<u64>();
+            let bytes = timestamps[start..start + core::mem::size_of::<u64>()]
+                .try_into()
+                .unwrap();
+
+            u64::from_ne_bytes(bytes)
+        };
+        let ticks = timestamp(1).saturating_sub(timestamp(0));
+
+        (ticks as f64 * period as f64) as u64
+    }
+}
+
 #[derive(new)]
 struct BufferReader {
     buffer: wgpu::Buffer,
@@ -258,6 +437,68 @@ where
         Reader::Concrete(self.buffer_reader(handle).read(&self.device))
     }
 
+    /// When the device supports [timestamp queries](wgpu::Features::TIMESTAMP_QUERY), the pending
+    /// tasks are timed on the device, otherwise the wall clock is used.
+    fn read_timed(&mut self, handle: &server::Handle<Self>) -> Reader<TimedRead> {
+        if !self
+            .device
+            .features()
+            .contains(wgpu::Features::TIMESTAMP_QUERY)
+        {
+            let start = time::Instant::now();
+
+            #[cfg(target_family = "wasm")]
+            {
+                let future = self.buffer_reader(handle).read(self.device.clone());
+                return Reader::Future(Box::pin(async move {
+                    let data = future.await;
+                    let elapsed_nanos = start.elapsed().as_nanos() as u64;
+
+                    TimedRead::new(data, elapsed_nanos, TimingMethod::WallClock)
+                }));
+            }
+
+            #[cfg(not(target_family = "wasm"))]
+            {
+                let data = self.buffer_reader(handle).read(&self.device);
+                let elapsed_nanos = start.elapsed().as_nanos() as u64;
+
+                return Reader::Concrete(TimedRead::new(
+                    data,
+                    elapsed_nanos,
+                    TimingMethod::WallClock,
+                ));
+            }
+        }
+
+        let timestamp = TimestampQuery::new(&self.device);
+        self.register_tasks_with(Some(&timestamp));
+        let timestamp_reader = timestamp.resolve(&mut self.encoder);
+        let data_reader = self.buffer_reader(handle);
+        let period = self.queue.get_timestamp_period();
+
+        #[cfg(target_family = "wasm")]
+        {
+            let device = self.device.clone();
+            return Reader::Future(Box::pin(async move {
+                let data = data_reader.read(device.clone()).await;
+                let timestamps = timestamp_reader.read(device).await;
+                let elapsed_nanos = TimestampQuery::elapsed_nanos(&timestamps, period);
+
+                TimedRead::new(data, elapsed_nanos, TimingMethod::Device)
+            }));
+        }
+
+        #[cfg(not(target_family = "wasm"))]
+        {
+            let data = data_reader.read(&self.device);
+            let timestamps = timestamp_reader.read(&self.device);
+            let elapsed_nanos = TimestampQuery::elapsed_nanos(&timestamps, period);
+
+            Reader::Concrete(TimedRead::new(data, elapsed_nanos, TimingMethod::Device))
+        }
+    }
+
     /// When we create a new handle from existing data, we use custom allocations so that we don't
     /// have to execute the current pending tasks.
     ///
@@ -331,4 +572,8 @@ where
 
         self.device.poll(wgpu::Maintain::Wait);
     }
+
+    fn memory_stats(&mut self) -> MemoryStats {
+        self.memory_management.memory_stats()
+    }
 }
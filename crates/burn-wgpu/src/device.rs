@@ -9,6 +9,7 @@
 ///
 /// let device_gpu_1 = WgpuDevice::DiscreteGpu(0); // First discrete GPU found.
 /// let device_gpu_2 = WgpuDevice::DiscreteGpu(1);  // Second discrete GPU found.
+/// let device_gpu_3 = WgpuDevice::ByName("RTX 4090".to_string()); // GPU found by name.
 /// ```
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum WgpuDevice {
@@ -27,6 +28,13 @@ pub enum WgpuDevice {
     /// CPU.
     Cpu,
 
+    /// Adapter matching the given name, ignoring case, e.g. `"RTX 4090"`.
+    ///
+    /// An adapter whose name is exactly the given one is preferred, otherwise the first adapter
+    /// whose name contains the given one is used. This is useful on systems with multiple GPUs
+    /// where the ordering of the adapters isn't stable between runs.
+    ByName(String),
+
     /// The best available device found with the current [graphics API](crate::GraphicsApi).
     ///
     /// Priority
@@ -48,3 +56,90 @@ impl Default for WgpuDevice {
         Self::BestAvailable
     }
 }
+
+/// Description of an adapter available on the system.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdapterDescription {
+    /// The name of the adapter.
+    pub name: String,
+    /// The type of the adapter.
+    pub device_type: wgpu::DeviceType,
+    /// The index of the adapter among the adapters of the same type, in enumeration order.
+    pub index: usize,
+    /// The backend used by the adapter.
+    pub backend: wgpu::Backend,
+    /// The PCI id of the vendor of the adapter.
+    pub vendor: u32,
+}
+
+impl AdapterDescription {
+    /// The [device](WgpuDevice) selecting this adapter.
+    ///
+    /// The index-based variant matching the type of the adapter is used, e.g.
+    /// [DiscreteGpu(index)](WgpuDevice::DiscreteGpu). Only the first CPU can be selected without a
+    /// name, so the other CPUs and the [other](wgpu::DeviceType::Other) adapters are selected
+    /// [by name](WgpuDevice::ByName).
+    pub fn device(&self) -> WgpuDevice {
+        match (self.device_type, self.index) {
+            (wgpu::DeviceType::DiscreteGpu, index) => WgpuDevice::DiscreteGpu(index),
+            (wgpu::DeviceType::IntegratedGpu, index) => WgpuDevice::IntegratedGpu(index),
+            (wgpu::DeviceType::VirtualGpu, index) => WgpuDevice::VirtualGpu(index),
+            (wgpu::DeviceType::Cpu, 0) => WgpuDevice::Cpu,
+            _ => WgpuDevice::ByName(self.name.clone()),
+        }
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn new(info: wgpu::AdapterInfo, index: usize) -> Self {
+        Self {
+            name: info.name,
+            device_type: info.device_type,
+            index,
+            backend: info.backend,
+            vendor: info.vendor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn description(device_type: wgpu::DeviceType, index: usize) -> AdapterDescription {
+        AdapterDescription {
+            name: "Adapter".to_string(),
+            device_type,
+            index,
+            backend: wgpu::Backend::Vulkan,
+            vendor: 0,
+        }
+    }
+
+    #[test]
+    fn adapter_description_should_select_the_device_by_index() {
+        assert_eq!(
+            description(wgpu::DeviceType::DiscreteGpu, 1).device(),
+            WgpuDevice::DiscreteGpu(1)
+        );
+        assert_eq!(
+            description(wgpu::DeviceType::IntegratedGpu, 0).device(),
+            WgpuDevice::IntegratedGpu(0)
+        );
+        assert_eq!(
+            description(wgpu::DeviceType::Cpu, 0).device(),
+            WgpuDevice::Cpu
+        );
+    }
+
+    #[test]
+    fn adapter_description_should_select_the_device_by_name_without_index() {
+        assert_eq!(
+            description(wgpu::DeviceType::Cpu, 1).device(),
+            WgpuDevice::ByName("Adapter".to_string())
+        );
+        assert_eq!(
+            description(wgpu::DeviceType::Other, 0).device(),
+            WgpuDevice::ByName("Adapter".to_string())
+        );
+    }
+}
@@ -9,6 +9,15 @@ impl FusionDevice for WgpuDevice {
             WgpuDevice::VirtualGpu(index) => DeviceId::new(2, *index as u32),
             WgpuDevice::Cpu => DeviceId::new(3, 0),
             WgpuDevice::BestAvailable => DeviceId::new(4, 0),
+            WgpuDevice::ByName(name) => DeviceId::new(5, name_id(name)),
         }
     }
 }
+
+fn name_id(name: &str) -> u32 {
+    use core::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    name.hash(&mut hasher);
+    hasher.finish() as u32
+}
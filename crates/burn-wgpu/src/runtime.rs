@@ -1,7 +1,9 @@
+#[cfg(not(target_family = "wasm"))]
+use crate::compute::PipelineCache;
 use crate::{
     compiler::wgsl,
     compute::{WgpuServer, WgpuStorage},
-    FloatElement, GraphicsApi, IntElement, WgpuDevice,
+    AdapterDescription, FloatElement, GraphicsApi, IntElement, WgpuDevice,
 };
 use alloc::sync::Arc;
 use burn_common::stub::RwLock;
@@ -32,6 +34,100 @@ static RUNTIME: ComputeRuntime<WgpuDevice, Server, MutexComputeChannel<Server>>
 
 type Server = WgpuServer<SimpleMemoryManagement<WgpuStorage>>;
 
+/// The compute client used by the [wgpu runtime](WgpuRuntime).
+pub type WgpuComputeClient = ComputeClient<Server, MutexComputeChannel<Server>>;
+
+/// Options used to create the [wgpu device](wgpu::Device) of a compute client.
+#[derive(Clone, Debug, Default)]
+pub struct WgpuSetup {
+    /// The features requested on the device.
+    ///
+    /// Features that aren't supported by the adapter are dropped with a warning.
+    pub features: wgpu::Features,
+    /// The limits requested on the device, the limits of the adapter are used when `None`.
+    pub limits: Option<wgpu::Limits>,
+    /// The maximum number of tasks registered before they are submitted to the device, which
+    /// should be positive.
+    ///
+    /// When `None`, the `BURN_WGPU_MAX_TASKS` environment variable is used if it is set,
+    /// otherwise it defaults to 64.
+    pub max_tasks: Option<usize>,
+    /// The directory where the compiled kernels are cached between processes, no cache is used
+    /// when `None`.
+    ///
+    /// The [SPIRV_SHADER_PASSTHROUGH](wgpu::Features::SPIRV_SHADER_PASSTHROUGH) feature is
+    /// requested with a cache, which is ignored when the adapter doesn't support it. The cached
+    /// modules are given to the driver without being validated again, so the directory should
+    /// only be writable by trusted processes.
+    #[cfg(not(target_family = "wasm"))]
+    pub pipeline_cache_dir: Option<std::path::PathBuf>,
+}
+
+/// The features required by the [pipeline cache](PipelineCache).
+#[cfg(not(target_family = "wasm"))]
+const PIPELINE_CACHE_FEATURES: wgpu::Features = wgpu::Features::SPIRV_SHADER_PASSTHROUGH;
+
+/// The default maximum number of tasks registered before they are submitted to the device.
+const DEFAULT_MAX_TASKS: usize = 64;
+
+/// Error that can happen when initializing a wgpu compute client.
+#[derive(Debug)]
+pub enum WgpuSetupError {
+    /// The maximum number of tasks of the [setup](WgpuSetup) or of the `BURN_WGPU_MAX_TASKS`
+    /// environment variable isn't a positive integer.
+    InvalidMaxTasks(String),
+    /// No adapter matches the name of a [device](WgpuDevice::ByName).
+    AdapterNotFound {
+        /// The name of the device.
+        name: String,
+        /// The names of the available adapters.
+        adapters: Vec<String>,
+    },
+}
+
+impl core::fmt::Display for WgpuSetupError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidMaxTasks(value) => write!(
+                f,
+                "The maximum number of tasks should be a positive integer, got {value:?}"
+            ),
+            Self::AdapterNotFound { name, adapters } => write!(
+                f,
+                "No adapter found with the name {name:?}, available adapters {adapters:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WgpuSetupError {}
+
+impl WgpuSetup {
+    fn max_tasks(&self) -> Result<usize, WgpuSetupError> {
+        if let Some(max_tasks) = self.max_tasks {
+            return match max_tasks > 0 {
+                true => Ok(max_tasks),
+                false => Err(WgpuSetupError::InvalidMaxTasks(max_tasks.to_string())),
+            };
+        }
+
+        match std::env::var("BURN_WGPU_MAX_TASKS") {
+            Ok(value) => match value.parse::<usize>() {
+                Ok(max_tasks) if max_tasks > 0 => Ok(max_tasks),
+                _ => Err(WgpuSetupError::InvalidMaxTasks(value)),
+            },
+            Err(_) => Ok(DEFAULT_MAX_TASKS),
+        }
+    }
+
+    fn max_tasks_or_default(&self) -> usize {
+        self.max_tasks().unwrap_or_else(|err| {
+            log::warn!("{err}, using the default of {DEFAULT_MAX_TASKS} tasks instead");
+            DEFAULT_MAX_TASKS
+        })
+    }
+}
+
 impl<G: GraphicsApi, F: FloatElement, I: IntElement> Runtime for WgpuRuntime<G, F, I> {
     type FullPrecisionRuntime = WgpuRuntime<G, f32, i32>;
     type Compiler = wgsl::WgslCompiler<F, I>;
@@ -42,7 +138,11 @@ impl<G: GraphicsApi, F: FloatElement, I: IntElement> Runtime for WgpuRuntime<G,
 
     fn client(device: &Self::Device) -> ComputeClient<Self::Server, Self::Channel> {
         RUNTIME.client(device, move || {
-            pollster::block_on(create_client::<G>(device))
+            let setup = WgpuSetup::default();
+            let max_tasks = setup.max_tasks_or_default();
+
+            pollster::block_on(create_client::<G>(device, &setup, max_tasks))
+                .unwrap_or_else(|err| panic!("{err}"))
         })
     }
 
@@ -53,34 +153,84 @@ impl<G: GraphicsApi, F: FloatElement, I: IntElement> Runtime for WgpuRuntime<G,
 
 /// Init the client async, necessary for wasm.
 pub async fn init_async<G: GraphicsApi>(device: &WgpuDevice) {
+    init_async_with::<G>(device, WgpuSetup::default())
+        .await
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Init the client async with the given [setup](WgpuSetup), necessary for wasm.
+///
+/// Returns an error when no adapter matches the device.
+pub async fn init_async_with<G: GraphicsApi>(
+    device: &WgpuDevice,
+    setup: WgpuSetup,
+) -> Result<(), WgpuSetupError> {
     let device = Arc::new(device);
-    let client = create_client::<G>(&device).await;
+    let max_tasks = setup.max_tasks_or_default();
+    let client = create_client::<G>(&device, &setup, max_tasks).await?;
+
+    RUNTIME.register(&device, client);
+
+    Ok(())
+}
+
+/// Create the compute client for the given device, requesting the given [features](wgpu::Features).
+///
+/// The client is registered for the device, so every tensor created on that device uses it.
+///
+/// # Panics
+///
+/// If a client is already registered for the given device or if no adapter matches the device.
+pub fn compute_client_with<G: GraphicsApi>(
+    device: &WgpuDevice,
+    features: wgpu::Features,
+) -> WgpuComputeClient {
+    let setup = WgpuSetup {
+        features,
+        ..Default::default()
+    };
+    let max_tasks = Some(setup.max_tasks_or_default());
 
-    RUNTIME.register(&device, client)
+    init_client::<G>(device, WgpuSetup { max_tasks, ..setup }).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Create the compute client for the given device with the given [setup](WgpuSetup).
+///
+/// The client is registered for the device, so every tensor created on that device uses it.
+/// This makes it possible to use different options for different devices in the same process.
+///
+/// Returns an error when the maximum number of tasks isn't a positive integer or when no adapter
+/// matches the device.
+///
+/// # Panics
+///
+/// If a client is already registered for the given device.
+pub fn init_client<G: GraphicsApi>(
+    device: &WgpuDevice,
+    setup: WgpuSetup,
+) -> Result<WgpuComputeClient, WgpuSetupError> {
+    let max_tasks = setup.max_tasks()?;
+    let client = pollster::block_on(create_client::<G>(device, &setup, max_tasks))?;
+
+    RUNTIME.register(device, client.clone());
+
+    Ok(client)
 }
 
 async fn create_client<G: GraphicsApi>(
     device: &WgpuDevice,
-) -> ComputeClient<
-    WgpuServer<SimpleMemoryManagement<WgpuStorage>>,
-    MutexComputeChannel<WgpuServer<SimpleMemoryManagement<WgpuStorage>>>,
-> {
-    let (device_wgpu, queue, info) = select_device::<G>(device).await;
+    setup: &WgpuSetup,
+    max_tasks: usize,
+) -> Result<WgpuComputeClient, WgpuSetupError> {
+    let (device_wgpu, queue, info) = select_device_with::<G>(device, setup).await?;
 
     log::info!(
-        "Created wgpu compute server on device {:?} => {:?}",
+        "Created wgpu compute server on device {:?} => {:?} with features {:?}",
         device,
-        info
+        info,
+        device_wgpu.features(),
     );
 
-    // TODO: Support a way to modify max_tasks without std.
-    let max_tasks = match std::env::var("BURN_WGPU_MAX_TASKS") {
-        Ok(value) => value
-            .parse::<usize>()
-            .expect("BURN_WGPU_MAX_TASKS should be a positive integer."),
-        Err(_) => 64, // 64 tasks by default
-    };
-
     let device = Arc::new(device_wgpu);
     let storage = WgpuStorage::new(device.clone());
     let memory_management = SimpleMemoryManagement::new(
@@ -88,30 +238,100 @@ async fn create_client<G: GraphicsApi>(
         DeallocStrategy::new_period_tick(max_tasks * 2),
         SliceStrategy::Ratio(0.8),
     );
-    let server = WgpuServer::new(memory_management, device, queue, max_tasks);
+    #[cfg(not(target_family = "wasm"))]
+    let pipeline_cache = match &setup.pipeline_cache_dir {
+        Some(directory) if device.features().contains(PIPELINE_CACHE_FEATURES) => {
+            Some(PipelineCache::new(directory, &info))
+        }
+        Some(directory) => {
+            log::warn!(
+                "The pipeline cache {directory:?} is ignored, the device doesn't support {:?}",
+                PIPELINE_CACHE_FEATURES
+            );
+            None
+        }
+        None => None,
+    };
+    let server = WgpuServer::new(
+        memory_management,
+        device,
+        queue,
+        max_tasks,
+        #[cfg(not(target_family = "wasm"))]
+        pipeline_cache,
+    );
     let channel = MutexComputeChannel::new(server);
 
     let tuner_device_id = tuner_device_id(info);
-    ComputeClient::new(channel, Arc::new(RwLock::new(Tuner::new(&tuner_device_id))))
+    Ok(ComputeClient::new(
+        channel,
+        Arc::new(RwLock::new(Tuner::new(&tuner_device_id))),
+    ))
 }
 
 /// Select the wgpu device and queue based on the provided [device](WgpuDevice).
+///
+/// # Panics
+///
+/// If no adapter matches the device.
 pub async fn select_device<G: GraphicsApi>(
     device: &WgpuDevice,
 ) -> (wgpu::Device, wgpu::Queue, wgpu::AdapterInfo) {
+    select_device_with::<G>(device, &WgpuSetup::default())
+        .await
+        .unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// Select the wgpu device and queue based on the provided [device](WgpuDevice), requesting the
+/// features and limits of the given [setup](WgpuSetup).
+///
+/// Returns an error when no adapter matches the [name](WgpuDevice::ByName) of the device.
+pub async fn select_device_with<G: GraphicsApi>(
+    device: &WgpuDevice,
+    setup: &WgpuSetup,
+) -> Result<(wgpu::Device, wgpu::Queue, wgpu::AdapterInfo), WgpuSetupError> {
     #[cfg(target_family = "wasm")]
     let adapter = select_adapter::<G>(device).await;
 
     #[cfg(not(target_family = "wasm"))]
-    let adapter = select_adapter::<G>(device);
+    let adapter = select_adapter::<G>(device)?;
 
-    let limits = adapter.limits();
+    let features = setup.features & adapter.features();
+    let features_dropped = setup.features - features;
+
+    // The pipeline cache is ignored with a warning when its features aren't supported.
+    #[cfg(not(target_family = "wasm"))]
+    let features = match setup.pipeline_cache_dir {
+        Some(_) => features | (PIPELINE_CACHE_FEATURES & adapter.features()),
+        None => features,
+    };
+
+    if !features_dropped.is_empty() {
+        log::warn!(
+            "Features {:?} aren't supported by the adapter {:?} and are ignored",
+            features_dropped,
+            adapter.get_info(),
+        );
+    }
+
+    let limits = match &setup.limits {
+        Some(limits) if limits.check_limits(&adapter.limits()) => limits.clone(),
+        Some(limits) => {
+            log::warn!(
+                "Limits {:?} aren't supported by the adapter {:?}, using the adapter limits instead",
+                limits,
+                adapter.get_info(),
+            );
+            adapter.limits()
+        }
+        None => adapter.limits(),
+    };
 
     let (device, queue) = adapter
         .request_device(
             &DeviceDescriptor {
                 label: None,
-                features: wgpu::Features::empty(),
+                features,
                 limits,
             },
             None,
@@ -126,7 +346,7 @@ pub async fn select_device<G: GraphicsApi>(
         })
         .unwrap();
 
-    (device, queue, adapter.get_info())
+    Ok((device, queue, adapter.get_info()))
 }
 
 fn tuner_device_id(info: AdapterInfo) -> String {
@@ -143,36 +363,66 @@ async fn select_adapter<G: GraphicsApi>(_device: &WgpuDevice) -> wgpu::Adapter {
         .unwrap()
 }
 
+/// List the adapters available with the given [graphics API](GraphicsApi).
+///
+/// The [device](AdapterDescription::device) of each description can be used to select the
+/// matching adapter, e.g. after presenting the list to the user.
 #[cfg(not(target_family = "wasm"))]
-fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
-    use wgpu::DeviceType;
+pub fn list_adapters<G: GraphicsApi>() -> Vec<AdapterDescription> {
+    let infos = enumerate_adapters::<G>()
+        .into_iter()
+        .map(|adapter| adapter.get_info())
+        .collect::<Vec<_>>();
+
+    infos
+        .iter()
+        .enumerate()
+        .map(|(position, info)| {
+            let index = infos[..position]
+                .iter()
+                .filter(|other| other.device_type == info.device_type)
+                .count();
+
+            AdapterDescription::new(info.clone(), index)
+        })
+        .collect()
+}
 
+#[cfg(not(target_family = "wasm"))]
+fn enumerate_adapters<G: GraphicsApi>() -> Vec<wgpu::Adapter> {
     let instance = wgpu::Instance::default();
+
+    instance.enumerate_adapters(G::backend().into()).collect()
+}
+
+#[cfg(not(target_family = "wasm"))]
+fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> Result<wgpu::Adapter, WgpuSetupError> {
+    use wgpu::DeviceType;
+
     let mut adapters_other = Vec::new();
     let mut adapters = Vec::new();
 
-    instance
-        .enumerate_adapters(G::backend().into())
-        .for_each(|adapter| {
-            let device_type = adapter.get_info().device_type;
-
-            if let DeviceType::Other = device_type {
-                adapters_other.push(adapter);
-                return;
-            }
+    enumerate_adapters::<G>().into_iter().for_each(|adapter| {
+        let device_type = adapter.get_info().device_type;
 
-            let is_same_type = match device {
-                WgpuDevice::DiscreteGpu(_) => device_type == DeviceType::DiscreteGpu,
-                WgpuDevice::IntegratedGpu(_) => device_type == DeviceType::IntegratedGpu,
-                WgpuDevice::VirtualGpu(_) => device_type == DeviceType::VirtualGpu,
-                WgpuDevice::Cpu => device_type == DeviceType::Cpu,
-                WgpuDevice::BestAvailable => true,
-            };
+        if let DeviceType::Other = device_type {
+            adapters_other.push(adapter);
+            return;
+        }
 
-            if is_same_type {
-                adapters.push(adapter);
-            }
-        });
+        let is_same_type = match device {
+            WgpuDevice::DiscreteGpu(_) => device_type == DeviceType::DiscreteGpu,
+            WgpuDevice::IntegratedGpu(_) => device_type == DeviceType::IntegratedGpu,
+            WgpuDevice::VirtualGpu(_) => device_type == DeviceType::VirtualGpu,
+            WgpuDevice::Cpu => device_type == DeviceType::Cpu,
+            WgpuDevice::BestAvailable => true,
+            WgpuDevice::ByName(_) => true,
+        };
+
+        if is_same_type {
+            adapters.push(adapter);
+        }
+    });
 
     fn select(
         num: usize,
@@ -202,6 +452,44 @@ fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
         adapters.remove(num)
     }
 
+    fn select_by_name(
+        name: &str,
+        mut adapters: Vec<wgpu::Adapter>,
+        mut adapters_other: Vec<wgpu::Adapter>,
+    ) -> Result<wgpu::Adapter, WgpuSetupError> {
+        let name_lowercase = name.to_lowercase();
+        let position = |adapters: &[wgpu::Adapter], exact: bool| {
+            adapters.iter().position(|adapter| {
+                let adapter_name = adapter.get_info().name.to_lowercase();
+
+                match exact {
+                    true => adapter_name == name_lowercase,
+                    false => adapter_name.contains(&name_lowercase),
+                }
+            })
+        };
+
+        // Exact matches are preferred over substring matches.
+        for exact in [true, false] {
+            if let Some(index) = position(&adapters, exact) {
+                return Ok(adapters.remove(index));
+            }
+
+            if let Some(index) = position(&adapters_other, exact) {
+                return Ok(adapters_other.remove(index));
+            }
+        }
+
+        Err(WgpuSetupError::AdapterNotFound {
+            name: name.to_string(),
+            adapters: adapters
+                .iter()
+                .chain(adapters_other.iter())
+                .map(|adapter| adapter.get_info().name)
+                .collect(),
+        })
+    }
+
     let adapter = match device {
         WgpuDevice::DiscreteGpu(num) => select(
             *num,
@@ -222,6 +510,7 @@ fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
             adapters_other,
         ),
         WgpuDevice::Cpu => select(0, "No CPU device found", adapters, adapters_other),
+        WgpuDevice::ByName(name) => select_by_name(name, adapters, adapters_other)?,
         WgpuDevice::BestAvailable => {
             let mut most_performant_adapter = None;
             let mut current_score = -1;
@@ -256,5 +545,61 @@ fn select_adapter<G: GraphicsApi>(device: &WgpuDevice) -> wgpu::Adapter {
 
     log::info!("Using adapter {:?}", adapter.get_info());
 
-    adapter
+    Ok(adapter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AutoGraphicsApi;
+
+    #[test]
+    fn max_tasks_from_setup_takes_precedence() {
+        let setup = WgpuSetup {
+            max_tasks: Some(8),
+            ..Default::default()
+        };
+
+        assert_eq!(setup.max_tasks().unwrap(), 8);
+    }
+
+    #[test]
+    fn max_tasks_from_setup_should_be_positive() {
+        let setup = WgpuSetup {
+            max_tasks: Some(0),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            setup.max_tasks(),
+            Err(WgpuSetupError::InvalidMaxTasks(_))
+        ));
+    }
+
+    #[test]
+    fn clients_should_use_the_max_tasks_of_their_setup() {
+        // Two devices selecting the same adapter, which aren't used by the other tests.
+        let name = list_adapters::<AutoGraphicsApi>()
+            .first()
+            .expect("An adapter should be available")
+            .name
+            .clone();
+        let device_1 = WgpuDevice::ByName(name.to_lowercase());
+        let device_2 = WgpuDevice::ByName(name.to_uppercase());
+
+        let init = |device: &WgpuDevice, max_tasks: usize| {
+            let setup = WgpuSetup {
+                max_tasks: Some(max_tasks),
+                ..Default::default()
+            };
+            init_client::<AutoGraphicsApi>(device, setup).unwrap()
+        };
+        let client_1 = init(&device_1, 3);
+        let client_2 = init(&device_2, 17);
+
+        // The server isn't reachable through the client, but its fields are part of the debug
+        // output of the client.
+        assert!(format!("{client_1:?}").contains("max_tasks: 3,"));
+        assert!(format!("{client_2:?}").contains("max_tasks: 17,"));
+    }
 }